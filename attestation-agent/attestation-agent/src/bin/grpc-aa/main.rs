@@ -6,13 +6,15 @@
 mod server;
 
 use anyhow::*;
-use attestation_agent::{initdata::Initdata, AttestationAPIs, AttestationAgent};
+use attestation_agent::{
+    config::watcher::ConfigWatcher, initdata::Initdata, AttestationAPIs, AttestationAgent,
+};
 use base64::Engine;
 use clap::Parser;
-use log::{debug, info};
+use log::{debug, info, warn};
 use tokio::signal::unix::{signal, SignalKind};
 
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::Arc};
 
 const DEFAULT_ATTESTATION_AGENT_ADDR: &str = "127.0.0.1:50002";
 
@@ -117,7 +119,6 @@ pub async fn main() -> Result<()> {
     // Start heartbeat task if enabled
     #[cfg(feature = "instance_info")]
     let heartbeat_task = if _heartbeat_enabled {
-        use log::warn;
         let config_file = cli.config_file.clone();
         Some(tokio::spawn(async move {
             use attestation_agent::instance_info::InstanceHeartbeat;
@@ -144,12 +145,32 @@ pub async fn main() -> Result<()> {
         None
     };
 
+    let aa = Arc::new(aa);
+    let mut config_watcher = ConfigWatcher::new(cli.config_file.as_deref())?;
+    let server = server::start_grpc_service(attestation_socket, aa.clone());
+    tokio::pin!(server);
+
     let mut interrupt = signal(SignalKind::interrupt())?;
     let mut hangup = signal(SignalKind::hangup())?;
-    tokio::select! {
-        _ = hangup.recv() => info!("Client terminal disconnected."),
-        _ = interrupt.recv() => info!("SIGINT received, gracefully shutdown."),
-        _ = server::start_grpc_service(attestation_socket, aa) => info!("AA exits."),
+    loop {
+        tokio::select! {
+            _ = hangup.recv() => {
+                info!("SIGHUP received, reload config.");
+                reload_config(&aa).await;
+            }
+            _ = config_watcher.changed() => {
+                info!("Config file changed, reload config.");
+                reload_config(&aa).await;
+            }
+            _ = interrupt.recv() => {
+                info!("SIGINT received, gracefully shutdown.");
+                break;
+            }
+            _ = &mut server => {
+                info!("AA exits.");
+                break;
+            }
+        }
     }
 
     // Cancel heartbeat task if it was started
@@ -160,3 +181,9 @@ pub async fn main() -> Result<()> {
 
     Ok(())
 }
+
+async fn reload_config(aa: &AttestationAgent) {
+    if let Err(e) = aa.reload_config().await {
+        warn!("Failed to reload config, keep the running one: {e:#}");
+    }
+}
@@ -8,13 +8,17 @@ use attestation::attestation_agent_service_server::{
     AttestationAgentService, AttestationAgentServiceServer,
 };
 use attestation::{
-    BindInitDataRequest, BindInitDataResponse, ExtendRuntimeMeasurementRequest,
-    ExtendRuntimeMeasurementResponse, GetAdditionalEvidenceRequest, GetEvidenceRequest,
-    GetEvidenceResponse, GetTeeTypeRequest, GetTeeTypeResponse, GetTokenRequest, GetTokenResponse,
+    BindInitDataRequest, BindInitDataResponse, DeviceEvidence, EventLogEntry,
+    ExtendRuntimeMeasurementRequest, ExtendRuntimeMeasurementResponse,
+    GetAdditionalEvidenceRequest, GetCompositeEvidenceRequest, GetCompositeEvidenceResponse,
+    GetEventLogRequest, GetEventLogResponse, GetEvidenceRequest, GetEvidenceResponse,
+    GetTeeTypeRequest, GetTeeTypeResponse, GetTokenRequest, GetTokenResponse, ReloadConfigRequest,
+    ReloadConfigResponse, VerifyEventLogRequest, VerifyEventLogResponse,
 };
 use attestation_agent::{AttestationAPIs, AttestationAgent};
+use kbs_types::Tee;
 use log::{debug, error};
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::Arc};
 use tonic::{transport::Server, Request, Response, Status};
 
 mod attestation {
@@ -24,7 +28,7 @@ mod attestation {
 pub const AGENT_NAME: &str = "attestation-agent";
 
 pub struct AA {
-    inner: AttestationAgent,
+    inner: Arc<AttestationAgent>,
 }
 
 #[tonic::async_trait]
@@ -103,6 +107,52 @@ impl AttestationAgentService for AA {
         Result::Ok(Response::new(reply))
     }
 
+    async fn get_composite_evidence(
+        &self,
+        request: Request<GetCompositeEvidenceRequest>,
+    ) -> Result<Response<GetCompositeEvidenceResponse>, Status> {
+        let request = request.into_inner();
+
+        debug!("AA (grpc): get composite evidence ...");
+
+        let evidence = self
+            .inner
+            .get_composite_evidence(&request.runtime_data)
+            .await
+            .map_err(|e| {
+                error!("AA (grpc): get composite evidence failed:\n{e:?}");
+                Status::internal(format!(
+                    "[ERROR:{AGENT_NAME}] AA get composite evidence failed"
+                ))
+            })?;
+
+        debug!("AA (grpc): get composite evidence succeeded.");
+
+        let reply = GetCompositeEvidenceResponse {
+            primary_tee: tee_name(evidence.primary_tee),
+            primary_evidence: evidence.primary_evidence,
+            additional_evidence: evidence
+                .additional_evidence
+                .into_iter()
+                .map(|(tee, res)| {
+                    let device = match res {
+                        Result::Ok(evidence) => DeviceEvidence {
+                            evidence,
+                            error: String::new(),
+                        },
+                        Err(error) => DeviceEvidence {
+                            evidence: vec![],
+                            error,
+                        },
+                    };
+                    (tee_name(tee), device)
+                })
+                .collect(),
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+
     async fn extend_runtime_measurement(
         &self,
         request: Request<ExtendRuntimeMeasurementRequest>,
@@ -178,9 +228,102 @@ impl AttestationAgentService for AA {
 
         Result::Ok(Response::new(reply))
     }
+
+    async fn get_event_log(
+        &self,
+        request: Request<GetEventLogRequest>,
+    ) -> Result<Response<GetEventLogResponse>, Status> {
+        let request = request.into_inner();
+
+        debug!("AA (grpc): get event log ...");
+
+        let entries = self
+            .inner
+            .get_event_log(request.domain.as_deref(), request.register_index)
+            .await
+            .map_err(|e| {
+                error!("AA (grpc): get event log failed:\n{e:?}");
+                Status::internal(format!("[ERROR:{AGENT_NAME}] AA get event log failed"))
+            })?;
+
+        debug!("AA (grpc): get event log succeeded.");
+
+        let reply = match request.raw {
+            true => GetEventLogResponse {
+                entries: vec![],
+                raw: entries.into_iter().flat_map(|entry| entry.raw).collect(),
+            },
+            false => GetEventLogResponse {
+                entries: entries
+                    .into_iter()
+                    .map(|entry| EventLogEntry {
+                        register_index: entry.register_index,
+                        domain: entry.domain,
+                        operation: entry.operation,
+                        content: entry.content,
+                        digests: entry.digests.into_iter().collect(),
+                    })
+                    .collect(),
+                raw: vec![],
+            },
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+
+    async fn verify_event_log(
+        &self,
+        _request: Request<VerifyEventLogRequest>,
+    ) -> Result<Response<VerifyEventLogResponse>, Status> {
+        debug!("AA (grpc): verify event log ...");
+
+        let reply = match self.inner.verify_event_log().await {
+            Result::Ok(()) => VerifyEventLogResponse {
+                consistent: true,
+                detail: String::new(),
+            },
+            Err(e) => {
+                error!("AA (grpc): verify event log failed:\n{e:?}");
+                VerifyEventLogResponse {
+                    consistent: false,
+                    detail: format!("{e:#}"),
+                }
+            }
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+
+    async fn reload_config(
+        &self,
+        _request: Request<ReloadConfigRequest>,
+    ) -> Result<Response<ReloadConfigResponse>, Status> {
+        debug!("AA (grpc): reload config ...");
+
+        self.inner.reload_config().await.map_err(|e| {
+            error!("AA (grpc): reload config failed:\n{e:?}");
+            Status::invalid_argument(format!(
+                "[ERROR:{AGENT_NAME}] AA reload config failed: {e:#}"
+            ))
+        })?;
+
+        debug!("AA (grpc): reload config succeeded.");
+
+        let reply = ReloadConfigResponse {};
+
+        Result::Ok(Response::new(reply))
+    }
+}
+
+/// The name of `tee` as used in the other APIs, e.g. `tdx`.
+fn tee_name(tee: Tee) -> String {
+    serde_json::to_string(&tee)
+        .unwrap_or_default()
+        .trim_matches('"')
+        .to_string()
 }
 
-pub async fn start_grpc_service(socket: SocketAddr, aa: AttestationAgent) -> Result<()> {
+pub async fn start_grpc_service(socket: SocketAddr, aa: Arc<AttestationAgent>) -> Result<()> {
     let service = AA { inner: aa };
     Server::builder()
         .add_service(AttestationAgentServiceServer::new(service))
@@ -11,7 +11,9 @@ use std::env;
 use ttrpc::context;
 use ttrpc_dep::ttrpc_protocol::{
     attestation_agent::{
-        ExtendRuntimeMeasurementRequest, GetEvidenceRequest, GetTeeTypeRequest, GetTokenRequest,
+        ExtendRuntimeMeasurementRequest, GetCompositeEvidenceRequest, GetEventLogRequest,
+        GetEvidenceRequest, GetTeeTypeRequest, GetTokenRequest, ReloadConfigRequest,
+        VerifyEventLogRequest,
     },
     attestation_agent_ttrpc::AttestationAgentServiceClient,
 };
@@ -69,11 +71,23 @@ enum Operation {
     /// Get parsed evidence
     GetParsedEvidence(GetEvidenceArgs),
 
+    /// Get evidence of the primary TEE and all additional devices
+    GetCompositeEvidence(GetEvidenceArgs),
+
     /// Get attestation token
     GetToken(GetTokenArgs),
 
     /// Extend runtime measurement
     ExtendRuntimeMeasurement(ExtendRuntimeMeasurementArgs),
+
+    /// Get eventlog entries
+    GetEventLog(GetEventLogArgs),
+
+    /// Replay the eventlog and check it against the measurement registers
+    VerifyEventLog,
+
+    /// Reload the token configs from the config file of AA
+    ReloadConfig,
 }
 
 #[derive(Args)]
@@ -116,6 +130,22 @@ struct ExtendRuntimeMeasurementArgs {
     pcr: Option<u64>,
 }
 
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct GetEventLogArgs {
+    /// only get the entries of this domain
+    #[arg(short, long)]
+    domain: Option<String>,
+
+    /// only get the entries extended to this PCR index
+    #[arg(short, long)]
+    pcr: Option<u64>,
+
+    /// print the base64 encoded TCG2 event entries
+    #[arg(short, long)]
+    raw: bool,
+}
+
 #[tokio::main]
 pub async fn main() {
     let args = Cli::parse();
@@ -174,6 +204,30 @@ pub async fn main() {
                 parse_evidence(tee_type_res.tee, evidence).expect("parse evidence")
             );
         }
+        Operation::GetCompositeEvidence(args) => {
+            let runtime_data = base64::engine::general_purpose::STANDARD
+                .decode(args.runtime_data)
+                .unwrap();
+            let req = GetCompositeEvidenceRequest {
+                RuntimeData: runtime_data,
+                ..Default::default()
+            };
+            let res = client
+                .get_composite_evidence(context::with_timeout(timeout), &req)
+                .await
+                .expect("request to AA");
+            println!(
+                "{}: {}",
+                res.PrimaryTee,
+                String::from_utf8_lossy(&res.PrimaryEvidence)
+            );
+            for (tee, device) in res.AdditionalEvidence {
+                match device.Error.is_empty() {
+                    true => println!("{tee}: {}", String::from_utf8_lossy(&device.Evidence)),
+                    false => println!("{tee}: [ERROR] {}", device.Error),
+                }
+            }
+        }
         Operation::GetToken(get_token_args) => {
             let req = GetTokenRequest {
                 TokenType: get_token_args.token_type,
@@ -202,5 +256,58 @@ pub async fn main() {
                 .expect("request to AA");
             println!("Extended.");
         }
+        Operation::GetEventLog(get_event_log_args) => {
+            let req = GetEventLogRequest {
+                Domain: get_event_log_args.domain,
+                RegisterIndex: get_event_log_args.pcr,
+                Raw: get_event_log_args.raw,
+                ..Default::default()
+            };
+
+            let res = client
+                .get_event_log(context::with_timeout(timeout), &req)
+                .await
+                .expect("request to AA");
+            if get_event_log_args.raw {
+                println!(
+                    "{}",
+                    base64::engine::general_purpose::STANDARD.encode(res.Raw)
+                );
+                return;
+            }
+
+            for entry in res.Entries {
+                println!(
+                    "{} {} {} {}",
+                    entry.RegisterIndex, entry.Domain, entry.Operation, entry.Content
+                );
+            }
+        }
+        Operation::VerifyEventLog => {
+            let req = VerifyEventLogRequest {
+                ..Default::default()
+            };
+
+            let res = client
+                .verify_event_log(context::with_timeout(timeout), &req)
+                .await
+                .expect("request to AA");
+            if !res.Consistent {
+                eprintln!("Eventlog does not match: {}", res.Detail);
+                std::process::exit(1);
+            }
+            println!("Eventlog verified.");
+        }
+        Operation::ReloadConfig => {
+            let req = ReloadConfigRequest {
+                ..Default::default()
+            };
+
+            client
+                .reload_config(context::with_timeout(timeout), &req)
+                .await
+                .expect("request to AA");
+            println!("Config reloaded.");
+        }
     }
 }
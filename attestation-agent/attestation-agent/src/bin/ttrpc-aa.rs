@@ -4,11 +4,13 @@
 //
 
 use anyhow::*;
-use attestation_agent::{initdata::Initdata, AttestationAPIs, AttestationAgent};
+use attestation_agent::{
+    config::watcher::ConfigWatcher, initdata::Initdata, AttestationAPIs, AttestationAgent,
+};
 use base64::Engine;
 use clap::{arg, command, Parser};
 use const_format::concatcp;
-use log::{debug, info};
+use log::{debug, info, warn};
 use std::{collections::HashMap, path::Path, sync::Arc};
 use tokio::signal::unix::{signal, SignalKind};
 use ttrpc::asynchronous::{Server, Service};
@@ -74,7 +76,7 @@ struct Cli {
     initdata_toml: Option<String>,
 }
 
-pub fn start_ttrpc_service(aa: AttestationAgent) -> Result<HashMap<String, Service>> {
+pub fn start_ttrpc_service(aa: Arc<AttestationAgent>) -> Result<HashMap<String, Service>> {
     let service = AA { inner: aa };
     let service = Arc::new(service);
     let get_resource_service = create_attestation_agent_service(service);
@@ -138,7 +140,9 @@ pub async fn main() -> Result<()> {
     #[cfg(feature = "instance_info")]
     std::fs::create_dir_all(std::path::Path::new("/run/attestation-agent/instance_info"))?;
 
-    let att = start_ttrpc_service(aa)?;
+    let aa = Arc::new(aa);
+    let mut config_watcher = ConfigWatcher::new(cli.config_file.as_deref())?;
+    let att = start_ttrpc_service(aa.clone())?;
 
     let mut atts = Server::new()
         .bind(&cli.attestation_sock)
@@ -157,7 +161,6 @@ pub async fn main() -> Result<()> {
         let config_file = cli.config_file.clone();
         Some(tokio::spawn(async move {
             use attestation_agent::instance_info::InstanceHeartbeat;
-            use log::warn;
             use tokio::time::{interval, Duration};
             let heartbeat = match InstanceHeartbeat::new_from_config_path(config_file.as_deref()) {
                 Result::Ok(h) => h,
@@ -183,16 +186,23 @@ pub async fn main() -> Result<()> {
 
     let mut interrupt = signal(SignalKind::interrupt())?;
     let mut hangup = signal(SignalKind::hangup())?;
-    tokio::select! {
-        _ = hangup.recv() => {
-            info!("Client terminal disconnected.");
-            atts.shutdown().await?;
-        }
-        _ = interrupt.recv() => {
-            info!("SIGINT received, gracefully shutdown.");
-            atts.shutdown().await?;
+    loop {
+        tokio::select! {
+            _ = hangup.recv() => {
+                info!("SIGHUP received, reload config.");
+                reload_config(&aa).await;
+            }
+            _ = config_watcher.changed() => {
+                info!("Config file changed, reload config.");
+                reload_config(&aa).await;
+            }
+            _ = interrupt.recv() => {
+                info!("SIGINT received, gracefully shutdown.");
+                atts.shutdown().await?;
+                break;
+            }
         }
-    };
+    }
 
     // Cancel heartbeat task if it was started
     #[cfg(feature = "instance_info")]
@@ -203,6 +213,12 @@ pub async fn main() -> Result<()> {
     Ok(())
 }
 
+async fn reload_config(aa: &AttestationAgent) {
+    if let Err(e) = aa.reload_config().await {
+        warn!("Failed to reload config, keep the running one: {e:#}");
+    }
+}
+
 fn clean_previous_sock_file(unix_socket: &str) -> Result<()> {
     let path = unix_socket
         .strip_prefix(UNIX_SOCKET_PREFIX)
@@ -6,13 +6,18 @@
 use ::ttrpc::proto::Code;
 use async_trait::async_trait;
 use attestation_agent::{AttestationAPIs, AttestationAgent};
+use kbs_types::Tee;
 use log::{debug, error};
+use std::sync::Arc;
 
 use crate::ttrpc_dep::ttrpc_protocol::{
     attestation_agent::{
-        ExtendRuntimeMeasurementRequest, ExtendRuntimeMeasurementResponse,
-        GetAdditionalEvidenceRequest, GetEvidenceRequest, GetEvidenceResponse, GetTeeTypeRequest,
-        GetTeeTypeResponse, GetTokenRequest, GetTokenResponse,
+        DeviceEvidence, EventLogEntry, ExtendRuntimeMeasurementRequest,
+        ExtendRuntimeMeasurementResponse, GetAdditionalEvidenceRequest,
+        GetCompositeEvidenceRequest, GetCompositeEvidenceResponse, GetEventLogRequest,
+        GetEventLogResponse, GetEvidenceRequest, GetEvidenceResponse, GetTeeTypeRequest,
+        GetTeeTypeResponse, GetTokenRequest, GetTokenResponse, ReloadConfigRequest,
+        ReloadConfigResponse, VerifyEventLogRequest, VerifyEventLogResponse,
     },
     attestation_agent_ttrpc::AttestationAgentService,
 };
@@ -20,7 +25,7 @@ use crate::ttrpc_dep::ttrpc_protocol::{
 pub const AGENT_NAME: &str = "attestation-agent";
 
 pub struct AA {
-    pub(crate) inner: AttestationAgent,
+    pub(crate) inner: Arc<AttestationAgent>,
 }
 
 #[async_trait]
@@ -108,6 +113,44 @@ impl AttestationAgentService for AA {
         ::ttrpc::Result::Ok(reply)
     }
 
+    async fn get_composite_evidence(
+        &self,
+        _ctx: &::ttrpc::r#async::TtrpcContext,
+        req: GetCompositeEvidenceRequest,
+    ) -> ::ttrpc::Result<GetCompositeEvidenceResponse> {
+        debug!("AA (ttrpc): get composite evidence ...");
+
+        let evidence = self
+            .inner
+            .get_composite_evidence(&req.RuntimeData)
+            .await
+            .map_err(|e| {
+                error!("AA (ttrpc): get composite evidence failed:\n {e:?}");
+                let mut error_status = ::ttrpc::proto::Status::new();
+                error_status.set_code(Code::INTERNAL);
+                error_status.set_message(format!(
+                    "[ERROR:{AGENT_NAME}] AA get composite evidence failed"
+                ));
+                ::ttrpc::Error::RpcStatus(error_status)
+            })?;
+
+        debug!("AA (ttrpc): get composite evidence succeeded.");
+
+        let mut reply = GetCompositeEvidenceResponse::new();
+        reply.PrimaryTee = tee_name(evidence.primary_tee);
+        reply.PrimaryEvidence = evidence.primary_evidence;
+        for (tee, res) in evidence.additional_evidence {
+            let mut device = DeviceEvidence::new();
+            match res {
+                Ok(evidence) => device.Evidence = evidence,
+                Err(e) => device.Error = e,
+            }
+            reply.AdditionalEvidence.insert(tee_name(tee), device);
+        }
+
+        ::ttrpc::Result::Ok(reply)
+    }
+
     async fn extend_runtime_measurement(
         &self,
         _ctx: &::ttrpc::r#async::TtrpcContext,
@@ -164,4 +207,94 @@ impl AttestationAgentService for AA {
         reply.tee = res;
         ::ttrpc::Result::Ok(reply)
     }
+
+    async fn get_event_log(
+        &self,
+        _ctx: &::ttrpc::r#async::TtrpcContext,
+        req: GetEventLogRequest,
+    ) -> ::ttrpc::Result<GetEventLogResponse> {
+        debug!("AA (ttrpc): get event log ...");
+
+        let entries = self
+            .inner
+            .get_event_log(req.Domain.as_deref(), req.RegisterIndex)
+            .await
+            .map_err(|e| {
+                error!("AA (ttrpc): get event log failed:\n {e:?}");
+                let mut error_status = ::ttrpc::proto::Status::new();
+                error_status.set_code(Code::INTERNAL);
+                error_status.set_message(format!("[ERROR:{AGENT_NAME}] AA get event log failed"));
+                ::ttrpc::Error::RpcStatus(error_status)
+            })?;
+
+        debug!("AA (ttrpc): get event log succeeded.");
+        let mut reply = GetEventLogResponse::new();
+        if req.Raw {
+            reply.Raw = entries.into_iter().flat_map(|entry| entry.raw).collect();
+        } else {
+            reply.Entries = entries
+                .into_iter()
+                .map(|entry| {
+                    let mut reply_entry = EventLogEntry::new();
+                    reply_entry.RegisterIndex = entry.register_index;
+                    reply_entry.Domain = entry.domain;
+                    reply_entry.Operation = entry.operation;
+                    reply_entry.Content = entry.content;
+                    reply_entry.Digests = entry.digests.into_iter().collect();
+                    reply_entry
+                })
+                .collect();
+        }
+
+        ::ttrpc::Result::Ok(reply)
+    }
+
+    async fn verify_event_log(
+        &self,
+        _ctx: &::ttrpc::r#async::TtrpcContext,
+        _req: VerifyEventLogRequest,
+    ) -> ::ttrpc::Result<VerifyEventLogResponse> {
+        debug!("AA (ttrpc): verify event log ...");
+
+        let mut reply = VerifyEventLogResponse::new();
+        match self.inner.verify_event_log().await {
+            Ok(()) => reply.Consistent = true,
+            Err(e) => {
+                error!("AA (ttrpc): verify event log failed:\n {e:?}");
+                reply.Detail = format!("{e:#}");
+            }
+        }
+
+        ::ttrpc::Result::Ok(reply)
+    }
+
+    async fn reload_config(
+        &self,
+        _ctx: &::ttrpc::r#async::TtrpcContext,
+        _req: ReloadConfigRequest,
+    ) -> ::ttrpc::Result<ReloadConfigResponse> {
+        debug!("AA (ttrpc): reload config ...");
+
+        self.inner.reload_config().await.map_err(|e| {
+            error!("AA (ttrpc): reload config failed:\n {e:?}");
+            let mut error_status = ::ttrpc::proto::Status::new();
+            error_status.set_code(Code::INVALID_ARGUMENT);
+            error_status.set_message(format!(
+                "[ERROR:{AGENT_NAME}] AA reload config failed: {e:#}"
+            ));
+            ::ttrpc::Error::RpcStatus(error_status)
+        })?;
+
+        debug!("AA (ttrpc): reload config succeeded.");
+        let reply = ReloadConfigResponse::new();
+        ::ttrpc::Result::Ok(reply)
+    }
+}
+
+/// The name of `tee` as used in the other APIs, e.g. `tdx`.
+fn tee_name(tee: Tee) -> String {
+    serde_json::to_string(&tee)
+        .unwrap_or_default()
+        .trim_matches('"')
+        .to_string()
 }
@@ -41,6 +41,11 @@ impl AttestationAgentServiceClient {
         ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "GetAdditionalEvidence", cres);
     }
 
+    pub async fn get_composite_evidence(&self, ctx: ttrpc::context::Context, req: &super::attestation_agent::GetCompositeEvidenceRequest) -> ::ttrpc::Result<super::attestation_agent::GetCompositeEvidenceResponse> {
+        let mut cres = super::attestation_agent::GetCompositeEvidenceResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "GetCompositeEvidence", cres);
+    }
+
     pub async fn get_token(&self, ctx: ttrpc::context::Context, req: &super::attestation_agent::GetTokenRequest) -> ::ttrpc::Result<super::attestation_agent::GetTokenResponse> {
         let mut cres = super::attestation_agent::GetTokenResponse::new();
         ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "GetToken", cres);
@@ -60,6 +65,21 @@ impl AttestationAgentServiceClient {
         let mut cres = super::attestation_agent::GetTeeTypeResponse::new();
         ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "GetTeeType", cres);
     }
+
+    pub async fn get_event_log(&self, ctx: ttrpc::context::Context, req: &super::attestation_agent::GetEventLogRequest) -> ::ttrpc::Result<super::attestation_agent::GetEventLogResponse> {
+        let mut cres = super::attestation_agent::GetEventLogResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "GetEventLog", cres);
+    }
+
+    pub async fn verify_event_log(&self, ctx: ttrpc::context::Context, req: &super::attestation_agent::VerifyEventLogRequest) -> ::ttrpc::Result<super::attestation_agent::VerifyEventLogResponse> {
+        let mut cres = super::attestation_agent::VerifyEventLogResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "VerifyEventLog", cres);
+    }
+
+    pub async fn reload_config(&self, ctx: ttrpc::context::Context, req: &super::attestation_agent::ReloadConfigRequest) -> ::ttrpc::Result<super::attestation_agent::ReloadConfigResponse> {
+        let mut cres = super::attestation_agent::ReloadConfigResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "attestation_agent.AttestationAgentService", "ReloadConfig", cres);
+    }
 }
 
 struct GetEvidenceMethod {
@@ -84,6 +104,17 @@ impl ::ttrpc::r#async::MethodHandler for GetAdditionalEvidenceMethod {
     }
 }
 
+struct GetCompositeEvidenceMethod {
+    service: Arc<dyn AttestationAgentService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for GetCompositeEvidenceMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, attestation_agent, GetCompositeEvidenceRequest, get_composite_evidence);
+    }
+}
+
 struct GetTokenMethod {
     service: Arc<dyn AttestationAgentService + Send + Sync>,
 }
@@ -128,6 +159,39 @@ impl ::ttrpc::r#async::MethodHandler for GetTeeTypeMethod {
     }
 }
 
+struct GetEventLogMethod {
+    service: Arc<dyn AttestationAgentService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for GetEventLogMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, attestation_agent, GetEventLogRequest, get_event_log);
+    }
+}
+
+struct VerifyEventLogMethod {
+    service: Arc<dyn AttestationAgentService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for VerifyEventLogMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, attestation_agent, VerifyEventLogRequest, verify_event_log);
+    }
+}
+
+struct ReloadConfigMethod {
+    service: Arc<dyn AttestationAgentService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for ReloadConfigMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, attestation_agent, ReloadConfigRequest, reload_config);
+    }
+}
+
 #[async_trait]
 pub trait AttestationAgentService: Sync {
     async fn get_evidence(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetEvidenceRequest) -> ::ttrpc::Result<super::attestation_agent::GetEvidenceResponse> {
@@ -136,6 +200,9 @@ pub trait AttestationAgentService: Sync {
     async fn get_additional_evidence(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetAdditionalEvidenceRequest) -> ::ttrpc::Result<super::attestation_agent::GetEvidenceResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/GetAdditionalEvidence is not supported".to_string())))
     }
+    async fn get_composite_evidence(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetCompositeEvidenceRequest) -> ::ttrpc::Result<super::attestation_agent::GetCompositeEvidenceResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/GetCompositeEvidence is not supported".to_string())))
+    }
     async fn get_token(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetTokenRequest) -> ::ttrpc::Result<super::attestation_agent::GetTokenResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/GetToken is not supported".to_string())))
     }
@@ -148,6 +215,15 @@ pub trait AttestationAgentService: Sync {
     async fn get_tee_type(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetTeeTypeRequest) -> ::ttrpc::Result<super::attestation_agent::GetTeeTypeResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/GetTeeType is not supported".to_string())))
     }
+    async fn get_event_log(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::GetEventLogRequest) -> ::ttrpc::Result<super::attestation_agent::GetEventLogResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/GetEventLog is not supported".to_string())))
+    }
+    async fn verify_event_log(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::VerifyEventLogRequest) -> ::ttrpc::Result<super::attestation_agent::VerifyEventLogResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/VerifyEventLog is not supported".to_string())))
+    }
+    async fn reload_config(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::attestation_agent::ReloadConfigRequest) -> ::ttrpc::Result<super::attestation_agent::ReloadConfigResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/attestation_agent.AttestationAgentService/ReloadConfig is not supported".to_string())))
+    }
 }
 
 pub fn create_attestation_agent_service(service: Arc<dyn AttestationAgentService + Send + Sync>) -> HashMap<String, ::ttrpc::r#async::Service> {
@@ -161,6 +237,9 @@ pub fn create_attestation_agent_service(service: Arc<dyn AttestationAgentService
     methods.insert("GetAdditionalEvidence".to_string(),
                     Box::new(GetAdditionalEvidenceMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
+    methods.insert("GetCompositeEvidence".to_string(),
+                    Box::new(GetCompositeEvidenceMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
     methods.insert("GetToken".to_string(),
                     Box::new(GetTokenMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
@@ -173,6 +252,15 @@ pub fn create_attestation_agent_service(service: Arc<dyn AttestationAgentService
     methods.insert("GetTeeType".to_string(),
                     Box::new(GetTeeTypeMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
+    methods.insert("GetEventLog".to_string(),
+                    Box::new(GetEventLogMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("VerifyEventLog".to_string(),
+                    Box::new(VerifyEventLogMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("ReloadConfig".to_string(),
+                    Box::new(ReloadConfigMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
     ret.insert("attestation_agent.AttestationAgentService".to_string(), ::ttrpc::r#async::Service{ methods, streams });
     ret
 }
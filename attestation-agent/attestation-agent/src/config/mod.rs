@@ -3,7 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::Result;
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
 use serde::Deserialize;
 
 /// Default PCR index used by AA. `17` is selected for its usage of dynamic root of trust for measurement.
@@ -11,6 +13,12 @@ use serde::Deserialize;
 /// - [TCG TRUSTED BOOT CHAIN IN EDK II](https://tianocore-docs.github.io/edk2-TrustedBootChain/release-1.00/3_TCG_Trusted_Boot_Chain_in_EDKII.html)
 const DEFAULT_PCR_INDEX: u64 = 17;
 
+/// Default seconds before a cached token expires to refresh it.
+const DEFAULT_TOKEN_REFRESH_MARGIN: u64 = 60;
+
+/// Default max seconds to cache a token.
+const DEFAULT_TOKEN_MAX_AGE: u64 = 300;
+
 pub mod aa_kbc_params;
 
 #[cfg(feature = "coco_as")]
@@ -19,6 +27,8 @@ pub mod coco_as;
 #[cfg(feature = "kbs")]
 pub mod kbs;
 
+pub mod watcher;
+
 pub const DEFAULT_AA_CONFIG_PATH: &str = "/etc/attestation-agent.conf";
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -43,6 +53,77 @@ pub struct EventlogConfig {
 
     /// Flag whether enable eventlog recording
     pub enable_eventlog: bool,
+
+    /// PCRs to extend the events of specific domains to. Events of the
+    /// other domains are extended to `init_pcr`.
+    #[serde(default)]
+    pub domains: Vec<EventlogDomain>,
+
+    /// Whether to replay the eventlog and check it against the measurement
+    /// registers when AA opens it at startup. Defaults to
+    /// [`EventlogVerifyMode::Off`].
+    #[serde(default)]
+    pub verify_on_startup: EventlogVerifyMode,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventlogVerifyMode {
+    /// Do not verify the eventlog.
+    #[default]
+    Off,
+
+    /// Log a warning if the eventlog does not match. AA keeps running but
+    /// refuses to append to the eventlog.
+    Warn,
+
+    /// Fail to start AA if the eventlog does not match.
+    Enforce,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct EventlogDomain {
+    /// Domain of the events, e.g. `github.com/confidential-containers`
+    pub domain: String,
+
+    /// PCR Register to extend the events of the domain
+    pub pcr: u64,
+}
+
+impl EventlogConfig {
+    /// All the PCRs configured for the eventlog.
+    pub fn pcrs(&self) -> Vec<u64> {
+        let mut pcrs = vec![self.init_pcr];
+        for d in &self.domains {
+            if !pcrs.contains(&d.pcr) {
+                pcrs.push(d.pcr);
+            }
+        }
+        pcrs
+    }
+
+    /// The PCR that events of `domain` are extended to if the caller does
+    /// not specify one.
+    pub fn pcr_of(&self, domain: &str) -> u64 {
+        self.domains
+            .iter()
+            .find(|d| d.domain == domain)
+            .map_or(self.init_pcr, |d| d.pcr)
+    }
+
+    fn validate(&self) -> Result<(), config::ConfigError> {
+        let mut domains = HashSet::new();
+        for d in &self.domains {
+            if !domains.insert(&d.domain[..]) {
+                return Err(config::ConfigError::Message(format!(
+                    "duplicated eventlog domain `{}`",
+                    d.domain
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "instance_info")]
@@ -75,6 +156,8 @@ impl Default for EventlogConfig {
         Self {
             init_pcr: DEFAULT_PCR_INDEX,
             enable_eventlog: false,
+            domains: Vec::new(),
+            verify_on_startup: EventlogVerifyMode::Off,
         }
     }
 }
@@ -88,6 +171,27 @@ impl Config {
             aa_instance: AAInstanceConfig::default(),
         })
     }
+
+    /// Check that `new` only differs from the running config in the items
+    /// that can be applied without restarting AA, i.e. `token_configs`, and
+    /// that its `token_configs` are valid.
+    ///
+    /// The `token_configs` are not validated at startup, so that config files
+    /// that used to be accepted, e.g. with a placeholder KBS cert, still are.
+    /// A reload must not replace a working config with a broken one though.
+    pub fn check_reloadable(&self, new: &Config) -> Result<()> {
+        if self.eventlog_config != new.eventlog_config {
+            bail!("`eventlog_config` cannot be reloaded, restart AA to apply it");
+        }
+
+        #[cfg(feature = "instance_info")]
+        if self.aa_instance != new.aa_instance {
+            bail!("`aa_instance` cannot be reloaded, restart AA to apply it");
+        }
+
+        new.token_configs.validate()?;
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Default)]
@@ -99,6 +203,40 @@ pub struct TokenConfigs {
     /// This config item is used when `kbs` feature is enabled.
     #[cfg(feature = "kbs")]
     pub kbs: Option<kbs::KbsConfig>,
+
+    /// Caching of the tokens
+    #[serde(default)]
+    pub cache: TokenCacheConfig,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct TokenCacheConfig {
+    /// Seconds before a cached token expires, or reaches `max_age`, to
+    /// refresh it in the background.
+    #[serde(default = "default_token_refresh_margin")]
+    pub refresh_margin: u64,
+
+    /// Max seconds to cache a token, even if it expires later. `0` disables
+    /// the cache.
+    #[serde(default = "default_token_max_age")]
+    pub max_age: u64,
+}
+
+fn default_token_refresh_margin() -> u64 {
+    DEFAULT_TOKEN_REFRESH_MARGIN
+}
+
+fn default_token_max_age() -> u64 {
+    DEFAULT_TOKEN_MAX_AGE
+}
+
+impl Default for TokenCacheConfig {
+    fn default() -> Self {
+        Self {
+            refresh_margin: DEFAULT_TOKEN_REFRESH_MARGIN,
+            max_age: DEFAULT_TOKEN_MAX_AGE,
+        }
+    }
 }
 
 impl TokenConfigs {
@@ -112,10 +250,40 @@ impl TokenConfigs {
 
             #[cfg(feature = "kbs")]
             kbs,
+
+            cache: TokenCacheConfig::default(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), config::ConfigError> {
+        #[cfg(feature = "kbs")]
+        if let Some(kbs) = &self.kbs {
+            validate_url("KBS", &kbs.url)?;
+            if let Some(cert) = &kbs.cert {
+                if !cert.contains("-----BEGIN CERTIFICATE-----") {
+                    return Err(config::ConfigError::Message(
+                        "KBS cert is not a PEM encoded certificate".into(),
+                    ));
+                }
+            }
+        }
+
+        #[cfg(feature = "coco_as")]
+        if let Some(coco_as) = &self.coco_as {
+            validate_url("CoCoAS", &coco_as.url)?;
         }
+
+        Ok(())
     }
 }
 
+#[cfg(any(feature = "kbs", feature = "coco_as"))]
+fn validate_url(service: &str, url: &str) -> Result<(), config::ConfigError> {
+    url::Url::parse(url)
+        .map_err(|e| config::ConfigError::Message(format!("invalid {service} URL `{url}`: {e}")))?;
+    Ok(())
+}
+
 impl TryFrom<&str> for Config {
     type Error = config::ConfigError;
     fn try_from(config_path: &str) -> Result<Self, Self::Error> {
@@ -125,14 +293,15 @@ impl TryFrom<&str> for Config {
             .set_default("eventlog_config.enable_eventlog", "false")?
             .build()?;
 
-        let cfg = c.try_deserialize()?;
+        let cfg: Self = c.try_deserialize()?;
+        cfg.eventlog_config.validate()?;
         Ok(cfg)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::{Config, EventlogConfig, TokenConfigs};
+    use crate::config::{Config, EventlogConfig, EventlogDomain, EventlogVerifyMode, TokenConfigs};
 
     #[rstest::rstest]
     #[case("tests/config.example.toml")]
@@ -176,11 +345,20 @@ DYEbQvoZIkUTc1gBUWDcAUS5ztbJg9LCb9WVtvUTqTP2lGuNymOvdsuXq+sAZh9b
 M9QaC1mzQ/OStg==
 -----END CERTIFICATE-----
 ".to_string()),
-            })
+            }),
+            cache: TokenCacheConfig {
+                refresh_margin: 30,
+                max_age: 600,
+            },
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![EventlogDomain {
+                domain: "github.com/confidential-containers".to_string(),
+                pcr: 18,
+            }],
+            verify_on_startup: EventlogVerifyMode::Warn,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -218,11 +396,20 @@ DYEbQvoZIkUTc1gBUWDcAUS5ztbJg9LCb9WVtvUTqTP2lGuNymOvdsuXq+sAZh9b
 M9QaC1mzQ/OStg==
 -----END CERTIFICATE-----
 ".to_string()),
-            })
+            }),
+            cache: TokenCacheConfig {
+                refresh_margin: 30,
+                max_age: 600,
+            },
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![EventlogDomain {
+                domain: "github.com/confidential-containers".to_string(),
+                pcr: 18,
+            }],
+            verify_on_startup: EventlogVerifyMode::Warn,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -239,11 +426,14 @@ M9QaC1mzQ/OStg==
             kbs: Some(crate::config::kbs::KbsConfig {
                 url: "https://127.0.0.1:8080".to_string(),
                 cert: Some("cert".to_string()),
-            })
+            }),
+            cache: TokenCacheConfig::default(),
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![],
+            verify_on_startup: EventlogVerifyMode::Off,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -258,11 +448,14 @@ M9QaC1mzQ/OStg==
             kbs: Some(crate::config::kbs::KbsConfig {
                 url: "https://127.0.0.1:8080".to_string(),
                 cert: Some("cert".to_string()),
-            })
+            }),
+            cache: TokenCacheConfig::default(),
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![],
+            verify_on_startup: EventlogVerifyMode::Off,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -279,11 +472,14 @@ M9QaC1mzQ/OStg==
             kbs: Some(crate::config::kbs::KbsConfig {
                 url: "https://127.0.0.1:8080".to_string(),
                 cert: None,
-            })
+            }),
+            cache: TokenCacheConfig::default(),
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![],
+            verify_on_startup: EventlogVerifyMode::Off,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -296,10 +492,13 @@ M9QaC1mzQ/OStg==
             coco_as: None,
             #[cfg(feature = "kbs")]
             kbs: None,
+            cache: TokenCacheConfig::default(),
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![],
+            verify_on_startup: EventlogVerifyMode::Off,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -312,10 +511,13 @@ M9QaC1mzQ/OStg==
             coco_as: None,
             #[cfg(feature = "kbs")]
             kbs: None,
+            cache: TokenCacheConfig::default(),
         },
         eventlog_config: EventlogConfig {
             init_pcr: 17,
             enable_eventlog: false,
+            domains: vec![],
+            verify_on_startup: EventlogVerifyMode::Off,
         },
         #[cfg(feature = "instance_info")]
         aa_instance: crate::config::AAInstanceConfig::default(),
@@ -328,10 +530,13 @@ M9QaC1mzQ/OStg==
                 coco_as: None,
                 #[cfg(feature = "kbs")]
                 kbs: None,
+                cache: TokenCacheConfig::default(),
             },
             eventlog_config: EventlogConfig {
                 init_pcr: 17,
                 enable_eventlog: false,
+                domains: vec![],
+                verify_on_startup: EventlogVerifyMode::Off,
             },
             #[cfg(feature = "instance_info")]
             aa_instance: crate::config::AAInstanceConfig::default(),
@@ -341,6 +546,90 @@ M9QaC1mzQ/OStg==
         assert_eq!(_config, expected);
     }
 
+    #[rstest::rstest]
+    #[case("github.com/confidential-containers", 18)]
+    #[case("github.com/kata-containers", 17)]
+    fn eventlog_pcr_of_domain(#[case] domain: &str, #[case] expected: u64) {
+        let config = Config::try_from("config.example.toml").expect("failed to parse config file");
+        assert_eq!(config.eventlog_config.pcr_of(domain), expected);
+    }
+
+    #[test]
+    fn duplicated_eventlog_domains() {
+        let config = EventlogConfig {
+            domains: vec![
+                EventlogDomain {
+                    domain: "domain".to_string(),
+                    pcr: 18,
+                },
+                EventlogDomain {
+                    domain: "domain".to_string(),
+                    pcr: 19,
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn token_cache_config() {
+        let config = Config::try_from("test/config1.toml").expect("failed to parse config file");
+        assert_eq!(config.token_configs.cache, TokenCacheConfig::default());
+
+        let config = Config::try_from("config.example.toml").expect("failed to parse config file");
+        assert_eq!(
+            config.token_configs.cache,
+            TokenCacheConfig {
+                refresh_margin: 30,
+                max_age: 600,
+            }
+        );
+    }
+
+    #[test]
+    fn reloadable_config() {
+        let config = Config::try_from("config.example.toml").expect("failed to parse config file");
+
+        let mut new = config.clone();
+        new.token_configs.cache.max_age = 0;
+        assert!(config.check_reloadable(&new).is_ok());
+
+        new.eventlog_config.init_pcr += 1;
+        assert!(config.check_reloadable(&new).is_err());
+    }
+
+    #[cfg(feature = "kbs")]
+    #[test]
+    fn invalid_token_configs_are_only_rejected_on_reload() {
+        // `cert = "cert"` is not a PEM certificate, but is accepted at startup
+        let config = Config::try_from("test/config1.toml").expect("failed to parse config file");
+        assert_eq!(
+            config.token_configs.kbs.as_ref().unwrap().cert.as_deref(),
+            Some("cert")
+        );
+
+        let running = Config::try_from("config.example.toml").expect("failed to parse config file");
+        let mut new = running.clone();
+        new.token_configs = config.token_configs;
+        assert!(running.check_reloadable(&new).is_err());
+    }
+
+    #[cfg(feature = "kbs")]
+    #[rstest::rstest]
+    #[case("127.0.0.1:8080", None)]
+    #[case("https://127.0.0.1:8080", Some("not a cert"))]
+    fn invalid_kbs_config(#[case] url: &str, #[case] cert: Option<&str>) {
+        let token_configs = TokenConfigs {
+            kbs: Some(crate::config::kbs::KbsConfig {
+                url: url.to_string(),
+                cert: cert.map(String::from),
+            }),
+            ..Default::default()
+        };
+        assert!(token_configs.validate().is_err());
+    }
+
     #[test]
     fn test_config_default() {
         let config = super::Config::new().expect("failed to create config");
@@ -0,0 +1,92 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Watch the AA config file to reload it once changed.
+//!
+//! The directory of the file is watched rather than the file itself, as
+//! editors and Kubernetes ConfigMaps replace the file instead of writing to
+//! it, which would silently end a watch on the file.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use log::{debug, warn};
+use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+pub struct ConfigWatcher {
+    changes: Option<UnboundedReceiver<()>>,
+}
+
+impl ConfigWatcher {
+    /// Watch the config file at `config_path`. If no config file is given,
+    /// the watcher never reports a change.
+    pub fn new(config_path: Option<&str>) -> Result<Self> {
+        let Some(config_path) = config_path else {
+            return Ok(Self { changes: None });
+        };
+
+        let path = Path::new(config_path);
+        let file_name = path
+            .file_name()
+            .context("config path has no file name")?
+            .to_os_string();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let inotify = Inotify::init(InitFlags::IN_CLOEXEC).context("init inotify")?;
+        inotify
+            .add_watch(
+                dir,
+                AddWatchFlags::IN_CLOSE_WRITE
+                    | AddWatchFlags::IN_MOVED_TO
+                    | AddWatchFlags::IN_CREATE,
+            )
+            .with_context(|| format!("watch directory {}", dir.display()))?;
+
+        let (tx, rx) = unbounded_channel();
+        std::thread::spawn(move || loop {
+            let events = match inotify.read_events() {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("Stop watching AA config file: {e}");
+                    return;
+                }
+            };
+
+            // Kubernetes swaps the `..data` symlink that the config file
+            // points through.
+            let changed = events.iter().any(|event| {
+                event
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| *name == file_name || name == "..data")
+            });
+            if changed {
+                debug!("AA config file changed");
+                if tx.send(()).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Ok(Self { changes: Some(rx) })
+    }
+
+    /// Wait until the config file changes.
+    pub async fn changed(&mut self) {
+        if let Some(changes) = &mut self.changes {
+            if changes.recv().await.is_some() {
+                return;
+            }
+
+            self.changes = None;
+        }
+
+        std::future::pending().await
+    }
+}
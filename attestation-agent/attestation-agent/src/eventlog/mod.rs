@@ -6,6 +6,7 @@
 pub mod tcg2;
 
 use std::{
+    collections::HashMap,
     fmt::Display,
     fs::{remove_file, File},
     io::{Read, Seek, SeekFrom, Write},
@@ -18,9 +19,12 @@ use attester::BoxedAttester;
 use const_format::concatcp;
 
 use kbs_types::HashAlgorithm;
-use log::warn;
+use log::{info, warn};
 
-use crate::eventlog::tcg2::Tcg2EventEntry;
+use crate::{
+    config::{EventlogConfig, EventlogVerifyMode},
+    eventlog::tcg2::{Tcg2EventEntry, TcgAlgorithm},
+};
 
 /// AA's eventlog will be put into this parent directory
 pub const EVENTLOG_PARENT_DIR_PATH: &str = "/run/attestation-agent";
@@ -36,6 +40,15 @@ pub struct EventLog {
     rtmr_extender: Arc<BoxedAttester>,
     alg: HashAlgorithm,
     pcr: u64,
+
+    /// The values the measurement registers should have due to the log,
+    /// indexed by the register. Only registers checked by [`EventLog::verify`]
+    /// and extended afterwards are tracked.
+    expected: HashMap<u64, Vec<u8>>,
+
+    /// Why the log and the registers disagree, if they do. No more entries
+    /// are appended once set.
+    diverged: Option<String>,
 }
 
 trait Writer: Sync + Send {
@@ -130,6 +143,8 @@ impl EventLog {
                     rtmr_extender,
                     alg,
                     pcr,
+                    expected: HashMap::new(),
+                    diverged: None,
                 })
             }
             Err(_) => bail!("Failed to read wal cache. This is a significant error caused by a previous crash. Please try delete `{WAL_CACHE}` and restart the attestation agent."),
@@ -138,10 +153,34 @@ impl EventLog {
                 rtmr_extender,
                 alg,
                 pcr,
+                expected: HashMap::new(),
+                diverged: None,
             })
         }
     }
 
+    /// Open the eventlog as configured by `config`. Unless
+    /// `verify_on_startup` is [`EventlogVerifyMode::Off`] (the default), the
+    /// log is replayed against the measurement registers right away, so that
+    /// no entry is appended to a log that already disagrees with them.
+    pub async fn open(rtmr_extender: Arc<BoxedAttester>, config: &EventlogConfig) -> Result<Self> {
+        let mut eventlog = Self::new(rtmr_extender, config.init_pcr).await?;
+        match config.verify_on_startup {
+            EventlogVerifyMode::Off => {}
+            EventlogVerifyMode::Warn => {
+                if let Err(e) = eventlog.verify(&config.pcrs()).await {
+                    warn!("Eventlog verification failed: {e:#}");
+                }
+            }
+            EventlogVerifyMode::Enforce => eventlog
+                .verify(&config.pcrs())
+                .await
+                .context("eventlog verification failed")?,
+        }
+
+        Ok(eventlog)
+    }
+
     /// Record the event and the target digest into cache file before write, this would do
     /// help when there is a crash between extending PCR and logging event.
     fn write_wal_cache(&self, wal_cache: WalCache) -> Result<()> {
@@ -195,21 +234,32 @@ impl EventLog {
     /// Once kernel provides a more robust way (atomic) to maintain an eventlog,
     /// we can remove the WAL cache file mechanism.
     pub async fn extend_entry(&mut self, log_entry: Event<'_>, pcr: u64) -> Result<()> {
+        if let Some(reason) = &self.diverged {
+            bail!("refuse to append to the eventlog, which disagrees with the measurement registers: {reason}");
+        }
+
         let aael_event_data = log_entry.to_string();
-        let rtmr = self.rtmr_extender.pcr_to_ccmr(self.pcr);
+        let rtmr = self.rtmr_extender.pcr_to_ccmr(pcr);
         let (tcg2_event, event_digest) = Into::<Tcg2EventEntry>::into(log_entry)
             .with_target_measurement_register(rtmr as u32)
             .digest(self.alg);
 
         let tcg2_event_data = tcg2_event.to_le_bytes();
         let mut current_pcr = self.rtmr_extender.get_runtime_measurement(pcr).await?;
+        if let Some(expected) = self.expected.get(&rtmr) {
+            if *expected != current_pcr {
+                let reason = format!("register of PCR {pcr} was extended out of the eventlog");
+                self.diverged = Some(reason.clone());
+                bail!("refuse to append to the eventlog: {reason}");
+            }
+        }
 
         current_pcr.extend_from_slice(&event_digest);
         let expected_pcr = self.alg.digest(&current_pcr);
 
         let event_offset = self.writer.current_pos();
         let wal_cache = WalCache {
-            expected_pcr,
+            expected_pcr: expected_pcr.clone(),
             event_data: aael_event_data,
             event_offset,
         };
@@ -224,8 +274,173 @@ impl EventLog {
             .context("write log entry")?;
 
         Self::clean_wal_cache().context("remove wal cache file failed")?;
+        if self.expected.contains_key(&rtmr) {
+            self.expected.insert(rtmr, expected_pcr);
+        }
+        Ok(())
+    }
+
+    /// Replay the whole eventlog with the hash algorithm of the platform and
+    /// compare the result with the measurement registers of `self.pcr` and
+    /// `pcrs`. The registers are expected to start from all zeros (or all
+    /// ones, like TPM PCRs 17 to 22) and to be extended only through AA.
+    ///
+    /// On disagreement the first diverging entry is reported, and no more
+    /// entries will be appended.
+    pub async fn verify(&mut self, pcrs: &[u64]) -> Result<()> {
+        if let Some(reason) = &self.diverged {
+            bail!("{reason}");
+        }
+
+        let data = std::fs::read(EVENTLOG_PATH).context("read AAEL file")?;
+        let entries = Tcg2EventEntry::parse_all(&data)?;
+
+        let mut checked = HashMap::new();
+        for &pcr in std::iter::once(&self.pcr).chain(pcrs) {
+            let register = self.rtmr_extender.pcr_to_ccmr(pcr);
+            if checked.contains_key(&register) {
+                continue;
+            }
+
+            let current = self
+                .rtmr_extender
+                .get_runtime_measurement(pcr)
+                .await
+                .context("get runtime measurement")?;
+            match replay(&entries, register, self.alg, &current) {
+                Ok(()) => {
+                    checked.insert(register, current);
+                }
+                Err(e) => {
+                    let reason = format!("PCR {pcr}: {e}");
+                    self.diverged = Some(reason.clone());
+                    bail!(reason);
+                }
+            }
+        }
+
+        info!("eventlog verified, {} entries replayed", entries.len());
+        self.expected = checked;
         Ok(())
     }
+
+    /// Read back all the entries of the eventlog file.
+    pub fn entries(&self) -> Result<Vec<EventLogEntry>> {
+        let data = std::fs::read(EVENTLOG_PATH).context("read AAEL file")?;
+        EventLogEntry::parse_all(&data)
+    }
+}
+
+/// Replay the entries of `register` and check that they end in `current`.
+fn replay(
+    entries: &[(Tcg2EventEntry, &[u8])],
+    register: u64,
+    alg: HashAlgorithm,
+    current: &[u8],
+) -> Result<()> {
+    let tcg_alg = TcgAlgorithm::from(alg);
+    let describe = |index: usize, entry: &Tcg2EventEntry| match entry.aael_event() {
+        Ok(event) => format!("entry {index} `{event}`"),
+        Err(_) => format!("entry {index}"),
+    };
+
+    // The register values after every replayed entry, from each initial value.
+    let mut states = vec![
+        vec![vec![0u8; alg.digest_len()]],
+        vec![vec![0xffu8; alg.digest_len()]],
+    ];
+    let mut replayed = vec![];
+    for (index, (entry, _)) in entries.iter().enumerate() {
+        if entry.target_measurement_register() as u64 != register {
+            continue;
+        }
+
+        let Some(digest) = entry.digests().iter().find(|d| d.alg == tcg_alg) else {
+            bail!(
+                "{} has no {} digest",
+                describe(index, entry),
+                tcg_alg.as_str()
+            );
+        };
+        if alg.digest(entry.event_data()) != digest.digest {
+            bail!("{} does not match its digest", describe(index, entry));
+        }
+
+        for states in states.iter_mut() {
+            let mut value = states.last().expect("initial value").clone();
+            value.extend_from_slice(&digest.digest);
+            states.push(alg.digest(&value));
+        }
+        replayed.push(index);
+    }
+
+    for states in &states {
+        if states.last().map(|s| &s[..]) == Some(current) {
+            return Ok(());
+        }
+
+        // A prefix of the log is reflected in the register, so the entry
+        // after it was logged but never extended.
+        if let Some(pos) = states.iter().position(|s| s == current) {
+            let index = replayed[pos];
+            bail!(
+                "{} and the later ones are not reflected in the register",
+                describe(index, &entries[index].0)
+            );
+        }
+    }
+
+    match replayed.first() {
+        Some(&index) => bail!(
+            "the register matches no replayed state from {}, it was extended out of the eventlog or an entry was lost",
+            describe(index, &entries[index].0)
+        ),
+        None => bail!("the register was extended but the eventlog has no entry of it"),
+    }
+}
+
+/// A decoded entry of the eventlog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventLogEntry {
+    /// The measurement register the entry was extended to, in the numbering
+    /// of the platform, e.g. the RTMR index for TDX.
+    pub register_index: u64,
+
+    pub domain: String,
+
+    pub operation: String,
+
+    pub content: String,
+
+    /// Digests of the entry, together with the names of the hash
+    /// algorithms, e.g. `SHA-384`.
+    pub digests: Vec<(String, Vec<u8>)>,
+
+    /// The TCG2 encoding of the entry.
+    pub raw: Vec<u8>,
+}
+
+impl EventLogEntry {
+    fn parse_all(data: &[u8]) -> Result<Vec<Self>> {
+        Tcg2EventEntry::parse_all(data)?
+            .into_iter()
+            .map(|(entry, raw)| {
+                let event = Event::try_from(entry.aael_event()?)?;
+                Ok(EventLogEntry {
+                    register_index: entry.target_measurement_register() as u64,
+                    domain: event.domain.to_string(),
+                    operation: event.operation.to_string(),
+                    content: event.content.0.to_string(),
+                    digests: entry
+                        .digests()
+                        .iter()
+                        .map(|d| (d.alg.as_str().to_string(), d.digest.clone()))
+                        .collect(),
+                    raw: raw.to_vec(),
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct Content<'a>(&'a str);
@@ -345,6 +560,8 @@ mod tests {
             pcr: 17,
             rtmr_extender: Arc::new(rtmr_extender),
             alg: HashAlgorithm::Sha384,
+            expected: HashMap::new(),
+            diverged: None,
         };
 
         el.extend_entry(
@@ -372,6 +589,52 @@ mod tests {
         assert_eq!(expected, lines.lock().unwrap().to_vec());
     }
 
+    #[test]
+    fn test_replay() {
+        let data = std::fs::read("./test/aael.bin").unwrap();
+        let entries = Tcg2EventEntry::parse_all(&data).unwrap();
+        let alg = HashAlgorithm::Sha384;
+
+        let mut register = vec![0u8; alg.digest_len()];
+        let mut states = vec![register.clone()];
+        for (entry, _) in &entries {
+            register.extend_from_slice(&entry.digests()[0].digest);
+            register = alg.digest(&register);
+            states.push(register.clone());
+        }
+
+        replay(&entries, 17, alg, &states[2]).unwrap();
+        let err = replay(&entries, 17, alg, &states[1]).unwrap_err();
+        assert!(err.to_string().starts_with("entry 1 "));
+        assert!(replay(&entries, 17, alg, &[1u8; 48]).is_err());
+        assert!(replay(&entries, 18, alg, &states[1]).is_err());
+        replay(&entries, 18, alg, &states[0]).unwrap();
+
+        // Tamper the content of the second entry
+        let mut tampered = data.clone();
+        let pos = tampered.len() - 1;
+        tampered[pos] = b'3';
+        let entries = Tcg2EventEntry::parse_all(&tampered).unwrap();
+        let err = replay(&entries, 17, alg, &states[2]).unwrap_err();
+        assert!(err.to_string().contains("does not match its digest"));
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let data = std::fs::read("./test/aael.bin").unwrap();
+        let entries = EventLogEntry::parse_all(&data).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].register_index, 17);
+        assert_eq!(entries[1].domain, "domain");
+        assert_eq!(entries[1].operation, "operation");
+        assert_eq!(entries[1].content, "content2");
+        assert_eq!(entries[1].digests[0].0, "SHA-384");
+        assert_eq!(
+            [entries[0].raw.clone(), entries[1].raw.clone()].concat(),
+            data
+        );
+    }
+
     #[rstest]
     #[case(
         "domain",
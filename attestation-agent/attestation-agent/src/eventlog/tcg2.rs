@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use anyhow::{anyhow, bail, Result};
 use kbs_types::HashAlgorithm;
 use serde::Serialize;
 
@@ -35,10 +36,41 @@ impl From<HashAlgorithm> for TcgAlgorithm {
     }
 }
 
+impl TryFrom<u16> for TcgAlgorithm {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0xB => Ok(TcgAlgorithm::Sha256),
+            0xC => Ok(TcgAlgorithm::Sha384),
+            0xD => Ok(TcgAlgorithm::Sha512),
+            0x12 => Ok(TcgAlgorithm::Sm3),
+            others => bail!("unknown TCG algorithm id {others:#x}"),
+        }
+    }
+}
+
 impl TcgAlgorithm {
     pub const fn to_le_bytes(self) -> [u8; 2] {
         (self as u16).to_le_bytes()
     }
+
+    pub const fn digest_len(self) -> usize {
+        match self {
+            TcgAlgorithm::Sha256 | TcgAlgorithm::Sm3 => 32,
+            TcgAlgorithm::Sha384 => 48,
+            TcgAlgorithm::Sha512 => 64,
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            TcgAlgorithm::Sha256 => "SHA-256",
+            TcgAlgorithm::Sha384 => "SHA-384",
+            TcgAlgorithm::Sha512 => "SHA-512",
+            TcgAlgorithm::Sm3 => "SM3",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -163,3 +195,123 @@ impl Tcg2EventEntry {
         (self, digest)
     }
 }
+
+/// Reads little-endian fields of TCG2 event entries.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(anyhow!("truncated event entry at offset {}", self.pos))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+}
+
+impl Tcg2EventEntry {
+    /// Parse all the entries of an AAEL file. Every entry is returned
+    /// together with its encoded bytes.
+    pub fn parse_all(data: &[u8]) -> Result<Vec<(Self, &[u8])>> {
+        let mut reader = Reader { data, pos: 0 };
+        let mut entries = vec![];
+        while reader.pos < data.len() {
+            let start = reader.pos;
+            let target_measurement_register = reader.u32()?;
+            let event_type_num = reader.u32()?;
+            let digest_count = reader.u32()?;
+            let mut digest = vec![];
+            for _ in 0..digest_count {
+                let alg = TcgAlgorithm::try_from(reader.u16()?)?;
+                let value = reader.take(alg.digest_len())?.to_vec();
+                digest.push(ElDigest { alg, digest: value });
+            }
+            let event_data_length = reader.u32()?;
+            let event_data = reader.take(event_data_length as usize)?.to_vec();
+
+            entries.push((
+                Tcg2EventEntry {
+                    target_measurement_register,
+                    event_type_num,
+                    digest_count,
+                    digest,
+                    event_data_length,
+                    event_data,
+                },
+                &data[start..reader.pos],
+            ));
+        }
+
+        Ok(entries)
+    }
+
+    pub fn target_measurement_register(&self) -> u32 {
+        self.target_measurement_register
+    }
+
+    pub fn digests(&self) -> &[ElDigest] {
+        &self.digest
+    }
+
+    /// The event data section, which the digests are calculated over.
+    pub fn event_data(&self) -> &[u8] {
+        &self.event_data
+    }
+
+    /// The AAEL plaintext carried by the entry.
+    pub fn aael_event(&self) -> Result<&str> {
+        if self.event_type_num != EV_EVENT_TAG_TYPE {
+            bail!("unexpected event type {:#x}", self.event_type_num);
+        }
+
+        let mut reader = Reader {
+            data: &self.event_data,
+            pos: 0,
+        };
+        if reader.u32()? != AAEL_TAGGED_EVENT_ID {
+            bail!("not an AAEL tagged event");
+        }
+        let size = reader.u32()?;
+        let event = reader.take(size as usize)?;
+        Ok(std::str::from_utf8(event)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tcg2EventEntry, TcgAlgorithm};
+
+    #[test]
+    fn parse_aael_file() {
+        let data = std::fs::read("./test/aael.bin").unwrap();
+        let entries = Tcg2EventEntry::parse_all(&data).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        for (i, (entry, raw)) in entries.iter().enumerate() {
+            assert_eq!(entry.target_measurement_register(), 17);
+            assert_eq!(entry.digests().len(), 1);
+            assert_eq!(entry.digests()[0].alg, TcgAlgorithm::Sha384);
+            assert_eq!(
+                entry.aael_event().unwrap(),
+                format!("domain operation content{}", i + 1)
+            );
+            assert_eq!(entry.to_le_bytes(), raw.to_vec());
+        }
+
+        assert!(Tcg2EventEntry::parse_all(&data[..data.len() - 1]).is_err());
+    }
+}
@@ -6,11 +6,13 @@
 use anyhow::{bail, Context, Result};
 use async_trait::async_trait;
 use attester::{detect_attestable_devices, detect_tee_type, BoxedAttester};
+use futures::future::join_all;
 use kbs_types::Tee;
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 use tokio::sync::{Mutex, RwLock};
 
 pub use attester::InitDataResult;
+pub use eventlog::EventLogEntry;
 
 pub mod config;
 mod eventlog;
@@ -39,6 +41,9 @@ use crate::{config::Config, eventlog::Event};
 /// - `bind_init_data`: bind the given data slice to the current confidential
 /// computing environment. This can be a verify operation or an extension of the TEE
 /// evidence
+/// - `get_event_log`: read back the entries of the eventlog.
+/// - `verify_event_log`: replay the eventlog and check it against the
+/// measurement registers.
 ///
 /// # Example
 ///
@@ -68,6 +73,11 @@ pub trait AttestationAPIs {
     /// included. If no additional attester is configured, it will return an empty vector.
     async fn get_additional_evidence(&self, runtime_data: &[u8]) -> Result<Vec<u8>>;
 
+    /// Get the evidence of the primary TEE and of all additional attesters
+    /// concurrently, all including the same runtime data. Failures of the
+    /// additional attesters are reported per TEE rather than failing the call.
+    async fn get_composite_evidence(&self, runtime_data: &[u8]) -> Result<CompositeEvidence>;
+
     /// Extend runtime measurement register
     async fn extend_runtime_measurement(
         &self,
@@ -80,17 +90,43 @@ pub trait AttestationAPIs {
     /// Bind initdata
     async fn bind_init_data(&self, init_data: &[u8]) -> Result<InitDataResult>;
 
+    /// Replay the eventlog and check it against the measurement registers.
+    /// An error describes the first diverging entry.
+    async fn verify_event_log(&self) -> Result<()>;
+
+    /// Get the eventlog entries, optionally only the ones of `domain` and/or
+    /// the ones extended to PCR `register_index`.
+    async fn get_event_log(
+        &self,
+        domain: Option<&str>,
+        register_index: Option<u64>,
+    ) -> Result<Vec<EventLogEntry>>;
+
     fn get_tee_type(&self) -> Tee;
 }
 
+/// Evidence of the primary TEE and the additional devices, bound to the same
+/// runtime data.
+#[derive(Debug)]
+pub struct CompositeEvidence {
+    pub primary_tee: Tee,
+    pub primary_evidence: Vec<u8>,
+
+    /// Evidence of each additional attester, or why it could not be got.
+    pub additional_evidence: HashMap<Tee, std::result::Result<Vec<u8>, String>>,
+}
+
 /// Attestation agent to provide attestation service.
 pub struct AttestationAgent {
     primary_tee: Tee,
     pub config: RwLock<Config>,
+    config_path: Option<String>,
     eventlog: Option<Mutex<EventLog>>,
     initdata: Option<String>,
     primary_attester: Arc<BoxedAttester>,
     additional_attesters: HashMap<Tee, BoxedAttester>,
+    #[cfg(feature = "token")]
+    token_cache: token::cache::TokenCache,
 }
 
 impl AttestationAgent {
@@ -126,12 +162,8 @@ impl AttestationAgent {
         }
 
         if config.eventlog_config.enable_eventlog {
-            let eventlog = EventLog::new(
-                self.primary_attester.clone(),
-                config.eventlog_config.init_pcr,
-            )
-            .await?;
-
+            let eventlog =
+                EventLog::open(self.primary_attester.clone(), &config.eventlog_config).await?;
             self.eventlog = Some(Mutex::new(eventlog));
         }
 
@@ -163,13 +195,59 @@ impl AttestationAgent {
         Ok(AttestationAgent {
             primary_tee,
             config,
+            config_path: config_path.map(String::from),
             eventlog: None,
             initdata: None,
             additional_attesters,
             primary_attester: Arc::new(primary_tee.try_into()?),
+            #[cfg(feature = "token")]
+            token_cache: token::cache::TokenCache::default(),
         })
     }
 
+    #[cfg(feature = "token")]
+    async fn cached_token(
+        &self,
+        token_type: &str,
+        additional_data: Option<&str>,
+        source: token::Source,
+    ) -> Result<Vec<u8>> {
+        let key = token::cache::TokenKey {
+            token_type: token_type.to_string(),
+            additional_data: additional_data.map(String::from),
+        };
+        let config = self.config.read().await.token_configs.cache.clone();
+        self.token_cache.get_or_fetch(key, source, &config).await
+    }
+
+    /// Re-read the config file AA was launched with and apply the new
+    /// `token_configs`. Cached tokens are dropped as they may come from the
+    /// old KBS or CoCoAS.
+    ///
+    /// An invalid config file, or one that changes the items which can only
+    /// be applied at startup, is rejected and the running config is kept.
+    pub async fn reload_config(&self) -> Result<()> {
+        let Some(config_path) = &self.config_path else {
+            bail!("AA was launched without a config file");
+        };
+
+        let new = Config::try_from(&config_path[..])
+            .with_context(|| format!("failed to parse AA config file {config_path}"))?;
+
+        let mut config = self.config.write().await;
+        config.check_reloadable(&new)?;
+        if config.token_configs == new.token_configs {
+            debug!("AA config file {config_path} has no changes");
+            return Ok(());
+        }
+
+        config.token_configs = new.token_configs;
+        #[cfg(feature = "token")]
+        self.token_cache.clear();
+        info!("Reloaded token configs from {config_path}");
+        Ok(())
+    }
+
     /// Set initdata toml as status of current AA instance.
     pub fn set_initdata_toml(&mut self, initdata_toml: String) {
         self.initdata = Some(initdata_toml);
@@ -178,30 +256,30 @@ impl AttestationAgent {
 
 #[async_trait]
 impl AttestationAPIs for AttestationAgent {
-    async fn get_token(&self, token_type: &str, _additional_data: Option<&str>) -> Result<Vec<u8>> {
+    /// Get the token from the token cache, or fetch a new one if there is no
+    /// valid one cached. Tokens are cached by token type and additional data.
+    async fn get_token(&self, token_type: &str, additional_data: Option<&str>) -> Result<Vec<u8>> {
         let token_type = TokenType::from_str(token_type).context("Unsupported token type")?;
 
         match token_type {
             #[cfg(feature = "kbs")]
             token::TokenType::Kbs => {
-                token::kbs::KbsTokenGetter::new(
-                    self.config
-                        .read()
-                        .await
-                        .token_configs
-                        .kbs
-                        .as_ref()
-                        .ok_or(anyhow::anyhow!(
-                            "kbs token config not configured in config file"
-                        ))?,
-                )
-                .get_token(self.initdata.as_deref())
-                .await
+                let getter =
+                    token::kbs::KbsTokenGetter::new(
+                        self.config.read().await.token_configs.kbs.as_ref().ok_or(
+                            anyhow::anyhow!("kbs token config not configured in config file"),
+                        )?,
+                    );
+                let source = token::Source::Kbs {
+                    getter: Arc::new(getter),
+                    initdata: self.initdata.clone(),
+                };
+                self.cached_token("kbs", additional_data, source).await
             }
             // TODO: add initdata plaintext for CoCoAS token
             #[cfg(feature = "coco_as")]
             token::TokenType::CoCoAS => {
-                token::coco_as::CoCoASTokenGetter::new(
+                let getter = token::coco_as::CoCoASTokenGetter::new(
                     self.config
                         .read()
                         .await
@@ -211,9 +289,12 @@ impl AttestationAPIs for AttestationAgent {
                         .ok_or(anyhow::anyhow!(
                             "coco_as token config not configured in config file"
                         ))?,
-                )
-                .get_token(_additional_data)
-                .await
+                );
+                let source = token::Source::CoCoAS {
+                    getter: Arc::new(getter),
+                    additional_data: additional_data.map(String::from),
+                };
+                self.cached_token("coco_as", additional_data, source).await
             }
         }
     }
@@ -247,12 +328,44 @@ impl AttestationAPIs for AttestationAgent {
         Ok(evidence)
     }
 
+    async fn get_composite_evidence(&self, runtime_data: &[u8]) -> Result<CompositeEvidence> {
+        let primary = self.primary_attester.get_evidence(runtime_data.to_vec());
+        let additional = join_all(self.additional_attesters.iter().map(|(tee, attester)| {
+            let runtime_data = runtime_data.to_vec();
+            async move { (*tee, attester.get_evidence(runtime_data).await) }
+        }));
+        let (primary, additional) = futures::join!(primary, additional);
+
+        let primary_evidence = primary
+            .with_context(|| format!("get evidence of primary TEE {:?}", self.primary_tee))?
+            .to_string()
+            .into_bytes();
+        let additional_evidence = additional
+            .into_iter()
+            .map(|(tee, evidence)| {
+                let evidence = evidence
+                    .map(|evidence| evidence.to_string().into_bytes())
+                    .map_err(|e| {
+                        warn!("Failed to get evidence of {tee:?}: {e:#}");
+                        format!("{e:#}")
+                    });
+                (tee, evidence)
+            })
+            .collect();
+
+        Ok(CompositeEvidence {
+            primary_tee: self.primary_tee,
+            primary_evidence,
+            additional_evidence,
+        })
+    }
+
     /// Extend runtime measurement register. Parameters
     /// - `events`: a event slice. Any single event will be calculated into a hash digest to extend the current
     /// platform's RTMR.
     /// - `register_index`: a target PCR that will be used to extend RTMR. Note that different platform
-    /// would have its own strategy to map a PCR index into a architectual RTMR index. If not given, a default one
-    /// will be used.
+    /// would have its own strategy to map a PCR index into a architectual RTMR index. If not given, the PCR
+    /// configured for the domain, or a default one will be used.
     async fn extend_runtime_measurement(
         &self,
         domain: &str,
@@ -268,8 +381,8 @@ impl AttestationAPIs for AttestationAgent {
             let config = self.config.read().await;
 
             let pcr = register_index.unwrap_or_else(|| {
-                let pcr = config.eventlog_config.init_pcr;
-                debug!("No PCR index provided, use default {pcr}");
+                let pcr = config.eventlog_config.pcr_of(domain);
+                debug!("No PCR index provided, use {pcr} of domain {domain}");
                 pcr
             });
 
@@ -284,9 +397,48 @@ impl AttestationAPIs for AttestationAgent {
     }
 
     /// Perform the initdata binding. If current platform does not support initdata
-    /// binding, return `InitdataResult::Unsupported`.
+    /// binding, return `InitdataResult::Unsupported`. Cached tokens are dropped
+    /// as their evidence does not cover the new initdata.
     async fn bind_init_data(&self, init_data: &[u8]) -> Result<InitDataResult> {
-        self.primary_attester.bind_init_data(init_data).await
+        let res = self.primary_attester.bind_init_data(init_data).await?;
+        #[cfg(feature = "token")]
+        self.token_cache.clear();
+        Ok(res)
+    }
+
+    /// Replay the eventlog and check it against the registers of all the
+    /// configured PCRs. Once they disagree, AA refuses to append to the
+    /// eventlog.
+    async fn verify_event_log(&self) -> Result<()> {
+        let Some(ref eventlog) = self.eventlog else {
+            bail!("Extend eventlog not enabled when launching!");
+        };
+
+        let pcrs = self.config.read().await.eventlog_config.pcrs();
+        eventlog.lock().await.verify(&pcrs).await
+    }
+
+    /// Read back the eventlog entries. A PCR given by `register_index` is
+    /// mapped to the measurement register of the platform first, so all the
+    /// entries extended to that register are returned.
+    async fn get_event_log(
+        &self,
+        domain: Option<&str>,
+        register_index: Option<u64>,
+    ) -> Result<Vec<EventLogEntry>> {
+        let Some(ref eventlog) = self.eventlog else {
+            bail!("Extend eventlog not enabled when launching!");
+        };
+
+        let register = register_index.map(|pcr| self.primary_attester.pcr_to_ccmr(pcr));
+        let entries = eventlog.lock().await.entries()?;
+        let entries = entries
+            .into_iter()
+            .filter(|entry| domain.map_or(true, |domain| entry.domain == domain))
+            .filter(|entry| register.map_or(true, |register| entry.register_index == register))
+            .collect();
+
+        Ok(entries)
     }
 
     /// Get the tee type of current platform. If no platform is detected,
@@ -307,11 +459,16 @@ mod tests {
         assert_eq!(aa.get_tee_type(), Tee::Sample);
         assert!(aa.get_token("kbs", None).await.is_err());
         assert!(aa.get_evidence(&[]).await.is_ok());
+        let composite = aa.get_composite_evidence(&[]).await.unwrap();
+        assert_eq!(composite.primary_tee, Tee::Sample);
+        assert!(composite.additional_evidence.is_empty());
         assert!(aa.bind_init_data(&[]).await.is_ok());
         assert!(aa
             .extend_runtime_measurement("domain", "event", "operation", None)
             .await
             .is_err());
+        assert!(aa.get_event_log(None, None).await.is_err());
+        assert!(aa.verify_event_log().await.is_err());
     }
 
     #[tokio::test]
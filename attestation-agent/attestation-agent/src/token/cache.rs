@@ -0,0 +1,289 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Cache of attestation tokens, so that not every token request leads to a
+//! new attestation.
+//!
+//! Tokens are cached by token type and additional data. A cached token is
+//! served until it expires due to its `exp` claim, or reaches the max age.
+//! When a token is served within the refresh margin of its end, a new one is
+//! fetched in the background.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use kbs_protocol::token_provider::Token;
+use log::{debug, warn};
+use tokio::sync::Mutex;
+
+use crate::config::TokenCacheConfig;
+
+/// Fetches new tokens of one token type.
+#[async_trait]
+pub trait TokenSource: Clone + Send + Sync + 'static {
+    async fn fetch(&self) -> Result<Vec<u8>>;
+
+    /// The JWT carried by `token`, if any, to check its validity.
+    fn jwt(&self, token: &[u8]) -> Option<Token>;
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TokenKey {
+    pub token_type: String,
+    pub additional_data: Option<String>,
+}
+
+struct Entry {
+    token: Vec<u8>,
+    jwt: Option<Token>,
+    fetched_at: Instant,
+
+    /// Whether a background refresh is in flight.
+    refreshing: bool,
+}
+
+impl Entry {
+    fn new(token: Vec<u8>, source: &impl TokenSource) -> Self {
+        let jwt = source.jwt(&token);
+        Self {
+            token,
+            jwt,
+            fetched_at: Instant::now(),
+            refreshing: false,
+        }
+    }
+
+    /// How much longer the token can be served, or `None` if it cannot.
+    fn remaining(&self, max_age: Duration) -> Option<Duration> {
+        if let Some(jwt) = &self.jwt {
+            jwt.check_valid().ok()?;
+        }
+
+        let by_age = max_age
+            .checked_sub(self.fetched_at.elapsed())
+            .filter(|d| !d.is_zero())?;
+        match self.jwt.as_ref().and_then(Token::valid_for) {
+            Some(by_exp) => Some(by_exp.min(by_age)),
+            None => Some(by_age),
+        }
+    }
+}
+
+/// A lazily filled entry. Requests of the same token wait for each other,
+/// so that a burst of requests leads to a single attestation.
+type Slot = Arc<Mutex<Option<Entry>>>;
+
+#[derive(Default)]
+pub struct TokenCache {
+    slots: StdMutex<HashMap<TokenKey, Slot>>,
+}
+
+impl TokenCache {
+    /// Get the token of `key` from the cache, or fetch it from `source`.
+    pub async fn get_or_fetch<S: TokenSource>(
+        &self,
+        key: TokenKey,
+        source: S,
+        config: &TokenCacheConfig,
+    ) -> Result<Vec<u8>> {
+        let max_age = Duration::from_secs(config.max_age);
+        if max_age.is_zero() {
+            return source.fetch().await;
+        }
+
+        let slot = self
+            .slots
+            .lock()
+            .expect("token cache poisoned")
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let mut entry = slot.lock().await;
+        if let Some(cached) = entry.as_mut() {
+            if let Some(remaining) = cached.remaining(max_age) {
+                if remaining <= Duration::from_secs(config.refresh_margin) && !cached.refreshing {
+                    debug!("refresh token `{}` in background", key.token_type);
+                    cached.refreshing = true;
+                    tokio::spawn(refresh(slot.clone(), source));
+                }
+
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let token = source.fetch().await?;
+        *entry = Some(Entry::new(token.clone(), &source));
+        drop(entry);
+
+        self.purge(max_age);
+        Ok(token)
+    }
+
+    /// Drop all the cached tokens.
+    pub fn clear(&self) {
+        self.slots.lock().expect("token cache poisoned").clear();
+    }
+
+    /// Drop the entries that can no longer be served.
+    fn purge(&self, max_age: Duration) {
+        self.slots
+            .lock()
+            .expect("token cache poisoned")
+            .retain(|_, slot| match slot.try_lock() {
+                Ok(entry) => entry
+                    .as_ref()
+                    .is_some_and(|e| e.refreshing || e.remaining(max_age).is_some()),
+                Err(_) => true,
+            });
+    }
+}
+
+async fn refresh(slot: Slot, source: impl TokenSource) {
+    let res = source.fetch().await;
+    let mut entry = slot.lock().await;
+    match res {
+        Ok(token) => *entry = Some(Entry::new(token, &source)),
+        Err(e) => {
+            warn!("failed to refresh token in background: {e:#}");
+            if let Some(entry) = entry.as_mut() {
+                entry.refreshing = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use kbs_protocol::token_provider::Token;
+    use rstest::rstest;
+
+    use super::{TokenCache, TokenKey, TokenSource};
+    use crate::config::TokenCacheConfig;
+
+    /// A JWT expired in 1970.
+    const EXPIRED_JWT: &str = "e30.eyJleHAiOjF9.c2ln";
+
+    #[derive(Clone)]
+    struct CountingSource {
+        fetched: Arc<AtomicUsize>,
+        token: &'static str,
+    }
+
+    #[async_trait]
+    impl TokenSource for CountingSource {
+        async fn fetch(&self) -> Result<Vec<u8>> {
+            self.fetched.fetch_add(1, Ordering::SeqCst);
+            Ok(self.token.as_bytes().to_vec())
+        }
+
+        fn jwt(&self, token: &[u8]) -> Option<Token> {
+            Token::new(String::from_utf8(token.to_vec()).ok()?).ok()
+        }
+    }
+
+    fn key(additional_data: Option<&str>) -> TokenKey {
+        TokenKey {
+            token_type: "kbs".into(),
+            additional_data: additional_data.map(String::from),
+        }
+    }
+
+    #[rstest]
+    #[case::cached("opaque", 300, 0, 1)]
+    #[case::disabled("opaque", 0, 0, 3)]
+    #[case::expired(EXPIRED_JWT, 300, 0, 3)]
+    #[tokio::test]
+    async fn fetch_count(
+        #[case] token: &'static str,
+        #[case] max_age: u64,
+        #[case] refresh_margin: u64,
+        #[case] expected: usize,
+    ) {
+        let cache = TokenCache::default();
+        let fetched = Arc::new(AtomicUsize::new(0));
+        let source = CountingSource {
+            fetched: fetched.clone(),
+            token,
+        };
+        let config = TokenCacheConfig {
+            refresh_margin,
+            max_age,
+        };
+
+        for _ in 0..3 {
+            let res = cache
+                .get_or_fetch(key(None), source.clone(), &config)
+                .await
+                .unwrap();
+            assert_eq!(res, token.as_bytes());
+        }
+        assert_eq!(fetched.load(Ordering::SeqCst), expected);
+    }
+
+    #[tokio::test]
+    async fn keyed_by_additional_data() {
+        let cache = TokenCache::default();
+        let fetched = Arc::new(AtomicUsize::new(0));
+        let source = CountingSource {
+            fetched: fetched.clone(),
+            token: "opaque",
+        };
+        let config = TokenCacheConfig::default();
+
+        for additional_data in [None, Some("a"), Some("a"), Some("b")] {
+            cache
+                .get_or_fetch(key(additional_data), source.clone(), &config)
+                .await
+                .unwrap();
+        }
+        assert_eq!(fetched.load(Ordering::SeqCst), 3);
+
+        cache.clear();
+        cache
+            .get_or_fetch(key(None), source.clone(), &config)
+            .await
+            .unwrap();
+        assert_eq!(fetched.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn refresh_in_background() {
+        let cache = TokenCache::default();
+        let fetched = Arc::new(AtomicUsize::new(0));
+        let source = CountingSource {
+            fetched: fetched.clone(),
+            token: "opaque",
+        };
+        let config = TokenCacheConfig {
+            refresh_margin: 300,
+            max_age: 300,
+        };
+
+        cache
+            .get_or_fetch(key(None), source.clone(), &config)
+            .await
+            .unwrap();
+        cache
+            .get_or_fetch(key(None), source.clone(), &config)
+            .await
+            .unwrap();
+        assert_eq!(fetched.load(Ordering::SeqCst), 1);
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(fetched.load(Ordering::SeqCst), 2);
+    }
+}
@@ -11,6 +11,9 @@ pub mod kbs;
 #[cfg(feature = "coco_as")]
 pub mod coco_as;
 
+#[cfg(feature = "token")]
+pub mod cache;
+
 #[derive(EnumString, Clone, Copy)]
 pub enum TokenType {
     #[cfg(feature = "kbs")]
@@ -21,3 +24,56 @@ pub enum TokenType {
     #[strum(serialize = "coco_as")]
     CoCoAS,
 }
+
+/// Fetches the tokens of one token type for the [`cache::TokenCache`].
+#[cfg(feature = "token")]
+#[derive(Clone)]
+pub(crate) enum Source {
+    #[cfg(feature = "kbs")]
+    Kbs {
+        getter: std::sync::Arc<kbs::KbsTokenGetter>,
+        initdata: Option<String>,
+    },
+
+    #[cfg(feature = "coco_as")]
+    CoCoAS {
+        getter: std::sync::Arc<coco_as::CoCoASTokenGetter>,
+        additional_data: Option<String>,
+    },
+}
+
+#[cfg(feature = "token")]
+#[async_trait::async_trait]
+impl cache::TokenSource for Source {
+    async fn fetch(&self) -> anyhow::Result<Vec<u8>> {
+        match *self {
+            #[cfg(feature = "kbs")]
+            Source::Kbs {
+                ref getter,
+                ref initdata,
+            } => getter.get_token(initdata.as_deref()).await,
+            #[cfg(feature = "coco_as")]
+            Source::CoCoAS {
+                ref getter,
+                ref additional_data,
+            } => getter.get_token(additional_data.as_deref()).await,
+        }
+    }
+
+    fn jwt(&self, token: &[u8]) -> Option<kbs_protocol::token_provider::Token> {
+        match *self {
+            // The KBS token is wrapped together with the TEE key pair.
+            #[cfg(feature = "kbs")]
+            Source::Kbs { .. } => {
+                let message: serde_json::Value = serde_json::from_slice(token).ok()?;
+                let token = message.get("token")?.as_str()?;
+                kbs_protocol::token_provider::Token::new(token.to_string()).ok()
+            }
+            #[cfg(feature = "coco_as")]
+            Source::CoCoAS { .. } => {
+                let token = String::from_utf8(token.to_vec()).ok()?;
+                kbs_protocol::token_provider::Token::new(token).ok()
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Encrypt or decrypt the layers of an OCI image layout directory offline,
+//! without skopeo and a running coco_keyprovider service.
+
+use std::path::PathBuf;
+
+use anyhow::*;
+use clap::{Args, Parser};
+use coco_keyprovider::layout::{decrypt_layout, encrypt_layout, EncryptParams};
+use jwt_simple::prelude::Ed25519KeyPair;
+use tokio::fs;
+
+#[derive(Parser)]
+#[command(name = "coco-image-crypt")]
+#[command(bin_name = "coco-image-crypt")]
+#[command(author, version, about, long_about = None)]
+enum Cli {
+    /// Encrypt the layers of an OCI image layout
+    Encrypt(EncryptArgs),
+
+    /// Decrypt the layers of an OCI image layout, e.g. to verify an
+    /// encrypted image
+    Decrypt(DecryptArgs),
+}
+
+#[derive(Args)]
+struct LayoutArgs {
+    /// Path of the source OCI image layout directory
+    #[arg(short, long)]
+    input: PathBuf,
+
+    /// Path of the OCI image layout directory to write the result to. It
+    /// must not contain an image layout yet
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct EncryptArgs {
+    #[command(flatten)]
+    layout: LayoutArgs,
+
+    /// Path of the KEK (32 bytes) to wrap the layer keys with
+    #[arg(short, long, required_unless_present_any = ["sample", "kms_config"])]
+    keypath: Option<String>,
+
+    /// Resource URI of the KEK, e.g. `kbs:///default/image-kek/1`. A random
+    /// one is generated if not given. For a KMS provider, the id of the key
+    /// inside the KMS
+    #[arg(long, required_if_eq_any = [("provider", "aliyun"), ("provider", "ehsm")])]
+    keyid: Option<String>,
+
+    /// Provider of the KEK, `kbs`, `aliyun` or `ehsm`. The KMS providers
+    /// need the `kms_config` and the matching cargo feature
+    #[arg(long, default_value = "kbs", value_parser = ["kbs", "aliyun", "ehsm"])]
+    provider: String,
+
+    /// Path of the configuration file to create the KMS client
+    #[arg(long, required_if_eq_any = [("provider", "aliyun"), ("provider", "ehsm")])]
+    kms_config: Option<String>,
+
+    /// Use the hard coded KEK of the sample KBC instead of `keypath`
+    #[arg(long, default_value = "false")]
+    sample: bool,
+
+    /// Algorithm to wrap the layer keys, `A256GCM` or `A256CTR`
+    #[arg(long, default_value = "A256GCM", value_parser = ["A256GCM", "A256CTR"])]
+    algorithm: String,
+
+    /// Index of a layer to encrypt, can be given multiple times. Negative
+    /// indexes count from the last layer. All layers are encrypted if not
+    /// given
+    #[arg(long = "layer", allow_negative_numbers = true)]
+    layers: Vec<i64>,
+
+    /// Cipher to encrypt the layers with, `AES_256_CTR_HMAC_SHA256`
+    /// (default) or `AES_256_GCM_CHUNKED`
+    #[arg(long, value_parser = ["AES_256_CTR_HMAC_SHA256", "AES_256_GCM_CHUNKED"])]
+    layer_cipher: Option<String>,
+
+    /// Address of Key Broker Service. If both `auth_private_key` and this
+    /// field are given, the KEK will be registered into the KBS
+    #[arg(long, requires = "auth_private_key")]
+    kbs: Option<String>,
+
+    /// Private key used to authenticate the resource registration endpoint
+    /// token (JWT) to Key Broker Service
+    #[arg(short, long, requires = "kbs")]
+    auth_private_key: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct DecryptArgs {
+    #[command(flatten)]
+    layout: LayoutArgs,
+
+    /// Path of the KEK (32 bytes) to unwrap the layer keys with
+    #[arg(short, long, required_unless_present = "sample")]
+    keypath: Option<String>,
+
+    /// Use the hard coded KEK of the sample KBC instead of `keypath`
+    #[arg(long, default_value = "false")]
+    sample: bool,
+}
+
+/// Build the keyprovider parameters, i.e. `<key1>=<value1>::<key2>=<value2>`
+fn keyprovider_params(pairs: &[(&str, Option<&str>)]) -> String {
+    pairs
+        .iter()
+        .filter_map(|(key, value)| value.map(|value| format!("{key}={value}")))
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+
+    match Cli::parse() {
+        Cli::Encrypt(args) => {
+            let auth_private_key = match args.auth_private_key {
+                Some(key_path) => {
+                    let pem = fs::read_to_string(key_path)
+                        .await
+                        .context("open auth private key")?;
+                    Some(Ed25519KeyPair::from_pem(&pem)?)
+                }
+                None => None,
+            };
+            let kbs = args
+                .kbs
+                .map(|addr| addr.parse())
+                .transpose()
+                .context("illegal KBS address")?;
+            let params = EncryptParams {
+                kbs,
+                auth_private_key,
+                params: keyprovider_params(&[
+                    ("sample", args.sample.then_some("true")),
+                    ("keypath", args.keypath.as_deref()),
+                    ("keyid", args.keyid.as_deref()),
+                    ("algorithm", Some(args.algorithm.as_str())),
+                    ("provider", Some(args.provider.as_str())),
+                    ("kms_config", args.kms_config.as_deref()),
+                ]),
+                layers: args.layers,
+                layer_cipher: args.layer_cipher,
+            };
+            encrypt_layout(&args.layout.input, &args.layout.output, &params).await
+        }
+        Cli::Decrypt(args) => {
+            let params = keyprovider_params(&[
+                ("sample", args.sample.then_some("true")),
+                ("keypath", args.keypath.as_deref()),
+            ]);
+            decrypt_layout(&args.layout.input, &args.layout.output, &params).await
+        }
+    }
+}
@@ -22,6 +22,16 @@ pub enum Algorithm {
     A256CTR,
 }
 
+impl Algorithm {
+    /// Length of the iv (or nonce) in bytes.
+    pub fn iv_len(&self) -> usize {
+        match self {
+            Algorithm::A256GCM => 12,
+            Algorithm::A256CTR => 16,
+        }
+    }
+}
+
 impl Display for Algorithm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -51,3 +61,18 @@ pub fn encrypt(data: &[u8], key: &[u8], iv: &[u8], algorithm: &Algorithm) -> Res
         }
     }
 }
+
+pub fn decrypt(data: &[u8], key: &[u8], iv: &[u8], algorithm: &Algorithm) -> Result<Vec<u8>> {
+    match algorithm {
+        Algorithm::A256GCM => {
+            use aes_gcm::KeyInit;
+            let decryption_key = Key::<Aes256Gcm>::from_slice(key);
+            let cipher = Aes256Gcm::new(decryption_key);
+            let nonce = Nonce::from_slice(iv);
+            cipher
+                .decrypt(nonce, data.as_ref())
+                .map_err(|e| anyhow!("Decrypt failed: {:?}", e))
+        }
+        Algorithm::A256CTR => encrypt(data, key, iv, algorithm),
+    }
+}
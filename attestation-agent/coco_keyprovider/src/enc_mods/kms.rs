@@ -0,0 +1,229 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Wrap the optsdata with a key inside a KMS through CDH's [`Encrypter`].
+//! The result is an [`AnnotationPacketV2`] that CDH unwraps in-guest with the
+//! [`Decrypter`](kms::Decrypter) of the same provider.
+
+use anyhow::*;
+use base64::Engine;
+use kms::{Annotations, Encrypter, ProviderSettings};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+/// Version of the [`AnnotationPacketV2`] generated.
+const ANNOTATION_PACKET_VERSION: &str = "0.1.0";
+
+/// `AnnotationPacketV2` is what CDH expects in the
+/// `org.opencontainers.image.enc.keys.provider.attestation-agent`
+/// annotation when the optsdata is wrapped by a KMS. It follows the
+/// definition in CDH's `image::annotation_packet::v2`.
+#[derive(Serialize, Deserialize)]
+pub struct AnnotationPacketV2 {
+    // Version of the AnnotationPacket
+    pub version: String,
+    // Key ID of the KEK inside the KMS
+    pub kid: String,
+    // Encrypted key to unwrap (base64-encoded)
+    pub wrapped_data: String,
+    // The KMS which holds the KEK, s.t. `aliyun`
+    pub provider: String,
+    // Only used when provider is `kbs`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv: Option<String>,
+    // Only used when provider is `kbs`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrap_type: Option<String>,
+    // Public parameters for CDH to create the KMS client
+    pub provider_settings: ProviderSettings,
+    // KMS specific fields to locate the key inside the KMS
+    pub annotations: Annotations,
+}
+
+/// Configuration to create the client of Alibaba Cloud KMS, which is read
+/// from the `kms_config` file.
+#[cfg(feature = "aliyun")]
+#[derive(Deserialize)]
+struct AliyunConfig {
+    /// path of the client key to access the KMS
+    client_key_file_path: String,
+
+    /// path of the password file of the client key
+    password_file_path: String,
+
+    /// path of the CA cert of the KMS instance
+    cert_path: String,
+
+    /// id of the KMS instance
+    kms_instance_id: String,
+}
+
+/// Configuration to create the client of eHSM-KMS, which is read from the
+/// `kms_config` file.
+#[cfg(feature = "ehsm")]
+#[derive(Deserialize)]
+struct EhsmConfig {
+    /// path of the credential file, which contains `AppId` and `ApiKey`
+    credential_file_path: String,
+
+    /// endpoint of the eHSM service
+    endpoint: String,
+}
+
+/// Create an [`Encrypter`] of the given provider with the configuration in
+/// the `kms_config` file. The returned [`ProviderSettings`] are the public
+/// parameters for CDH to create the client in-guest.
+async fn new_encrypter(
+    provider: &str,
+    kms_config: &str,
+) -> Result<(Box<dyn Encrypter>, ProviderSettings)> {
+    let _config = fs::read(kms_config).await.context("read KMS config")?;
+    match provider {
+        #[cfg(feature = "aliyun")]
+        "aliyun" => {
+            use kms::plugins::aliyun::AliyunKmsClient;
+
+            let config: AliyunConfig =
+                serde_json::from_slice(&_config).context("parse aliyun KMS config")?;
+            let client_key = fs::read_to_string(&config.client_key_file_path)
+                .await
+                .context("read client key")?;
+            let password = fs::read_to_string(&config.password_file_path)
+                .await
+                .context("read password")?;
+            let cert_pem = fs::read_to_string(&config.cert_path)
+                .await
+                .context("read KMS CA cert")?;
+            let client =
+                AliyunKmsClient::new(&client_key, &config.kms_instance_id, &password, &cert_pem)
+                    .context("create aliyun KMS client")?;
+            let provider_settings = client
+                .export_provider_settings()
+                .context("export aliyun provider settings")?;
+            Ok((Box::new(client), provider_settings))
+        }
+        #[cfg(feature = "ehsm")]
+        "ehsm" => {
+            use kms::plugins::ehsm::EhsmKmsClient;
+
+            #[derive(Deserialize)]
+            #[serde(rename_all = "PascalCase")]
+            struct Credential {
+                app_id: String,
+                api_key: String,
+            }
+
+            let config: EhsmConfig =
+                serde_json::from_slice(&_config).context("parse ehsm KMS config")?;
+            let credential = fs::read(&config.credential_file_path)
+                .await
+                .context("read ehsm credential")?;
+            let credential: Credential =
+                serde_json::from_slice(&credential).context("parse ehsm credential")?;
+            let client =
+                EhsmKmsClient::new(&credential.app_id, &credential.api_key, &config.endpoint)
+                    .context("create ehsm KMS client")?;
+            let provider_settings = client
+                .export_provider_settings()
+                .context("export ehsm provider settings")?;
+            Ok((Box::new(client), provider_settings))
+        }
+        others => bail!("KMS provider {others} is not supported"),
+    }
+}
+
+/// Wrap the optsdata with the key `kid` inside the KMS, and return the
+/// serialized `AnnotationPacketV2`.
+async fn wrap_optsdata(
+    encrypter: &mut dyn Encrypter,
+    provider: &str,
+    provider_settings: ProviderSettings,
+    kid: &str,
+    optsdata: &[u8],
+) -> Result<String> {
+    let (wrapped_data, annotations) = encrypter
+        .encrypt(optsdata, kid)
+        .await
+        .context("wrap optsdata with KMS")?;
+
+    let annotation = AnnotationPacketV2 {
+        version: ANNOTATION_PACKET_VERSION.into(),
+        kid: kid.into(),
+        wrapped_data: base64::engine::general_purpose::STANDARD.encode(wrapped_data),
+        provider: provider.into(),
+        iv: None,
+        wrap_type: None,
+        provider_settings,
+        annotations,
+    };
+
+    serde_json::to_string(&annotation).context("serialize AnnotationPacketV2")
+}
+
+/// Wrap the optsdata with the key `kid` inside the KMS of `provider`, whose
+/// client is created with the configuration in the `kms_config` file.
+pub(crate) async fn kms_optsdata_gen_anno(
+    provider: &str,
+    kms_config: &str,
+    kid: &str,
+    optsdata: &[u8],
+) -> Result<String> {
+    let (mut encrypter, provider_settings) = new_encrypter(provider, kms_config).await?;
+    wrap_optsdata(
+        encrypter.as_mut(),
+        provider,
+        provider_settings,
+        kid,
+        optsdata,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use kms::{Annotations, Encrypter, ProviderSettings, Result};
+    use serde_json::json;
+
+    use super::AnnotationPacketV2;
+
+    struct MockEncrypter;
+
+    #[async_trait]
+    impl Encrypter for MockEncrypter {
+        async fn encrypt(&mut self, data: &[u8], key_id: &str) -> Result<(Vec<u8>, Annotations)> {
+            let mut wrapped = key_id.as_bytes().to_vec();
+            wrapped.extend_from_slice(data);
+            let annotations = json!({ "iv": "mock-iv" });
+            Ok((wrapped, annotations.as_object().unwrap().clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wrap_optsdata() {
+        let provider_settings: ProviderSettings = json!({ "kms_instance_id": "kst-1" })
+            .as_object()
+            .unwrap()
+            .clone();
+        let annotation = super::wrap_optsdata(
+            &mut MockEncrypter,
+            "aliyun",
+            provider_settings.clone(),
+            "key-1",
+            b"optsdata",
+        )
+        .await
+        .expect("wrap optsdata");
+
+        let packet: AnnotationPacketV2 = serde_json::from_str(&annotation).unwrap();
+        assert_eq!(packet.provider, "aliyun");
+        assert_eq!(packet.kid, "key-1");
+        assert_eq!(packet.wrapped_data, "a2V5LTFvcHRzZGF0YQ==");
+        assert_eq!(packet.provider_settings, provider_settings);
+        assert_eq!(packet.annotations["iv"], "mock-iv");
+        assert!(packet.iv.is_none());
+        assert!(packet.wrap_type.is_none());
+    }
+}
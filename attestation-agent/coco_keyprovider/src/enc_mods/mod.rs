@@ -18,6 +18,8 @@ use self::{crypto::Algorithm, kbs::register_kek};
 
 mod crypto;
 mod kbs;
+#[cfg(feature = "kms")]
+mod kms;
 
 /// `AnnotationPacket` is what a encrypted image layer's
 /// `org.opencontainers.image.enc.keys.provider.attestation-agent`
@@ -58,6 +60,16 @@ struct InputParams {
     /// - `A256GCM`: aes 256 gcm (default)
     /// - `A256CTR`: aes 256 ctr
     algorithm: Algorithm,
+
+    /// Provider of the KEK. By default `kbs`. Other values
+    /// (s.t. `aliyun`, `ehsm`) mean the optsdata is wrapped
+    /// by the KMS key `keyid`, and an `AnnotationPacketV2`
+    /// is generated.
+    provider: String,
+
+    /// Path of the configuration file to create the KMS
+    /// client. Only used when `provider` is a KMS.
+    kms_config: Option<String>,
 }
 
 const HARD_CODED_KEYID: &str = "kbs:///default/test-key/1";
@@ -68,6 +80,8 @@ const DEFAULT_KEY_REPO_PATH: &str = "/default/image-kek";
 
 const KBS_RESOURCE_URL_PREFIX: &str = "kbs://";
 
+const KBS_PROVIDER: &str = "kbs";
+
 fn parse_input_params(input: &str) -> Result<InputParams> {
     let map: HashMap<&str, &str> = input
         .split("::")
@@ -83,14 +97,18 @@ fn parse_input_params(input: &str) -> Result<InputParams> {
     let keyid = map.get("keyid").map(|id| id.to_string());
     let keypath = map.get("keypath").map(|p| p.to_string());
     let algorithm = map
-        .get("keypath")
+        .get("algorithm")
         .map(|alg| (*alg).try_into().unwrap_or_default())
         .unwrap_or_default();
+    let provider = map.get("provider").unwrap_or(&KBS_PROVIDER).to_string();
+    let kms_config = map.get("kms_config").map(|p| p.to_string());
     Ok(InputParams {
         sample,
         keyid,
         keypath,
         algorithm,
+        provider,
+        kms_config,
     })
 }
 
@@ -103,7 +121,7 @@ async fn generate_key_parameters(input_params: &InputParams) -> Result<(Vec<u8>,
             info!("Use sample keyprovider (HARDCODED KEY and IV)");
             Ok((
                 crypto::HARDCODED_KEY.to_vec(),
-                [0; 12].to_vec(),
+                vec![0; input_params.algorithm.iv_len()],
                 HARD_CODED_KEYID.into(),
             ))
         }
@@ -112,7 +130,7 @@ async fn generate_key_parameters(input_params: &InputParams) -> Result<(Vec<u8>,
             Some(kpath) => {
                 debug!("use given key from: {kpath}");
                 let key = fs::read(kpath).await.context("read Key file failed")?;
-                let mut iv = [0; 12];
+                let mut iv = vec![0; input_params.algorithm.iv_len()];
                 rand::rngs::OsRng.try_fill_bytes(&mut iv)?;
                 let kid = match &input_params.keyid {
                     Some(kid) => kid.to_string(),
@@ -123,12 +141,12 @@ async fn generate_key_parameters(input_params: &InputParams) -> Result<(Vec<u8>,
                     }
                 };
 
-                Ok((key.to_vec(), iv.to_vec(), kid))
+                Ok((key.to_vec(), iv, kid))
             }
             None => {
                 debug!("no key input, generate a random key");
 
-                let mut iv = [0; 12];
+                let mut iv = vec![0; input_params.algorithm.iv_len()];
                 rand::rngs::OsRng.try_fill_bytes(&mut iv)?;
 
                 let mut key = [0; 32];
@@ -142,7 +160,7 @@ async fn generate_key_parameters(input_params: &InputParams) -> Result<(Vec<u8>,
                         format!("{DEFAULT_KEY_REPO_PATH}/{tag}")
                     }
                 };
-                Ok((key.to_vec(), iv.to_vec(), kid))
+                Ok((key.to_vec(), iv, kid))
             }
         },
     }
@@ -185,15 +203,24 @@ fn normalize_path(keyid: &str) -> Result<(String, String)> {
 /// | keyid     | a KBS Resource URI, s.t. `kbs://..`  | Specify the KEK of this image. keyid field will be included in AnnotationPacket                  |
 /// | keypath   | path to the KEK, e.g. `/home/key`    | Specify the KEK to encrypted the image in local filesystem                                       |
 /// | algorithm | `A256GCM` or `A256CTR`               | Encryption algorithm, included in the `wrap_type` field of AnnotationPacket. By default `A256GCM`|
+/// | provider  | `kbs`, `aliyun` or `ehsm`            | Provider of the KEK. By default `kbs`. For a KMS, the optsdata is wrapped by the KMS key `keyid` |
+/// | kms_config| path to the KMS client config        | Configuration to create the KMS client. Required if `provider` is a KMS                          |
 pub async fn enc_optsdata_gen_anno(
     kbs_parameter: (&Option<Url>, &Option<Ed25519KeyPair>),
     optsdata: &[u8],
     params: Vec<String>,
 ) -> Result<String> {
     let input_params = parse_input_params(&params[0])?;
+    if input_params.provider != KBS_PROVIDER {
+        return kms_optsdata_gen_anno(&input_params, optsdata).await;
+    }
+
     let (key, iv, kid) = generate_key_parameters(&input_params)
         .await
         .context("generating key params")?;
+    if key.len() != 32 {
+        bail!("KEK must be 32 bytes, got {} bytes", key.len());
+    }
 
     let (kbs_addr, k_path) = normalize_path(&kid)?;
 
@@ -222,10 +249,105 @@ pub async fn enc_optsdata_gen_anno(
     serde_json::to_string(&annotation).map_err(|_| anyhow!("Serialize annotation failed"))
 }
 
+/// Wrap the optsdata with a KMS key and generate an `AnnotationPacketV2`.
+async fn kms_optsdata_gen_anno(input_params: &InputParams, optsdata: &[u8]) -> Result<String> {
+    let Some(kid) = &input_params.keyid else {
+        bail!("`keyid` of the KMS key must be given");
+    };
+    let Some(kms_config) = &input_params.kms_config else {
+        bail!("`kms_config` must be given to create the KMS client");
+    };
+
+    #[cfg(feature = "kms")]
+    {
+        let annotation =
+            kms::kms_optsdata_gen_anno(&input_params.provider, kms_config, kid, optsdata)
+                .await
+                .with_context(|| format!("wrap optsdata with {} KMS", input_params.provider))?;
+        info!(
+            "wrap optsdata with {} KMS succeeded.",
+            input_params.provider
+        );
+        Ok(annotation)
+    }
+
+    #[cfg(not(feature = "kms"))]
+    {
+        let _ = (kid, kms_config, optsdata);
+        bail!(
+            "provider {} is not supported, please rebuild with the `kms` feature",
+            input_params.provider
+        )
+    }
+}
+
+/// Decrypt the optsdata wrapped in the given [`AnnotationPacket`], which is
+/// the reverse of [`enc_optsdata_gen_anno`]. The params follow the same
+/// format, but only `sample` and `keypath` are used to get the KEK.
+pub async fn dec_optsdata_from_anno(annotation: &[u8], params: &str) -> Result<Vec<u8>> {
+    let input_params = parse_input_params(params)?;
+    let annotation: serde_json::Value =
+        serde_json::from_slice(annotation).context("parse AnnotationPacket")?;
+    if let Some(provider) = annotation.get("provider").and_then(|p| p.as_str()) {
+        if provider != KBS_PROVIDER {
+            bail!("optsdata wrapped by {provider} KMS can only be unwrapped by CDH");
+        }
+    }
+    let annotation: AnnotationPacket =
+        serde_json::from_value(annotation).context("parse AnnotationPacket")?;
+
+    let key = match (input_params.sample, &input_params.keypath) {
+        (true, _) => crypto::HARDCODED_KEY.to_vec(),
+        (false, Some(kpath)) => fs::read(kpath).await.context("read Key file failed")?,
+        (false, None) => bail!("either `sample` or `keypath` must be given to get the KEK"),
+    };
+    if key.len() != 32 {
+        bail!("KEK must be 32 bytes, got {} bytes", key.len());
+    }
+
+    let algorithm: Algorithm = annotation
+        .wrap_type
+        .parse()
+        .map_err(|_| anyhow!("unsupported wrap type {}", annotation.wrap_type))?;
+
+    let engine = base64::engine::general_purpose::STANDARD;
+    let wrapped_data = engine
+        .decode(annotation.wrapped_data)
+        .context("base64 decode wrapped data")?;
+    let iv = engine.decode(annotation.iv).context("base64 decode iv")?;
+
+    crypto::decrypt(&wrapped_data, &key, &iv, &algorithm)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
+    #[rstest]
+    #[case("sample=true")]
+    #[case("sample=true::algorithm=A256CTR")]
+    #[tokio::test]
+    async fn test_optsdata_roundtrip(#[case] params: &str) {
+        let optsdata = b"layer block cipher options";
+        let annotation =
+            crate::enc_mods::enc_optsdata_gen_anno((&None, &None), optsdata, vec![params.into()])
+                .await
+                .expect("encrypt failed");
+        let decrypted = crate::enc_mods::dec_optsdata_from_anno(annotation.as_bytes(), params)
+            .await
+            .expect("decrypt failed");
+        assert_eq!(decrypted, optsdata);
+    }
+
+    #[tokio::test]
+    async fn test_dec_optsdata_kms_provider() {
+        let annotation = r#"{"version":"0.1.0","kid":"key-1","wrapped_data":"","provider":"aliyun","provider_settings":{},"annotations":{}}"#;
+        let err = crate::enc_mods::dec_optsdata_from_anno(annotation.as_bytes(), "sample=true")
+            .await
+            .expect_err("KMS wrapped optsdata must not be unwrapped");
+        assert!(err.to_string().contains("aliyun"));
+    }
+
     #[rstest]
     #[case("kbs://a/b/c/d", ("a", "b/c/d"))]
     #[case("kbs:///b/c/d", ("", "b/c/d"))]
@@ -0,0 +1,534 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Encrypt and decrypt the layers of an
+//! [OCI image layout](https://github.com/opencontainers/image-spec/blob/main/image-layout.md)
+//! directory without a running keyprovider service. The layers are encrypted
+//! by ocicrypt-rs and the layer keys are wrapped by [`enc_mods`], so the
+//! result is the same image as encrypting with skopeo and the gRPC service.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::*;
+use base64::Engine;
+use futures::future::BoxFuture;
+use jwt_simple::prelude::Ed25519KeyPair;
+use log::{debug, info, warn};
+use ocicrypt_rs::{
+    config::EncryptConfig,
+    encryption::{async_decrypt_layer, encrypt_layer},
+};
+use reqwest::Url;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::enc_mods;
+
+const ANNOTATION_KEY_PROVIDER: &str =
+    "org.opencontainers.image.enc.keys.provider.attestation-agent";
+const ANNOTATION_PUBOPTS: &str = "org.opencontainers.image.enc.pubopts";
+const ANNOTATION_ENC_PREFIX: &str = "org.opencontainers.image.enc.";
+
+const ENCRYPTED_SUFFIX: &str = "+encrypted";
+
+const MEDIA_TYPE_OCI_INDEX: &str = "application/vnd.oci.image.index.v1+json";
+const MEDIA_TYPE_OCI_MANIFEST: &str = "application/vnd.oci.image.manifest.v1+json";
+const MEDIA_TYPE_DOCKER_MANIFEST_LIST: &str =
+    "application/vnd.docker.distribution.manifest.list.v2+json";
+const MEDIA_TYPE_DOCKER_MANIFEST: &str = "application/vnd.docker.distribution.manifest.v2+json";
+
+/// Parameters to encrypt the layers of an image layout.
+pub struct EncryptParams {
+    /// Address of the KBS to register the KEK to. Only works together
+    /// with `auth_private_key`.
+    pub kbs: Option<Url>,
+
+    /// Private key to sign the KEK registration token.
+    pub auth_private_key: Option<Ed25519KeyPair>,
+
+    /// Parameters to wrap the layer keys, in the same format as the gRPC
+    /// service, s.t. `keyid=kbs:///default/key/1::keypath=/key`.
+    pub params: String,
+
+    /// Indexes of the layers to encrypt. Negative indexes count from the
+    /// last layer. All layers are encrypted if empty.
+    pub layers: Vec<i64>,
+
+    /// Layer cipher of ocicrypt-rs, `AES_256_CTR_HMAC_SHA256` if not set.
+    pub layer_cipher: Option<String>,
+}
+
+/// Read the image layout at `input`, encrypt the selected layers of all its
+/// images and write the result to `output`. Only the blobs referenced by the
+/// encrypted images are written to `output`, so no plaintext layer is left
+/// over.
+pub async fn encrypt_layout(input: &Path, output: &Path, params: &EncryptParams) -> Result<()> {
+    process_layout(input, output, Operation::Encrypt(params)).await
+}
+
+/// Read the image layout at `input`, decrypt all the encrypted layers of its
+/// images and write the result to `output`. The `params` to get the KEK are
+/// in the same format as [`EncryptParams::params`], s.t. `keypath=/key` or
+/// `sample=true`.
+pub async fn decrypt_layout(input: &Path, output: &Path, params: &str) -> Result<()> {
+    process_layout(input, output, Operation::Decrypt(params)).await
+}
+
+#[derive(Clone, Copy)]
+enum Operation<'a> {
+    Encrypt(&'a EncryptParams),
+    Decrypt(&'a str),
+}
+
+async fn process_layout(input: &Path, output: &Path, operation: Operation<'_>) -> Result<()> {
+    if !input.join("oci-layout").is_file() {
+        bail!("{} is not an OCI image layout", input.display());
+    }
+
+    if output.join("index.json").exists() {
+        bail!("output image layout {} already exists", output.display());
+    }
+
+    fs::create_dir_all(output.join("blobs").join("sha256")).context("create output blobs dir")?;
+    fs::copy(input.join("oci-layout"), output.join("oci-layout")).context("copy oci-layout")?;
+
+    let mut index: Value =
+        serde_json::from_slice(&fs::read(input.join("index.json")).context("read index.json")?)
+            .context("parse index.json")?;
+
+    let layout = Layout {
+        input,
+        output,
+        operation,
+    };
+    layout.process_index(&mut index).await?;
+
+    fs::write(output.join("index.json"), serde_json::to_vec(&index)?)
+        .context("write index.json")?;
+    Ok(())
+}
+
+struct Layout<'a> {
+    input: &'a Path,
+    output: &'a Path,
+    operation: Operation<'a>,
+}
+
+impl Layout<'_> {
+    async fn process_index(&self, index: &mut Value) -> Result<()> {
+        let Some(manifests) = index.get_mut("manifests").and_then(Value::as_array_mut) else {
+            bail!("illegal image index without manifests");
+        };
+
+        for descriptor in manifests {
+            self.process_descriptor(descriptor).await?;
+        }
+
+        Ok(())
+    }
+
+    fn process_descriptor<'b>(&'b self, descriptor: &'b mut Value) -> BoxFuture<'b, Result<()>> {
+        Box::pin(async move {
+            let media_type = get_str(descriptor, "mediaType")?.to_string();
+            let digest = get_str(descriptor, "digest")?.to_string();
+
+            let mut blob: Value = match media_type.as_str() {
+                MEDIA_TYPE_OCI_INDEX
+                | MEDIA_TYPE_DOCKER_MANIFEST_LIST
+                | MEDIA_TYPE_OCI_MANIFEST
+                | MEDIA_TYPE_DOCKER_MANIFEST => serde_json::from_slice(
+                    &fs::read(blob_path(self.input, &digest)?)
+                        .with_context(|| format!("read blob {digest}"))?,
+                )
+                .with_context(|| format!("parse {media_type} {digest}"))?,
+                _ => {
+                    debug!("copy {media_type} {digest}");
+                    return self.copy_blob(&digest);
+                }
+            };
+
+            match media_type.as_str() {
+                MEDIA_TYPE_OCI_INDEX | MEDIA_TYPE_DOCKER_MANIFEST_LIST => {
+                    self.process_index(&mut blob).await?
+                }
+                _ => self.process_manifest(&mut blob).await?,
+            }
+
+            let (digest, size) = self.write_blob(&serde_json::to_vec(&blob)?)?;
+            descriptor["digest"] = digest.into();
+            descriptor["size"] = size.into();
+            Ok(())
+        })
+    }
+
+    async fn process_manifest(&self, manifest: &mut Value) -> Result<()> {
+        let config = manifest
+            .get("config")
+            .ok_or_else(|| anyhow!("illegal image manifest without config"))?;
+        self.copy_blob(get_str(config, "digest")?)?;
+
+        let Some(layers) = manifest.get_mut("layers").and_then(Value::as_array_mut) else {
+            bail!("illegal image manifest without layers");
+        };
+
+        let selected = match self.operation {
+            Operation::Encrypt(params) => select_layers(&params.layers, layers.len())?,
+            Operation::Decrypt(_) => vec![true; layers.len()],
+        };
+
+        for (layer, selected) in layers.iter_mut().zip(selected) {
+            if !selected {
+                self.copy_blob(get_str(layer, "digest")?)?;
+                continue;
+            }
+
+            match self.operation {
+                Operation::Encrypt(params) => self.encrypt_layer(layer, params).await?,
+                Operation::Decrypt(params) => self.decrypt_layer(layer, params).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn encrypt_layer(&self, layer: &mut Value, params: &EncryptParams) -> Result<()> {
+        let media_type = get_str(layer, "mediaType")?.to_string();
+        let digest = get_str(layer, "digest")?.to_string();
+        if media_type.ends_with(ENCRYPTED_SUFFIX) {
+            warn!("layer {digest} is already encrypted, skip it");
+            return self.copy_blob(&digest);
+        }
+
+        info!("encrypt layer {digest}");
+        let mut ec = EncryptConfig::default();
+        if let Some(layer_cipher) = &params.layer_cipher {
+            ec.encrypt_with_layer_cipher(layer_cipher);
+        }
+
+        let input_path = blob_path(self.input, &digest)?;
+        let tmp_path = self.tmp_blob_path(&digest)?;
+        let (priv_opts, pub_opts) = tokio::task::spawn_blocking({
+            let tmp_path = tmp_path.clone();
+            let digest = digest.clone();
+            move || {
+                let reader = File::open(input_path).context("open layer blob")?;
+                let (encryptor, mut finalizer) = encrypt_layer(&ec, reader, None, &digest)?;
+                let mut encryptor =
+                    encryptor.ok_or_else(|| anyhow!("layer {digest} is already encrypted"))?;
+                let mut writer = File::create(tmp_path).context("create encrypted layer blob")?;
+                io::copy(&mut encryptor, &mut writer).context("encrypt layer")?;
+                finalizer.finalize_opts(&encryptor)
+            }
+        })
+        .await??;
+
+        let annotation = enc_mods::enc_optsdata_gen_anno(
+            (&params.kbs, &params.auth_private_key),
+            &priv_opts,
+            vec![params.params.clone()],
+        )
+        .await
+        .context("wrap layer key")?;
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        let mut annotations = get_annotations(layer)?;
+        annotations.insert(ANNOTATION_KEY_PROVIDER.into(), engine.encode(annotation));
+        annotations.insert(ANNOTATION_PUBOPTS.into(), engine.encode(pub_opts));
+
+        let (digest, size) = self.commit_blob(&tmp_path)?;
+        layer["mediaType"] = format!("{media_type}{ENCRYPTED_SUFFIX}").into();
+        layer["digest"] = digest.into();
+        layer["size"] = size.into();
+        layer["annotations"] = serde_json::to_value(annotations)?;
+        Ok(())
+    }
+
+    async fn decrypt_layer(&self, layer: &mut Value, params: &str) -> Result<()> {
+        let media_type = get_str(layer, "mediaType")?.to_string();
+        let digest = get_str(layer, "digest")?.to_string();
+        let Some(plain_media_type) = media_type.strip_suffix(ENCRYPTED_SUFFIX) else {
+            return self.copy_blob(&digest);
+        };
+
+        info!("decrypt layer {digest}");
+        let mut annotations = get_annotations(layer)?;
+        let packets = annotations
+            .get(ANNOTATION_KEY_PROVIDER)
+            .ok_or_else(|| anyhow!("layer {digest} is not encrypted by attestation-agent"))?;
+
+        // Like ocicrypt, try all the comma separated wrapped keys until one
+        // can be unwrapped.
+        let engine = base64::engine::general_purpose::STANDARD;
+        let mut priv_opts = None;
+        let mut errs = Vec::new();
+        for packet in packets.split(',') {
+            let packet = engine.decode(packet).context("base64 decode annotation")?;
+            match enc_mods::dec_optsdata_from_anno(&packet, params).await {
+                Result::Ok(opts) => {
+                    priv_opts = Some(opts);
+                    break;
+                }
+                Err(e) => errs.push(format!("{e:#}")),
+            }
+        }
+        let priv_opts =
+            priv_opts.ok_or_else(|| anyhow!("unwrap key of layer {digest} failed: {errs:?}"))?;
+
+        let reader = tokio::fs::File::open(blob_path(self.input, &digest)?)
+            .await
+            .context("open layer blob")?;
+        let (decryptor, plain_digest) =
+            async_decrypt_layer(reader, Some(&annotations), &priv_opts)?;
+        tokio::pin!(decryptor);
+
+        let tmp_path = self.tmp_blob_path(&digest)?;
+        let mut writer = tokio::fs::File::create(&tmp_path)
+            .await
+            .context("create decrypted layer blob")?;
+        tokio::io::copy(&mut decryptor, &mut writer)
+            .await
+            .with_context(|| format!("decrypt layer {digest}"))?;
+
+        let (digest, size) = self.commit_blob(&tmp_path)?;
+        if !plain_digest.is_empty() && plain_digest != digest {
+            bail!("decrypted layer digest {digest} does not match the original {plain_digest}");
+        }
+
+        annotations.retain(|key, _| !key.starts_with(ANNOTATION_ENC_PREFIX));
+        layer["mediaType"] = plain_media_type.into();
+        layer["digest"] = digest.into();
+        layer["size"] = size.into();
+        if annotations.is_empty() {
+            if let Some(layer) = layer.as_object_mut() {
+                layer.remove("annotations");
+            }
+        } else {
+            layer["annotations"] = serde_json::to_value(annotations)?;
+        }
+
+        Ok(())
+    }
+
+    fn copy_blob(&self, digest: &str) -> Result<()> {
+        let target = blob_path(self.output, digest)?;
+        if !target.exists() {
+            fs::copy(blob_path(self.input, digest)?, target)
+                .with_context(|| format!("copy blob {digest}"))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_blob(&self, content: &[u8]) -> Result<(String, u64)> {
+        let digest = format!("sha256:{}", hex::encode(Sha256::digest(content)));
+        fs::write(blob_path(self.output, &digest)?, content)
+            .with_context(|| format!("write blob {digest}"))?;
+        Ok((digest, content.len() as u64))
+    }
+
+    fn tmp_blob_path(&self, digest: &str) -> Result<PathBuf> {
+        let mut path = blob_path(self.output, digest)?;
+        path.set_extension("tmp");
+        Ok(path)
+    }
+
+    /// Move a blob written to a temporary path to the path of its digest.
+    fn commit_blob(&self, tmp_path: &Path) -> Result<(String, u64)> {
+        let mut hasher = Sha256::new();
+        let size = io::copy(&mut File::open(tmp_path)?, &mut hasher)?;
+        let digest = format!("sha256:{}", hex::encode(hasher.finalize()));
+        fs::rename(tmp_path, blob_path(self.output, &digest)?)
+            .with_context(|| format!("write blob {digest}"))?;
+        Ok((digest, size))
+    }
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> Result<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("illegal descriptor without {key}"))
+}
+
+fn get_annotations(descriptor: &Value) -> Result<BTreeMap<String, String>> {
+    match descriptor.get("annotations") {
+        Some(annotations) => {
+            serde_json::from_value(annotations.clone()).context("parse annotations")
+        }
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn blob_path(layout: &Path, digest: &str) -> Result<PathBuf> {
+    let Some(("sha256", encoded)) = digest.split_once(':') else {
+        bail!("unsupported digest {digest}, only sha256 is supported");
+    };
+
+    if encoded.len() != 64 || !encoded.bytes().all(|b| b.is_ascii_hexdigit()) {
+        bail!("illegal sha256 digest {digest}");
+    }
+
+    Ok(layout.join("blobs").join("sha256").join(encoded))
+}
+
+/// Resolve the given layer indexes, where negative indexes count from the
+/// last layer, into a selection flag for each of the `count` layers.
+fn select_layers(indexes: &[i64], count: usize) -> Result<Vec<bool>> {
+    if indexes.is_empty() {
+        return Ok(vec![true; count]);
+    }
+
+    let mut selected = vec![false; count];
+    for index in indexes {
+        let resolved = match *index < 0 {
+            true => count as i64 + index,
+            false => *index,
+        };
+        if resolved < 0 || resolved >= count as i64 {
+            bail!("layer index {index} is out of range, the image has {count} layers");
+        }
+
+        selected[resolved as usize] = true;
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use super::{decrypt_layout, encrypt_layout, select_layers, EncryptParams, Layout, Operation};
+
+    #[rstest]
+    #[case(&[], 3, Some(vec![true, true, true]))]
+    #[case(&[0, -1], 3, Some(vec![true, false, true]))]
+    #[case(&[-3], 3, Some(vec![true, false, false]))]
+    #[case(&[3], 3, None)]
+    #[case(&[-4], 3, None)]
+    fn test_select_layers(
+        #[case] indexes: &[i64],
+        #[case] count: usize,
+        #[case] expected: Option<Vec<bool>>,
+    ) {
+        assert_eq!(select_layers(indexes, count).ok(), expected);
+    }
+
+    fn read_manifest(layout: &Path) -> Value {
+        let index: Value =
+            serde_json::from_slice(&std::fs::read(layout.join("index.json")).unwrap()).unwrap();
+        let digest = index["manifests"][0]["digest"].as_str().unwrap();
+        let path = super::blob_path(layout, digest).unwrap();
+        serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("AES_256_GCM_CHUNKED"))]
+    #[tokio::test]
+    async fn test_encrypt_decrypt_layout(#[case] layer_cipher: Option<&str>) {
+        let work_dir = tempfile::tempdir().unwrap();
+        let plain = work_dir.path().join("plain");
+        std::fs::create_dir_all(plain.join("blobs").join("sha256")).unwrap();
+        std::fs::write(
+            plain.join("oci-layout"),
+            r#"{"imageLayoutVersion":"1.0.0"}"#,
+        )
+        .unwrap();
+
+        let layout = Layout {
+            input: &plain,
+            output: &plain,
+            operation: Operation::Decrypt(""),
+        };
+        let layer_contents: Vec<Vec<u8>> = vec![vec![1; 1000], vec![2; 100_000]];
+        let layers: Vec<Value> = layer_contents
+            .iter()
+            .map(|content| {
+                let (digest, size) = layout.write_blob(content).unwrap();
+                json!({
+                    "mediaType": "application/vnd.oci.image.layer.v1.tar+gzip",
+                    "digest": digest,
+                    "size": size,
+                    "annotations": { "org.opencontainers.image.title": "layer" },
+                })
+            })
+            .collect();
+        let (config_digest, config_size) = layout.write_blob(b"{}").unwrap();
+        let manifest = json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "config": {
+                "mediaType": "application/vnd.oci.image.config.v1+json",
+                "digest": config_digest,
+                "size": config_size,
+            },
+            "layers": layers,
+        });
+        let (manifest_digest, manifest_size) = layout
+            .write_blob(&serde_json::to_vec(&manifest).unwrap())
+            .unwrap();
+        let index = json!({
+            "schemaVersion": 2,
+            "manifests": [{
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": manifest_digest,
+                "size": manifest_size,
+            }],
+        });
+        std::fs::write(plain.join("index.json"), index.to_string()).unwrap();
+
+        // Only encrypt the last layer
+        let encrypted = work_dir.path().join("encrypted");
+        let params = EncryptParams {
+            kbs: None,
+            auth_private_key: None,
+            params: "sample=true".into(),
+            layers: vec![-1],
+            layer_cipher: layer_cipher.map(String::from),
+        };
+        encrypt_layout(&plain, &encrypted, &params).await.unwrap();
+
+        let encrypted_manifest = read_manifest(&encrypted);
+        assert_eq!(encrypted_manifest["layers"][0], manifest["layers"][0]);
+        let encrypted_layer = &encrypted_manifest["layers"][1];
+        assert_eq!(
+            encrypted_layer["mediaType"],
+            "application/vnd.oci.image.layer.v1.tar+gzip+encrypted"
+        );
+        assert_ne!(encrypted_layer["digest"], manifest["layers"][1]["digest"]);
+        let annotations = encrypted_layer["annotations"].as_object().unwrap();
+        assert!(annotations.contains_key(super::ANNOTATION_KEY_PROVIDER));
+        assert!(annotations.contains_key(super::ANNOTATION_PUBOPTS));
+        assert!(annotations.contains_key("org.opencontainers.image.title"));
+
+        // The plaintext layer must not be left in the encrypted layout
+        let plain_layer_digest = manifest["layers"][1]["digest"].as_str().unwrap();
+        assert!(!super::blob_path(&encrypted, plain_layer_digest)
+            .unwrap()
+            .exists());
+
+        // Decrypting with a wrong key fails
+        let wrong_key = work_dir.path().join("wrong_key");
+        std::fs::write(&wrong_key, [0; 32]).unwrap();
+        let wrong = work_dir.path().join("wrong");
+        let params = format!("keypath={}", wrong_key.display());
+        assert!(decrypt_layout(&encrypted, &wrong, &params).await.is_err());
+
+        let decrypted = work_dir.path().join("decrypted");
+        decrypt_layout(&encrypted, &decrypted, "sample=true")
+            .await
+            .unwrap();
+        assert_eq!(read_manifest(&decrypted), manifest);
+    }
+}
@@ -0,0 +1,8 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+pub mod enc_mods;
+pub mod grpc;
+pub mod layout;
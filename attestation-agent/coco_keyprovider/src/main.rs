@@ -5,14 +5,12 @@
 
 use anyhow::*;
 use clap::{arg, command, Parser};
+use coco_keyprovider::grpc;
 use daemonize::Daemonize;
 use log::*;
 use std::{fs::File, net::SocketAddr, path::PathBuf};
 use tokio::fs;
 
-pub mod enc_mods;
-pub mod grpc;
-
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -0,0 +1,62 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::time::Duration;
+
+/// How long the KBS allows a resource to be cached, as given by the
+/// `Cache-Control` header of the resource response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheHint {
+    /// The resource must not be cached (`no-store` or `no-cache`).
+    NoStore,
+
+    /// The resource can be cached for the given duration (`max-age`).
+    MaxAge(Duration),
+}
+
+impl CacheHint {
+    /// Parse the value of a `Cache-Control` header. Directives other than
+    /// `no-store`, `no-cache` and `max-age` are ignored.
+    pub fn from_cache_control(value: &str) -> Option<Self> {
+        let mut hint = None;
+        for directive in value.split(',').map(str::trim) {
+            let directive = directive.to_ascii_lowercase();
+            if directive == "no-store" || directive == "no-cache" {
+                return Some(Self::NoStore);
+            }
+
+            if let Some(seconds) = directive.strip_prefix("max-age=") {
+                if let Ok(seconds) = seconds.trim_matches('"').parse() {
+                    hint = Some(Self::MaxAge(Duration::from_secs(seconds)));
+                }
+            }
+        }
+
+        hint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::CacheHint;
+
+    #[rstest]
+    #[case("max-age=60", Some(CacheHint::MaxAge(Duration::from_secs(60))))]
+    #[case(
+        "private, Max-Age=\"5\"",
+        Some(CacheHint::MaxAge(Duration::from_secs(5)))
+    )]
+    #[case("max-age=60, no-store", Some(CacheHint::NoStore))]
+    #[case("no-cache", Some(CacheHint::NoStore))]
+    #[case("private", None)]
+    #[case("max-age=forever", None)]
+    fn parse_cache_control(#[case] value: &str, #[case] expected: Option<CacheHint>) {
+        assert_eq!(CacheHint::from_cache_control(value), expected);
+    }
+}
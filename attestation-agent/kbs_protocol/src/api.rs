@@ -5,9 +5,17 @@
 
 use crate::Result;
 use async_trait::async_trait;
-pub use resource_uri::ResourceUri;
+pub use resource_uri::{CacheHint, ResourceUri};
 
 #[async_trait]
 pub trait KbsClientCapabilities {
     async fn get_resource(&mut self, resource_uri: ResourceUri) -> Result<Vec<u8>>;
+
+    /// Get the resource together with the [`CacheHint`] of the KBS, if any.
+    async fn get_resource_with_cache_hint(
+        &mut self,
+        resource_uri: ResourceUri,
+    ) -> Result<(Vec<u8>, Option<CacheHint>)> {
+        Ok((self.get_resource(resource_uri).await?, None))
+    }
 }
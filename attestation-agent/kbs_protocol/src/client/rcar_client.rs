@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
-    api::KbsClientCapabilities,
+    api::{CacheHint, KbsClientCapabilities},
     client::{
         ClientTee, KbsClient, KBS_GET_RESOURCE_MAX_ATTEMPT, KBS_PREFIX, KBS_PROTOCOL_VERSION,
     },
@@ -341,6 +341,14 @@ impl KbsClient<Box<dyn EvidenceProvider>> {
 #[async_trait]
 impl KbsClientCapabilities for KbsClient<Box<dyn EvidenceProvider>> {
     async fn get_resource(&mut self, resource_uri: ResourceUri) -> Result<Vec<u8>> {
+        let (resource, _) = self.get_resource_with_cache_hint(resource_uri).await?;
+        Ok(resource)
+    }
+
+    async fn get_resource_with_cache_hint(
+        &mut self,
+        resource_uri: ResourceUri,
+    ) -> Result<(Vec<u8>, Option<CacheHint>)> {
         let mut remote_url = format!(
             "{}/{KBS_PREFIX}/resource/{}/{}/{}",
             self.kbs_host_url, resource_uri.repository, resource_uri.r#type, resource_uri.tag
@@ -375,6 +383,11 @@ impl KbsClientCapabilities for KbsClient<Box<dyn EvidenceProvider>> {
 
             match res.status() {
                 reqwest::StatusCode::OK => {
+                    let cache_hint = res
+                        .headers()
+                        .get(reqwest::header::CACHE_CONTROL)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(CacheHint::from_cache_control);
                     let response = res
                         .json::<Response>()
                         .await
@@ -383,7 +396,7 @@ impl KbsClientCapabilities for KbsClient<Box<dyn EvidenceProvider>> {
                         .tee_key
                         .decrypt_response(response)
                         .map_err(|e| Error::DecryptResponseFailed(e.to_string()))?;
-                    return Ok(payload_data);
+                    return Ok((payload_data, cache_hint));
                 }
                 reqwest::StatusCode::UNAUTHORIZED => {
                     warn!(
@@ -68,6 +68,15 @@ impl Token {
 
         Ok(())
     }
+
+    /// How long the token stays valid, if it has an expiration time.
+    pub fn valid_for(&self) -> Option<std::time::Duration> {
+        let now = Clock::now_since_epoch();
+        self.exp.map(|exp| match exp > now {
+            true => std::time::Duration::from_secs((exp - now).as_secs()),
+            false => std::time::Duration::ZERO,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -79,5 +88,6 @@ mod tests {
         let token_provider = TestTokenProvider::default();
         let token = token_provider.get_token().await.unwrap();
         assert!(token.0.check_valid().is_ok());
+        assert!(token.0.valid_for().unwrap() > std::time::Duration::ZERO);
     }
 }
@@ -390,27 +390,149 @@ impl ::protobuf::reflect::ProtobufValue for GetEvidenceResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:attestation_agent.GetTokenRequest)
+// @@protoc_insertion_point(message:attestation_agent.GetCompositeEvidenceRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetTokenRequest {
+pub struct GetCompositeEvidenceRequest {
     // message fields
-    // @@protoc_insertion_point(field:attestation_agent.GetTokenRequest.TokenType)
-    pub TokenType: ::std::string::String,
-    // @@protoc_insertion_point(field:attestation_agent.GetTokenRequest.AdditionalData)
-    pub AdditionalData: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:attestation_agent.GetCompositeEvidenceRequest.RuntimeData)
+    pub RuntimeData: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:attestation_agent.GetTokenRequest.special_fields)
+    // @@protoc_insertion_point(special_field:attestation_agent.GetCompositeEvidenceRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetTokenRequest {
-    fn default() -> &'a GetTokenRequest {
-        <GetTokenRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetCompositeEvidenceRequest {
+    fn default() -> &'a GetCompositeEvidenceRequest {
+        <GetCompositeEvidenceRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetTokenRequest {
-    pub fn new() -> GetTokenRequest {
+impl GetCompositeEvidenceRequest {
+    pub fn new() -> GetCompositeEvidenceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "RuntimeData",
+            |m: &GetCompositeEvidenceRequest| { &m.RuntimeData },
+            |m: &mut GetCompositeEvidenceRequest| { &mut m.RuntimeData },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCompositeEvidenceRequest>(
+            "GetCompositeEvidenceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCompositeEvidenceRequest {
+    const NAME: &'static str = "GetCompositeEvidenceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.RuntimeData = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.RuntimeData.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.RuntimeData);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.RuntimeData.is_empty() {
+            os.write_bytes(1, &self.RuntimeData)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCompositeEvidenceRequest {
+        GetCompositeEvidenceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.RuntimeData.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCompositeEvidenceRequest {
+        static instance: GetCompositeEvidenceRequest = GetCompositeEvidenceRequest {
+            RuntimeData: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetCompositeEvidenceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCompositeEvidenceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCompositeEvidenceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCompositeEvidenceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.DeviceEvidence)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeviceEvidence {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.DeviceEvidence.Evidence)
+    pub Evidence: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:attestation_agent.DeviceEvidence.Error)
+    pub Error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.DeviceEvidence.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeviceEvidence {
+    fn default() -> &'a DeviceEvidence {
+        <DeviceEvidence as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeviceEvidence {
+    pub fn new() -> DeviceEvidence {
         ::std::default::Default::default()
     }
 
@@ -418,25 +540,1248 @@ impl GetTokenRequest {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "TokenType",
-            |m: &GetTokenRequest| { &m.TokenType },
-            |m: &mut GetTokenRequest| { &mut m.TokenType },
+            "Evidence",
+            |m: &DeviceEvidence| { &m.Evidence },
+            |m: &mut DeviceEvidence| { &mut m.Evidence },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "AdditionalData",
-            |m: &GetTokenRequest| { &m.AdditionalData },
-            |m: &mut GetTokenRequest| { &mut m.AdditionalData },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Error",
+            |m: &DeviceEvidence| { &m.Error },
+            |m: &mut DeviceEvidence| { &mut m.Error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeviceEvidence>(
+            "DeviceEvidence",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeviceEvidence {
+    const NAME: &'static str = "DeviceEvidence";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.Evidence = is.read_bytes()?;
+                },
+                18 => {
+                    self.Error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.Evidence.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.Evidence);
+        }
+        if !self.Error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.Evidence.is_empty() {
+            os.write_bytes(1, &self.Evidence)?;
+        }
+        if !self.Error.is_empty() {
+            os.write_string(2, &self.Error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeviceEvidence {
+        DeviceEvidence::new()
+    }
+
+    fn clear(&mut self) {
+        self.Evidence.clear();
+        self.Error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeviceEvidence {
+        static instance: DeviceEvidence = DeviceEvidence {
+            Evidence: ::std::vec::Vec::new(),
+            Error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeviceEvidence {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeviceEvidence").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeviceEvidence {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeviceEvidence {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.GetCompositeEvidenceResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetCompositeEvidenceResponse {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.GetCompositeEvidenceResponse.PrimaryTee)
+    pub PrimaryTee: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.GetCompositeEvidenceResponse.PrimaryEvidence)
+    pub PrimaryEvidence: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:attestation_agent.GetCompositeEvidenceResponse.AdditionalEvidence)
+    pub AdditionalEvidence: ::std::collections::HashMap<::std::string::String, DeviceEvidence>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.GetCompositeEvidenceResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetCompositeEvidenceResponse {
+    fn default() -> &'a GetCompositeEvidenceResponse {
+        <GetCompositeEvidenceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCompositeEvidenceResponse {
+    pub fn new() -> GetCompositeEvidenceResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "PrimaryTee",
+            |m: &GetCompositeEvidenceResponse| { &m.PrimaryTee },
+            |m: &mut GetCompositeEvidenceResponse| { &mut m.PrimaryTee },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "PrimaryEvidence",
+            |m: &GetCompositeEvidenceResponse| { &m.PrimaryEvidence },
+            |m: &mut GetCompositeEvidenceResponse| { &mut m.PrimaryEvidence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "AdditionalEvidence",
+            |m: &GetCompositeEvidenceResponse| { &m.AdditionalEvidence },
+            |m: &mut GetCompositeEvidenceResponse| { &mut m.AdditionalEvidence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCompositeEvidenceResponse>(
+            "GetCompositeEvidenceResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCompositeEvidenceResponse {
+    const NAME: &'static str = "GetCompositeEvidenceResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.PrimaryTee = is.read_string()?;
+                },
+                18 => {
+                    self.PrimaryEvidence = is.read_bytes()?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_message()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.AdditionalEvidence.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.PrimaryTee.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.PrimaryTee);
+        }
+        if !self.PrimaryEvidence.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.PrimaryEvidence);
+        }
+        for (k, v) in &self.AdditionalEvidence {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.compute_size();
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.PrimaryTee.is_empty() {
+            os.write_string(1, &self.PrimaryTee)?;
+        }
+        if !self.PrimaryEvidence.is_empty() {
+            os.write_bytes(2, &self.PrimaryEvidence)?;
+        }
+        for (k, v) in &self.AdditionalEvidence {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.cached_size() as u64;
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCompositeEvidenceResponse {
+        GetCompositeEvidenceResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.PrimaryTee.clear();
+        self.PrimaryEvidence.clear();
+        self.AdditionalEvidence.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCompositeEvidenceResponse {
+        static instance: ::protobuf::rt::Lazy<GetCompositeEvidenceResponse> = ::protobuf::rt::Lazy::new();
+        instance.get(GetCompositeEvidenceResponse::new)
+    }
+}
+
+impl ::protobuf::MessageFull for GetCompositeEvidenceResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCompositeEvidenceResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCompositeEvidenceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCompositeEvidenceResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.GetTokenRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetTokenRequest {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.GetTokenRequest.TokenType)
+    pub TokenType: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.GetTokenRequest.AdditionalData)
+    pub AdditionalData: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.GetTokenRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetTokenRequest {
+    fn default() -> &'a GetTokenRequest {
+        <GetTokenRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetTokenRequest {
+    pub fn new() -> GetTokenRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "TokenType",
+            |m: &GetTokenRequest| { &m.TokenType },
+            |m: &mut GetTokenRequest| { &mut m.TokenType },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "AdditionalData",
+            |m: &GetTokenRequest| { &m.AdditionalData },
+            |m: &mut GetTokenRequest| { &mut m.AdditionalData },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetTokenRequest>(
+            "GetTokenRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetTokenRequest {
+    const NAME: &'static str = "GetTokenRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.TokenType = is.read_string()?;
+                },
+                18 => {
+                    self.AdditionalData = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.TokenType.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.TokenType);
+        }
+        if let Some(v) = self.AdditionalData.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.TokenType.is_empty() {
+            os.write_string(1, &self.TokenType)?;
+        }
+        if let Some(v) = self.AdditionalData.as_ref() {
+            os.write_string(2, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetTokenRequest {
+        GetTokenRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.TokenType.clear();
+        self.AdditionalData = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetTokenRequest {
+        static instance: GetTokenRequest = GetTokenRequest {
+            TokenType: ::std::string::String::new(),
+            AdditionalData: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetTokenRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTokenRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetTokenRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetTokenRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.GetTokenResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetTokenResponse {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.GetTokenResponse.Token)
+    pub Token: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.GetTokenResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetTokenResponse {
+    fn default() -> &'a GetTokenResponse {
+        <GetTokenResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetTokenResponse {
+    pub fn new() -> GetTokenResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Token",
+            |m: &GetTokenResponse| { &m.Token },
+            |m: &mut GetTokenResponse| { &mut m.Token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetTokenResponse>(
+            "GetTokenResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetTokenResponse {
+    const NAME: &'static str = "GetTokenResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.Token = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.Token.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.Token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.Token.is_empty() {
+            os.write_bytes(1, &self.Token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetTokenResponse {
+        GetTokenResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.Token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetTokenResponse {
+        static instance: GetTokenResponse = GetTokenResponse {
+            Token: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetTokenResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTokenResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetTokenResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetTokenResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.ExtendRuntimeMeasurementRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ExtendRuntimeMeasurementRequest {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Domain)
+    pub Domain: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Operation)
+    pub Operation: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Content)
+    pub Content: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.RegisterIndex)
+    pub RegisterIndex: ::std::option::Option<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.ExtendRuntimeMeasurementRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ExtendRuntimeMeasurementRequest {
+    fn default() -> &'a ExtendRuntimeMeasurementRequest {
+        <ExtendRuntimeMeasurementRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExtendRuntimeMeasurementRequest {
+    pub fn new() -> ExtendRuntimeMeasurementRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Domain",
+            |m: &ExtendRuntimeMeasurementRequest| { &m.Domain },
+            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Domain },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Operation",
+            |m: &ExtendRuntimeMeasurementRequest| { &m.Operation },
+            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Operation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Content",
+            |m: &ExtendRuntimeMeasurementRequest| { &m.Content },
+            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Content },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "RegisterIndex",
+            |m: &ExtendRuntimeMeasurementRequest| { &m.RegisterIndex },
+            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.RegisterIndex },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExtendRuntimeMeasurementRequest>(
+            "ExtendRuntimeMeasurementRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
+    const NAME: &'static str = "ExtendRuntimeMeasurementRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.Domain = is.read_string()?;
+                },
+                18 => {
+                    self.Operation = is.read_string()?;
+                },
+                26 => {
+                    self.Content = is.read_string()?;
+                },
+                32 => {
+                    self.RegisterIndex = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.Domain.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.Domain);
+        }
+        if !self.Operation.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Operation);
+        }
+        if !self.Content.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.Content);
+        }
+        if let Some(v) = self.RegisterIndex {
+            my_size += ::protobuf::rt::uint64_size(4, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.Domain.is_empty() {
+            os.write_string(1, &self.Domain)?;
+        }
+        if !self.Operation.is_empty() {
+            os.write_string(2, &self.Operation)?;
+        }
+        if !self.Content.is_empty() {
+            os.write_string(3, &self.Content)?;
+        }
+        if let Some(v) = self.RegisterIndex {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ExtendRuntimeMeasurementRequest {
+        ExtendRuntimeMeasurementRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.Domain.clear();
+        self.Operation.clear();
+        self.Content.clear();
+        self.RegisterIndex = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ExtendRuntimeMeasurementRequest {
+        static instance: ExtendRuntimeMeasurementRequest = ExtendRuntimeMeasurementRequest {
+            Domain: ::std::string::String::new(),
+            Operation: ::std::string::String::new(),
+            Content: ::std::string::String::new(),
+            RegisterIndex: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ExtendRuntimeMeasurementRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtendRuntimeMeasurementRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ExtendRuntimeMeasurementRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExtendRuntimeMeasurementRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.ExtendRuntimeMeasurementResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ExtendRuntimeMeasurementResponse {
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.ExtendRuntimeMeasurementResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ExtendRuntimeMeasurementResponse {
+    fn default() -> &'a ExtendRuntimeMeasurementResponse {
+        <ExtendRuntimeMeasurementResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExtendRuntimeMeasurementResponse {
+    pub fn new() -> ExtendRuntimeMeasurementResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExtendRuntimeMeasurementResponse>(
+            "ExtendRuntimeMeasurementResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ExtendRuntimeMeasurementResponse {
+    const NAME: &'static str = "ExtendRuntimeMeasurementResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ExtendRuntimeMeasurementResponse {
+        ExtendRuntimeMeasurementResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ExtendRuntimeMeasurementResponse {
+        static instance: ExtendRuntimeMeasurementResponse = ExtendRuntimeMeasurementResponse {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ExtendRuntimeMeasurementResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtendRuntimeMeasurementResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ExtendRuntimeMeasurementResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExtendRuntimeMeasurementResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.GetEventLogRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetEventLogRequest {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.GetEventLogRequest.Domain)
+    pub Domain: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:attestation_agent.GetEventLogRequest.RegisterIndex)
+    pub RegisterIndex: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:attestation_agent.GetEventLogRequest.Raw)
+    pub Raw: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.GetEventLogRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetEventLogRequest {
+    fn default() -> &'a GetEventLogRequest {
+        <GetEventLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetEventLogRequest {
+    pub fn new() -> GetEventLogRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "Domain",
+            |m: &GetEventLogRequest| { &m.Domain },
+            |m: &mut GetEventLogRequest| { &mut m.Domain },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "RegisterIndex",
+            |m: &GetEventLogRequest| { &m.RegisterIndex },
+            |m: &mut GetEventLogRequest| { &mut m.RegisterIndex },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Raw",
+            |m: &GetEventLogRequest| { &m.Raw },
+            |m: &mut GetEventLogRequest| { &mut m.Raw },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetEventLogRequest>(
+            "GetEventLogRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetEventLogRequest {
+    const NAME: &'static str = "GetEventLogRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.Domain = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.RegisterIndex = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.Raw = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.Domain.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.RegisterIndex {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if self.Raw != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.Domain.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.RegisterIndex {
+            os.write_uint64(2, v)?;
+        }
+        if self.Raw != false {
+            os.write_bool(3, self.Raw)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetEventLogRequest {
+        GetEventLogRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.Domain = ::std::option::Option::None;
+        self.RegisterIndex = ::std::option::Option::None;
+        self.Raw = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetEventLogRequest {
+        static instance: GetEventLogRequest = GetEventLogRequest {
+            Domain: ::std::option::Option::None,
+            RegisterIndex: ::std::option::Option::None,
+            Raw: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetEventLogRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetEventLogRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetEventLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetEventLogRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.EventLogEntry)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EventLogEntry {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.EventLogEntry.RegisterIndex)
+    pub RegisterIndex: u64,
+    // @@protoc_insertion_point(field:attestation_agent.EventLogEntry.Domain)
+    pub Domain: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.EventLogEntry.Operation)
+    pub Operation: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.EventLogEntry.Content)
+    pub Content: ::std::string::String,
+    // @@protoc_insertion_point(field:attestation_agent.EventLogEntry.Digests)
+    pub Digests: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.EventLogEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EventLogEntry {
+    fn default() -> &'a EventLogEntry {
+        <EventLogEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventLogEntry {
+    pub fn new() -> EventLogEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "RegisterIndex",
+            |m: &EventLogEntry| { &m.RegisterIndex },
+            |m: &mut EventLogEntry| { &mut m.RegisterIndex },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Domain",
+            |m: &EventLogEntry| { &m.Domain },
+            |m: &mut EventLogEntry| { &mut m.Domain },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Operation",
+            |m: &EventLogEntry| { &m.Operation },
+            |m: &mut EventLogEntry| { &mut m.Operation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Content",
+            |m: &EventLogEntry| { &m.Content },
+            |m: &mut EventLogEntry| { &mut m.Content },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "Digests",
+            |m: &EventLogEntry| { &m.Digests },
+            |m: &mut EventLogEntry| { &mut m.Digests },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventLogEntry>(
+            "EventLogEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EventLogEntry {
+    const NAME: &'static str = "EventLogEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.RegisterIndex = is.read_uint64()?;
+                },
+                18 => {
+                    self.Domain = is.read_string()?;
+                },
+                26 => {
+                    self.Operation = is.read_string()?;
+                },
+                34 => {
+                    self.Content = is.read_string()?;
+                },
+                42 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_bytes()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.Digests.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.RegisterIndex != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.RegisterIndex);
+        }
+        if !self.Domain.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Domain);
+        }
+        if !self.Operation.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.Operation);
+        }
+        if !self.Content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.Content);
+        }
+        for (k, v) in &self.Digests {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.RegisterIndex != 0 {
+            os.write_uint64(1, self.RegisterIndex)?;
+        }
+        if !self.Domain.is_empty() {
+            os.write_string(2, &self.Domain)?;
+        }
+        if !self.Operation.is_empty() {
+            os.write_string(3, &self.Operation)?;
+        }
+        if !self.Content.is_empty() {
+            os.write_string(4, &self.Content)?;
+        }
+        for (k, v) in &self.Digests {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            os.write_raw_varint32(42)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_bytes(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EventLogEntry {
+        EventLogEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.RegisterIndex = 0;
+        self.Domain.clear();
+        self.Operation.clear();
+        self.Content.clear();
+        self.Digests.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EventLogEntry {
+        static instance: ::protobuf::rt::Lazy<EventLogEntry> = ::protobuf::rt::Lazy::new();
+        instance.get(EventLogEntry::new)
+    }
+}
+
+impl ::protobuf::MessageFull for EventLogEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventLogEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EventLogEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventLogEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.GetEventLogResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetEventLogResponse {
+    // message fields
+    // @@protoc_insertion_point(field:attestation_agent.GetEventLogResponse.Entries)
+    pub Entries: ::std::vec::Vec<EventLogEntry>,
+    // @@protoc_insertion_point(field:attestation_agent.GetEventLogResponse.Raw)
+    pub Raw: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.GetEventLogResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetEventLogResponse {
+    fn default() -> &'a GetEventLogResponse {
+        <GetEventLogResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetEventLogResponse {
+    pub fn new() -> GetEventLogResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "Entries",
+            |m: &GetEventLogResponse| { &m.Entries },
+            |m: &mut GetEventLogResponse| { &mut m.Entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Raw",
+            |m: &GetEventLogResponse| { &m.Raw },
+            |m: &mut GetEventLogResponse| { &mut m.Raw },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetTokenRequest>(
-            "GetTokenRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetEventLogResponse>(
+            "GetEventLogResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetTokenRequest {
-    const NAME: &'static str = "GetTokenRequest";
+impl ::protobuf::Message for GetEventLogResponse {
+    const NAME: &'static str = "GetEventLogResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -446,10 +1791,10 @@ impl ::protobuf::Message for GetTokenRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.TokenType = is.read_string()?;
+                    self.Entries.push(is.read_message()?);
                 },
                 18 => {
-                    self.AdditionalData = ::std::option::Option::Some(is.read_string()?);
+                    self.Raw = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -463,11 +1808,12 @@ impl ::protobuf::Message for GetTokenRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.TokenType.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.TokenType);
-        }
-        if let Some(v) = self.AdditionalData.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
+        for value in &self.Entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.Raw.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.Raw);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -475,11 +1821,11 @@ impl ::protobuf::Message for GetTokenRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.TokenType.is_empty() {
-            os.write_string(1, &self.TokenType)?;
-        }
-        if let Some(v) = self.AdditionalData.as_ref() {
-            os.write_string(2, v)?;
+        for v in &self.Entries {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.Raw.is_empty() {
+            os.write_bytes(2, &self.Raw)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -493,83 +1839,75 @@ impl ::protobuf::Message for GetTokenRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetTokenRequest {
-        GetTokenRequest::new()
+    fn new() -> GetEventLogResponse {
+        GetEventLogResponse::new()
     }
 
     fn clear(&mut self) {
-        self.TokenType.clear();
-        self.AdditionalData = ::std::option::Option::None;
+        self.Entries.clear();
+        self.Raw.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetTokenRequest {
-        static instance: GetTokenRequest = GetTokenRequest {
-            TokenType: ::std::string::String::new(),
-            AdditionalData: ::std::option::Option::None,
+    fn default_instance() -> &'static GetEventLogResponse {
+        static instance: GetEventLogResponse = GetEventLogResponse {
+            Entries: ::std::vec::Vec::new(),
+            Raw: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetTokenRequest {
+impl ::protobuf::MessageFull for GetEventLogResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTokenRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetEventLogResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetTokenRequest {
+impl ::std::fmt::Display for GetEventLogResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetTokenRequest {
+impl ::protobuf::reflect::ProtobufValue for GetEventLogResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:attestation_agent.GetTokenResponse)
+// @@protoc_insertion_point(message:attestation_agent.VerifyEventLogRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetTokenResponse {
-    // message fields
-    // @@protoc_insertion_point(field:attestation_agent.GetTokenResponse.Token)
-    pub Token: ::std::vec::Vec<u8>,
+pub struct VerifyEventLogRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:attestation_agent.GetTokenResponse.special_fields)
+    // @@protoc_insertion_point(special_field:attestation_agent.VerifyEventLogRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetTokenResponse {
-    fn default() -> &'a GetTokenResponse {
-        <GetTokenResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a VerifyEventLogRequest {
+    fn default() -> &'a VerifyEventLogRequest {
+        <VerifyEventLogRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetTokenResponse {
-    pub fn new() -> GetTokenResponse {
+impl VerifyEventLogRequest {
+    pub fn new() -> VerifyEventLogRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Token",
-            |m: &GetTokenResponse| { &m.Token },
-            |m: &mut GetTokenResponse| { &mut m.Token },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetTokenResponse>(
-            "GetTokenResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VerifyEventLogRequest>(
+            "VerifyEventLogRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetTokenResponse {
-    const NAME: &'static str = "GetTokenResponse";
+impl ::protobuf::Message for VerifyEventLogRequest {
+    const NAME: &'static str = "VerifyEventLogRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -578,9 +1916,6 @@ impl ::protobuf::Message for GetTokenResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.Token = is.read_bytes()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -593,18 +1928,12 @@ impl ::protobuf::Message for GetTokenResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.Token.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.Token);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.Token.is_empty() {
-            os.write_bytes(1, &self.Token)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -617,102 +1946,86 @@ impl ::protobuf::Message for GetTokenResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetTokenResponse {
-        GetTokenResponse::new()
+    fn new() -> VerifyEventLogRequest {
+        VerifyEventLogRequest::new()
     }
 
     fn clear(&mut self) {
-        self.Token.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetTokenResponse {
-        static instance: GetTokenResponse = GetTokenResponse {
-            Token: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static VerifyEventLogRequest {
+        static instance: VerifyEventLogRequest = VerifyEventLogRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetTokenResponse {
+impl ::protobuf::MessageFull for VerifyEventLogRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTokenResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("VerifyEventLogRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetTokenResponse {
+impl ::std::fmt::Display for VerifyEventLogRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetTokenResponse {
+impl ::protobuf::reflect::ProtobufValue for VerifyEventLogRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:attestation_agent.ExtendRuntimeMeasurementRequest)
+// @@protoc_insertion_point(message:attestation_agent.VerifyEventLogResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ExtendRuntimeMeasurementRequest {
+pub struct VerifyEventLogResponse {
     // message fields
-    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Domain)
-    pub Domain: ::std::string::String,
-    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Operation)
-    pub Operation: ::std::string::String,
-    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.Content)
-    pub Content: ::std::string::String,
-    // @@protoc_insertion_point(field:attestation_agent.ExtendRuntimeMeasurementRequest.RegisterIndex)
-    pub RegisterIndex: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:attestation_agent.VerifyEventLogResponse.Consistent)
+    pub Consistent: bool,
+    // @@protoc_insertion_point(field:attestation_agent.VerifyEventLogResponse.Detail)
+    pub Detail: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:attestation_agent.ExtendRuntimeMeasurementRequest.special_fields)
+    // @@protoc_insertion_point(special_field:attestation_agent.VerifyEventLogResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ExtendRuntimeMeasurementRequest {
-    fn default() -> &'a ExtendRuntimeMeasurementRequest {
-        <ExtendRuntimeMeasurementRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a VerifyEventLogResponse {
+    fn default() -> &'a VerifyEventLogResponse {
+        <VerifyEventLogResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ExtendRuntimeMeasurementRequest {
-    pub fn new() -> ExtendRuntimeMeasurementRequest {
+impl VerifyEventLogResponse {
+    pub fn new() -> VerifyEventLogResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Domain",
-            |m: &ExtendRuntimeMeasurementRequest| { &m.Domain },
-            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Domain },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Operation",
-            |m: &ExtendRuntimeMeasurementRequest| { &m.Operation },
-            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Operation },
+            "Consistent",
+            |m: &VerifyEventLogResponse| { &m.Consistent },
+            |m: &mut VerifyEventLogResponse| { &mut m.Consistent },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Content",
-            |m: &ExtendRuntimeMeasurementRequest| { &m.Content },
-            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.Content },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "RegisterIndex",
-            |m: &ExtendRuntimeMeasurementRequest| { &m.RegisterIndex },
-            |m: &mut ExtendRuntimeMeasurementRequest| { &mut m.RegisterIndex },
+            "Detail",
+            |m: &VerifyEventLogResponse| { &m.Detail },
+            |m: &mut VerifyEventLogResponse| { &mut m.Detail },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExtendRuntimeMeasurementRequest>(
-            "ExtendRuntimeMeasurementRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VerifyEventLogResponse>(
+            "VerifyEventLogResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
-    const NAME: &'static str = "ExtendRuntimeMeasurementRequest";
+impl ::protobuf::Message for VerifyEventLogResponse {
+    const NAME: &'static str = "VerifyEventLogResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -721,17 +2034,11 @@ impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.Domain = is.read_string()?;
+                8 => {
+                    self.Consistent = is.read_bool()?;
                 },
                 18 => {
-                    self.Operation = is.read_string()?;
-                },
-                26 => {
-                    self.Content = is.read_string()?;
-                },
-                32 => {
-                    self.RegisterIndex = ::std::option::Option::Some(is.read_uint64()?);
+                    self.Detail = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -745,17 +2052,11 @@ impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.Domain.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.Domain);
-        }
-        if !self.Operation.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.Operation);
+        if self.Consistent != false {
+            my_size += 1 + 1;
         }
-        if !self.Content.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.Content);
-        }
-        if let Some(v) = self.RegisterIndex {
-            my_size += ::protobuf::rt::uint64_size(4, v);
+        if !self.Detail.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Detail);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -763,18 +2064,119 @@ impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.Domain.is_empty() {
-            os.write_string(1, &self.Domain)?;
-        }
-        if !self.Operation.is_empty() {
-            os.write_string(2, &self.Operation)?;
+        if self.Consistent != false {
+            os.write_bool(1, self.Consistent)?;
         }
-        if !self.Content.is_empty() {
-            os.write_string(3, &self.Content)?;
+        if !self.Detail.is_empty() {
+            os.write_string(2, &self.Detail)?;
         }
-        if let Some(v) = self.RegisterIndex {
-            os.write_uint64(4, v)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> VerifyEventLogResponse {
+        VerifyEventLogResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.Consistent = false;
+        self.Detail.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static VerifyEventLogResponse {
+        static instance: VerifyEventLogResponse = VerifyEventLogResponse {
+            Consistent: false,
+            Detail: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for VerifyEventLogResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("VerifyEventLogResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for VerifyEventLogResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VerifyEventLogResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:attestation_agent.ReloadConfigRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReloadConfigRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:attestation_agent.ReloadConfigRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReloadConfigRequest {
+    fn default() -> &'a ReloadConfigRequest {
+        <ReloadConfigRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReloadConfigRequest {
+    pub fn new() -> ReloadConfigRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReloadConfigRequest>(
+            "ReloadConfigRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReloadConfigRequest {
+    const NAME: &'static str = "ReloadConfigRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -787,79 +2189,71 @@ impl ::protobuf::Message for ExtendRuntimeMeasurementRequest {
         &mut self.special_fields
     }
 
-    fn new() -> ExtendRuntimeMeasurementRequest {
-        ExtendRuntimeMeasurementRequest::new()
+    fn new() -> ReloadConfigRequest {
+        ReloadConfigRequest::new()
     }
 
     fn clear(&mut self) {
-        self.Domain.clear();
-        self.Operation.clear();
-        self.Content.clear();
-        self.RegisterIndex = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ExtendRuntimeMeasurementRequest {
-        static instance: ExtendRuntimeMeasurementRequest = ExtendRuntimeMeasurementRequest {
-            Domain: ::std::string::String::new(),
-            Operation: ::std::string::String::new(),
-            Content: ::std::string::String::new(),
-            RegisterIndex: ::std::option::Option::None,
+    fn default_instance() -> &'static ReloadConfigRequest {
+        static instance: ReloadConfigRequest = ReloadConfigRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ExtendRuntimeMeasurementRequest {
+impl ::protobuf::MessageFull for ReloadConfigRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtendRuntimeMeasurementRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReloadConfigRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ExtendRuntimeMeasurementRequest {
+impl ::std::fmt::Display for ReloadConfigRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ExtendRuntimeMeasurementRequest {
+impl ::protobuf::reflect::ProtobufValue for ReloadConfigRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:attestation_agent.ExtendRuntimeMeasurementResponse)
+// @@protoc_insertion_point(message:attestation_agent.ReloadConfigResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ExtendRuntimeMeasurementResponse {
+pub struct ReloadConfigResponse {
     // special fields
-    // @@protoc_insertion_point(special_field:attestation_agent.ExtendRuntimeMeasurementResponse.special_fields)
+    // @@protoc_insertion_point(special_field:attestation_agent.ReloadConfigResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ExtendRuntimeMeasurementResponse {
-    fn default() -> &'a ExtendRuntimeMeasurementResponse {
-        <ExtendRuntimeMeasurementResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReloadConfigResponse {
+    fn default() -> &'a ReloadConfigResponse {
+        <ReloadConfigResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ExtendRuntimeMeasurementResponse {
-    pub fn new() -> ExtendRuntimeMeasurementResponse {
+impl ReloadConfigResponse {
+    pub fn new() -> ReloadConfigResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExtendRuntimeMeasurementResponse>(
-            "ExtendRuntimeMeasurementResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReloadConfigResponse>(
+            "ReloadConfigResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ExtendRuntimeMeasurementResponse {
-    const NAME: &'static str = "ExtendRuntimeMeasurementResponse";
+impl ::protobuf::Message for ReloadConfigResponse {
+    const NAME: &'static str = "ReloadConfigResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -898,36 +2292,36 @@ impl ::protobuf::Message for ExtendRuntimeMeasurementResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ExtendRuntimeMeasurementResponse {
-        ExtendRuntimeMeasurementResponse::new()
+    fn new() -> ReloadConfigResponse {
+        ReloadConfigResponse::new()
     }
 
     fn clear(&mut self) {
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ExtendRuntimeMeasurementResponse {
-        static instance: ExtendRuntimeMeasurementResponse = ExtendRuntimeMeasurementResponse {
+    fn default_instance() -> &'static ReloadConfigResponse {
+        static instance: ReloadConfigResponse = ReloadConfigResponse {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ExtendRuntimeMeasurementResponse {
+impl ::protobuf::MessageFull for ReloadConfigResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtendRuntimeMeasurementResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReloadConfigResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ExtendRuntimeMeasurementResponse {
+impl ::std::fmt::Display for ReloadConfigResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ExtendRuntimeMeasurementResponse {
+impl ::protobuf::reflect::ProtobufValue for ReloadConfigResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -1526,31 +2920,63 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ceRequest\x12\x20\n\x0bRuntimeData\x18\x01\x20\x01(\x0cR\x0bRuntimeData\
     \"@\n\x1cGetAdditionalEvidenceRequest\x12\x20\n\x0bRuntimeData\x18\x01\
     \x20\x01(\x0cR\x0bRuntimeData\"1\n\x13GetEvidenceResponse\x12\x1a\n\x08E\
-    vidence\x18\x01\x20\x01(\x0cR\x08Evidence\"o\n\x0fGetTokenRequest\x12\
-    \x1c\n\tTokenType\x18\x01\x20\x01(\tR\tTokenType\x12+\n\x0eAdditionalDat\
-    a\x18\x02\x20\x01(\tH\0R\x0eAdditionalData\x88\x01\x01B\x11\n\x0f_Additi\
-    onalData\"(\n\x10GetTokenResponse\x12\x14\n\x05Token\x18\x01\x20\x01(\
-    \x0cR\x05Token\"\xae\x01\n\x1fExtendRuntimeMeasurementRequest\x12\x16\n\
-    \x06Domain\x18\x01\x20\x01(\tR\x06Domain\x12\x1c\n\tOperation\x18\x02\
-    \x20\x01(\tR\tOperation\x12\x18\n\x07Content\x18\x03\x20\x01(\tR\x07Cont\
-    ent\x12)\n\rRegisterIndex\x18\x04\x20\x01(\x04H\0R\rRegisterIndex\x88\
-    \x01\x01B\x10\n\x0e_RegisterIndex\"\"\n\x20ExtendRuntimeMeasurementRespo\
-    nse\"K\n\x11InitDataPlaintext\x12\x18\n\x07Content\x18\x01\x20\x01(\x0cR\
-    \x07Content\x12\x1c\n\tAlgorithm\x18\x02\x20\x01(\tR\tAlgorithm\"-\n\x13\
-    BindInitDataRequest\x12\x16\n\x06Digest\x18\x01\x20\x01(\x0cR\x06Digest\
-    \"\x16\n\x14BindInitDataResponse\"\x13\n\x11GetTeeTypeRequest\"&\n\x12Ge\
-    tTeeTypeResponse\x12\x10\n\x03tee\x18\x01\x20\x01(\tR\x03tee2\x80\x05\n\
-    \x17AttestationAgentService\x12\\\n\x0bGetEvidence\x12%.attestation_agen\
-    t.GetEvidenceRequest\x1a&.attestation_agent.GetEvidenceResponse\x12p\n\
-    \x15GetAdditionalEvidence\x12/.attestation_agent.GetAdditionalEvidenceRe\
-    quest\x1a&.attestation_agent.GetEvidenceResponse\x12S\n\x08GetToken\x12\
-    \".attestation_agent.GetTokenRequest\x1a#.attestation_agent.GetTokenResp\
-    onse\x12\x83\x01\n\x18ExtendRuntimeMeasurement\x122.attestation_agent.Ex\
-    tendRuntimeMeasurementRequest\x1a3.attestation_agent.ExtendRuntimeMeasur\
-    ementResponse\x12_\n\x0cBindInitData\x12&.attestation_agent.BindInitData\
-    Request\x1a'.attestation_agent.BindInitDataResponse\x12Y\n\nGetTeeType\
-    \x12$.attestation_agent.GetTeeTypeRequest\x1a%.attestation_agent.GetTeeT\
-    ypeResponseb\x06proto3\
+    vidence\x18\x01\x20\x01(\x0cR\x08Evidence\"?\n\x1bGetCompositeEvidenceRe\
+    quest\x12\x20\n\x0bRuntimeData\x18\x01\x20\x01(\x0cR\x0bRuntimeData\"B\n\
+    \x0eDeviceEvidence\x12\x1a\n\x08Evidence\x18\x01\x20\x01(\x0cR\x08Eviden\
+    ce\x12\x14\n\x05Error\x18\x02\x20\x01(\tR\x05Error\"\xcb\x02\n\x1cGetCom\
+    positeEvidenceResponse\x12\x1e\n\nPrimaryTee\x18\x01\x20\x01(\tR\nPrimar\
+    yTee\x12(\n\x0fPrimaryEvidence\x18\x02\x20\x01(\x0cR\x0fPrimaryEvidence\
+    \x12w\n\x12AdditionalEvidence\x18\x03\x20\x03(\x0b2G.attestation_agent.G\
+    etCompositeEvidenceResponse.AdditionalEvidenceEntryR\x12AdditionalEviden\
+    ce\x1ah\n\x17AdditionalEvidenceEntry\x12\x10\n\x03key\x18\x01\x20\x01(\t\
+    R\x03key\x127\n\x05value\x18\x02\x20\x01(\x0b2!.attestation_agent.Device\
+    EvidenceR\x05value:\x028\x01\"o\n\x0fGetTokenRequest\x12\x1c\n\tTokenTyp\
+    e\x18\x01\x20\x01(\tR\tTokenType\x12+\n\x0eAdditionalData\x18\x02\x20\
+    \x01(\tH\0R\x0eAdditionalData\x88\x01\x01B\x11\n\x0f_AdditionalData\"(\n\
+    \x10GetTokenResponse\x12\x14\n\x05Token\x18\x01\x20\x01(\x0cR\x05Token\"\
+    \xae\x01\n\x1fExtendRuntimeMeasurementRequest\x12\x16\n\x06Domain\x18\
+    \x01\x20\x01(\tR\x06Domain\x12\x1c\n\tOperation\x18\x02\x20\x01(\tR\tOpe\
+    ration\x12\x18\n\x07Content\x18\x03\x20\x01(\tR\x07Content\x12)\n\rRegis\
+    terIndex\x18\x04\x20\x01(\x04H\0R\rRegisterIndex\x88\x01\x01B\x10\n\x0e_\
+    RegisterIndex\"\"\n\x20ExtendRuntimeMeasurementResponse\"\x8b\x01\n\x12G\
+    etEventLogRequest\x12\x1b\n\x06Domain\x18\x01\x20\x01(\tH\0R\x06Domain\
+    \x88\x01\x01\x12)\n\rRegisterIndex\x18\x02\x20\x01(\x04H\x01R\rRegisterI\
+    ndex\x88\x01\x01\x12\x10\n\x03Raw\x18\x03\x20\x01(\x08R\x03RawB\t\n\x07_\
+    DomainB\x10\n\x0e_RegisterIndex\"\x8a\x02\n\rEventLogEntry\x12$\n\rRegis\
+    terIndex\x18\x01\x20\x01(\x04R\rRegisterIndex\x12\x16\n\x06Domain\x18\
+    \x02\x20\x01(\tR\x06Domain\x12\x1c\n\tOperation\x18\x03\x20\x01(\tR\tOpe\
+    ration\x12\x18\n\x07Content\x18\x04\x20\x01(\tR\x07Content\x12G\n\x07Dig\
+    ests\x18\x05\x20\x03(\x0b2-.attestation_agent.EventLogEntry.DigestsEntry\
+    R\x07Digests\x1a:\n\x0cDigestsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value:\x028\x01\"c\n\
+    \x13GetEventLogResponse\x12:\n\x07Entries\x18\x01\x20\x03(\x0b2\x20.atte\
+    station_agent.EventLogEntryR\x07Entries\x12\x10\n\x03Raw\x18\x02\x20\x01\
+    (\x0cR\x03Raw\"\x17\n\x15VerifyEventLogRequest\"P\n\x16VerifyEventLogRes\
+    ponse\x12\x1e\n\nConsistent\x18\x01\x20\x01(\x08R\nConsistent\x12\x16\n\
+    \x06Detail\x18\x02\x20\x01(\tR\x06Detail\"\x15\n\x13ReloadConfigRequest\
+    \"\x16\n\x14ReloadConfigResponse\"K\n\x11InitDataPlaintext\x12\x18\n\x07\
+    Content\x18\x01\x20\x01(\x0cR\x07Content\x12\x1c\n\tAlgorithm\x18\x02\
+    \x20\x01(\tR\tAlgorithm\"-\n\x13BindInitDataRequest\x12\x16\n\x06Digest\
+    \x18\x01\x20\x01(\x0cR\x06Digest\"\x16\n\x14BindInitDataResponse\"\x13\n\
+    \x11GetTeeTypeRequest\"&\n\x12GetTeeTypeResponse\x12\x10\n\x03tee\x18\
+    \x01\x20\x01(\tR\x03tee2\x9f\x08\n\x17AttestationAgentService\x12\\\n\
+    \x0bGetEvidence\x12%.attestation_agent.GetEvidenceRequest\x1a&.attestati\
+    on_agent.GetEvidenceResponse\x12p\n\x15GetAdditionalEvidence\x12/.attest\
+    ation_agent.GetAdditionalEvidenceRequest\x1a&.attestation_agent.GetEvide\
+    nceResponse\x12w\n\x14GetCompositeEvidence\x12..attestation_agent.GetCom\
+    positeEvidenceRequest\x1a/.attestation_agent.GetCompositeEvidenceRespons\
+    e\x12S\n\x08GetToken\x12\".attestation_agent.GetTokenRequest\x1a#.attest\
+    ation_agent.GetTokenResponse\x12\x83\x01\n\x18ExtendRuntimeMeasurement\
+    \x122.attestation_agent.ExtendRuntimeMeasurementRequest\x1a3.attestation\
+    _agent.ExtendRuntimeMeasurementResponse\x12_\n\x0cBindInitData\x12&.atte\
+    station_agent.BindInitDataRequest\x1a'.attestation_agent.BindInitDataRes\
+    ponse\x12Y\n\nGetTeeType\x12$.attestation_agent.GetTeeTypeRequest\x1a%.a\
+    ttestation_agent.GetTeeTypeResponse\x12\\\n\x0bGetEventLog\x12%.attestat\
+    ion_agent.GetEventLogRequest\x1a&.attestation_agent.GetEventLogResponse\
+    \x12e\n\x0eVerifyEventLog\x12(.attestation_agent.VerifyEventLogRequest\
+    \x1a).attestation_agent.VerifyEventLogResponse\x12_\n\x0cReloadConfig\
+    \x12&.attestation_agent.ReloadConfigRequest\x1a'.attestation_agent.Reloa\
+    dConfigResponseb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1568,14 +2994,24 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(12);
+            let mut messages = ::std::vec::Vec::with_capacity(22);
             messages.push(GetEvidenceRequest::generated_message_descriptor_data());
             messages.push(GetAdditionalEvidenceRequest::generated_message_descriptor_data());
             messages.push(GetEvidenceResponse::generated_message_descriptor_data());
+            messages.push(GetCompositeEvidenceRequest::generated_message_descriptor_data());
+            messages.push(DeviceEvidence::generated_message_descriptor_data());
+            messages.push(GetCompositeEvidenceResponse::generated_message_descriptor_data());
             messages.push(GetTokenRequest::generated_message_descriptor_data());
             messages.push(GetTokenResponse::generated_message_descriptor_data());
             messages.push(ExtendRuntimeMeasurementRequest::generated_message_descriptor_data());
             messages.push(ExtendRuntimeMeasurementResponse::generated_message_descriptor_data());
+            messages.push(GetEventLogRequest::generated_message_descriptor_data());
+            messages.push(EventLogEntry::generated_message_descriptor_data());
+            messages.push(GetEventLogResponse::generated_message_descriptor_data());
+            messages.push(VerifyEventLogRequest::generated_message_descriptor_data());
+            messages.push(VerifyEventLogResponse::generated_message_descriptor_data());
+            messages.push(ReloadConfigRequest::generated_message_descriptor_data());
+            messages.push(ReloadConfigResponse::generated_message_descriptor_data());
             messages.push(InitDataPlaintext::generated_message_descriptor_data());
             messages.push(BindInitDataRequest::generated_message_descriptor_data());
             messages.push(BindInitDataResponse::generated_message_descriptor_data());
@@ -4,7 +4,9 @@
 //
 
 use async_trait::async_trait;
+use image_rs::image::ImageMeta;
 
+use crate::kms::plugins::kbs::CacheStats;
 use crate::storage::volume_type::Storage;
 use crate::Result;
 
@@ -35,6 +37,14 @@ pub trait DataHub {
     /// <https://github.com/confidential-containers/guest-components/blob/main/attestation-agent/docs/KBS_URI.md>
     async fn get_resource(&self, uri: String) -> Result<Vec<u8>>;
 
+    /// Drop the cached resources whose KBS Resource URI starts with
+    /// `prefix`, or all the cached resources if `prefix` is empty. Returns
+    /// the number of dropped resources.
+    async fn invalidate_resource_cache(&self, prefix: &str) -> Result<u64>;
+
+    /// Get the hit/miss counters and the size of the resource cache.
+    async fn resource_cache_stats(&self) -> Result<CacheStats>;
+
     async fn prepare_resource_injection(
         &self,
         resource_path: String,
@@ -48,8 +58,33 @@ pub trait DataHub {
         encrypted_resource: Vec<u8>,
     ) -> Result<()>;
 
+    /// Mount the given [`Storage`] and return the mount path. CDH records
+    /// the active mount by its mount point.
     async fn secure_mount(&self, storage: Storage) -> Result<String>;
 
-    /// Pull image of image url (reference), and place the merged layers in the `bundle_path/rootfs`
-    async fn pull_image(&self, _image_url: &str, _bundle_path: &str) -> Result<String>;
+    /// Tear down the secure mount on `mount_point` created by
+    /// [`DataHub::secure_mount`], i.e. unmount it, close the opened
+    /// dm-crypt/dm-integrity devices and scrub the key material.
+    async fn secure_umount(&self, mount_point: &str) -> Result<()>;
+
+    /// Pull image of image url (reference), and place the merged layers in the `bundle_path/rootfs`.
+    /// `platform` overrides the platform of the image config to pull from
+    /// multi-platform images, in the form of `os/architecture[/variant][@manifest-digest]`.
+    async fn pull_image(
+        &self,
+        _image_url: &str,
+        _bundle_path: &str,
+        _platform: Option<&str>,
+    ) -> Result<String>;
+
+    /// Remove the image of the given reference or image ID, tear down the
+    /// bundles created from it, and delete the layers no longer used.
+    async fn remove_image(&self, image: &str) -> Result<()>;
+
+    /// Get the metadata of all the pulled images.
+    async fn list_images(&self) -> Result<Vec<ImageMeta>>;
+
+    /// Get the metadata of the pulled image of the given reference or image
+    /// ID.
+    async fn inspect_image(&self, image: &str) -> Result<ImageMeta>;
 }
@@ -90,6 +90,10 @@ struct PullImageArgs {
     /// Path to store the bundle
     #[arg(short, long)]
     bundle_path: String,
+
+    /// Platform to pull from a multi-platform image, e.g. `linux/arm64/v8`
+    #[arg(short, long)]
+    platform: Option<String>,
 }
 
 #[tokio::main]
@@ -177,7 +181,11 @@ async fn main() {
         }
         Operation::PullImage(op_args) => {
             let manifest_digest = cdh
-                .pull_image(&op_args.image_url, &op_args.bundle_path)
+                .pull_image(
+                    &op_args.image_url,
+                    &op_args.bundle_path,
+                    op_args.platform.as_deref(),
+                )
                 .await
                 .expect("failed to pull image");
             println!("image digest: {manifest_digest}");
@@ -9,10 +9,13 @@
 
 use api::{
     get_resource_service_client::GetResourceServiceClient,
+    image_pull_service_client::ImagePullServiceClient,
     key_provider_service_client::KeyProviderServiceClient,
     sealed_secret_service_client::SealedSecretServiceClient,
-    secure_mount_service_client::SecureMountServiceClient, GetResourceRequest,
-    KeyProviderKeyWrapProtocolInput, SecureMountRequest, UnsealSecretInput,
+    secure_mount_service_client::SecureMountServiceClient, GetResourceCacheStatsRequest,
+    GetResourceRequest, InspectImageRequest, InvalidateResourceCacheRequest,
+    KeyProviderKeyWrapProtocolInput, ListImagesRequest, SecureMountRequest, SecureUnmountRequest,
+    UnsealSecretInput,
 };
 use base64::{engine::general_purpose::STANDARD, Engine};
 use clap::{Args, Parser, Subcommand};
@@ -48,8 +51,23 @@ enum Operation {
     /// Get Resource from KBS
     GetResource(GetResourceArgs),
 
+    /// Drop cached KBS resources
+    InvalidateResourceCache(InvalidateResourceCacheArgs),
+
+    /// Get the hit/miss counters of the KBS resource cache
+    GetResourceCacheStats,
+
     /// Secure mount
     SecureMount(SecureMountArgs),
+
+    /// Tear down a secure mount
+    SecureUnmount(SecureUnmountArgs),
+
+    /// List the pulled images
+    ListImages,
+
+    /// Show the metadata of a pulled image
+    InspectImage(InspectImageArgs),
 }
 
 #[derive(Args)]
@@ -76,6 +94,14 @@ struct GetResourceArgs {
     resource_uri: String,
 }
 
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct InvalidateResourceCacheArgs {
+    /// prefix of the KBS Resource URIs to drop, all resources if not given
+    #[arg(short, long, default_value = "")]
+    prefix: String,
+}
+
 #[derive(Args)]
 #[command(author, version, about, long_about = None)]
 struct SecureMountArgs {
@@ -84,6 +110,22 @@ struct SecureMountArgs {
     storage_path: String,
 }
 
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct SecureUnmountArgs {
+    /// the mount point of the secure mount
+    #[arg(short, long)]
+    mount_point: String,
+}
+
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct InspectImageArgs {
+    /// Reference or ID of the image
+    #[arg(short, long)]
+    image: String,
+}
+
 #[tokio::main]
 async fn main() {
     let args = Cli::parse();
@@ -124,6 +166,34 @@ async fn main() {
             let res = STANDARD.encode(res.into_inner().resource);
             println!("{res}");
         }
+        Operation::InvalidateResourceCache(arg) => {
+            let mut client = GetResourceServiceClient::connect(args.socket)
+                .await
+                .expect("initialize client");
+            let req = tonic::Request::new(InvalidateResourceCacheRequest {
+                resource_path_prefix: arg.prefix,
+            });
+            let res = client
+                .invalidate_resource_cache(req)
+                .await
+                .expect("request to CDH");
+            println!("invalidated: {}", res.into_inner().invalidated);
+        }
+        Operation::GetResourceCacheStats => {
+            let mut client = GetResourceServiceClient::connect(args.socket)
+                .await
+                .expect("initialize client");
+            let req = tonic::Request::new(GetResourceCacheStatsRequest {});
+            let res = client
+                .get_resource_cache_stats(req)
+                .await
+                .expect("request to CDH")
+                .into_inner();
+            println!(
+                "hits: {}, misses: {}, entries: {}",
+                res.hits, res.misses, res.entries
+            );
+        }
         Operation::SecureMount(arg) => {
             let mut client = SecureMountServiceClient::connect(args.socket)
                 .await
@@ -140,5 +210,65 @@ async fn main() {
             let res = client.secure_mount(req).await.expect("request to CDH");
             println!("mount path: {}", res.into_inner().mount_path);
         }
+        Operation::SecureUnmount(arg) => {
+            let mut client = SecureMountServiceClient::connect(args.socket)
+                .await
+                .expect("initialize client");
+            let req = tonic::Request::new(SecureUnmountRequest {
+                mount_point: arg.mount_point.clone(),
+            });
+            client.secure_unmount(req).await.expect("request to CDH");
+            println!("unmounted: {}", arg.mount_point);
+        }
+        Operation::ListImages => {
+            let mut client = ImagePullServiceClient::connect(args.socket)
+                .await
+                .expect("initialize client");
+            let req = tonic::Request::new(ListImagesRequest {});
+            let res = client
+                .list_images(req)
+                .await
+                .expect("request to CDH")
+                .into_inner();
+            for image in res.images {
+                println!(
+                    "{} {} {} signed: {}",
+                    image.id, image.reference, image.digest, image.signed
+                );
+            }
+        }
+        Operation::InspectImage(arg) => {
+            let mut client = ImagePullServiceClient::connect(args.socket)
+                .await
+                .expect("initialize client");
+            let req = tonic::Request::new(InspectImageRequest { image: arg.image });
+            let res = client
+                .inspect_image(req)
+                .await
+                .expect("request to CDH")
+                .into_inner();
+            let image = res.image.unwrap_or_default();
+            println!("id: {}", image.id);
+            println!("reference: {}", image.reference);
+            println!("digest: {}", image.digest);
+            println!("signed: {}", image.signed);
+            println!("platform: {}", image.platform);
+            println!("config: {}", image.config);
+            for layer in image.layers {
+                println!(
+                    "layer: {} uncompressed: {} compression: {} encrypted: {}",
+                    layer.compressed_digest,
+                    layer.uncompressed_digest,
+                    layer.compression,
+                    layer.encrypted
+                );
+            }
+            for verification in image.verifications {
+                println!(
+                    "verified: {} image digest: {} policy digest: {}",
+                    verification.reference, verification.image_digest, verification.policy_digest
+                );
+            }
+        }
     }
 }
@@ -9,6 +9,7 @@ use confidential_data_hub::{
     storage::volume_type::Storage,
     {hub::Hub, DataHub},
 };
+use image_rs::image::ImageMeta;
 use log::{debug, error};
 use std::{error::Error as _, net::SocketAddr, sync::Arc};
 use tonic::{transport::Server, Request, Response, Status};
@@ -23,11 +24,15 @@ use api::{
     key_provider_service_server::{KeyProviderService, KeyProviderServiceServer},
     sealed_secret_service_server::{SealedSecretService, SealedSecretServiceServer},
     secure_mount_service_server::{SecureMountService, SecureMountServiceServer},
-    CommitResourceInjectionRequest, CommitResourceInjectionResponse, GetResourceRequest,
-    GetResourceResponse, ImagePullRequest, ImagePullResponse, KeyProviderKeyWrapProtocolInput,
-    KeyProviderKeyWrapProtocolOutput, PrepareResourceInjectionRequest,
-    PrepareResourceInjectionResponse, SecureMountRequest, SecureMountResponse, UnsealSecretInput,
-    UnsealSecretOutput,
+    CommitResourceInjectionRequest, CommitResourceInjectionResponse, GetResourceCacheStatsRequest,
+    GetResourceCacheStatsResponse, GetResourceRequest, GetResourceResponse, ImageInfo,
+    ImagePullRequest, ImagePullResponse, InspectImageRequest, InspectImageResponse,
+    InvalidateResourceCacheRequest, InvalidateResourceCacheResponse,
+    KeyProviderKeyWrapProtocolInput, KeyProviderKeyWrapProtocolOutput, LayerInfo,
+    ListImagesRequest, ListImagesResponse, PrepareResourceInjectionRequest,
+    PrepareResourceInjectionResponse, RemoveImageRequest, RemoveImageResponse, SecureMountRequest,
+    SecureMountResponse, SecureUnmountRequest, SecureUnmountResponse, SignatureVerification,
+    UnsealSecretInput, UnsealSecretOutput,
 };
 
 mod api {
@@ -92,6 +97,59 @@ impl GetResourceService for Arc<Cdh> {
         Result::Ok(Response::new(reply))
     }
 
+    async fn invalidate_resource_cache(
+        &self,
+        request: Request<InvalidateResourceCacheRequest>,
+    ) -> Result<Response<InvalidateResourceCacheResponse>, Status> {
+        debug!("[gRPC CDH] get new InvalidateResourceCache request");
+        let request = request.into_inner();
+
+        let invalidated = self
+            .inner
+            .invalidate_resource_cache(&request.resource_path_prefix)
+            .await
+            .map_err(|e| {
+                let detailed_error = format_error!(e);
+                error!(
+                    "[gRPC CDH] Call CDH to invalidate resource cache failed:\n{detailed_error}"
+                );
+                Status::internal(format!(
+                    "[ERROR] CDH invalidate resource cache failed: {}",
+                    e
+                ))
+            })?;
+
+        debug!("[gRPC CDH] Invalidate resource cache successfully!");
+
+        let reply = InvalidateResourceCacheResponse { invalidated };
+
+        Result::Ok(Response::new(reply))
+    }
+
+    async fn get_resource_cache_stats(
+        &self,
+        _request: Request<GetResourceCacheStatsRequest>,
+    ) -> Result<Response<GetResourceCacheStatsResponse>, Status> {
+        debug!("[gRPC CDH] get new GetResourceCacheStats request");
+
+        let stats = self.inner.resource_cache_stats().await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[gRPC CDH] Call CDH to get resource cache stats failed:\n{detailed_error}");
+            Status::internal(format!(
+                "[ERROR] CDH get resource cache stats failed: {}",
+                e
+            ))
+        })?;
+
+        let reply = GetResourceCacheStatsResponse {
+            hits: stats.hits,
+            misses: stats.misses,
+            entries: stats.entries,
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+
     async fn prepare_resource_injection(
         &self,
         request: Request<PrepareResourceInjectionRequest>,
@@ -178,6 +236,27 @@ impl SecureMountService for Arc<Cdh> {
 
         Result::Ok(Response::new(reply))
     }
+
+    async fn secure_unmount(
+        &self,
+        request: Request<SecureUnmountRequest>,
+    ) -> Result<Response<SecureUnmountResponse>, Status> {
+        debug!("[gRPC CDH] get new SecureUnmount request");
+        let request = request.into_inner();
+
+        self.inner
+            .secure_umount(&request.mount_point)
+            .await
+            .map_err(|e| {
+                let detailed_error = format_error!(e);
+                error!("[gRPC CDH] Call CDH to secure unmount failed:\n{detailed_error}");
+                Status::internal(format!("[ERROR] CDH secure unmount failed: {}", e))
+            })?;
+
+        debug!("[gRPC CDH] Secure unmount successfully!");
+
+        Result::Ok(Response::new(SecureUnmountResponse {}))
+    }
 }
 
 #[tonic::async_trait]
@@ -191,7 +270,11 @@ impl ImagePullService for Arc<Cdh> {
 
         let manifest_digest = self
             .inner
-            .pull_image(&request.image_url, &request.bundle_path)
+            .pull_image(
+                &request.image_url,
+                &request.bundle_path,
+                Some(request.platform.as_str()).filter(|p| !p.is_empty()),
+            )
             .await
             .map_err(|e| {
                 let detailed_error = format_error!(e);
@@ -205,6 +288,100 @@ impl ImagePullService for Arc<Cdh> {
 
         Result::Ok(Response::new(reply))
     }
+
+    async fn remove_image(
+        &self,
+        request: Request<RemoveImageRequest>,
+    ) -> Result<Response<RemoveImageResponse>, Status> {
+        debug!("[gRPC CDH] get new RemoveImage request");
+        let request = request.into_inner();
+
+        self.inner.remove_image(&request.image).await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[gRPC CDH] Call CDH to remove image failed:\n{detailed_error}");
+            Status::internal(format!("[ERROR] CDH image removal failed: {}", e))
+        })?;
+
+        debug!("[gRPC CDH] Remove image successfully!");
+
+        Result::Ok(Response::new(RemoveImageResponse {}))
+    }
+
+    async fn list_images(
+        &self,
+        _request: Request<ListImagesRequest>,
+    ) -> Result<Response<ListImagesResponse>, Status> {
+        debug!("[gRPC CDH] get new ListImages request");
+
+        let images = self.inner.list_images().await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[gRPC CDH] Call CDH to list images failed:\n{detailed_error}");
+            Status::internal(format!("[ERROR] CDH list images failed: {}", e))
+        })?;
+
+        let reply = ListImagesResponse {
+            images: images.into_iter().map(image_info).collect(),
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+
+    async fn inspect_image(
+        &self,
+        request: Request<InspectImageRequest>,
+    ) -> Result<Response<InspectImageResponse>, Status> {
+        debug!("[gRPC CDH] get new InspectImage request");
+        let request = request.into_inner();
+
+        let image = self
+            .inner
+            .inspect_image(&request.image)
+            .await
+            .map_err(|e| {
+                let detailed_error = format_error!(e);
+                error!("[gRPC CDH] Call CDH to inspect image failed:\n{detailed_error}");
+                Status::internal(format!("[ERROR] CDH inspect image failed: {}", e))
+            })?;
+
+        let reply = InspectImageResponse {
+            image: Some(image_info(image)),
+        };
+
+        Result::Ok(Response::new(reply))
+    }
+}
+
+fn image_info(image: ImageMeta) -> ImageInfo {
+    ImageInfo {
+        config: serde_json::to_string(&image.image_config).unwrap_or_default(),
+        id: image.id,
+        reference: image.reference,
+        digest: image.digest,
+        layers: image
+            .layer_metas
+            .into_iter()
+            .map(|layer| LayerInfo {
+                compressed_digest: layer.compressed_digest,
+                uncompressed_digest: layer.uncompressed_digest,
+                compression: layer.decoder.to_string(),
+                encrypted: layer.encrypted,
+            })
+            .collect(),
+        signed: image.signed,
+        platform: image
+            .platform
+            .map(|platform| platform.to_string())
+            .unwrap_or_default(),
+        verifications: image
+            .verifications
+            .into_iter()
+            .map(|(reference, verification)| SignatureVerification {
+                reference,
+                image_digest: verification.image_digest,
+                policy_digest: verification.policy_digest,
+            })
+            .collect(),
+    }
 }
 
 #[tonic::async_trait]
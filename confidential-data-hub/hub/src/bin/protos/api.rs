@@ -512,53 +512,2060 @@ impl ::protobuf::reflect::ProtobufValue for GetResourceResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:api.InvalidateResourceCacheRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct InvalidateResourceCacheRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.InvalidateResourceCacheRequest.ResourcePathPrefix)
+    pub ResourcePathPrefix: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.InvalidateResourceCacheRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InvalidateResourceCacheRequest {
+    fn default() -> &'a InvalidateResourceCacheRequest {
+        <InvalidateResourceCacheRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InvalidateResourceCacheRequest {
+    pub fn new() -> InvalidateResourceCacheRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ResourcePathPrefix",
+            |m: &InvalidateResourceCacheRequest| { &m.ResourcePathPrefix },
+            |m: &mut InvalidateResourceCacheRequest| { &mut m.ResourcePathPrefix },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InvalidateResourceCacheRequest>(
+            "InvalidateResourceCacheRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InvalidateResourceCacheRequest {
+    const NAME: &'static str = "InvalidateResourceCacheRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.ResourcePathPrefix = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.ResourcePathPrefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ResourcePathPrefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.ResourcePathPrefix.is_empty() {
+            os.write_string(1, &self.ResourcePathPrefix)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InvalidateResourceCacheRequest {
+        InvalidateResourceCacheRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.ResourcePathPrefix.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InvalidateResourceCacheRequest {
+        static instance: InvalidateResourceCacheRequest = InvalidateResourceCacheRequest {
+            ResourcePathPrefix: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for InvalidateResourceCacheRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InvalidateResourceCacheRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InvalidateResourceCacheRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InvalidateResourceCacheRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.InvalidateResourceCacheResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct InvalidateResourceCacheResponse {
+    // message fields
+    // @@protoc_insertion_point(field:api.InvalidateResourceCacheResponse.Invalidated)
+    pub Invalidated: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.InvalidateResourceCacheResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InvalidateResourceCacheResponse {
+    fn default() -> &'a InvalidateResourceCacheResponse {
+        <InvalidateResourceCacheResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InvalidateResourceCacheResponse {
+    pub fn new() -> InvalidateResourceCacheResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Invalidated",
+            |m: &InvalidateResourceCacheResponse| { &m.Invalidated },
+            |m: &mut InvalidateResourceCacheResponse| { &mut m.Invalidated },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InvalidateResourceCacheResponse>(
+            "InvalidateResourceCacheResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InvalidateResourceCacheResponse {
+    const NAME: &'static str = "InvalidateResourceCacheResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.Invalidated = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.Invalidated != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.Invalidated);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.Invalidated != 0 {
+            os.write_uint64(1, self.Invalidated)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InvalidateResourceCacheResponse {
+        InvalidateResourceCacheResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.Invalidated = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InvalidateResourceCacheResponse {
+        static instance: InvalidateResourceCacheResponse = InvalidateResourceCacheResponse {
+            Invalidated: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for InvalidateResourceCacheResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InvalidateResourceCacheResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InvalidateResourceCacheResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InvalidateResourceCacheResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.GetResourceCacheStatsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetResourceCacheStatsRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:api.GetResourceCacheStatsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetResourceCacheStatsRequest {
+    fn default() -> &'a GetResourceCacheStatsRequest {
+        <GetResourceCacheStatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetResourceCacheStatsRequest {
+    pub fn new() -> GetResourceCacheStatsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetResourceCacheStatsRequest>(
+            "GetResourceCacheStatsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetResourceCacheStatsRequest {
+    const NAME: &'static str = "GetResourceCacheStatsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetResourceCacheStatsRequest {
+        GetResourceCacheStatsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetResourceCacheStatsRequest {
+        static instance: GetResourceCacheStatsRequest = GetResourceCacheStatsRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetResourceCacheStatsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetResourceCacheStatsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetResourceCacheStatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetResourceCacheStatsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.GetResourceCacheStatsResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetResourceCacheStatsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:api.GetResourceCacheStatsResponse.Hits)
+    pub Hits: u64,
+    // @@protoc_insertion_point(field:api.GetResourceCacheStatsResponse.Misses)
+    pub Misses: u64,
+    // @@protoc_insertion_point(field:api.GetResourceCacheStatsResponse.Entries)
+    pub Entries: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.GetResourceCacheStatsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetResourceCacheStatsResponse {
+    fn default() -> &'a GetResourceCacheStatsResponse {
+        <GetResourceCacheStatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetResourceCacheStatsResponse {
+    pub fn new() -> GetResourceCacheStatsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Hits",
+            |m: &GetResourceCacheStatsResponse| { &m.Hits },
+            |m: &mut GetResourceCacheStatsResponse| { &mut m.Hits },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Misses",
+            |m: &GetResourceCacheStatsResponse| { &m.Misses },
+            |m: &mut GetResourceCacheStatsResponse| { &mut m.Misses },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Entries",
+            |m: &GetResourceCacheStatsResponse| { &m.Entries },
+            |m: &mut GetResourceCacheStatsResponse| { &mut m.Entries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetResourceCacheStatsResponse>(
+            "GetResourceCacheStatsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetResourceCacheStatsResponse {
+    const NAME: &'static str = "GetResourceCacheStatsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.Hits = is.read_uint64()?;
+                },
+                16 => {
+                    self.Misses = is.read_uint64()?;
+                },
+                24 => {
+                    self.Entries = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.Hits != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.Hits);
+        }
+        if self.Misses != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.Misses);
+        }
+        if self.Entries != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.Entries);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.Hits != 0 {
+            os.write_uint64(1, self.Hits)?;
+        }
+        if self.Misses != 0 {
+            os.write_uint64(2, self.Misses)?;
+        }
+        if self.Entries != 0 {
+            os.write_uint64(3, self.Entries)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetResourceCacheStatsResponse {
+        GetResourceCacheStatsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.Hits = 0;
+        self.Misses = 0;
+        self.Entries = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetResourceCacheStatsResponse {
+        static instance: GetResourceCacheStatsResponse = GetResourceCacheStatsResponse {
+            Hits: 0,
+            Misses: 0,
+            Entries: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetResourceCacheStatsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetResourceCacheStatsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetResourceCacheStatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetResourceCacheStatsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:api.PrepareResourceInjectionRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct PrepareResourceInjectionRequest {
-    // message fields
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionRequest.ResourcePath)
-    pub ResourcePath: ::std::string::String,
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionRequest.Nonce)
-    pub Nonce: ::std::string::String,
+pub struct PrepareResourceInjectionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionRequest.ResourcePath)
+    pub ResourcePath: ::std::string::String,
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionRequest.Nonce)
+    pub Nonce: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.PrepareResourceInjectionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PrepareResourceInjectionRequest {
+    fn default() -> &'a PrepareResourceInjectionRequest {
+        <PrepareResourceInjectionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrepareResourceInjectionRequest {
+    pub fn new() -> PrepareResourceInjectionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ResourcePath",
+            |m: &PrepareResourceInjectionRequest| { &m.ResourcePath },
+            |m: &mut PrepareResourceInjectionRequest| { &mut m.ResourcePath },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Nonce",
+            |m: &PrepareResourceInjectionRequest| { &m.Nonce },
+            |m: &mut PrepareResourceInjectionRequest| { &mut m.Nonce },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PrepareResourceInjectionRequest>(
+            "PrepareResourceInjectionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PrepareResourceInjectionRequest {
+    const NAME: &'static str = "PrepareResourceInjectionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.ResourcePath = is.read_string()?;
+                },
+                18 => {
+                    self.Nonce = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.ResourcePath.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ResourcePath);
+        }
+        if !self.Nonce.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Nonce);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.ResourcePath.is_empty() {
+            os.write_string(1, &self.ResourcePath)?;
+        }
+        if !self.Nonce.is_empty() {
+            os.write_string(2, &self.Nonce)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PrepareResourceInjectionRequest {
+        PrepareResourceInjectionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.ResourcePath.clear();
+        self.Nonce.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PrepareResourceInjectionRequest {
+        static instance: PrepareResourceInjectionRequest = PrepareResourceInjectionRequest {
+            ResourcePath: ::std::string::String::new(),
+            Nonce: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PrepareResourceInjectionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PrepareResourceInjectionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PrepareResourceInjectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrepareResourceInjectionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.PrepareResourceInjectionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PrepareResourceInjectionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.SessionId)
+    pub SessionId: ::std::string::String,
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.Nonce)
+    pub Nonce: ::std::string::String,
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.TeePubKey)
+    pub TeePubKey: ::std::string::String,
+    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.Evidence)
+    pub Evidence: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.PrepareResourceInjectionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PrepareResourceInjectionResponse {
+    fn default() -> &'a PrepareResourceInjectionResponse {
+        <PrepareResourceInjectionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrepareResourceInjectionResponse {
+    pub fn new() -> PrepareResourceInjectionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "SessionId",
+            |m: &PrepareResourceInjectionResponse| { &m.SessionId },
+            |m: &mut PrepareResourceInjectionResponse| { &mut m.SessionId },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Nonce",
+            |m: &PrepareResourceInjectionResponse| { &m.Nonce },
+            |m: &mut PrepareResourceInjectionResponse| { &mut m.Nonce },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "TeePubKey",
+            |m: &PrepareResourceInjectionResponse| { &m.TeePubKey },
+            |m: &mut PrepareResourceInjectionResponse| { &mut m.TeePubKey },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "Evidence",
+            |m: &PrepareResourceInjectionResponse| { &m.Evidence },
+            |m: &mut PrepareResourceInjectionResponse| { &mut m.Evidence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PrepareResourceInjectionResponse>(
+            "PrepareResourceInjectionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PrepareResourceInjectionResponse {
+    const NAME: &'static str = "PrepareResourceInjectionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.SessionId = is.read_string()?;
+                },
+                18 => {
+                    self.Nonce = is.read_string()?;
+                },
+                26 => {
+                    self.TeePubKey = is.read_string()?;
+                },
+                34 => {
+                    self.Evidence = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.SessionId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.SessionId);
+        }
+        if !self.Nonce.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.Nonce);
+        }
+        if !self.TeePubKey.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.TeePubKey);
+        }
+        if !self.Evidence.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.Evidence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.SessionId.is_empty() {
+            os.write_string(1, &self.SessionId)?;
+        }
+        if !self.Nonce.is_empty() {
+            os.write_string(2, &self.Nonce)?;
+        }
+        if !self.TeePubKey.is_empty() {
+            os.write_string(3, &self.TeePubKey)?;
+        }
+        if !self.Evidence.is_empty() {
+            os.write_bytes(4, &self.Evidence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PrepareResourceInjectionResponse {
+        PrepareResourceInjectionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.SessionId.clear();
+        self.Nonce.clear();
+        self.TeePubKey.clear();
+        self.Evidence.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PrepareResourceInjectionResponse {
+        static instance: PrepareResourceInjectionResponse = PrepareResourceInjectionResponse {
+            SessionId: ::std::string::String::new(),
+            Nonce: ::std::string::String::new(),
+            TeePubKey: ::std::string::String::new(),
+            Evidence: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PrepareResourceInjectionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PrepareResourceInjectionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PrepareResourceInjectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrepareResourceInjectionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.CommitResourceInjectionRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CommitResourceInjectionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.SessionId)
+    pub SessionId: ::std::string::String,
+    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.ResourcePath)
+    pub ResourcePath: ::std::string::String,
+    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.EncryptedResource)
+    pub EncryptedResource: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.CommitResourceInjectionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CommitResourceInjectionRequest {
+    fn default() -> &'a CommitResourceInjectionRequest {
+        <CommitResourceInjectionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitResourceInjectionRequest {
+    pub fn new() -> CommitResourceInjectionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "SessionId",
+            |m: &CommitResourceInjectionRequest| { &m.SessionId },
+            |m: &mut CommitResourceInjectionRequest| { &mut m.SessionId },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ResourcePath",
+            |m: &CommitResourceInjectionRequest| { &m.ResourcePath },
+            |m: &mut CommitResourceInjectionRequest| { &mut m.ResourcePath },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "EncryptedResource",
+            |m: &CommitResourceInjectionRequest| { &m.EncryptedResource },
+            |m: &mut CommitResourceInjectionRequest| { &mut m.EncryptedResource },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitResourceInjectionRequest>(
+            "CommitResourceInjectionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CommitResourceInjectionRequest {
+    const NAME: &'static str = "CommitResourceInjectionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.SessionId = is.read_string()?;
+                },
+                18 => {
+                    self.ResourcePath = is.read_string()?;
+                },
+                26 => {
+                    self.EncryptedResource = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.SessionId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.SessionId);
+        }
+        if !self.ResourcePath.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.ResourcePath);
+        }
+        if !self.EncryptedResource.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.EncryptedResource);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.SessionId.is_empty() {
+            os.write_string(1, &self.SessionId)?;
+        }
+        if !self.ResourcePath.is_empty() {
+            os.write_string(2, &self.ResourcePath)?;
+        }
+        if !self.EncryptedResource.is_empty() {
+            os.write_bytes(3, &self.EncryptedResource)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CommitResourceInjectionRequest {
+        CommitResourceInjectionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.SessionId.clear();
+        self.ResourcePath.clear();
+        self.EncryptedResource.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CommitResourceInjectionRequest {
+        static instance: CommitResourceInjectionRequest = CommitResourceInjectionRequest {
+            SessionId: ::std::string::String::new(),
+            ResourcePath: ::std::string::String::new(),
+            EncryptedResource: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CommitResourceInjectionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitResourceInjectionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CommitResourceInjectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitResourceInjectionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.CommitResourceInjectionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CommitResourceInjectionResponse {
+    // special fields
+    // @@protoc_insertion_point(special_field:api.CommitResourceInjectionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CommitResourceInjectionResponse {
+    fn default() -> &'a CommitResourceInjectionResponse {
+        <CommitResourceInjectionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitResourceInjectionResponse {
+    pub fn new() -> CommitResourceInjectionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitResourceInjectionResponse>(
+            "CommitResourceInjectionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CommitResourceInjectionResponse {
+    const NAME: &'static str = "CommitResourceInjectionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CommitResourceInjectionResponse {
+        CommitResourceInjectionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CommitResourceInjectionResponse {
+        static instance: CommitResourceInjectionResponse = CommitResourceInjectionResponse {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CommitResourceInjectionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitResourceInjectionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CommitResourceInjectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitResourceInjectionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.SecureMountRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SecureMountRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.SecureMountRequest.volume_type)
+    pub volume_type: ::std::string::String,
+    // @@protoc_insertion_point(field:api.SecureMountRequest.options)
+    pub options: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:api.SecureMountRequest.flags)
+    pub flags: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:api.SecureMountRequest.mount_point)
+    pub mount_point: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.SecureMountRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SecureMountRequest {
+    fn default() -> &'a SecureMountRequest {
+        <SecureMountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SecureMountRequest {
+    pub fn new() -> SecureMountRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "volume_type",
+            |m: &SecureMountRequest| { &m.volume_type },
+            |m: &mut SecureMountRequest| { &mut m.volume_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "options",
+            |m: &SecureMountRequest| { &m.options },
+            |m: &mut SecureMountRequest| { &mut m.options },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "flags",
+            |m: &SecureMountRequest| { &m.flags },
+            |m: &mut SecureMountRequest| { &mut m.flags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mount_point",
+            |m: &SecureMountRequest| { &m.mount_point },
+            |m: &mut SecureMountRequest| { &mut m.mount_point },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureMountRequest>(
+            "SecureMountRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SecureMountRequest {
+    const NAME: &'static str = "SecureMountRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.volume_type = is.read_string()?;
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.options.insert(key, value);
+                },
+                26 => {
+                    self.flags.push(is.read_string()?);
+                },
+                34 => {
+                    self.mount_point = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.volume_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.volume_type);
+        }
+        for (k, v) in &self.options {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for value in &self.flags {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if !self.mount_point.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.mount_point);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.volume_type.is_empty() {
+            os.write_string(1, &self.volume_type)?;
+        }
+        for (k, v) in &self.options {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        for v in &self.flags {
+            os.write_string(3, &v)?;
+        };
+        if !self.mount_point.is_empty() {
+            os.write_string(4, &self.mount_point)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SecureMountRequest {
+        SecureMountRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.volume_type.clear();
+        self.options.clear();
+        self.flags.clear();
+        self.mount_point.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SecureMountRequest {
+        static instance: ::protobuf::rt::Lazy<SecureMountRequest> = ::protobuf::rt::Lazy::new();
+        instance.get(SecureMountRequest::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SecureMountRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureMountRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SecureMountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SecureMountRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.SecureMountResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SecureMountResponse {
+    // message fields
+    // @@protoc_insertion_point(field:api.SecureMountResponse.mount_path)
+    pub mount_path: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.SecureMountResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SecureMountResponse {
+    fn default() -> &'a SecureMountResponse {
+        <SecureMountResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SecureMountResponse {
+    pub fn new() -> SecureMountResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mount_path",
+            |m: &SecureMountResponse| { &m.mount_path },
+            |m: &mut SecureMountResponse| { &mut m.mount_path },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureMountResponse>(
+            "SecureMountResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SecureMountResponse {
+    const NAME: &'static str = "SecureMountResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.mount_path = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.mount_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.mount_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.mount_path.is_empty() {
+            os.write_string(1, &self.mount_path)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SecureMountResponse {
+        SecureMountResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.mount_path.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SecureMountResponse {
+        static instance: SecureMountResponse = SecureMountResponse {
+            mount_path: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SecureMountResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureMountResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SecureMountResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SecureMountResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.SecureUnmountRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SecureUnmountRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.SecureUnmountRequest.mount_point)
+    pub mount_point: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.SecureUnmountRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SecureUnmountRequest {
+    fn default() -> &'a SecureUnmountRequest {
+        <SecureUnmountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SecureUnmountRequest {
+    pub fn new() -> SecureUnmountRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mount_point",
+            |m: &SecureUnmountRequest| { &m.mount_point },
+            |m: &mut SecureUnmountRequest| { &mut m.mount_point },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureUnmountRequest>(
+            "SecureUnmountRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SecureUnmountRequest {
+    const NAME: &'static str = "SecureUnmountRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.mount_point = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.mount_point.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.mount_point);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.mount_point.is_empty() {
+            os.write_string(1, &self.mount_point)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SecureUnmountRequest {
+        SecureUnmountRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.mount_point.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SecureUnmountRequest {
+        static instance: SecureUnmountRequest = SecureUnmountRequest {
+            mount_point: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SecureUnmountRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureUnmountRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SecureUnmountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SecureUnmountRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.SecureUnmountResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SecureUnmountResponse {
+    // special fields
+    // @@protoc_insertion_point(special_field:api.SecureUnmountResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SecureUnmountResponse {
+    fn default() -> &'a SecureUnmountResponse {
+        <SecureUnmountResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SecureUnmountResponse {
+    pub fn new() -> SecureUnmountResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureUnmountResponse>(
+            "SecureUnmountResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SecureUnmountResponse {
+    const NAME: &'static str = "SecureUnmountResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SecureUnmountResponse {
+        SecureUnmountResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SecureUnmountResponse {
+        static instance: SecureUnmountResponse = SecureUnmountResponse {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SecureUnmountResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureUnmountResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SecureUnmountResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SecureUnmountResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.ImagePullRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ImagePullRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.ImagePullRequest.image_url)
+    pub image_url: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImagePullRequest.bundle_path)
+    pub bundle_path: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImagePullRequest.platform)
+    pub platform: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.ImagePullRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ImagePullRequest {
+    fn default() -> &'a ImagePullRequest {
+        <ImagePullRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImagePullRequest {
+    pub fn new() -> ImagePullRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "image_url",
+            |m: &ImagePullRequest| { &m.image_url },
+            |m: &mut ImagePullRequest| { &mut m.image_url },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bundle_path",
+            |m: &ImagePullRequest| { &m.bundle_path },
+            |m: &mut ImagePullRequest| { &mut m.bundle_path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "platform",
+            |m: &ImagePullRequest| { &m.platform },
+            |m: &mut ImagePullRequest| { &mut m.platform },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ImagePullRequest>(
+            "ImagePullRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ImagePullRequest {
+    const NAME: &'static str = "ImagePullRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.image_url = is.read_string()?;
+                },
+                18 => {
+                    self.bundle_path = is.read_string()?;
+                },
+                26 => {
+                    self.platform = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.image_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image_url);
+        }
+        if !self.bundle_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.bundle_path);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.platform);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.image_url.is_empty() {
+            os.write_string(1, &self.image_url)?;
+        }
+        if !self.bundle_path.is_empty() {
+            os.write_string(2, &self.bundle_path)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(3, &self.platform)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ImagePullRequest {
+        ImagePullRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.image_url.clear();
+        self.bundle_path.clear();
+        self.platform.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ImagePullRequest {
+        static instance: ImagePullRequest = ImagePullRequest {
+            image_url: ::std::string::String::new(),
+            bundle_path: ::std::string::String::new(),
+            platform: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ImagePullRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ImagePullRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ImagePullRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImagePullRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.ImagePullResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ImagePullResponse {
+    // message fields
+    // @@protoc_insertion_point(field:api.ImagePullResponse.manifest_digest)
+    pub manifest_digest: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.ImagePullResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ImagePullResponse {
+    fn default() -> &'a ImagePullResponse {
+        <ImagePullResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImagePullResponse {
+    pub fn new() -> ImagePullResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "manifest_digest",
+            |m: &ImagePullResponse| { &m.manifest_digest },
+            |m: &mut ImagePullResponse| { &mut m.manifest_digest },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ImagePullResponse>(
+            "ImagePullResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ImagePullResponse {
+    const NAME: &'static str = "ImagePullResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.manifest_digest = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.manifest_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.manifest_digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.manifest_digest.is_empty() {
+            os.write_string(1, &self.manifest_digest)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ImagePullResponse {
+        ImagePullResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.manifest_digest.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ImagePullResponse {
+        static instance: ImagePullResponse = ImagePullResponse {
+            manifest_digest: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ImagePullResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ImagePullResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ImagePullResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImagePullResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.RemoveImageRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RemoveImageRequest {
+    // message fields
+    // @@protoc_insertion_point(field:api.RemoveImageRequest.image)
+    pub image: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:api.RemoveImageRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RemoveImageRequest {
+    fn default() -> &'a RemoveImageRequest {
+        <RemoveImageRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RemoveImageRequest {
+    pub fn new() -> RemoveImageRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "image",
+            |m: &RemoveImageRequest| { &m.image },
+            |m: &mut RemoveImageRequest| { &mut m.image },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RemoveImageRequest>(
+            "RemoveImageRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RemoveImageRequest {
+    const NAME: &'static str = "RemoveImageRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.image = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.image.is_empty() {
+            os.write_string(1, &self.image)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RemoveImageRequest {
+        RemoveImageRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.image.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RemoveImageRequest {
+        static instance: RemoveImageRequest = RemoveImageRequest {
+            image: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RemoveImageRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RemoveImageRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RemoveImageRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RemoveImageRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:api.RemoveImageResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RemoveImageResponse {
     // special fields
-    // @@protoc_insertion_point(special_field:api.PrepareResourceInjectionRequest.special_fields)
+    // @@protoc_insertion_point(special_field:api.RemoveImageResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a PrepareResourceInjectionRequest {
-    fn default() -> &'a PrepareResourceInjectionRequest {
-        <PrepareResourceInjectionRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RemoveImageResponse {
+    fn default() -> &'a RemoveImageResponse {
+        <RemoveImageResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PrepareResourceInjectionRequest {
-    pub fn new() -> PrepareResourceInjectionRequest {
+impl RemoveImageResponse {
+    pub fn new() -> RemoveImageResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "ResourcePath",
-            |m: &PrepareResourceInjectionRequest| { &m.ResourcePath },
-            |m: &mut PrepareResourceInjectionRequest| { &mut m.ResourcePath },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Nonce",
-            |m: &PrepareResourceInjectionRequest| { &m.Nonce },
-            |m: &mut PrepareResourceInjectionRequest| { &mut m.Nonce },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PrepareResourceInjectionRequest>(
-            "PrepareResourceInjectionRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RemoveImageResponse>(
+            "RemoveImageResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for PrepareResourceInjectionRequest {
-    const NAME: &'static str = "PrepareResourceInjectionRequest";
+impl ::protobuf::Message for RemoveImageResponse {
+    const NAME: &'static str = "RemoveImageResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -567,12 +2574,6 @@ impl ::protobuf::Message for PrepareResourceInjectionRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.ResourcePath = is.read_string()?;
-                },
-                18 => {
-                    self.Nonce = is.read_string()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -585,24 +2586,12 @@ impl ::protobuf::Message for PrepareResourceInjectionRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.ResourcePath.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.ResourcePath);
-        }
-        if !self.Nonce.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.Nonce);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.ResourcePath.is_empty() {
-            os.write_string(1, &self.ResourcePath)?;
-        }
-        if !self.Nonce.is_empty() {
-            os.write_string(2, &self.Nonce)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -615,68 +2604,64 @@ impl ::protobuf::Message for PrepareResourceInjectionRequest {
         &mut self.special_fields
     }
 
-    fn new() -> PrepareResourceInjectionRequest {
-        PrepareResourceInjectionRequest::new()
+    fn new() -> RemoveImageResponse {
+        RemoveImageResponse::new()
     }
 
     fn clear(&mut self) {
-        self.ResourcePath.clear();
-        self.Nonce.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static PrepareResourceInjectionRequest {
-        static instance: PrepareResourceInjectionRequest = PrepareResourceInjectionRequest {
-            ResourcePath: ::std::string::String::new(),
-            Nonce: ::std::string::String::new(),
+    fn default_instance() -> &'static RemoveImageResponse {
+        static instance: RemoveImageResponse = RemoveImageResponse {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for PrepareResourceInjectionRequest {
+impl ::protobuf::MessageFull for RemoveImageResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("PrepareResourceInjectionRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RemoveImageResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for PrepareResourceInjectionRequest {
+impl ::std::fmt::Display for RemoveImageResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PrepareResourceInjectionRequest {
+impl ::protobuf::reflect::ProtobufValue for RemoveImageResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.PrepareResourceInjectionResponse)
+// @@protoc_insertion_point(message:api.LayerInfo)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct PrepareResourceInjectionResponse {
+pub struct LayerInfo {
     // message fields
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.SessionId)
-    pub SessionId: ::std::string::String,
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.Nonce)
-    pub Nonce: ::std::string::String,
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.TeePubKey)
-    pub TeePubKey: ::std::string::String,
-    // @@protoc_insertion_point(field:api.PrepareResourceInjectionResponse.Evidence)
-    pub Evidence: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:api.LayerInfo.compressed_digest)
+    pub compressed_digest: ::std::string::String,
+    // @@protoc_insertion_point(field:api.LayerInfo.uncompressed_digest)
+    pub uncompressed_digest: ::std::string::String,
+    // @@protoc_insertion_point(field:api.LayerInfo.compression)
+    pub compression: ::std::string::String,
+    // @@protoc_insertion_point(field:api.LayerInfo.encrypted)
+    pub encrypted: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:api.PrepareResourceInjectionResponse.special_fields)
+    // @@protoc_insertion_point(special_field:api.LayerInfo.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a PrepareResourceInjectionResponse {
-    fn default() -> &'a PrepareResourceInjectionResponse {
-        <PrepareResourceInjectionResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a LayerInfo {
+    fn default() -> &'a LayerInfo {
+        <LayerInfo as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PrepareResourceInjectionResponse {
-    pub fn new() -> PrepareResourceInjectionResponse {
+impl LayerInfo {
+    pub fn new() -> LayerInfo {
         ::std::default::Default::default()
     }
 
@@ -684,35 +2669,35 @@ impl PrepareResourceInjectionResponse {
         let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "SessionId",
-            |m: &PrepareResourceInjectionResponse| { &m.SessionId },
-            |m: &mut PrepareResourceInjectionResponse| { &mut m.SessionId },
+            "compressed_digest",
+            |m: &LayerInfo| { &m.compressed_digest },
+            |m: &mut LayerInfo| { &mut m.compressed_digest },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Nonce",
-            |m: &PrepareResourceInjectionResponse| { &m.Nonce },
-            |m: &mut PrepareResourceInjectionResponse| { &mut m.Nonce },
+            "uncompressed_digest",
+            |m: &LayerInfo| { &m.uncompressed_digest },
+            |m: &mut LayerInfo| { &mut m.uncompressed_digest },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "TeePubKey",
-            |m: &PrepareResourceInjectionResponse| { &m.TeePubKey },
-            |m: &mut PrepareResourceInjectionResponse| { &mut m.TeePubKey },
+            "compression",
+            |m: &LayerInfo| { &m.compression },
+            |m: &mut LayerInfo| { &mut m.compression },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "Evidence",
-            |m: &PrepareResourceInjectionResponse| { &m.Evidence },
-            |m: &mut PrepareResourceInjectionResponse| { &mut m.Evidence },
+            "encrypted",
+            |m: &LayerInfo| { &m.encrypted },
+            |m: &mut LayerInfo| { &mut m.encrypted },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PrepareResourceInjectionResponse>(
-            "PrepareResourceInjectionResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LayerInfo>(
+            "LayerInfo",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for PrepareResourceInjectionResponse {
-    const NAME: &'static str = "PrepareResourceInjectionResponse";
+impl ::protobuf::Message for LayerInfo {
+    const NAME: &'static str = "LayerInfo";
 
     fn is_initialized(&self) -> bool {
         true
@@ -722,16 +2707,16 @@ impl ::protobuf::Message for PrepareResourceInjectionResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.SessionId = is.read_string()?;
+                    self.compressed_digest = is.read_string()?;
                 },
                 18 => {
-                    self.Nonce = is.read_string()?;
+                    self.uncompressed_digest = is.read_string()?;
                 },
                 26 => {
-                    self.TeePubKey = is.read_string()?;
+                    self.compression = is.read_string()?;
                 },
-                34 => {
-                    self.Evidence = is.read_bytes()?;
+                32 => {
+                    self.encrypted = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -745,17 +2730,17 @@ impl ::protobuf::Message for PrepareResourceInjectionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.SessionId.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.SessionId);
+        if !self.compressed_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.compressed_digest);
         }
-        if !self.Nonce.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.Nonce);
+        if !self.uncompressed_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.uncompressed_digest);
         }
-        if !self.TeePubKey.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.TeePubKey);
+        if !self.compression.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.compression);
         }
-        if !self.Evidence.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.Evidence);
+        if self.encrypted != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -763,17 +2748,17 @@ impl ::protobuf::Message for PrepareResourceInjectionResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.SessionId.is_empty() {
-            os.write_string(1, &self.SessionId)?;
+        if !self.compressed_digest.is_empty() {
+            os.write_string(1, &self.compressed_digest)?;
         }
-        if !self.Nonce.is_empty() {
-            os.write_string(2, &self.Nonce)?;
+        if !self.uncompressed_digest.is_empty() {
+            os.write_string(2, &self.uncompressed_digest)?;
         }
-        if !self.TeePubKey.is_empty() {
-            os.write_string(3, &self.TeePubKey)?;
+        if !self.compression.is_empty() {
+            os.write_string(3, &self.compression)?;
         }
-        if !self.Evidence.is_empty() {
-            os.write_bytes(4, &self.Evidence)?;
+        if self.encrypted != false {
+            os.write_bool(4, self.encrypted)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -787,70 +2772,70 @@ impl ::protobuf::Message for PrepareResourceInjectionResponse {
         &mut self.special_fields
     }
 
-    fn new() -> PrepareResourceInjectionResponse {
-        PrepareResourceInjectionResponse::new()
+    fn new() -> LayerInfo {
+        LayerInfo::new()
     }
 
     fn clear(&mut self) {
-        self.SessionId.clear();
-        self.Nonce.clear();
-        self.TeePubKey.clear();
-        self.Evidence.clear();
+        self.compressed_digest.clear();
+        self.uncompressed_digest.clear();
+        self.compression.clear();
+        self.encrypted = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static PrepareResourceInjectionResponse {
-        static instance: PrepareResourceInjectionResponse = PrepareResourceInjectionResponse {
-            SessionId: ::std::string::String::new(),
-            Nonce: ::std::string::String::new(),
-            TeePubKey: ::std::string::String::new(),
-            Evidence: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static LayerInfo {
+        static instance: LayerInfo = LayerInfo {
+            compressed_digest: ::std::string::String::new(),
+            uncompressed_digest: ::std::string::String::new(),
+            compression: ::std::string::String::new(),
+            encrypted: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for PrepareResourceInjectionResponse {
+impl ::protobuf::MessageFull for LayerInfo {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("PrepareResourceInjectionResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LayerInfo").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for PrepareResourceInjectionResponse {
+impl ::std::fmt::Display for LayerInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PrepareResourceInjectionResponse {
+impl ::protobuf::reflect::ProtobufValue for LayerInfo {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.CommitResourceInjectionRequest)
+// @@protoc_insertion_point(message:api.SignatureVerification)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CommitResourceInjectionRequest {
+pub struct SignatureVerification {
     // message fields
-    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.SessionId)
-    pub SessionId: ::std::string::String,
-    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.ResourcePath)
-    pub ResourcePath: ::std::string::String,
-    // @@protoc_insertion_point(field:api.CommitResourceInjectionRequest.EncryptedResource)
-    pub EncryptedResource: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:api.SignatureVerification.reference)
+    pub reference: ::std::string::String,
+    // @@protoc_insertion_point(field:api.SignatureVerification.image_digest)
+    pub image_digest: ::std::string::String,
+    // @@protoc_insertion_point(field:api.SignatureVerification.policy_digest)
+    pub policy_digest: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:api.CommitResourceInjectionRequest.special_fields)
+    // @@protoc_insertion_point(special_field:api.SignatureVerification.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CommitResourceInjectionRequest {
-    fn default() -> &'a CommitResourceInjectionRequest {
-        <CommitResourceInjectionRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SignatureVerification {
+    fn default() -> &'a SignatureVerification {
+        <SignatureVerification as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CommitResourceInjectionRequest {
-    pub fn new() -> CommitResourceInjectionRequest {
+impl SignatureVerification {
+    pub fn new() -> SignatureVerification {
         ::std::default::Default::default()
     }
 
@@ -858,30 +2843,30 @@ impl CommitResourceInjectionRequest {
         let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "SessionId",
-            |m: &CommitResourceInjectionRequest| { &m.SessionId },
-            |m: &mut CommitResourceInjectionRequest| { &mut m.SessionId },
+            "reference",
+            |m: &SignatureVerification| { &m.reference },
+            |m: &mut SignatureVerification| { &mut m.reference },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "ResourcePath",
-            |m: &CommitResourceInjectionRequest| { &m.ResourcePath },
-            |m: &mut CommitResourceInjectionRequest| { &mut m.ResourcePath },
+            "image_digest",
+            |m: &SignatureVerification| { &m.image_digest },
+            |m: &mut SignatureVerification| { &mut m.image_digest },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "EncryptedResource",
-            |m: &CommitResourceInjectionRequest| { &m.EncryptedResource },
-            |m: &mut CommitResourceInjectionRequest| { &mut m.EncryptedResource },
+            "policy_digest",
+            |m: &SignatureVerification| { &m.policy_digest },
+            |m: &mut SignatureVerification| { &mut m.policy_digest },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitResourceInjectionRequest>(
-            "CommitResourceInjectionRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SignatureVerification>(
+            "SignatureVerification",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CommitResourceInjectionRequest {
-    const NAME: &'static str = "CommitResourceInjectionRequest";
+impl ::protobuf::Message for SignatureVerification {
+    const NAME: &'static str = "SignatureVerification";
 
     fn is_initialized(&self) -> bool {
         true
@@ -891,13 +2876,13 @@ impl ::protobuf::Message for CommitResourceInjectionRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.SessionId = is.read_string()?;
+                    self.reference = is.read_string()?;
                 },
                 18 => {
-                    self.ResourcePath = is.read_string()?;
+                    self.image_digest = is.read_string()?;
                 },
                 26 => {
-                    self.EncryptedResource = is.read_bytes()?;
+                    self.policy_digest = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -911,14 +2896,14 @@ impl ::protobuf::Message for CommitResourceInjectionRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.SessionId.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.SessionId);
+        if !self.reference.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reference);
         }
-        if !self.ResourcePath.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.ResourcePath);
+        if !self.image_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.image_digest);
         }
-        if !self.EncryptedResource.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.EncryptedResource);
+        if !self.policy_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.policy_digest);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -926,14 +2911,14 @@ impl ::protobuf::Message for CommitResourceInjectionRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.SessionId.is_empty() {
-            os.write_string(1, &self.SessionId)?;
+        if !self.reference.is_empty() {
+            os.write_string(1, &self.reference)?;
         }
-        if !self.ResourcePath.is_empty() {
-            os.write_string(2, &self.ResourcePath)?;
+        if !self.image_digest.is_empty() {
+            os.write_string(2, &self.image_digest)?;
         }
-        if !self.EncryptedResource.is_empty() {
-            os.write_bytes(3, &self.EncryptedResource)?;
+        if !self.policy_digest.is_empty() {
+            os.write_string(3, &self.policy_digest)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -947,77 +2932,134 @@ impl ::protobuf::Message for CommitResourceInjectionRequest {
         &mut self.special_fields
     }
 
-    fn new() -> CommitResourceInjectionRequest {
-        CommitResourceInjectionRequest::new()
+    fn new() -> SignatureVerification {
+        SignatureVerification::new()
     }
 
     fn clear(&mut self) {
-        self.SessionId.clear();
-        self.ResourcePath.clear();
-        self.EncryptedResource.clear();
+        self.reference.clear();
+        self.image_digest.clear();
+        self.policy_digest.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CommitResourceInjectionRequest {
-        static instance: CommitResourceInjectionRequest = CommitResourceInjectionRequest {
-            SessionId: ::std::string::String::new(),
-            ResourcePath: ::std::string::String::new(),
-            EncryptedResource: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SignatureVerification {
+        static instance: SignatureVerification = SignatureVerification {
+            reference: ::std::string::String::new(),
+            image_digest: ::std::string::String::new(),
+            policy_digest: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for CommitResourceInjectionRequest {
+impl ::protobuf::MessageFull for SignatureVerification {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitResourceInjectionRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SignatureVerification").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CommitResourceInjectionRequest {
+impl ::std::fmt::Display for SignatureVerification {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CommitResourceInjectionRequest {
+impl ::protobuf::reflect::ProtobufValue for SignatureVerification {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.CommitResourceInjectionResponse)
+// @@protoc_insertion_point(message:api.ImageInfo)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CommitResourceInjectionResponse {
+pub struct ImageInfo {
+    // message fields
+    // @@protoc_insertion_point(field:api.ImageInfo.id)
+    pub id: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImageInfo.reference)
+    pub reference: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImageInfo.digest)
+    pub digest: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImageInfo.config)
+    pub config: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ImageInfo.layers)
+    pub layers: ::std::vec::Vec<LayerInfo>,
+    // @@protoc_insertion_point(field:api.ImageInfo.signed)
+    pub signed: bool,
+    // @@protoc_insertion_point(field:api.ImageInfo.verifications)
+    pub verifications: ::std::vec::Vec<SignatureVerification>,
+    // @@protoc_insertion_point(field:api.ImageInfo.platform)
+    pub platform: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:api.CommitResourceInjectionResponse.special_fields)
+    // @@protoc_insertion_point(special_field:api.ImageInfo.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CommitResourceInjectionResponse {
-    fn default() -> &'a CommitResourceInjectionResponse {
-        <CommitResourceInjectionResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ImageInfo {
+    fn default() -> &'a ImageInfo {
+        <ImageInfo as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CommitResourceInjectionResponse {
-    pub fn new() -> CommitResourceInjectionResponse {
+impl ImageInfo {
+    pub fn new() -> ImageInfo {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitResourceInjectionResponse>(
-            "CommitResourceInjectionResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ImageInfo| { &m.id },
+            |m: &mut ImageInfo| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reference",
+            |m: &ImageInfo| { &m.reference },
+            |m: &mut ImageInfo| { &mut m.reference },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "digest",
+            |m: &ImageInfo| { &m.digest },
+            |m: &mut ImageInfo| { &mut m.digest },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "config",
+            |m: &ImageInfo| { &m.config },
+            |m: &mut ImageInfo| { &mut m.config },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "layers",
+            |m: &ImageInfo| { &m.layers },
+            |m: &mut ImageInfo| { &mut m.layers },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "signed",
+            |m: &ImageInfo| { &m.signed },
+            |m: &mut ImageInfo| { &mut m.signed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "verifications",
+            |m: &ImageInfo| { &m.verifications },
+            |m: &mut ImageInfo| { &mut m.verifications },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "platform",
+            |m: &ImageInfo| { &m.platform },
+            |m: &mut ImageInfo| { &mut m.platform },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ImageInfo>(
+            "ImageInfo",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CommitResourceInjectionResponse {
-    const NAME: &'static str = "CommitResourceInjectionResponse";
+impl ::protobuf::Message for ImageInfo {
+    const NAME: &'static str = "ImageInfo";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1026,6 +3068,30 @@ impl ::protobuf::Message for CommitResourceInjectionResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                10 => {
+                    self.id = is.read_string()?;
+                },
+                18 => {
+                    self.reference = is.read_string()?;
+                },
+                26 => {
+                    self.digest = is.read_string()?;
+                },
+                34 => {
+                    self.config = is.read_string()?;
+                },
+                42 => {
+                    self.layers.push(is.read_message()?);
+                },
+                48 => {
+                    self.signed = is.read_bool()?;
+                },
+                58 => {
+                    self.verifications.push(is.read_message()?);
+                },
+                66 => {
+                    self.platform = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1038,12 +3104,62 @@ impl ::protobuf::Message for CommitResourceInjectionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.reference.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reference);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.digest);
+        }
+        if !self.config.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.config);
+        }
+        for value in &self.layers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.signed != false {
+            my_size += 1 + 1;
+        }
+        for value in &self.verifications {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.platform);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.reference.is_empty() {
+            os.write_string(2, &self.reference)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(3, &self.digest)?;
+        }
+        if !self.config.is_empty() {
+            os.write_string(4, &self.config)?;
+        }
+        for v in &self.layers {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.signed != false {
+            os.write_bool(6, self.signed)?;
+        }
+        for v in &self.verifications {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        };
+        if !self.platform.is_empty() {
+            os.write_string(8, &self.platform)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1056,100 +3172,87 @@ impl ::protobuf::Message for CommitResourceInjectionResponse {
         &mut self.special_fields
     }
 
-    fn new() -> CommitResourceInjectionResponse {
-        CommitResourceInjectionResponse::new()
+    fn new() -> ImageInfo {
+        ImageInfo::new()
     }
 
     fn clear(&mut self) {
+        self.id.clear();
+        self.reference.clear();
+        self.digest.clear();
+        self.config.clear();
+        self.layers.clear();
+        self.signed = false;
+        self.verifications.clear();
+        self.platform.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CommitResourceInjectionResponse {
-        static instance: CommitResourceInjectionResponse = CommitResourceInjectionResponse {
+    fn default_instance() -> &'static ImageInfo {
+        static instance: ImageInfo = ImageInfo {
+            id: ::std::string::String::new(),
+            reference: ::std::string::String::new(),
+            digest: ::std::string::String::new(),
+            config: ::std::string::String::new(),
+            layers: ::std::vec::Vec::new(),
+            signed: false,
+            verifications: ::std::vec::Vec::new(),
+            platform: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for CommitResourceInjectionResponse {
+impl ::protobuf::MessageFull for ImageInfo {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitResourceInjectionResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ImageInfo").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CommitResourceInjectionResponse {
+impl ::std::fmt::Display for ImageInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CommitResourceInjectionResponse {
+impl ::protobuf::reflect::ProtobufValue for ImageInfo {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.SecureMountRequest)
+// @@protoc_insertion_point(message:api.ListImagesRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SecureMountRequest {
-    // message fields
-    // @@protoc_insertion_point(field:api.SecureMountRequest.volume_type)
-    pub volume_type: ::std::string::String,
-    // @@protoc_insertion_point(field:api.SecureMountRequest.options)
-    pub options: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-    // @@protoc_insertion_point(field:api.SecureMountRequest.flags)
-    pub flags: ::std::vec::Vec<::std::string::String>,
-    // @@protoc_insertion_point(field:api.SecureMountRequest.mount_point)
-    pub mount_point: ::std::string::String,
+pub struct ListImagesRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:api.SecureMountRequest.special_fields)
+    // @@protoc_insertion_point(special_field:api.ListImagesRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SecureMountRequest {
-    fn default() -> &'a SecureMountRequest {
-        <SecureMountRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListImagesRequest {
+    fn default() -> &'a ListImagesRequest {
+        <ListImagesRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SecureMountRequest {
-    pub fn new() -> SecureMountRequest {
+impl ListImagesRequest {
+    pub fn new() -> ListImagesRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "volume_type",
-            |m: &SecureMountRequest| { &m.volume_type },
-            |m: &mut SecureMountRequest| { &mut m.volume_type },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
-            "options",
-            |m: &SecureMountRequest| { &m.options },
-            |m: &mut SecureMountRequest| { &mut m.options },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "flags",
-            |m: &SecureMountRequest| { &m.flags },
-            |m: &mut SecureMountRequest| { &mut m.flags },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "mount_point",
-            |m: &SecureMountRequest| { &m.mount_point },
-            |m: &mut SecureMountRequest| { &mut m.mount_point },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureMountRequest>(
-            "SecureMountRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListImagesRequest>(
+            "ListImagesRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SecureMountRequest {
-    const NAME: &'static str = "SecureMountRequest";
+impl ::protobuf::Message for ListImagesRequest {
+    const NAME: &'static str = "ListImagesRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1158,30 +3261,6 @@ impl ::protobuf::Message for SecureMountRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.volume_type = is.read_string()?;
-                },
-                18 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            10 => key = is.read_string()?,
-                            18 => value = is.read_string()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.options.insert(key, value);
-                },
-                26 => {
-                    self.flags.push(is.read_string()?);
-                },
-                34 => {
-                    self.mount_point = is.read_string()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1194,45 +3273,12 @@ impl ::protobuf::Message for SecureMountRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.volume_type.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.volume_type);
-        }
-        for (k, v) in &self.options {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
-        for value in &self.flags {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        if !self.mount_point.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.mount_point);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.volume_type.is_empty() {
-            os.write_string(1, &self.volume_type)?;
-        }
-        for (k, v) in &self.options {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            os.write_raw_varint32(18)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_string(1, &k)?;
-            os.write_string(2, &v)?;
-        };
-        for v in &self.flags {
-            os.write_string(3, &v)?;
-        };
-        if !self.mount_point.is_empty() {
-            os.write_string(4, &self.mount_point)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1245,81 +3291,79 @@ impl ::protobuf::Message for SecureMountRequest {
         &mut self.special_fields
     }
 
-    fn new() -> SecureMountRequest {
-        SecureMountRequest::new()
+    fn new() -> ListImagesRequest {
+        ListImagesRequest::new()
     }
 
     fn clear(&mut self) {
-        self.volume_type.clear();
-        self.options.clear();
-        self.flags.clear();
-        self.mount_point.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SecureMountRequest {
-        static instance: ::protobuf::rt::Lazy<SecureMountRequest> = ::protobuf::rt::Lazy::new();
-        instance.get(SecureMountRequest::new)
+    fn default_instance() -> &'static ListImagesRequest {
+        static instance: ListImagesRequest = ListImagesRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for SecureMountRequest {
+impl ::protobuf::MessageFull for ListImagesRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureMountRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListImagesRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SecureMountRequest {
+impl ::std::fmt::Display for ListImagesRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SecureMountRequest {
+impl ::protobuf::reflect::ProtobufValue for ListImagesRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.SecureMountResponse)
+// @@protoc_insertion_point(message:api.ListImagesResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SecureMountResponse {
+pub struct ListImagesResponse {
     // message fields
-    // @@protoc_insertion_point(field:api.SecureMountResponse.mount_path)
-    pub mount_path: ::std::string::String,
+    // @@protoc_insertion_point(field:api.ListImagesResponse.images)
+    pub images: ::std::vec::Vec<ImageInfo>,
     // special fields
-    // @@protoc_insertion_point(special_field:api.SecureMountResponse.special_fields)
+    // @@protoc_insertion_point(special_field:api.ListImagesResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SecureMountResponse {
-    fn default() -> &'a SecureMountResponse {
-        <SecureMountResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListImagesResponse {
+    fn default() -> &'a ListImagesResponse {
+        <ListImagesResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SecureMountResponse {
-    pub fn new() -> SecureMountResponse {
+impl ListImagesResponse {
+    pub fn new() -> ListImagesResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "mount_path",
-            |m: &SecureMountResponse| { &m.mount_path },
-            |m: &mut SecureMountResponse| { &mut m.mount_path },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "images",
+            |m: &ListImagesResponse| { &m.images },
+            |m: &mut ListImagesResponse| { &mut m.images },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SecureMountResponse>(
-            "SecureMountResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListImagesResponse>(
+            "ListImagesResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SecureMountResponse {
-    const NAME: &'static str = "SecureMountResponse";
+impl ::protobuf::Message for ListImagesResponse {
+    const NAME: &'static str = "ListImagesResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1329,7 +3373,7 @@ impl ::protobuf::Message for SecureMountResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.mount_path = is.read_string()?;
+                    self.images.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1343,18 +3387,19 @@ impl ::protobuf::Message for SecureMountResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.mount_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.mount_path);
-        }
+        for value in &self.images {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.mount_path.is_empty() {
-            os.write_string(1, &self.mount_path)?;
-        }
+        for v in &self.images {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1367,88 +3412,81 @@ impl ::protobuf::Message for SecureMountResponse {
         &mut self.special_fields
     }
 
-    fn new() -> SecureMountResponse {
-        SecureMountResponse::new()
+    fn new() -> ListImagesResponse {
+        ListImagesResponse::new()
     }
 
     fn clear(&mut self) {
-        self.mount_path.clear();
+        self.images.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SecureMountResponse {
-        static instance: SecureMountResponse = SecureMountResponse {
-            mount_path: ::std::string::String::new(),
+    fn default_instance() -> &'static ListImagesResponse {
+        static instance: ListImagesResponse = ListImagesResponse {
+            images: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SecureMountResponse {
+impl ::protobuf::MessageFull for ListImagesResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SecureMountResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListImagesResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SecureMountResponse {
+impl ::std::fmt::Display for ListImagesResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SecureMountResponse {
+impl ::protobuf::reflect::ProtobufValue for ListImagesResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.ImagePullRequest)
+// @@protoc_insertion_point(message:api.InspectImageRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ImagePullRequest {
+pub struct InspectImageRequest {
     // message fields
-    // @@protoc_insertion_point(field:api.ImagePullRequest.image_url)
-    pub image_url: ::std::string::String,
-    // @@protoc_insertion_point(field:api.ImagePullRequest.bundle_path)
-    pub bundle_path: ::std::string::String,
+    // @@protoc_insertion_point(field:api.InspectImageRequest.image)
+    pub image: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:api.ImagePullRequest.special_fields)
+    // @@protoc_insertion_point(special_field:api.InspectImageRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ImagePullRequest {
-    fn default() -> &'a ImagePullRequest {
-        <ImagePullRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InspectImageRequest {
+    fn default() -> &'a InspectImageRequest {
+        <InspectImageRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ImagePullRequest {
-    pub fn new() -> ImagePullRequest {
+impl InspectImageRequest {
+    pub fn new() -> InspectImageRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "image_url",
-            |m: &ImagePullRequest| { &m.image_url },
-            |m: &mut ImagePullRequest| { &mut m.image_url },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "bundle_path",
-            |m: &ImagePullRequest| { &m.bundle_path },
-            |m: &mut ImagePullRequest| { &mut m.bundle_path },
+            "image",
+            |m: &InspectImageRequest| { &m.image },
+            |m: &mut InspectImageRequest| { &mut m.image },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ImagePullRequest>(
-            "ImagePullRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InspectImageRequest>(
+            "InspectImageRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ImagePullRequest {
-    const NAME: &'static str = "ImagePullRequest";
+impl ::protobuf::Message for InspectImageRequest {
+    const NAME: &'static str = "InspectImageRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1458,10 +3496,7 @@ impl ::protobuf::Message for ImagePullRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.image_url = is.read_string()?;
-                },
-                18 => {
-                    self.bundle_path = is.read_string()?;
+                    self.image = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1475,11 +3510,8 @@ impl ::protobuf::Message for ImagePullRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.image_url.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.image_url);
-        }
-        if !self.bundle_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.bundle_path);
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1487,11 +3519,8 @@ impl ::protobuf::Message for ImagePullRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.image_url.is_empty() {
-            os.write_string(1, &self.image_url)?;
-        }
-        if !self.bundle_path.is_empty() {
-            os.write_string(2, &self.bundle_path)?;
+        if !self.image.is_empty() {
+            os.write_string(1, &self.image)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1505,83 +3534,81 @@ impl ::protobuf::Message for ImagePullRequest {
         &mut self.special_fields
     }
 
-    fn new() -> ImagePullRequest {
-        ImagePullRequest::new()
+    fn new() -> InspectImageRequest {
+        InspectImageRequest::new()
     }
 
     fn clear(&mut self) {
-        self.image_url.clear();
-        self.bundle_path.clear();
+        self.image.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ImagePullRequest {
-        static instance: ImagePullRequest = ImagePullRequest {
-            image_url: ::std::string::String::new(),
-            bundle_path: ::std::string::String::new(),
+    fn default_instance() -> &'static InspectImageRequest {
+        static instance: InspectImageRequest = InspectImageRequest {
+            image: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ImagePullRequest {
+impl ::protobuf::MessageFull for InspectImageRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ImagePullRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InspectImageRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ImagePullRequest {
+impl ::std::fmt::Display for InspectImageRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ImagePullRequest {
+impl ::protobuf::reflect::ProtobufValue for InspectImageRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:api.ImagePullResponse)
+// @@protoc_insertion_point(message:api.InspectImageResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ImagePullResponse {
+pub struct InspectImageResponse {
     // message fields
-    // @@protoc_insertion_point(field:api.ImagePullResponse.manifest_digest)
-    pub manifest_digest: ::std::string::String,
+    // @@protoc_insertion_point(field:api.InspectImageResponse.image)
+    pub image: ::protobuf::MessageField<ImageInfo>,
     // special fields
-    // @@protoc_insertion_point(special_field:api.ImagePullResponse.special_fields)
+    // @@protoc_insertion_point(special_field:api.InspectImageResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ImagePullResponse {
-    fn default() -> &'a ImagePullResponse {
-        <ImagePullResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InspectImageResponse {
+    fn default() -> &'a InspectImageResponse {
+        <InspectImageResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ImagePullResponse {
-    pub fn new() -> ImagePullResponse {
+impl InspectImageResponse {
+    pub fn new() -> InspectImageResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "manifest_digest",
-            |m: &ImagePullResponse| { &m.manifest_digest },
-            |m: &mut ImagePullResponse| { &mut m.manifest_digest },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ImageInfo>(
+            "image",
+            |m: &InspectImageResponse| { &m.image },
+            |m: &mut InspectImageResponse| { &mut m.image },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ImagePullResponse>(
-            "ImagePullResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InspectImageResponse>(
+            "InspectImageResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ImagePullResponse {
-    const NAME: &'static str = "ImagePullResponse";
+impl ::protobuf::Message for InspectImageResponse {
+    const NAME: &'static str = "InspectImageResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1591,7 +3618,7 @@ impl ::protobuf::Message for ImagePullResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.manifest_digest = is.read_string()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.image)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1605,8 +3632,9 @@ impl ::protobuf::Message for ImagePullResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.manifest_digest.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.manifest_digest);
+        if let Some(v) = self.image.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1614,8 +3642,8 @@ impl ::protobuf::Message for ImagePullResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.manifest_digest.is_empty() {
-            os.write_string(1, &self.manifest_digest)?;
+        if let Some(v) = self.image.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1629,38 +3657,38 @@ impl ::protobuf::Message for ImagePullResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ImagePullResponse {
-        ImagePullResponse::new()
+    fn new() -> InspectImageResponse {
+        InspectImageResponse::new()
     }
 
     fn clear(&mut self) {
-        self.manifest_digest.clear();
+        self.image.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ImagePullResponse {
-        static instance: ImagePullResponse = ImagePullResponse {
-            manifest_digest: ::std::string::String::new(),
+    fn default_instance() -> &'static InspectImageResponse {
+        static instance: InspectImageResponse = InspectImageResponse {
+            image: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ImagePullResponse {
+impl ::protobuf::MessageFull for InspectImageResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ImagePullResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InspectImageResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ImagePullResponse {
+impl ::std::fmt::Display for InspectImageResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ImagePullResponse {
+impl ::protobuf::reflect::ProtobufValue for InspectImageResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -1669,38 +3697,74 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x01\x20\x01(\x0cR\x06secret\"2\n\x12UnsealSecretOutput\x12\x1c\n\tp\
     laintext\x18\x01\x20\x01(\x0cR\tplaintext\"8\n\x12GetResourceRequest\x12\
     \"\n\x0cResourcePath\x18\x01\x20\x01(\tR\x0cResourcePath\"1\n\x13GetReso\
-    urceResponse\x12\x1a\n\x08Resource\x18\x01\x20\x01(\x0cR\x08Resource\"[\
-    \n\x1fPrepareResourceInjectionRequest\x12\"\n\x0cResourcePath\x18\x01\
-    \x20\x01(\tR\x0cResourcePath\x12\x14\n\x05Nonce\x18\x02\x20\x01(\tR\x05N\
-    once\"\x90\x01\n\x20PrepareResourceInjectionResponse\x12\x1c\n\tSessionI\
-    d\x18\x01\x20\x01(\tR\tSessionId\x12\x14\n\x05Nonce\x18\x02\x20\x01(\tR\
-    \x05Nonce\x12\x1c\n\tTeePubKey\x18\x03\x20\x01(\tR\tTeePubKey\x12\x1a\n\
-    \x08Evidence\x18\x04\x20\x01(\x0cR\x08Evidence\"\x90\x01\n\x1eCommitReso\
-    urceInjectionRequest\x12\x1c\n\tSessionId\x18\x01\x20\x01(\tR\tSessionId\
-    \x12\"\n\x0cResourcePath\x18\x02\x20\x01(\tR\x0cResourcePath\x12,\n\x11E\
-    ncryptedResource\x18\x03\x20\x01(\x0cR\x11EncryptedResource\"!\n\x1fComm\
-    itResourceInjectionResponse\"\xe8\x01\n\x12SecureMountRequest\x12\x1f\n\
-    \x0bvolume_type\x18\x01\x20\x01(\tR\nvolumeType\x12>\n\x07options\x18\
-    \x02\x20\x03(\x0b2$.api.SecureMountRequest.OptionsEntryR\x07options\x12\
-    \x14\n\x05flags\x18\x03\x20\x03(\tR\x05flags\x12\x1f\n\x0bmount_point\
-    \x18\x04\x20\x01(\tR\nmountPoint\x1a:\n\x0cOptionsEntry\x12\x10\n\x03key\
-    \x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05va\
-    lue:\x028\x01\"4\n\x13SecureMountResponse\x12\x1d\n\nmount_path\x18\x01\
-    \x20\x01(\tR\tmountPath\"P\n\x10ImagePullRequest\x12\x1b\n\timage_url\
-    \x18\x01\x20\x01(\tR\x08imageUrl\x12\x1f\n\x0bbundle_path\x18\x02\x20\
-    \x01(\tR\nbundlePath\"<\n\x11ImagePullResponse\x12'\n\x0fmanifest_digest\
-    \x18\x01\x20\x01(\tR\x0emanifestDigest2V\n\x13SealedSecretService\x12?\n\
-    \x0cUnsealSecret\x12\x16.api.UnsealSecretInput\x1a\x17.api.UnsealSecretO\
-    utput2\xa5\x02\n\x12GetResourceService\x12@\n\x0bGetResource\x12\x17.api\
-    .GetResourceRequest\x1a\x18.api.GetResourceResponse\x12g\n\x18PrepareRes\
-    ourceInjection\x12$.api.PrepareResourceInjectionRequest\x1a%.api.Prepare\
-    ResourceInjectionResponse\x12d\n\x17CommitResourceInjection\x12#.api.Com\
-    mitResourceInjectionRequest\x1a$.api.CommitResourceInjectionResponse2V\n\
-    \x12SecureMountService\x12@\n\x0bSecureMount\x12\x17.api.SecureMountRequ\
-    est\x1a\x18.api.SecureMountResponse2N\n\x10ImagePullService\x12:\n\tPull\
-    Image\x12\x15.api.ImagePullRequest\x1a\x16.api.ImagePullResponseBaZ_gith\
-    ub.com/confidential-containers/guest-components/confidential-data-hub/go\
-    lang/pkg/api/cdhapib\x06proto3\
+    urceResponse\x12\x1a\n\x08Resource\x18\x01\x20\x01(\x0cR\x08Resource\"P\
+    \n\x1eInvalidateResourceCacheRequest\x12.\n\x12ResourcePathPrefix\x18\
+    \x01\x20\x01(\tR\x12ResourcePathPrefix\"C\n\x1fInvalidateResourceCacheRe\
+    sponse\x12\x20\n\x0bInvalidated\x18\x01\x20\x01(\x04R\x0bInvalidated\"\
+    \x1e\n\x1cGetResourceCacheStatsRequest\"e\n\x1dGetResourceCacheStatsResp\
+    onse\x12\x12\n\x04Hits\x18\x01\x20\x01(\x04R\x04Hits\x12\x16\n\x06Misses\
+    \x18\x02\x20\x01(\x04R\x06Misses\x12\x18\n\x07Entries\x18\x03\x20\x01(\
+    \x04R\x07Entries\"[\n\x1fPrepareResourceInjectionRequest\x12\"\n\x0cReso\
+    urcePath\x18\x01\x20\x01(\tR\x0cResourcePath\x12\x14\n\x05Nonce\x18\x02\
+    \x20\x01(\tR\x05Nonce\"\x90\x01\n\x20PrepareResourceInjectionResponse\
+    \x12\x1c\n\tSessionId\x18\x01\x20\x01(\tR\tSessionId\x12\x14\n\x05Nonce\
+    \x18\x02\x20\x01(\tR\x05Nonce\x12\x1c\n\tTeePubKey\x18\x03\x20\x01(\tR\t\
+    TeePubKey\x12\x1a\n\x08Evidence\x18\x04\x20\x01(\x0cR\x08Evidence\"\x90\
+    \x01\n\x1eCommitResourceInjectionRequest\x12\x1c\n\tSessionId\x18\x01\
+    \x20\x01(\tR\tSessionId\x12\"\n\x0cResourcePath\x18\x02\x20\x01(\tR\x0cR\
+    esourcePath\x12,\n\x11EncryptedResource\x18\x03\x20\x01(\x0cR\x11Encrypt\
+    edResource\"!\n\x1fCommitResourceInjectionResponse\"\xe8\x01\n\x12Secure\
+    MountRequest\x12\x1f\n\x0bvolume_type\x18\x01\x20\x01(\tR\nvolumeType\
+    \x12>\n\x07options\x18\x02\x20\x03(\x0b2$.api.SecureMountRequest.Options\
+    EntryR\x07options\x12\x14\n\x05flags\x18\x03\x20\x03(\tR\x05flags\x12\
+    \x1f\n\x0bmount_point\x18\x04\x20\x01(\tR\nmountPoint\x1a:\n\x0cOptionsE\
+    ntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\tR\x05value:\x028\x01\"4\n\x13SecureMountResponse\x12\x1d\
+    \n\nmount_path\x18\x01\x20\x01(\tR\tmountPath\"7\n\x14SecureUnmountReque\
+    st\x12\x1f\n\x0bmount_point\x18\x01\x20\x01(\tR\nmountPoint\"\x17\n\x15S\
+    ecureUnmountResponse\"l\n\x10ImagePullRequest\x12\x1b\n\timage_url\x18\
+    \x01\x20\x01(\tR\x08imageUrl\x12\x1f\n\x0bbundle_path\x18\x02\x20\x01(\t\
+    R\nbundlePath\x12\x1a\n\x08platform\x18\x03\x20\x01(\tR\x08platform\"<\n\
+    \x11ImagePullResponse\x12'\n\x0fmanifest_digest\x18\x01\x20\x01(\tR\x0em\
+    anifestDigest\"*\n\x12RemoveImageRequest\x12\x14\n\x05image\x18\x01\x20\
+    \x01(\tR\x05image\"\x15\n\x13RemoveImageResponse\"\xa9\x01\n\tLayerInfo\
+    \x12+\n\x11compressed_digest\x18\x01\x20\x01(\tR\x10compressedDigest\x12\
+    /\n\x13uncompressed_digest\x18\x02\x20\x01(\tR\x12uncompressedDigest\x12\
+    \x20\n\x0bcompression\x18\x03\x20\x01(\tR\x0bcompression\x12\x1c\n\tencr\
+    ypted\x18\x04\x20\x01(\x08R\tencrypted\"}\n\x15SignatureVerification\x12\
+    \x1c\n\treference\x18\x01\x20\x01(\tR\treference\x12!\n\x0cimage_digest\
+    \x18\x02\x20\x01(\tR\x0bimageDigest\x12#\n\rpolicy_digest\x18\x03\x20\
+    \x01(\tR\x0cpolicyDigest\"\x87\x02\n\tImageInfo\x12\x0e\n\x02id\x18\x01\
+    \x20\x01(\tR\x02id\x12\x1c\n\treference\x18\x02\x20\x01(\tR\treference\
+    \x12\x16\n\x06digest\x18\x03\x20\x01(\tR\x06digest\x12\x16\n\x06config\
+    \x18\x04\x20\x01(\tR\x06config\x12&\n\x06layers\x18\x05\x20\x03(\x0b2\
+    \x0e.api.LayerInfoR\x06layers\x12\x16\n\x06signed\x18\x06\x20\x01(\x08R\
+    \x06signed\x12@\n\rverifications\x18\x07\x20\x03(\x0b2\x1a.api.Signature\
+    VerificationR\rverifications\x12\x1a\n\x08platform\x18\x08\x20\x01(\tR\
+    \x08platform\"\x13\n\x11ListImagesRequest\"<\n\x12ListImagesResponse\x12\
+    &\n\x06images\x18\x01\x20\x03(\x0b2\x0e.api.ImageInfoR\x06images\"+\n\
+    \x13InspectImageRequest\x12\x14\n\x05image\x18\x01\x20\x01(\tR\x05image\
+    \"<\n\x14InspectImageResponse\x12$\n\x05image\x18\x01\x20\x01(\x0b2\x0e.\
+    api.ImageInfoR\x05image2V\n\x13SealedSecretService\x12?\n\x0cUnsealSecre\
+    t\x12\x16.api.UnsealSecretInput\x1a\x17.api.UnsealSecretOutput2\xeb\x03\
+    \n\x12GetResourceService\x12@\n\x0bGetResource\x12\x17.api.GetResourceRe\
+    quest\x1a\x18.api.GetResourceResponse\x12d\n\x17InvalidateResourceCache\
+    \x12#.api.InvalidateResourceCacheRequest\x1a$.api.InvalidateResourceCach\
+    eResponse\x12^\n\x15GetResourceCacheStats\x12!.api.GetResourceCacheStats\
+    Request\x1a\".api.GetResourceCacheStatsResponse\x12g\n\x18PrepareResourc\
+    eInjection\x12$.api.PrepareResourceInjectionRequest\x1a%.api.PrepareReso\
+    urceInjectionResponse\x12d\n\x17CommitResourceInjection\x12#.api.CommitR\
+    esourceInjectionRequest\x1a$.api.CommitResourceInjectionResponse2\x9e\
+    \x01\n\x12SecureMountService\x12@\n\x0bSecureMount\x12\x17.api.SecureMou\
+    ntRequest\x1a\x18.api.SecureMountResponse\x12F\n\rSecureUnmount\x12\x19.\
+    api.SecureUnmountRequest\x1a\x1a.api.SecureUnmountResponse2\x94\x02\n\
+    \x10ImagePullService\x12:\n\tPullImage\x12\x15.api.ImagePullRequest\x1a\
+    \x16.api.ImagePullResponse\x12@\n\x0bRemoveImage\x12\x17.api.RemoveImage\
+    Request\x1a\x18.api.RemoveImageResponse\x12=\n\nListImages\x12\x16.api.L\
+    istImagesRequest\x1a\x17.api.ListImagesResponse\x12C\n\x0cInspectImage\
+    \x12\x18.api.InspectImageRequest\x1a\x19.api.InspectImageResponseBaZ_git\
+    hub.com/confidential-containers/guest-components/confidential-data-hub/g\
+    olang/pkg/api/cdhapib\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1718,19 +3782,34 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(12);
+            let mut messages = ::std::vec::Vec::with_capacity(27);
             messages.push(UnsealSecretInput::generated_message_descriptor_data());
             messages.push(UnsealSecretOutput::generated_message_descriptor_data());
             messages.push(GetResourceRequest::generated_message_descriptor_data());
             messages.push(GetResourceResponse::generated_message_descriptor_data());
+            messages.push(InvalidateResourceCacheRequest::generated_message_descriptor_data());
+            messages.push(InvalidateResourceCacheResponse::generated_message_descriptor_data());
+            messages.push(GetResourceCacheStatsRequest::generated_message_descriptor_data());
+            messages.push(GetResourceCacheStatsResponse::generated_message_descriptor_data());
             messages.push(PrepareResourceInjectionRequest::generated_message_descriptor_data());
             messages.push(PrepareResourceInjectionResponse::generated_message_descriptor_data());
             messages.push(CommitResourceInjectionRequest::generated_message_descriptor_data());
             messages.push(CommitResourceInjectionResponse::generated_message_descriptor_data());
             messages.push(SecureMountRequest::generated_message_descriptor_data());
             messages.push(SecureMountResponse::generated_message_descriptor_data());
+            messages.push(SecureUnmountRequest::generated_message_descriptor_data());
+            messages.push(SecureUnmountResponse::generated_message_descriptor_data());
             messages.push(ImagePullRequest::generated_message_descriptor_data());
             messages.push(ImagePullResponse::generated_message_descriptor_data());
+            messages.push(RemoveImageRequest::generated_message_descriptor_data());
+            messages.push(RemoveImageResponse::generated_message_descriptor_data());
+            messages.push(LayerInfo::generated_message_descriptor_data());
+            messages.push(SignatureVerification::generated_message_descriptor_data());
+            messages.push(ImageInfo::generated_message_descriptor_data());
+            messages.push(ListImagesRequest::generated_message_descriptor_data());
+            messages.push(ListImagesResponse::generated_message_descriptor_data());
+            messages.push(InspectImageRequest::generated_message_descriptor_data());
+            messages.push(InspectImageResponse::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
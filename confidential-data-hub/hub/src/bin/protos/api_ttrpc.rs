@@ -84,6 +84,16 @@ impl GetResourceServiceClient {
         ::ttrpc::async_client_request!(self, ctx, req, "api.GetResourceService", "GetResource", cres);
     }
 
+    pub async fn invalidate_resource_cache(&self, ctx: ttrpc::context::Context, req: &super::api::InvalidateResourceCacheRequest) -> ::ttrpc::Result<super::api::InvalidateResourceCacheResponse> {
+        let mut cres = super::api::InvalidateResourceCacheResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.GetResourceService", "InvalidateResourceCache", cres);
+    }
+
+    pub async fn get_resource_cache_stats(&self, ctx: ttrpc::context::Context, req: &super::api::GetResourceCacheStatsRequest) -> ::ttrpc::Result<super::api::GetResourceCacheStatsResponse> {
+        let mut cres = super::api::GetResourceCacheStatsResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.GetResourceService", "GetResourceCacheStats", cres);
+    }
+
     pub async fn prepare_resource_injection(&self, ctx: ttrpc::context::Context, req: &super::api::PrepareResourceInjectionRequest) -> ::ttrpc::Result<super::api::PrepareResourceInjectionResponse> {
         let mut cres = super::api::PrepareResourceInjectionResponse::new();
         ::ttrpc::async_client_request!(self, ctx, req, "api.GetResourceService", "PrepareResourceInjection", cres);
@@ -106,6 +116,28 @@ impl ::ttrpc::r#async::MethodHandler for GetResourceMethod {
     }
 }
 
+struct InvalidateResourceCacheMethod {
+    service: Arc<dyn GetResourceService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for InvalidateResourceCacheMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, InvalidateResourceCacheRequest, invalidate_resource_cache);
+    }
+}
+
+struct GetResourceCacheStatsMethod {
+    service: Arc<dyn GetResourceService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for GetResourceCacheStatsMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, GetResourceCacheStatsRequest, get_resource_cache_stats);
+    }
+}
+
 struct PrepareResourceInjectionMethod {
     service: Arc<dyn GetResourceService + Send + Sync>,
 }
@@ -133,6 +165,12 @@ pub trait GetResourceService: Sync {
     async fn get_resource(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::GetResourceRequest) -> ::ttrpc::Result<super::api::GetResourceResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.GetResourceService/GetResource is not supported".to_string())))
     }
+    async fn invalidate_resource_cache(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::InvalidateResourceCacheRequest) -> ::ttrpc::Result<super::api::InvalidateResourceCacheResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.GetResourceService/InvalidateResourceCache is not supported".to_string())))
+    }
+    async fn get_resource_cache_stats(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::GetResourceCacheStatsRequest) -> ::ttrpc::Result<super::api::GetResourceCacheStatsResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.GetResourceService/GetResourceCacheStats is not supported".to_string())))
+    }
     async fn prepare_resource_injection(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::PrepareResourceInjectionRequest) -> ::ttrpc::Result<super::api::PrepareResourceInjectionResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.GetResourceService/PrepareResourceInjection is not supported".to_string())))
     }
@@ -149,6 +187,12 @@ pub fn create_get_resource_service(service: Arc<dyn GetResourceService + Send +
     methods.insert("GetResource".to_string(),
                     Box::new(GetResourceMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
+    methods.insert("InvalidateResourceCache".to_string(),
+                    Box::new(InvalidateResourceCacheMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("GetResourceCacheStats".to_string(),
+                    Box::new(GetResourceCacheStatsMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
     methods.insert("PrepareResourceInjection".to_string(),
                     Box::new(PrepareResourceInjectionMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
@@ -175,6 +219,11 @@ impl SecureMountServiceClient {
         let mut cres = super::api::SecureMountResponse::new();
         ::ttrpc::async_client_request!(self, ctx, req, "api.SecureMountService", "SecureMount", cres);
     }
+
+    pub async fn secure_unmount(&self, ctx: ttrpc::context::Context, req: &super::api::SecureUnmountRequest) -> ::ttrpc::Result<super::api::SecureUnmountResponse> {
+        let mut cres = super::api::SecureUnmountResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.SecureMountService", "SecureUnmount", cres);
+    }
 }
 
 struct SecureMountMethod {
@@ -188,11 +237,25 @@ impl ::ttrpc::r#async::MethodHandler for SecureMountMethod {
     }
 }
 
+struct SecureUnmountMethod {
+    service: Arc<dyn SecureMountService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for SecureUnmountMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, SecureUnmountRequest, secure_unmount);
+    }
+}
+
 #[async_trait]
 pub trait SecureMountService: Sync {
     async fn secure_mount(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::SecureMountRequest) -> ::ttrpc::Result<super::api::SecureMountResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.SecureMountService/SecureMount is not supported".to_string())))
     }
+    async fn secure_unmount(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::SecureUnmountRequest) -> ::ttrpc::Result<super::api::SecureUnmountResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.SecureMountService/SecureUnmount is not supported".to_string())))
+    }
 }
 
 pub fn create_secure_mount_service(service: Arc<dyn SecureMountService + Send + Sync>) -> HashMap<String, ::ttrpc::r#async::Service> {
@@ -203,6 +266,9 @@ pub fn create_secure_mount_service(service: Arc<dyn SecureMountService + Send +
     methods.insert("SecureMount".to_string(),
                     Box::new(SecureMountMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
+    methods.insert("SecureUnmount".to_string(),
+                    Box::new(SecureUnmountMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
     ret.insert("api.SecureMountService".to_string(), ::ttrpc::r#async::Service{ methods, streams });
     ret
 }
@@ -223,6 +289,21 @@ impl ImagePullServiceClient {
         let mut cres = super::api::ImagePullResponse::new();
         ::ttrpc::async_client_request!(self, ctx, req, "api.ImagePullService", "PullImage", cres);
     }
+
+    pub async fn remove_image(&self, ctx: ttrpc::context::Context, req: &super::api::RemoveImageRequest) -> ::ttrpc::Result<super::api::RemoveImageResponse> {
+        let mut cres = super::api::RemoveImageResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.ImagePullService", "RemoveImage", cres);
+    }
+
+    pub async fn list_images(&self, ctx: ttrpc::context::Context, req: &super::api::ListImagesRequest) -> ::ttrpc::Result<super::api::ListImagesResponse> {
+        let mut cres = super::api::ListImagesResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.ImagePullService", "ListImages", cres);
+    }
+
+    pub async fn inspect_image(&self, ctx: ttrpc::context::Context, req: &super::api::InspectImageRequest) -> ::ttrpc::Result<super::api::InspectImageResponse> {
+        let mut cres = super::api::InspectImageResponse::new();
+        ::ttrpc::async_client_request!(self, ctx, req, "api.ImagePullService", "InspectImage", cres);
+    }
 }
 
 struct PullImageMethod {
@@ -236,11 +317,53 @@ impl ::ttrpc::r#async::MethodHandler for PullImageMethod {
     }
 }
 
+struct RemoveImageMethod {
+    service: Arc<dyn ImagePullService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for RemoveImageMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, RemoveImageRequest, remove_image);
+    }
+}
+
+struct ListImagesMethod {
+    service: Arc<dyn ImagePullService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for ListImagesMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, ListImagesRequest, list_images);
+    }
+}
+
+struct InspectImageMethod {
+    service: Arc<dyn ImagePullService + Send + Sync>,
+}
+
+#[async_trait]
+impl ::ttrpc::r#async::MethodHandler for InspectImageMethod {
+    async fn handler(&self, ctx: ::ttrpc::r#async::TtrpcContext, req: ::ttrpc::Request) -> ::ttrpc::Result<::ttrpc::Response> {
+        ::ttrpc::async_request_handler!(self, ctx, req, api, InspectImageRequest, inspect_image);
+    }
+}
+
 #[async_trait]
 pub trait ImagePullService: Sync {
     async fn pull_image(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::ImagePullRequest) -> ::ttrpc::Result<super::api::ImagePullResponse> {
         Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.ImagePullService/PullImage is not supported".to_string())))
     }
+    async fn remove_image(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::RemoveImageRequest) -> ::ttrpc::Result<super::api::RemoveImageResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.ImagePullService/RemoveImage is not supported".to_string())))
+    }
+    async fn list_images(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::ListImagesRequest) -> ::ttrpc::Result<super::api::ListImagesResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.ImagePullService/ListImages is not supported".to_string())))
+    }
+    async fn inspect_image(&self, _ctx: &::ttrpc::r#async::TtrpcContext, _: super::api::InspectImageRequest) -> ::ttrpc::Result<super::api::InspectImageResponse> {
+        Err(::ttrpc::Error::RpcStatus(::ttrpc::get_status(::ttrpc::Code::NOT_FOUND, "/api.ImagePullService/InspectImage is not supported".to_string())))
+    }
 }
 
 pub fn create_image_pull_service(service: Arc<dyn ImagePullService + Send + Sync>) -> HashMap<String, ::ttrpc::r#async::Service> {
@@ -251,6 +374,15 @@ pub fn create_image_pull_service(service: Arc<dyn ImagePullService + Send + Sync
     methods.insert("PullImage".to_string(),
                     Box::new(PullImageMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
 
+    methods.insert("RemoveImage".to_string(),
+                    Box::new(RemoveImageMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("ListImages".to_string(),
+                    Box::new(ListImagesMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
+    methods.insert("InspectImage".to_string(),
+                    Box::new(InspectImageMethod{service: service.clone()}) as Box<dyn ::ttrpc::r#async::MethodHandler + Send + Sync>);
+
     ret.insert("api.ImagePullService".to_string(), ::ttrpc::r#async::Service{ methods, streams });
     ret
 }
@@ -14,7 +14,8 @@ use confidential_data_hub::kms::plugins::ehsm::EhsmKmsClient;
 use confidential_data_hub::kms::{Encrypter, ProviderSettings};
 use confidential_data_hub::secret::{
     layout::{envelope::EnvelopeSecret, vault::VaultSecret},
-    Secret, SecretContent, VERSION,
+    signature::parse_jwks,
+    Secret, SecretContent, SecretVerifier, SignaturePolicy, SigningKey, VERSION,
 };
 
 use crypto::WrapType;
@@ -34,6 +35,10 @@ enum Cli {
 
     /// Unseal the given secret
     Unseal(UnsealArgs),
+
+    /// Print the public JWK of a signing key, which can be added to the
+    /// `trusted_keys` of CDH
+    PublicKey(PublicKeyArgs),
 }
 
 #[derive(Args)]
@@ -42,6 +47,27 @@ struct SealArgs {
     /// Type of the Secret, i.e. `vault` or `envelope`
     #[command(subcommand)]
     r#type: TypeArgs,
+
+    /// path of the PKCS#8 private key (PEM or DER) to sign the secret. If
+    /// not given, a dummy signature is used
+    #[arg(long)]
+    signing_key: Option<String>,
+
+    /// key id (`kid`) put into the JWS header of the signed secret
+    #[arg(long)]
+    signing_key_id: Option<String>,
+}
+
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct PublicKeyArgs {
+    /// path of the PKCS#8 private key (PEM or DER)
+    #[arg(long)]
+    signing_key: String,
+
+    /// key id (`kid`) of the key
+    #[arg(long)]
+    signing_key_id: Option<String>,
 }
 
 #[derive(Args)]
@@ -58,6 +84,11 @@ struct UnsealArgs {
     /// configuration for connecting to KBS provider
     #[arg(short, long)]
     aa_kbc_params: Option<String>,
+
+    /// path of the trusted public key (JWK or JWK set). If given, the
+    /// signature of the secret must be verified by it
+    #[arg(long)]
+    public_key: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -158,14 +189,35 @@ async fn main() {
         Cli::Seal(seal_args) => {
             seal_secret(&seal_args).await;
         }
+        Cli::PublicKey(args) => {
+            let key = load_signing_key(&args.signing_key, args.signing_key_id.clone()).await;
+            let jwk = serde_json::to_string_pretty(&key.public_jwk())
+                .expect("failed to serialize public key");
+            println!("{jwk}");
+        }
     }
 }
 
+async fn load_signing_key(path: &str, kid: Option<String>) -> SigningKey {
+    let key = fs::read(path).await.expect("failed to read signing key");
+    SigningKey::from_pkcs8(&key, kid).expect("failed to parse signing key")
+}
+
 async fn unseal_secret(unseal_args: &UnsealArgs) {
     let secret_string = fs::read_to_string(&unseal_args.file_path)
         .await
         .expect("failed to read sealed secret");
-    let secret = Secret::from_signed_base64_string(secret_string).expect("Failed to parse secret.");
+    let secret = match &unseal_args.public_key {
+        Some(path) => {
+            let jwks = fs::read(path).await.expect("failed to read public key");
+            let keys = parse_jwks(&jwks).expect("failed to parse public key");
+            SecretVerifier::new(SignaturePolicy::Reject, keys)
+                .verify(&secret_string)
+                .await
+                .expect("Failed to verify secret.")
+        }
+        None => Secret::from_signed_base64_string(secret_string).expect("Failed to parse secret."),
+    };
 
     // Setup secret provider
     let secret_provider = match secret.r#type {
@@ -282,9 +334,15 @@ async fn seal_secret(seal_args: &SealArgs) {
         version: VERSION.into(),
         r#type: sc,
     };
-    let secret_string = secret
-        .to_signed_base64_string()
-        .expect("failed to serialize secret");
+    let secret_string = match &seal_args.signing_key {
+        Some(path) => {
+            let key = load_signing_key(path, seal_args.signing_key_id.clone()).await;
+            secret.sign(&key).expect("failed to sign secret")
+        }
+        None => secret
+            .to_signed_base64_string()
+            .expect("failed to serialize secret"),
+    };
     println!("{secret_string}");
 }
 
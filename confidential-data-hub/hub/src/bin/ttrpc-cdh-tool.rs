@@ -54,11 +54,29 @@ enum Operation {
     /// Get Resource from KBS
     GetResource(GetResourceArgs),
 
+    /// Drop cached KBS resources
+    InvalidateResourceCache(InvalidateResourceCacheArgs),
+
+    /// Get the hit/miss counters of the KBS resource cache
+    GetResourceCacheStats,
+
     /// Secure mount
     SecureMount(SecureMountArgs),
 
+    /// Tear down a secure mount
+    SecureUnmount(SecureUnmountArgs),
+
     /// Pull image
     PullImage(PullImageArgs),
+
+    /// Remove a pulled image
+    RemoveImage(RemoveImageArgs),
+
+    /// List the pulled images
+    ListImages,
+
+    /// Show the metadata of a pulled image
+    InspectImage(InspectImageArgs),
 }
 
 #[derive(Args)]
@@ -85,6 +103,14 @@ struct GetResourceArgs {
     resource_uri: String,
 }
 
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct InvalidateResourceCacheArgs {
+    /// prefix of the KBS Resource URIs to drop, all resources if not given
+    #[arg(short, long, default_value = "")]
+    prefix: String,
+}
+
 #[derive(Args)]
 #[command(author, version, about, long_about = None)]
 struct SecureMountArgs {
@@ -93,6 +119,14 @@ struct SecureMountArgs {
     storage_path: String,
 }
 
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct SecureUnmountArgs {
+    /// the mount point of the secure mount
+    #[arg(short, long)]
+    mount_point: String,
+}
+
 #[derive(Args)]
 #[command(author, version, about, long_about = None)]
 struct PullImageArgs {
@@ -103,6 +137,26 @@ struct PullImageArgs {
     /// Path to store the image bundle
     #[arg(short, long)]
     bundle_path: String,
+
+    /// Platform to pull from a multi-platform image, e.g. `linux/arm64/v8`
+    #[arg(short, long, default_value = "")]
+    platform: String,
+}
+
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct RemoveImageArgs {
+    /// Reference or ID of the image
+    #[arg(short, long)]
+    image: String,
+}
+
+#[derive(Args)]
+#[command(author, version, about, long_about = None)]
+struct InspectImageArgs {
+    /// Reference or ID of the image
+    #[arg(short, long)]
+    image: String,
 }
 
 #[tokio::main]
@@ -154,6 +208,36 @@ async fn main() {
             let res = STANDARD.encode(res.Resource);
             println!("{res}");
         }
+        Operation::InvalidateResourceCache(arg) => {
+            let client = GetResourceServiceClient::new(inner);
+            let req = InvalidateResourceCacheRequest {
+                ResourcePathPrefix: arg.prefix,
+                ..Default::default()
+            };
+            let res = client
+                .invalidate_resource_cache(
+                    context::with_timeout(args.timeout * NANO_PER_SECOND),
+                    &req,
+                )
+                .await
+                .expect("request to CDH");
+            println!("invalidated: {}", res.Invalidated);
+        }
+        Operation::GetResourceCacheStats => {
+            let client = GetResourceServiceClient::new(inner);
+            let req = GetResourceCacheStatsRequest::default();
+            let res = client
+                .get_resource_cache_stats(
+                    context::with_timeout(args.timeout * NANO_PER_SECOND),
+                    &req,
+                )
+                .await
+                .expect("request to CDH");
+            println!(
+                "hits: {}, misses: {}, entries: {}",
+                res.Hits, res.Misses, res.Entries
+            );
+        }
         Operation::SecureMount(arg) => {
             let client = SecureMountServiceClient::new(inner);
             let storage_manifest = tokio::fs::read(arg.storage_path).await.expect("read file");
@@ -172,11 +256,24 @@ async fn main() {
                 .expect("request to CDH");
             println!("mount path: {}", res.mount_path);
         }
+        Operation::SecureUnmount(arg) => {
+            let client = SecureMountServiceClient::new(inner);
+            let req = SecureUnmountRequest {
+                mount_point: arg.mount_point,
+                ..Default::default()
+            };
+            client
+                .secure_unmount(context::with_timeout(args.timeout * NANO_PER_SECOND), &req)
+                .await
+                .expect("request to CDH");
+            println!("unmounted: {}", req.mount_point);
+        }
         Operation::PullImage(arg) => {
             let client = ImagePullServiceClient::new(inner);
             let req = ImagePullRequest {
                 image_url: arg.image_url,
                 bundle_path: arg.bundle_path,
+                platform: arg.platform,
                 ..Default::default()
             };
             let manifest_digest = client
@@ -185,5 +282,64 @@ async fn main() {
                 .expect("request to CDH");
             println!("Image pulled: {manifest_digest}")
         }
+        Operation::RemoveImage(arg) => {
+            let client = ImagePullServiceClient::new(inner);
+            let req = RemoveImageRequest {
+                image: arg.image,
+                ..Default::default()
+            };
+            client
+                .remove_image(context::with_timeout(args.timeout * NANO_PER_SECOND), &req)
+                .await
+                .expect("request to CDH");
+            println!("Image removed: {}", req.image);
+        }
+        Operation::ListImages => {
+            let client = ImagePullServiceClient::new(inner);
+            let req = ListImagesRequest::default();
+            let res = client
+                .list_images(context::with_timeout(args.timeout * NANO_PER_SECOND), &req)
+                .await
+                .expect("request to CDH");
+            for image in res.images {
+                println!(
+                    "{} {} {} signed: {}",
+                    image.id, image.reference, image.digest, image.signed
+                );
+            }
+        }
+        Operation::InspectImage(arg) => {
+            let client = ImagePullServiceClient::new(inner);
+            let req = InspectImageRequest {
+                image: arg.image,
+                ..Default::default()
+            };
+            let res = client
+                .inspect_image(context::with_timeout(args.timeout * NANO_PER_SECOND), &req)
+                .await
+                .expect("request to CDH");
+            let image = res.image.unwrap_or_default();
+            println!("id: {}", image.id);
+            println!("reference: {}", image.reference);
+            println!("digest: {}", image.digest);
+            println!("signed: {}", image.signed);
+            println!("platform: {}", image.platform);
+            println!("config: {}", image.config);
+            for layer in image.layers {
+                println!(
+                    "layer: {} uncompressed: {} compression: {} encrypted: {}",
+                    layer.compressed_digest,
+                    layer.uncompressed_digest,
+                    layer.compression,
+                    layer.encrypted
+                );
+            }
+            for verification in image.verifications {
+                println!(
+                    "verified: {} image digest: {} policy digest: {}",
+                    verification.reference, verification.image_digest, verification.policy_digest
+                );
+            }
+        }
     }
 }
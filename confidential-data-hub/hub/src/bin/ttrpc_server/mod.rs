@@ -11,7 +11,9 @@ use confidential_data_hub::{
     storage::volume_type::Storage,
     {hub::Hub, CdhConfig, DataHub},
 };
+use image_rs::image::ImageMeta;
 use log::{debug, error};
+use protobuf::MessageField;
 use ttrpc::{asynchronous::TtrpcContext, Code, Error, Status};
 
 use crate::{
@@ -19,10 +21,14 @@ use crate::{
     message::{KeyProviderInput, KeyUnwrapOutput, KeyUnwrapResults},
     protos::{
         api::{
-            CommitResourceInjectionRequest, CommitResourceInjectionResponse, GetResourceRequest,
-            GetResourceResponse, ImagePullRequest, ImagePullResponse,
-            PrepareResourceInjectionRequest, PrepareResourceInjectionResponse, SecureMountRequest,
-            SecureMountResponse, UnsealSecretInput, UnsealSecretOutput,
+            CommitResourceInjectionRequest, CommitResourceInjectionResponse,
+            GetResourceCacheStatsRequest, GetResourceCacheStatsResponse, GetResourceRequest,
+            GetResourceResponse, ImageInfo, ImagePullRequest, ImagePullResponse,
+            InspectImageRequest, InspectImageResponse, InvalidateResourceCacheRequest,
+            InvalidateResourceCacheResponse, LayerInfo, ListImagesRequest, ListImagesResponse,
+            PrepareResourceInjectionRequest, PrepareResourceInjectionResponse, RemoveImageRequest,
+            RemoveImageResponse, SecureMountRequest, SecureMountResponse, SecureUnmountRequest,
+            SecureUnmountResponse, SignatureVerification, UnsealSecretInput, UnsealSecretOutput,
         },
         api_ttrpc::{
             GetResourceService, ImagePullService, SealedSecretService, SecureMountService,
@@ -91,6 +97,53 @@ impl GetResourceService for Server {
         Ok(reply)
     }
 
+    async fn invalidate_resource_cache(
+        &self,
+        _ctx: &TtrpcContext,
+        req: InvalidateResourceCacheRequest,
+    ) -> ::ttrpc::Result<InvalidateResourceCacheResponse> {
+        debug!("[ttRPC CDH] get new InvalidateResourceCache request");
+        let invalidated = self
+            .hub
+            .invalidate_resource_cache(&req.ResourcePathPrefix)
+            .await
+            .map_err(|e| {
+                let detailed_error = format_error!(e);
+                error!("[ttRPC CDH] InvalidateResourceCache :\n{detailed_error}");
+                let mut status = Status::new();
+                status.set_code(Code::INTERNAL);
+                status.set_message("[CDH] [ERROR]: InvalidateResourceCache failed".into());
+                Error::RpcStatus(status)
+            })?;
+
+        let mut reply = InvalidateResourceCacheResponse::new();
+        reply.Invalidated = invalidated;
+        debug!("[ttRPC CDH] invalidate resource cache succeeded");
+        Ok(reply)
+    }
+
+    async fn get_resource_cache_stats(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: GetResourceCacheStatsRequest,
+    ) -> ::ttrpc::Result<GetResourceCacheStatsResponse> {
+        debug!("[ttRPC CDH] get new GetResourceCacheStats request");
+        let stats = self.hub.resource_cache_stats().await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[ttRPC CDH] GetResourceCacheStats :\n{detailed_error}");
+            let mut status = Status::new();
+            status.set_code(Code::INTERNAL);
+            status.set_message("[CDH] [ERROR]: GetResourceCacheStats failed".into());
+            Error::RpcStatus(status)
+        })?;
+
+        let mut reply = GetResourceCacheStatsResponse::new();
+        reply.Hits = stats.hits;
+        reply.Misses = stats.misses;
+        reply.Entries = stats.entries;
+        Ok(reply)
+    }
+
     async fn prepare_resource_injection(
         &self,
         _ctx: &TtrpcContext,
@@ -228,6 +281,28 @@ impl SecureMountService for Server {
         debug!("[ttRPC CDH] secure mount succeeded.");
         Ok(reply)
     }
+
+    async fn secure_unmount(
+        &self,
+        _ctx: &TtrpcContext,
+        req: SecureUnmountRequest,
+    ) -> ::ttrpc::Result<SecureUnmountResponse> {
+        debug!("[ttRPC CDH] get new secure unmount request");
+        self.hub
+            .secure_umount(&req.mount_point)
+            .await
+            .map_err(|e| {
+                let detailed_error = format_error!(e);
+                error!("[ttRPC CDH] Secure Unmount :\n{detailed_error}");
+                let mut status = Status::new();
+                status.set_code(Code::INTERNAL);
+                status.set_message("[CDH] [ERROR]: secure unmount failed".to_string());
+                Error::RpcStatus(status)
+            })?;
+
+        debug!("[ttRPC CDH] secure unmount succeeded.");
+        Ok(SecureUnmountResponse::new())
+    }
 }
 
 #[async_trait]
@@ -240,7 +315,11 @@ impl ImagePullService for Server {
         debug!("[ttRPC CDH] get new image pull request");
         let manifest_digest = self
             .hub
-            .pull_image(&req.image_url, &req.bundle_path)
+            .pull_image(
+                &req.image_url,
+                &req.bundle_path,
+                Some(req.platform.as_str()).filter(|p| !p.is_empty()),
+            )
             .await
             .map_err(|e| {
                 let detailed_error = format_error!(e);
@@ -256,4 +335,100 @@ impl ImagePullService for Server {
         debug!("[ttRPC CDH] pull image succeeded.");
         Ok(reply)
     }
+
+    async fn remove_image(
+        &self,
+        _ctx: &TtrpcContext,
+        req: RemoveImageRequest,
+    ) -> ::ttrpc::Result<RemoveImageResponse> {
+        debug!("[ttRPC CDH] get new remove image request");
+        self.hub.remove_image(&req.image).await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[ttRPC CDH] Remove Image :\n{detailed_error}");
+            let mut status = Status::new();
+            status.set_code(Code::INTERNAL);
+            status.set_message("[CDH] [ERROR]: remove image failed".to_string());
+            Error::RpcStatus(status)
+        })?;
+
+        debug!("[ttRPC CDH] remove image succeeded.");
+        Ok(RemoveImageResponse::new())
+    }
+
+    async fn list_images(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: ListImagesRequest,
+    ) -> ::ttrpc::Result<ListImagesResponse> {
+        debug!("[ttRPC CDH] get new list images request");
+        let images = self.hub.list_images().await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[ttRPC CDH] List Images :\n{detailed_error}");
+            let mut status = Status::new();
+            status.set_code(Code::INTERNAL);
+            status.set_message("[CDH] [ERROR]: list images failed".to_string());
+            Error::RpcStatus(status)
+        })?;
+
+        let mut reply = ListImagesResponse::new();
+        reply.images = images.into_iter().map(image_info).collect();
+        Ok(reply)
+    }
+
+    async fn inspect_image(
+        &self,
+        _ctx: &TtrpcContext,
+        req: InspectImageRequest,
+    ) -> ::ttrpc::Result<InspectImageResponse> {
+        debug!("[ttRPC CDH] get new inspect image request");
+        let image = self.hub.inspect_image(&req.image).await.map_err(|e| {
+            let detailed_error = format_error!(e);
+            error!("[ttRPC CDH] Inspect Image :\n{detailed_error}");
+            let mut status = Status::new();
+            status.set_code(Code::INTERNAL);
+            status.set_message("[CDH] [ERROR]: inspect image failed".to_string());
+            Error::RpcStatus(status)
+        })?;
+
+        let mut reply = InspectImageResponse::new();
+        reply.image = MessageField::some(image_info(image));
+        Ok(reply)
+    }
+}
+
+fn image_info(image: ImageMeta) -> ImageInfo {
+    let mut info = ImageInfo::new();
+    info.config = serde_json::to_string(&image.image_config).unwrap_or_default();
+    info.layers = image
+        .layer_metas
+        .into_iter()
+        .map(|layer| {
+            let mut layer_info = LayerInfo::new();
+            layer_info.compressed_digest = layer.compressed_digest;
+            layer_info.uncompressed_digest = layer.uncompressed_digest;
+            layer_info.compression = layer.decoder.to_string();
+            layer_info.encrypted = layer.encrypted;
+            layer_info
+        })
+        .collect();
+    info.verifications = image
+        .verifications
+        .into_iter()
+        .map(|(reference, verification)| {
+            let mut verification_info = SignatureVerification::new();
+            verification_info.reference = reference;
+            verification_info.image_digest = verification.image_digest;
+            verification_info.policy_digest = verification.policy_digest;
+            verification_info
+        })
+        .collect();
+    info.id = image.id;
+    info.reference = image.reference;
+    info.digest = image.digest;
+    info.signed = image.signed;
+    info.platform = image
+        .platform
+        .map(|platform| platform.to_string())
+        .unwrap_or_default();
+    info
 }
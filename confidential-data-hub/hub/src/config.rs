@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::{env, fs, path::Path};
+use std::{collections::HashSet, env, fs, path::Path, time::Duration};
 
 use anyhow::*;
 use attestation_agent::config::aa_kbc_params::AaKbcParams;
@@ -12,6 +12,9 @@ use image_rs::config::ImageConfig;
 use log::{debug, info};
 use serde::Deserialize;
 
+use crate::kms::plugins::kbs::{CachePolicy, CacheTtlRule};
+use crate::secret::SignaturePolicy;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "ttrpc")] {
         const DEFAULT_CDH_SOCKET_ADDR: &str = "unix:///run/confidential-containers/cdh.sock";
@@ -22,6 +25,13 @@ cfg_if::cfg_if! {
     }
 }
 
+/// Default seconds between two checks of the image GC. The GC removes pulled
+/// images, so it is disabled unless configured.
+const DEFAULT_IMAGE_GC_INTERVAL: u64 = 0;
+
+/// Default disk usage in percent above which the image GC removes images.
+const DEFAULT_IMAGE_GC_HIGH_WATER_MARK: u8 = 80;
+
 #[derive(Clone, Deserialize, Debug, PartialEq)]
 pub struct KbsConfig {
     pub name: String,
@@ -44,22 +54,141 @@ impl KbsConfig {
     }
 }
 
+/// An additional KBS backend. Resources of `kbs://<host>/...` URIs whose
+/// authority equals `host` are fetched from this backend rather than the
+/// default one given by `kbc`.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub struct KbsBackendConfig {
+    /// The authority (`host[:port]`) of the resource URIs routed to this
+    /// backend, e.g. `kbs-b.example` for `kbs://kbs-b.example/default/key/1`.
+    pub host: String,
+
+    /// The KBC name. It could be `cc_kbc`, `online_sev_kbc` or `offline_fs_kbc`.
+    pub name: String,
+
+    pub url: String,
+
+    pub kbs_cert: Option<String>,
+}
+
 #[derive(Clone, Deserialize, Debug, PartialEq)]
 pub struct Credential {
     pub resource_uri: String,
     pub path: String,
 }
 
+/// Configurations of sealed secret signature verification.
+#[derive(Clone, Deserialize, Debug, PartialEq, Default)]
+pub struct SealedSecretConfig {
+    /// Whether a sealed secret whose signature cannot be verified is
+    /// rejected (`reject`, the default) or unsealed with a warning (`warn`).
+    #[serde(default)]
+    pub signature_policy: SignaturePolicy,
+
+    /// Public keys trusted to sign sealed secrets. Each item is either a KBS
+    /// resource URI (e.g. `kbs:///default/sealed-secret/pubkey`) or a local
+    /// file path. The content must be a JWK or a JWK set.
+    #[serde(default)]
+    pub trusted_keys: Vec<String>,
+}
+
+/// TTL of the cached resources of a repository and/or a type. An omitted
+/// field matches any value.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub struct ResourceCacheTtl {
+    pub repository: Option<String>,
+
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+
+    /// TTL in seconds. 0 disables caching of the matched resources.
+    pub ttl: u64,
+}
+
+/// Configurations of the in-memory cache of KBS resources.
+#[derive(Clone, Deserialize, Debug, PartialEq, Default)]
+pub struct ResourceCacheConfig {
+    /// TTL in seconds of the resources matched by no item of `ttls`. The
+    /// default 0 disables caching of them.
+    #[serde(default)]
+    pub default_ttl: u64,
+
+    /// TTLs of specific resources. The first matching item applies.
+    #[serde(default)]
+    pub ttls: Vec<ResourceCacheTtl>,
+}
+
+impl ResourceCacheConfig {
+    pub fn to_policy(&self) -> CachePolicy {
+        CachePolicy {
+            default_ttl: Duration::from_secs(self.default_ttl),
+            rules: self
+                .ttls
+                .iter()
+                .map(|it| CacheTtlRule {
+                    repository: it.repository.clone(),
+                    resource_type: it.resource_type.clone(),
+                    ttl: Duration::from_secs(it.ttl),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Configurations of the periodic garbage collection of pulled images.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub struct ImageGcConfig {
+    /// Seconds between two checks of the disk usage. 0, the default,
+    /// disables the GC.
+    #[serde(default = "default_image_gc_interval")]
+    pub interval: u64,
+
+    /// Disk usage in percent of the filesystem of the image work dir. Once
+    /// exceeded, the images not used by any bundle anymore and the layers
+    /// not used by any image are removed.
+    #[serde(default = "default_image_gc_high_water_mark")]
+    pub high_water_mark: u8,
+}
+
+fn default_image_gc_interval() -> u64 {
+    DEFAULT_IMAGE_GC_INTERVAL
+}
+
+fn default_image_gc_high_water_mark() -> u8 {
+    DEFAULT_IMAGE_GC_HIGH_WATER_MARK
+}
+
+impl Default for ImageGcConfig {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_IMAGE_GC_INTERVAL,
+            high_water_mark: DEFAULT_IMAGE_GC_HIGH_WATER_MARK,
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Debug, PartialEq)]
 pub struct CdhConfig {
     pub kbc: KbsConfig,
 
+    #[serde(default)]
+    pub kbs_backends: Vec<KbsBackendConfig>,
+
     #[serde(default)]
     pub credentials: Vec<Credential>,
 
     #[serde(default)]
     pub image: ImageConfig,
 
+    #[serde(default)]
+    pub sealed_secret: SealedSecretConfig,
+
+    #[serde(default)]
+    pub resource_cache: ResourceCacheConfig,
+
+    #[serde(default)]
+    pub image_gc: ImageGcConfig,
+
     pub socket: String,
 
     pub aa_socket: String,
@@ -88,10 +217,14 @@ impl CdhConfig {
                 info!("No config path specified, use a default config.");
                 Self {
                     kbc: KbsConfig::new()?,
+                    kbs_backends: Vec::new(),
                     credentials: Vec::new(),
                     socket: DEFAULT_CDH_SOCKET_ADDR.into(),
                     aa_socket: DEFAULT_AA_SOCKET_ADDR.into(),
                     image: ImageConfig::default(),
+                    sealed_secret: SealedSecretConfig::default(),
+                    resource_cache: ResourceCacheConfig::default(),
+                    image_gc: ImageGcConfig::default(),
                 }
             }
         };
@@ -110,10 +243,29 @@ impl CdhConfig {
             .add_source(File::with_name(config_path))
             .build()?;
 
-        let res = c.try_deserialize().context("invalid config")?;
+        let res: Self = c.try_deserialize().context("invalid config")?;
+        res.validate_kbs_backends()?;
+        if res.image_gc.high_water_mark > 100 {
+            bail!("image_gc: `high_water_mark` must not exceed 100");
+        }
         Ok(res)
     }
 
+    fn validate_kbs_backends(&self) -> Result<()> {
+        let mut hosts = HashSet::new();
+        for backend in &self.kbs_backends {
+            if backend.host.is_empty() {
+                bail!("kbs_backends: `host` must not be empty");
+            }
+
+            if !hosts.insert(&backend.host) {
+                bail!("kbs_backends: duplicated host {}", backend.host);
+            }
+        }
+
+        Ok(())
+    }
+
     /// all the resource ids can be from the kernel commandline in the following format:
     /// ```shell
     /// cdh.kbs_resources=<resource id 1>::<target path 1>,<resource id 2>::<target path 2>...
@@ -175,7 +327,9 @@ mod tests {
 
     use crate::{
         config::{DEFAULT_AA_SOCKET_ADDR, DEFAULT_CDH_SOCKET_ADDR},
-        CdhConfig, KbsConfig,
+        secret::SignaturePolicy,
+        CdhConfig, ImageGcConfig, KbsBackendConfig, KbsConfig, ResourceCacheConfig,
+        ResourceCacheTtl, SealedSecretConfig,
     };
 
     #[rstest]
@@ -202,6 +356,7 @@ image_pull_proxy = "http://127.0.0.1:8080"
                 url: "".to_string(),
                 kbs_cert: Some("".to_string()),
             },
+            kbs_backends: vec![],
             credentials: vec![],
             image: ImageConfig {
                 max_concurrent_layer_downloads_per_image: 3,
@@ -213,6 +368,9 @@ image_pull_proxy = "http://127.0.0.1:8080"
                 extra_root_certificates: vec!["cert1".into(), "cert2".into()],
                 ..Default::default()
             },
+            sealed_secret: SealedSecretConfig::default(),
+            resource_cache: ResourceCacheConfig::default(),
+            image_gc: ImageGcConfig::default(),
             socket: "unix:///run/confidential-containers/cdh.sock".to_string(),
             aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
         })
@@ -241,6 +399,7 @@ name = "offline_fs_kbc"
             url: "".to_string(),
             kbs_cert: None,
         },
+        kbs_backends: vec![],
         credentials: vec![],
         image: ImageConfig {
                 sigstore_config_uri: None,
@@ -250,6 +409,9 @@ name = "offline_fs_kbc"
                 skip_proxy_ips: None,
                 ..Default::default()
         },
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig::default(),
         socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
         aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
     })
@@ -268,6 +430,7 @@ some_undefined_field = "unknown value"
             url: "".to_string(),
             kbs_cert: None,
         },
+        kbs_backends: vec![],
         credentials: vec![],
         image: ImageConfig {
                 sigstore_config_uri: None,
@@ -277,10 +440,219 @@ some_undefined_field = "unknown value"
                 skip_proxy_ips: None,
                 ..Default::default()
         },
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig::default(),
+        socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
+        aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
+    })
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "offline_fs_kbc"
+
+[sealed_secret]
+signature_policy = "reject"
+trusted_keys = ["kbs:///default/sealed-secret/pubkey", "/etc/cdh/secret-pubkey.jwk"]
+"#,
+    Some(CdhConfig {
+        kbc: KbsConfig {
+            name: "offline_fs_kbc".to_string(),
+            url: "".to_string(),
+            kbs_cert: None,
+        },
+        kbs_backends: vec![],
+        credentials: vec![],
+        image: ImageConfig::default(),
+        sealed_secret: SealedSecretConfig {
+            signature_policy: SignaturePolicy::Reject,
+            trusted_keys: vec![
+                "kbs:///default/sealed-secret/pubkey".into(),
+                "/etc/cdh/secret-pubkey.jwk".into(),
+            ],
+        },
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig::default(),
         socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
         aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
     })
     )]
+    #[case(
+        r#"
+[kbc]
+name = "cc_kbc"
+url = "https://kbs-a.example:8080"
+
+[[kbs_backends]]
+host = "kbs-b.example"
+name = "cc_kbc"
+url = "https://kbs-b.example:8080"
+kbs_cert = "cert"
+
+[[kbs_backends]]
+host = "127.0.0.1:8081"
+name = "offline_fs_kbc"
+url = ""
+"#,
+    Some(CdhConfig {
+        kbc: KbsConfig {
+            name: "cc_kbc".to_string(),
+            url: "https://kbs-a.example:8080".to_string(),
+            kbs_cert: None,
+        },
+        kbs_backends: vec![
+            KbsBackendConfig {
+                host: "kbs-b.example".into(),
+                name: "cc_kbc".into(),
+                url: "https://kbs-b.example:8080".into(),
+                kbs_cert: Some("cert".into()),
+            },
+            KbsBackendConfig {
+                host: "127.0.0.1:8081".into(),
+                name: "offline_fs_kbc".into(),
+                url: "".into(),
+                kbs_cert: None,
+            },
+        ],
+        credentials: vec![],
+        image: ImageConfig::default(),
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig::default(),
+        socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
+        aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
+    })
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "cc_kbc"
+url = "https://kbs-a.example:8080"
+
+[[kbs_backends]]
+host = "kbs-b.example"
+name = "cc_kbc"
+url = "https://kbs-b.example:8080"
+
+[[kbs_backends]]
+host = "kbs-b.example"
+name = "cc_kbc"
+url = "https://kbs-c.example:8080"
+"#,
+        None
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "offline_fs_kbc"
+
+[resource_cache]
+default_ttl = 60
+
+[[resource_cache.ttls]]
+repository = "default"
+type = "key"
+ttl = 600
+
+[[resource_cache.ttls]]
+type = "token"
+ttl = 0
+"#,
+    Some(CdhConfig {
+        kbc: KbsConfig {
+            name: "offline_fs_kbc".to_string(),
+            url: "".to_string(),
+            kbs_cert: None,
+        },
+        kbs_backends: vec![],
+        credentials: vec![],
+        image: ImageConfig::default(),
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig {
+            default_ttl: 60,
+            ttls: vec![
+                ResourceCacheTtl {
+                    repository: Some("default".into()),
+                    resource_type: Some("key".into()),
+                    ttl: 600,
+                },
+                ResourceCacheTtl {
+                    repository: None,
+                    resource_type: Some("token".into()),
+                    ttl: 0,
+                },
+            ],
+        },
+        socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
+        aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
+    })
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "offline_fs_kbc"
+
+[image_gc]
+high_water_mark = 90
+"#,
+    Some(CdhConfig {
+        kbc: KbsConfig {
+            name: "offline_fs_kbc".to_string(),
+            url: "".to_string(),
+            kbs_cert: None,
+        },
+        kbs_backends: vec![],
+        credentials: vec![],
+        image: ImageConfig::default(),
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig {
+            interval: 0,
+            high_water_mark: 90,
+        },
+        socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
+        aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
+    })
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "offline_fs_kbc"
+
+[image_gc]
+interval = 300
+"#,
+    Some(CdhConfig {
+        kbc: KbsConfig {
+            name: "offline_fs_kbc".to_string(),
+            url: "".to_string(),
+            kbs_cert: None,
+        },
+        kbs_backends: vec![],
+        credentials: vec![],
+        image: ImageConfig::default(),
+        sealed_secret: SealedSecretConfig::default(),
+        resource_cache: ResourceCacheConfig::default(),
+        image_gc: ImageGcConfig {
+            interval: 300,
+            high_water_mark: 80,
+        },
+        socket: DEFAULT_CDH_SOCKET_ADDR.to_string(),
+        aa_socket: DEFAULT_AA_SOCKET_ADDR.to_string(),
+    })
+    )]
+    #[case(
+        r#"
+[kbc]
+name = "offline_fs_kbc"
+
+[image_gc]
+interval = 300
+high_water_mark = 101
+"#,
+        None
+    )]
     fn read_config(#[case] config: &str, #[case] expected: Option<CdhConfig>) {
         let mut file = tempfile::Builder::new()
             .append(true)
@@ -307,10 +679,14 @@ some_undefined_field = "unknown value"
                 url: "".into(),
                 kbs_cert: None,
             },
+            kbs_backends: Vec::new(),
             credentials: Vec::new(),
             socket: DEFAULT_CDH_SOCKET_ADDR.into(),
             aa_socket: DEFAULT_AA_SOCKET_ADDR.into(),
             image: ImageConfig::default(),
+            sealed_secret: SealedSecretConfig::default(),
+            resource_cache: ResourceCacheConfig::default(),
+            image_gc: ImageGcConfig::default(),
         };
         assert_eq!(config, expected);
 
@@ -34,12 +34,30 @@ pub enum Error {
     #[error("secure mount failed")]
     SecureMount(#[from] storage::Error),
 
+    #[error("{0} is already a secure mount point")]
+    MountPointBusy(String),
+
+    #[error("no secure mount found on {0}")]
+    MountNotFound(String),
+
     #[error("image pull failed")]
     ImagePull {
         #[source]
         source: anyhow::Error,
     },
 
+    #[error("image removal failed")]
+    ImageRemove {
+        #[source]
+        source: anyhow::Error,
+    },
+
+    #[error("image inspection failed")]
+    ImageInspect {
+        #[source]
+        source: anyhow::Error,
+    },
+
     #[error("resource injection failed: {0}")]
     ResourceInjection(String),
 }
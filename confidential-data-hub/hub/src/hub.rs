@@ -3,29 +3,61 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use image_rs::{builder::ClientBuilder, config::ImageConfig, image::ImageClient};
-use log::{debug, info};
-use tokio::sync::{Mutex, OnceCell};
+use image_rs::{
+    builder::ClientBuilder,
+    config::ImageConfig,
+    image::{ImageClient, ImageMeta},
+    meta_store::MetaStore,
+    platform::Platform,
+};
+use log::{debug, info, warn};
+use tokio::sync::{Mutex, OnceCell, RwLock};
 
 use crate::kms;
+use crate::kms::plugins::kbs::CacheStats;
 use crate::kms::{Annotations, ProviderSettings};
 #[cfg(feature = "resource_injection")]
 use crate::resource_injection::ResourceInjection;
-use crate::storage::volume_type::Storage;
-use crate::{image, secret, CdhConfig, DataHub, Error, PrepareResourceInjectionResult, Result};
+use crate::storage::volume_type::{MountHandle, Storage};
+use crate::{
+    image, secret, CdhConfig, DataHub, Error, ImageGcConfig, PrepareResourceInjectionResult, Result,
+};
 
 pub struct Hub {
     #[allow(dead_code)]
     pub(crate) credentials: HashMap<String, String>,
-    image_client: OnceCell<Mutex<ImageClient>>,
+    image_client: Arc<OnceCell<Images>>,
     config: CdhConfig,
+    /// Active secure mounts, indexed by mount point. The lock is only held
+    /// to update the map, not during the slow mount and umount operations.
+    mounts: Mutex<HashMap<String, MountState>>,
     #[cfg(feature = "resource_injection")]
     resource_injection: ResourceInjection,
 }
 
+/// The image client, and its meta store, which is read without waiting for
+/// the pulls in progress.
+struct Images {
+    client: Mutex<ImageClient>,
+    meta_store: Arc<RwLock<MetaStore>>,
+}
+
+/// State of a secure mount point.
+enum MountState {
+    /// A mount or umount is in progress.
+    Busy,
+
+    Mounted(MountHandle),
+}
+
 impl Hub {
     pub async fn new(config: CdhConfig) -> Result<Self> {
         config.set_configuration_envs();
@@ -34,18 +66,50 @@ impl Hub {
             .iter()
             .map(|it| (it.path.clone(), it.resource_uri.clone()))
             .collect();
+
+        let backends = config
+            .kbs_backends
+            .iter()
+            .map(|backend| {
+                let params = kms::plugins::kbs::KbcParams {
+                    kbc: backend.name.clone(),
+                    url: backend.url.clone(),
+                    kbs_cert: backend.kbs_cert.clone(),
+                };
+                (backend.host.clone(), params)
+            })
+            .collect();
+        kms::plugins::kbs::set_backends(backends).await;
+        kms::plugins::kbs::set_cache_policy(config.resource_cache.to_policy());
+
         #[cfg(feature = "resource_injection")]
         let resource_injection = ResourceInjection::new(config.aa_socket.clone());
 
+        let image_client = Arc::new(OnceCell::const_new());
+        if config.image_gc.interval != 0 {
+            tokio::spawn(image_gc(
+                image_client.clone(),
+                config.image.work_dir.clone(),
+                config.image_gc.clone(),
+            ));
+        }
+
         let mut hub = Self {
             credentials,
             config,
-            image_client: OnceCell::const_new(),
+            image_client,
+            mounts: Mutex::new(HashMap::new()),
             #[cfg(feature = "resource_injection")]
             resource_injection,
         };
 
         hub.init().await?;
+
+        secret::set_verifier(secret::SecretVerifier::from_config(
+            &hub.config.sealed_secret,
+        ))
+        .await;
+
         Ok(hub)
     }
 }
@@ -82,6 +146,16 @@ impl DataHub for Hub {
         Ok(res)
     }
 
+    async fn invalidate_resource_cache(&self, prefix: &str) -> Result<u64> {
+        info!("invalidate resource cache called: {prefix}");
+        let invalidated = kms::plugins::kbs::invalidate_cache(prefix);
+        Ok(invalidated as u64)
+    }
+
+    async fn resource_cache_stats(&self) -> Result<CacheStats> {
+        Ok(kms::plugins::kbs::cache_stats())
+    }
+
     async fn prepare_resource_injection(
         &self,
         resource_path: String,
@@ -121,28 +195,129 @@ impl DataHub for Hub {
 
     async fn secure_mount(&self, storage: Storage) -> Result<String> {
         info!("secure mount called");
-        let res = storage.mount().await?;
-        Ok(res)
+        // Reserve the mount point to avoid concurrent mounts on it, while
+        // mounts on other mount points can go ahead.
+        {
+            let mut mounts = self.mounts.lock().await;
+            if mounts.contains_key(&storage.mount_point) {
+                return Err(Error::MountPointBusy(storage.mount_point));
+            }
+            mounts.insert(storage.mount_point.clone(), MountState::Busy);
+        }
+
+        let res = storage.mount().await;
+        let mut mounts = self.mounts.lock().await;
+        mounts.remove(&storage.mount_point);
+        let handle = res?;
+        let mount_path = handle.mount_point.clone();
+        mounts.insert(mount_path.clone(), MountState::Mounted(handle));
+        Ok(mount_path)
+    }
+
+    async fn secure_umount(&self, mount_point: &str) -> Result<()> {
+        info!("secure umount called: {mount_point}");
+        let handle = {
+            let mut mounts = self.mounts.lock().await;
+            let state = mounts
+                .get_mut(mount_point)
+                .ok_or_else(|| Error::MountNotFound(mount_point.to_string()))?;
+            match std::mem::replace(state, MountState::Busy) {
+                MountState::Busy => return Err(Error::MountPointBusy(mount_point.to_string())),
+                MountState::Mounted(handle) => handle,
+            }
+        };
+
+        let res = Storage::umount(&handle).await;
+        let mut mounts = self.mounts.lock().await;
+        match res {
+            Ok(()) => {
+                mounts.remove(mount_point);
+                Ok(())
+            }
+            Err(e) => {
+                mounts.insert(mount_point.to_string(), MountState::Mounted(handle));
+                Err(e.into())
+            }
+        }
     }
 
-    async fn pull_image(&self, image_url: &str, bundle_path: &str) -> Result<String> {
-        let client = self
+    async fn pull_image(
+        &self,
+        image_url: &str,
+        bundle_path: &str,
+        platform: Option<&str>,
+    ) -> Result<String> {
+        let platform = platform
+            .map(str::parse::<Platform>)
+            .transpose()
+            .map_err(|e| Error::ImagePull { source: e })?;
+        let images = self
             .image_client
             .get_or_try_init(
                 || async move { initialize_image_client(self.config.image.clone()).await },
             )
             .await?;
-        let manifest_digest = client
+        let manifest_digest = images
+            .client
             .lock()
             .await
-            .pull_image(image_url, Path::new(bundle_path), &None, &None)
+            .pull_image_for_platform(
+                image_url,
+                Path::new(bundle_path),
+                &None,
+                &None,
+                platform.as_ref(),
+            )
             .await
             .map_err(|e| Error::ImagePull { source: e })?;
         Ok(manifest_digest)
     }
+
+    async fn remove_image(&self, image: &str) -> Result<()> {
+        let images = self
+            .image_client
+            .get_or_try_init(
+                || async move { initialize_image_client(self.config.image.clone()).await },
+            )
+            .await?;
+        images
+            .client
+            .lock()
+            .await
+            .remove_image(image)
+            .await
+            .map_err(|e| Error::ImageRemove { source: e })?;
+        Ok(())
+    }
+
+    async fn list_images(&self) -> Result<Vec<ImageMeta>> {
+        let images = self
+            .image_client
+            .get_or_try_init(
+                || async move { initialize_image_client(self.config.image.clone()).await },
+            )
+            .await?;
+        Ok(images.meta_store.read().await.images())
+    }
+
+    async fn inspect_image(&self, image: &str) -> Result<ImageMeta> {
+        let images = self
+            .image_client
+            .get_or_try_init(
+                || async move { initialize_image_client(self.config.image.clone()).await },
+            )
+            .await?;
+        let image = images
+            .meta_store
+            .read()
+            .await
+            .find_image(image)
+            .map_err(|e| Error::ImageInspect { source: e })?;
+        Ok(image)
+    }
 }
 
-async fn initialize_image_client(config: ImageConfig) -> Result<Mutex<ImageClient>> {
+async fn initialize_image_client(config: ImageConfig) -> Result<Images> {
     debug!("Image client lazy initializing...");
 
     let image_client = Into::<ClientBuilder>::into(config)
@@ -152,5 +327,55 @@ async fn initialize_image_client(config: ImageConfig) -> Result<Mutex<ImageClien
             Error::InitializationFailed(format!("failed to initialize image pull client :{e:?}"))
         })?;
 
-    Ok(Mutex::new(image_client))
+    Ok(Images {
+        meta_store: image_client.meta_store(),
+        client: Mutex::new(image_client),
+    })
+}
+
+/// Check the disk usage of the image work dir every `config.interval`, and
+/// once above the high-water mark, remove the images not used by any bundle
+/// anymore together with their layers.
+async fn image_gc(image_client: Arc<OnceCell<Images>>, work_dir: PathBuf, config: ImageGcConfig) {
+    let mut timer = tokio::time::interval(Duration::from_secs(config.interval));
+    loop {
+        timer.tick().await;
+
+        // Nothing pulled yet.
+        let Some(images) = image_client.get() else {
+            continue;
+        };
+
+        let usage = match disk_usage(&work_dir) {
+            Ok(usage) => usage,
+            Err(e) => {
+                warn!("Image GC: failed to get disk usage of {work_dir:?}: {e}");
+                continue;
+            }
+        };
+        if usage < u64::from(config.high_water_mark) {
+            continue;
+        }
+
+        info!(
+            "Image GC: disk usage {usage}% exceeds {}%",
+            config.high_water_mark
+        );
+        if let Err(e) = images.client.lock().await.prune_unused_images().await {
+            warn!("Image GC failed: {e:#}");
+        }
+    }
+}
+
+/// Used space of the filesystem of `path` in percent.
+fn disk_usage(path: &Path) -> nix::Result<u64> {
+    let stat = nix::sys::statvfs::statvfs(path)?;
+    let blocks = stat.blocks();
+    if blocks == 0 {
+        return Ok(0);
+    }
+
+    // The block counts are not `u64` on every platform.
+    let used = (blocks - stat.blocks_available().min(blocks)) as f64;
+    Ok((used * 100.0 / blocks as f64) as u64)
 }
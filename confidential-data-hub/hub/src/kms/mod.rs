@@ -3,11 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-pub mod api;
-pub use api::*;
+//! The api and the KMS plugins are defined in the `kms` crate. The KBS
+//! plugin and the dispatch by provider name stay in CDH.
 
-pub mod error;
-pub use error::*;
+pub use kms::*;
 
 pub mod plugins;
 pub use plugins::{new_decryptor, new_getter};
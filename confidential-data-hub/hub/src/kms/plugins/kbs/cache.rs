@@ -0,0 +1,239 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! An in-memory cache of KBS resources.
+//!
+//! The time-to-live of a resource is given by the first [`CacheTtlRule`]
+//! matching its repository and type, or the default TTL. The KBS can only
+//! shorten it with a [`CacheHint`]. The cached values are zeroized when they
+//! are evicted.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+pub use resource_uri::CacheHint;
+use resource_uri::ResourceUri;
+use zeroize::Zeroizing;
+
+/// TTL of the resources of a repository and/or a type. A `None` field
+/// matches any value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheTtlRule {
+    pub repository: Option<String>,
+    pub resource_type: Option<String>,
+    pub ttl: Duration,
+}
+
+impl CacheTtlRule {
+    fn matches(&self, uri: &ResourceUri) -> bool {
+        self.repository
+            .as_ref()
+            .map_or(true, |r| *r == uri.repository)
+            && self
+                .resource_type
+                .as_ref()
+                .map_or(true, |t| *t == uri.r#type)
+    }
+}
+
+/// Decides how long a resource is cached. A zero TTL disables caching.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CachePolicy {
+    pub default_ttl: Duration,
+    pub rules: Vec<CacheTtlRule>,
+}
+
+impl CachePolicy {
+    fn ttl_of(&self, uri: &ResourceUri, hint: Option<CacheHint>) -> Duration {
+        let ttl = self
+            .rules
+            .iter()
+            .find(|rule| rule.matches(uri))
+            .map_or(self.default_ttl, |rule| rule.ttl);
+
+        match hint {
+            Some(CacheHint::NoStore) => Duration::ZERO,
+            Some(CacheHint::MaxAge(max_age)) => ttl.min(max_age),
+            None => ttl,
+        }
+    }
+}
+
+/// Counters of the cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: u64,
+}
+
+struct Entry {
+    value: Zeroizing<Vec<u8>>,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+pub struct ResourceCache {
+    policy: CachePolicy,
+
+    /// Cached resources indexed by the whole resource URI.
+    entries: HashMap<String, Entry>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ResourceCache {
+    /// Replace the policy. Cached entries are dropped, as their TTL might
+    /// no longer be valid.
+    pub fn set_policy(&mut self, policy: CachePolicy) {
+        self.policy = policy;
+        self.entries.clear();
+    }
+
+    pub fn get(&mut self, uri: &ResourceUri) -> Option<Vec<u8>> {
+        let key = uri.whole_uri();
+        match self.entries.get(&key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                self.hits += 1;
+                Some(entry.value.to_vec())
+            }
+            Some(_) => {
+                self.entries.remove(&key);
+                self.misses += 1;
+                None
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(&mut self, uri: &ResourceUri, value: &[u8], hint: Option<CacheHint>) {
+        let now = Instant::now();
+        self.entries.retain(|_, entry| entry.expires_at > now);
+
+        let ttl = self.policy.ttl_of(uri, hint);
+        if ttl.is_zero() {
+            return;
+        }
+
+        self.entries.insert(
+            uri.whole_uri(),
+            Entry {
+                value: Zeroizing::new(value.to_vec()),
+                expires_at: now + ttl,
+            },
+        );
+    }
+
+    /// Drop the entries whose resource URI starts with `prefix`, or all the
+    /// entries if `prefix` is empty. Returns the number of dropped entries.
+    pub fn invalidate(&mut self, prefix: &str) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|key, _| !key.starts_with(prefix));
+        before - self.entries.len()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use resource_uri::ResourceUri;
+    use rstest::rstest;
+
+    use super::{CacheHint, CachePolicy, CacheStats, CacheTtlRule, ResourceCache};
+
+    fn policy() -> CachePolicy {
+        CachePolicy {
+            default_ttl: Duration::from_secs(60),
+            rules: vec![
+                CacheTtlRule {
+                    repository: Some("default".into()),
+                    resource_type: Some("token".into()),
+                    ttl: Duration::ZERO,
+                },
+                CacheTtlRule {
+                    repository: None,
+                    resource_type: Some("key".into()),
+                    ttl: Duration::from_secs(600),
+                },
+            ],
+        }
+    }
+
+    #[rstest]
+    #[case("kbs:///default/key/1", None, 600)]
+    #[case("kbs:///tenant/key/1", None, 600)]
+    #[case("kbs:///default/token/1", None, 0)]
+    #[case("kbs:///default/config/1", None, 60)]
+    #[case(
+        "kbs:///default/key/1",
+        Some(CacheHint::MaxAge(Duration::from_secs(5))),
+        5
+    )]
+    #[case(
+        "kbs:///default/config/1",
+        Some(CacheHint::MaxAge(Duration::from_secs(3600))),
+        60
+    )]
+    #[case("kbs:///default/key/1", Some(CacheHint::NoStore), 0)]
+    fn ttl(#[case] uri: &str, #[case] hint: Option<CacheHint>, #[case] expected: u64) {
+        let uri = ResourceUri::try_from(uri).unwrap();
+        assert_eq!(policy().ttl_of(&uri, hint), Duration::from_secs(expected));
+    }
+
+    #[test]
+    fn get_insert_invalidate() {
+        let mut cache = ResourceCache::default();
+        cache.set_policy(policy());
+        let key = ResourceUri::try_from("kbs:///default/key/1").unwrap();
+        let token = ResourceUri::try_from("kbs:///default/token/1").unwrap();
+
+        assert_eq!(cache.get(&key), None);
+        cache.insert(&key, b"key", None);
+        cache.insert(&token, b"token", None);
+        assert_eq!(cache.get(&key), Some(b"key".to_vec()));
+        assert_eq!(cache.get(&token), None);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 2,
+                entries: 1
+            }
+        );
+
+        assert_eq!(cache.invalidate("kbs:///default/config/"), 0);
+        assert_eq!(cache.invalidate("kbs:///default/key/"), 1);
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn expired_entries_are_evicted() {
+        let mut cache = ResourceCache::default();
+        cache.set_policy(CachePolicy {
+            default_ttl: Duration::from_millis(10),
+            rules: vec![],
+        });
+        let key = ResourceUri::try_from("kbs:///default/key/1").unwrap();
+        cache.insert(&key, b"key", None);
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
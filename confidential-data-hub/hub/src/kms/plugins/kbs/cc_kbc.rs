@@ -3,8 +3,6 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::env;
-
 use async_trait::async_trait;
 use kbs_protocol::{
     client::KbsClient as KbsProtocolClient,
@@ -13,7 +11,7 @@ use kbs_protocol::{
 };
 use log::{info, warn};
 
-use super::{Error, Result};
+use super::{CacheHint, Error, Result};
 
 use super::Kbc;
 
@@ -22,7 +20,7 @@ pub struct CcKbc {
 }
 
 impl CcKbc {
-    pub async fn new(kbs_host_url: &str) -> Result<Self> {
+    pub async fn new(kbs_host_url: &str, kbs_cert: Option<&str>) -> Result<Self> {
         let evidence_provider = AAEvidenceProvider::new().await.map_err(|e| {
             Error::KbsClientError(format!("create AA evidence provider failed: {e:?}"))
         })?;
@@ -31,13 +29,13 @@ impl CcKbc {
             kbs_host_url,
         );
 
-        let client = match env::var("KBS_CERT") {
-            Ok(cert_pem) => {
-                info!("Use KBS public key cert");
-                client.add_kbs_cert(&cert_pem)
+        let client = match kbs_cert {
+            Some(cert_pem) => {
+                info!("Use KBS public key cert for {kbs_host_url}");
+                client.add_kbs_cert(cert_pem)
             }
-            Err(e) => {
-                warn!("KBS_CERT get failed: {e:?}. Use no KBS public key certs.");
+            None => {
+                warn!("No KBS public key cert given for {kbs_host_url}.");
                 client
             }
         };
@@ -60,4 +58,14 @@ impl Kbc for CcKbc {
             .map_err(|e| Error::KbsClientError(format!("get resource failed: {e:?}")))?;
         Ok(secret)
     }
+
+    async fn get_resource_with_cache_hint(
+        &mut self,
+        rid: ResourceUri,
+    ) -> Result<(Vec<u8>, Option<CacheHint>)> {
+        self.client
+            .get_resource_with_cache_hint(rid)
+            .await
+            .map_err(|e| Error::KbsClientError(format!("get resource failed: {e:?}")))
+    }
 }
@@ -5,6 +5,9 @@
 
 //! Abstraction for KBCs as a KMS plugin.
 
+mod cache;
+pub use cache::{CacheHint, CachePolicy, CacheStats, CacheTtlRule};
+
 #[cfg(feature = "kbs")]
 mod cc_kbc;
 
@@ -13,16 +16,52 @@ mod sev;
 
 mod offline_fs;
 
-use std::{env, sync::Arc};
+use std::{
+    collections::HashMap,
+    env,
+    sync::{Arc, Mutex as StdMutex},
+};
 
 use async_trait::async_trait;
 use attestation_agent::config::aa_kbc_params::AaKbcParams;
 use lazy_static::lazy_static;
+use log::debug;
 pub use resource_uri::ResourceUri;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::kms::{Annotations, Error, Getter, Result};
 
+/// Parameters to connect to one KBS backend.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KbcParams {
+    /// The KBC type, one of `cc_kbc`, `online_sev_kbc` and `offline_fs_kbc`.
+    pub kbc: String,
+
+    /// The URL of the KBS.
+    pub url: String,
+
+    /// The public key cert of the KBS.
+    pub kbs_cert: Option<String>,
+}
+
+impl KbcParams {
+    /// Parameters of the default backend, given by the `AA_KBC_PARAMS` and
+    /// `KBS_CERT` envs.
+    fn from_env() -> Result<Self> {
+        let params = env::var("AA_KBC_PARAMS").map_err(|_| {
+            Error::KbsClientError("`AA_KBC_PARAMS` of the default KBS backend is not set".into())
+        })?;
+        let params = AaKbcParams::try_from(params)
+            .map_err(|e| Error::KbsClientError(format!("Failed to parse aa_kbc_params: {e:?}")))?;
+
+        Ok(Self {
+            kbc: params.kbc,
+            url: params.uri,
+            kbs_cert: env::var("KBS_CERT").ok(),
+        })
+    }
+}
+
 enum RealClient {
     #[cfg(feature = "kbs")]
     Cc(cc_kbc::CcKbc),
@@ -32,40 +71,154 @@ enum RealClient {
 }
 
 impl RealClient {
-    async fn new() -> Result<Self> {
-        let params = env::var("AA_KBC_PARAMS").expect("must be initialized");
-        let params = AaKbcParams::try_from(params)
-            .map_err(|e| Error::KbsClientError(format!("Failed to parse aa_kbc_params: {e:?}")))?;
-
+    async fn new(params: &KbcParams) -> Result<Self> {
         let c = match &params.kbc[..] {
             #[cfg(feature = "kbs")]
-            "cc_kbc" => RealClient::Cc(cc_kbc::CcKbc::new(&params.uri).await?),
+            "cc_kbc" => {
+                RealClient::Cc(cc_kbc::CcKbc::new(&params.url, params.kbs_cert.as_deref()).await?)
+            }
             #[cfg(feature = "sev")]
-            "online_sev_kbc" => RealClient::Sev(sev::OnlineSevKbc::new(&params.uri).await?),
+            "online_sev_kbc" => RealClient::Sev(sev::OnlineSevKbc::new(&params.url).await?),
             "offline_fs_kbc" => RealClient::OfflineFs(offline_fs::OfflineFsKbc::new().await?),
-            others => return Err(Error::KbsClientError(format!("unknown kbc name {others}, only support `cc_kbc`(feature `kbs`), `online_sev_kbc` (feature `sev`) and `offline_fs_kbc`."))),
+            others => {
+                return Err(Error::KbsClientError(format!(
+                    "unknown kbc name {others}, only support `cc_kbc`(feature `kbs`), \
+                     `online_sev_kbc` (feature `sev`) and `offline_fs_kbc`."
+                )))
+            }
         };
 
         Ok(c)
     }
+
+    async fn get_resource(&mut self, rid: ResourceUri) -> Result<(Vec<u8>, Option<CacheHint>)> {
+        match self {
+            #[cfg(feature = "kbs")]
+            RealClient::Cc(c) => c.get_resource_with_cache_hint(rid).await,
+            #[cfg(feature = "sev")]
+            RealClient::Sev(c) => c.get_resource_with_cache_hint(rid).await,
+            RealClient::OfflineFs(c) => c.get_resource_with_cache_hint(rid).await,
+        }
+    }
 }
 
+/// A lazily initialized KBC of one backend. Each backend has its own lock,
+/// so that requests to different backends do not wait for each other.
+type SharedClient = Arc<Mutex<Option<RealClient>>>;
+
+/// Index of the default backend in [`KBS_CLIENTS`].
+const DEFAULT_BACKEND: &str = "";
+
 lazy_static! {
-    static ref KBS_CLIENT: Arc<Mutex<Option<RealClient>>> = Arc::new(Mutex::new(None));
+    /// Parameters of the additional KBS backends, indexed by the authority
+    /// (`host[:port]`) of the `kbs://` URIs routed to them.
+    static ref KBS_BACKENDS: RwLock<HashMap<String, KbcParams>> = RwLock::new(HashMap::new());
+
+    /// KBCs indexed the same way as [`KBS_BACKENDS`]. The default backend is
+    /// indexed by [`DEFAULT_BACKEND`].
+    static ref KBS_CLIENTS: Mutex<HashMap<String, SharedClient>> = Mutex::new(HashMap::new());
+
+    /// Resources fetched from all the backends. Only held for short,
+    /// non-async critical sections.
+    static ref RESOURCE_CACHE: StdMutex<cache::ResourceCache> =
+        StdMutex::new(cache::ResourceCache::default());
+}
+
+/// Set the policy of the resource cache. Cached resources are dropped.
+pub fn set_cache_policy(policy: CachePolicy) {
+    RESOURCE_CACHE
+        .lock()
+        .expect("resource cache poisoned")
+        .set_policy(policy);
+}
+
+/// Drop the cached resources whose URI starts with `prefix`, or all of them
+/// if `prefix` is empty. Returns the number of dropped resources.
+pub fn invalidate_cache(prefix: &str) -> usize {
+    RESOURCE_CACHE
+        .lock()
+        .expect("resource cache poisoned")
+        .invalidate(prefix)
+}
+
+/// Hits, misses and size of the resource cache.
+pub fn cache_stats() -> CacheStats {
+    RESOURCE_CACHE
+        .lock()
+        .expect("resource cache poisoned")
+        .stats()
+}
+
+fn cached(uri: &ResourceUri) -> Option<Vec<u8>> {
+    RESOURCE_CACHE
+        .lock()
+        .expect("resource cache poisoned")
+        .get(uri)
+}
+
+/// Set the additional KBS backends, indexed by the authority (`host[:port]`)
+/// of the `kbs://` URIs to route to them. Resource URIs whose authority
+/// matches no backend go to the default backend given by `AA_KBC_PARAMS`.
+pub async fn set_backends(backends: HashMap<String, KbcParams>) {
+    let mut current = KBS_BACKENDS.write().await;
+    let mut clients = KBS_CLIENTS.lock().await;
+    clients.retain(|host, _| host == DEFAULT_BACKEND || current.get(host) == backends.get(host));
+    *current = backends;
+}
+
+/// Get the KBC of the backend that `kbs_addr` is routed to, initializing
+/// it on first use.
+async fn client_of(kbs_addr: &str) -> Result<SharedClient> {
+    let params = KBS_BACKENDS.read().await.get(kbs_addr).cloned();
+    let index = match params {
+        Some(_) => kbs_addr,
+        None => DEFAULT_BACKEND,
+    };
+    debug!("route kbs://{kbs_addr} to backend `{index}`");
+
+    let client = KBS_CLIENTS
+        .lock()
+        .await
+        .entry(index.to_string())
+        .or_default()
+        .clone();
+
+    {
+        let mut guard = client.lock().await;
+        if guard.is_none() {
+            let params = match params {
+                Some(params) => params,
+                None => KbcParams::from_env()?,
+            };
+            *guard = Some(RealClient::new(&params).await?);
+        }
+    }
+
+    Ok(client)
 }
 
 #[async_trait]
 pub trait Kbc: Send + Sync {
     async fn get_resource(&mut self, _rid: ResourceUri) -> Result<Vec<u8>>;
+
+    /// Get the resource together with the [`CacheHint`] of the KBS, if any.
+    async fn get_resource_with_cache_hint(
+        &mut self,
+        rid: ResourceUri,
+    ) -> Result<(Vec<u8>, Option<CacheHint>)> {
+        Ok((self.get_resource(rid).await?, None))
+    }
 }
 
 /// A fake KbcClient to carry the [`Getter`] semantics. The real `new()`
-/// and `get_resource()` will happen to the static variable [`KBS_CLIENT`].
+/// and `get_resource()` will happen to the static variable [`KBS_CLIENTS`].
 ///
 /// Why we use a static variable here is the initialization of kbc is not
 /// idempotent. For example online-sev-kbc will delete a file on local
 /// filesystem, so we should try to reuse the online-sev-kbc created at the
-/// first time.
+/// first time. Every backend keeps its own KBC, and thus its own RCAR
+/// session with the KBS. Resources are served from the resource cache
+/// when possible.
 pub struct KbcClient;
 
 #[async_trait]
@@ -73,35 +226,61 @@ impl Getter for KbcClient {
     async fn get_secret(&self, name: &str, _annotations: &Annotations) -> Result<Vec<u8>> {
         let resource_uri = ResourceUri::try_from(name)
             .map_err(|_| Error::KbsClientError(format!("illegal kbs resource uri: {name}")))?;
-        let real_client = KBS_CLIENT.clone();
-        let mut client = real_client.lock().await;
-
-        if client.is_none() {
-            let c = RealClient::new().await?;
-            *client = Some(c);
+        if let Some(resource) = cached(&resource_uri) {
+            debug!("resource cache hit: {name}");
+            return Ok(resource);
         }
 
-        let client = client.as_mut().expect("must be initialized");
+        let client = client_of(&resource_uri.kbs_addr).await?;
+        let mut client = client.lock().await;
 
-        match client {
-            #[cfg(feature = "kbs")]
-            RealClient::Cc(c) => c.get_resource(resource_uri).await,
-            #[cfg(feature = "sev")]
-            RealClient::Sev(c) => c.get_resource(resource_uri).await,
-            RealClient::OfflineFs(c) => c.get_resource(resource_uri).await,
+        // A concurrent request for the same resource may have fetched it
+        // while we were waiting for the client.
+        if let Some(resource) = cached(&resource_uri) {
+            debug!("resource cache hit after waiting for the client: {name}");
+            return Ok(resource);
         }
+
+        let (resource, hint) = client
+            .as_mut()
+            .expect("must be initialized")
+            .get_resource(resource_uri.clone())
+            .await?;
+
+        RESOURCE_CACHE
+            .lock()
+            .expect("resource cache poisoned")
+            .insert(&resource_uri, &resource, hint);
+        Ok(resource)
     }
 }
 
 impl KbcClient {
+    /// The KBC of each backend, including the default one, is created when
+    /// the first resource is fetched from it. Thus a config that defines only
+    /// named backends does not need `AA_KBC_PARAMS`.
     pub async fn new() -> Result<Self> {
-        let client = KBS_CLIENT.clone();
-        let mut client = client.lock().await;
-        if client.is_none() {
-            let c = RealClient::new().await?;
-            *client = Some(c);
-        }
-
         Ok(KbcClient {})
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::KbcClient;
+    use crate::kms::{Annotations, Error, Getter};
+
+    #[tokio::test]
+    #[serial]
+    async fn default_backend_without_aa_kbc_params() {
+        std::env::remove_var("AA_KBC_PARAMS");
+
+        // No KBC is created until a resource is fetched
+        let client = KbcClient::new().await.unwrap();
+        let res = client
+            .get_secret("kbs:///default/key/1", &Annotations::default())
+            .await;
+        assert!(matches!(res, Err(Error::KbsClientError(_))));
+    }
+}
@@ -9,15 +9,13 @@ use strum::{AsRefStr, EnumString};
 
 use super::{Decrypter, Error, Getter, ProviderSettings, Result};
 
-const _IN_GUEST_DEFAULT_KEY_PATH: &str = "/run/confidential-containers/cdh/kms-credential";
-
 #[cfg(feature = "aliyun")]
-pub mod aliyun;
+pub use kms::plugins::aliyun;
 
 pub mod kbs;
 
 #[cfg(feature = "ehsm")]
-pub mod ehsm;
+pub use kms::plugins::ehsm;
 
 #[derive(AsRefStr, EnumString)]
 pub enum DecryptorProvider {
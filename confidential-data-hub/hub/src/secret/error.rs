@@ -25,4 +25,16 @@ pub enum SecretError {
 
     #[error("parse SealedSecret failed: {0}")]
     ParseFailed(&'static str),
+
+    #[error("verify SealedSecret signature failed: {0}")]
+    SignatureVerificationFailed(String),
+
+    #[error("unsupported JWS algorithm {0}, only ES256, RS256 and EdDSA are supported")]
+    UnsupportedAlgorithm(String),
+
+    #[error("invalid signing or verification key: {0}")]
+    InvalidKey(String),
+
+    #[error("sign SealedSecret failed")]
+    SigningFailed,
 }
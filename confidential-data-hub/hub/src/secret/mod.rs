@@ -5,13 +5,17 @@
 
 pub mod error;
 pub mod layout;
+pub mod signature;
 
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD as b64, Engine};
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 
 use self::layout::{envelope::EnvelopeSecret, vault::VaultSecret};
 
 pub use error::{Result, SecretError};
+pub use signature::{SecretVerifier, SignaturePolicy, SigningKey};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -30,11 +34,24 @@ pub struct Secret {
 
 pub const VERSION: &str = "0.1.0";
 
+lazy_static! {
+    /// The verifier used by [`unseal_secret`]. It is set once CDH loads the
+    /// trusted keys from its config, see [`set_verifier`].
+    static ref SECRET_VERIFIER: RwLock<SecretVerifier> = RwLock::new(SecretVerifier::default());
+}
+
+/// Set the verifier that checks the signatures of all sealed secrets
+/// unsealed by [`unseal_secret`].
+pub async fn set_verifier(verifier: SecretVerifier) {
+    *SECRET_VERIFIER.write().await = verifier;
+}
+
+/// Verify the signature of the given sealed secret and unseal it.
 pub async fn unseal_secret(secret: &[u8]) -> Result<Vec<u8>> {
     let secret_string = String::from_utf8(secret.to_vec())
         .map_err(|_| SecretError::ParseFailed("Secret string must be UTF-8"))?;
 
-    let secret = Secret::from_signed_base64_string(secret_string)?;
+    let secret = SECRET_VERIFIER.read().await.verify(&secret_string).await?;
     secret.unseal().await
 }
 
@@ -50,28 +67,14 @@ impl Secret {
         }
     }
 
-    // TODO: check the signature
+    /// Parse the sealed secret string. Note that the signature is NOT checked
+    /// here, use [`SecretVerifier::verify`] to do that.
     pub fn from_signed_base64_string(secret: String) -> Result<Self> {
-        let sections: Vec<_> = secret.split('.').collect();
-
-        if sections.len() != 4 {
-            return Err(SecretError::ParseFailed("malformed input sealed secret"));
-        }
-
-        let secret_json = b64
-            .decode(sections[2])
-            .map_err(|_| SecretError::ParseFailed("base64 decode Secret body"))?;
-
-        let secret: Secret = serde_json::from_slice(&secret_json).map_err(|_| {
-            SecretError::ParseFailed(
-                "malformed input sealed secret format (json deserialization failed)",
-            )
-        })?;
-
-        Ok(secret)
+        Self::from_sealed_string_unverified(&secret)
     }
 
-    // TODO: add real signature generation
+    /// Serialize the secret with a dummy JWS header and signature. Use
+    /// [`Secret::sign`] to generate a sealed secret with a real signature.
     pub fn to_signed_base64_string(&self) -> Result<String> {
         let secret_json = serde_json::to_string(&self)
             .map_err(|_| SecretError::ParseFailed("JSON serialization failed"))?;
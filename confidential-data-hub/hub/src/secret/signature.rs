@@ -0,0 +1,608 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! JWS signing and verification of sealed secrets.
+//!
+//! A sealed secret is of the form
+//! `sealed`.`JWS header`.`JWS body (secret content)`.`signature`, where
+//! everything after the `sealed.` prefix is a JWS in compact serialization
+//! (<https://datatracker.ietf.org/doc/html/rfc7515#section-7.1>).
+//!
+//! Supported algorithms are `ES256`, `RS256` and `EdDSA` (Ed25519). Trusted
+//! public keys are given as JWKs or JWK sets
+//! (<https://datatracker.ietf.org/doc/html/rfc7517>).
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD as b64},
+    Engine,
+};
+use log::warn;
+use ring::{
+    rand::SystemRandom,
+    signature::{
+        self, EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, RsaPublicKeyComponents,
+        UnparsedPublicKey,
+    },
+};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+use tokio::sync::OnceCell;
+
+use super::{Result, Secret, SecretError};
+use crate::{
+    kms::{self, Annotations, ProviderSettings},
+    SealedSecretConfig,
+};
+
+/// The prefix of a sealed secret string.
+pub const SEALED_SECRET_PREFIX: &str = "sealed";
+
+/// JWS algorithms that can be used to sign a sealed secret.
+#[derive(Serialize, Deserialize, AsRefStr, EnumString, Clone, Copy, Debug, PartialEq)]
+pub enum SignatureAlgorithm {
+    ES256,
+    RS256,
+    EdDSA,
+}
+
+/// What CDH does when the signature of a sealed secret cannot be verified.
+#[derive(Deserialize, Default, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SignaturePolicy {
+    /// Refuse to unseal the secret.
+    #[default]
+    Reject,
+
+    /// Log a warning and unseal the secret anyway. This keeps sealed
+    /// secrets with dummy headers and signatures working.
+    Warn,
+}
+
+/// The protected header of the JWS.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct JwsHeader {
+    pub alg: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+/// A public JSON Web Key. Only the fields needed by the supported
+/// algorithms are parsed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Jwk {
+    pub kty: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+/// Parse a JWK or a JWK set (`{"keys": [...]}`) into a list of keys.
+pub fn parse_jwks(content: &[u8]) -> Result<Vec<Jwk>> {
+    if let Ok(set) = serde_json::from_slice::<JwkSet>(content) {
+        return Ok(set.keys);
+    }
+
+    let key = serde_json::from_slice::<Jwk>(content)
+        .map_err(|e| SecretError::InvalidKey(format!("neither a JWK nor a JWK set: {e}")))?;
+    Ok(vec![key])
+}
+
+fn decode_field(field: &Option<String>, name: &str) -> Result<Vec<u8>> {
+    let value = field
+        .as_ref()
+        .ok_or_else(|| SecretError::InvalidKey(format!("JWK field `{name}` is missing")))?;
+    b64.decode(value)
+        .map_err(|e| SecretError::InvalidKey(format!("JWK field `{name}` is not base64url: {e}")))
+}
+
+impl Jwk {
+    /// The algorithm this key can be used with.
+    fn algorithm(&self) -> Result<SignatureAlgorithm> {
+        match (&self.kty[..], self.crv.as_deref()) {
+            ("EC", Some("P-256")) => Ok(SignatureAlgorithm::ES256),
+            ("RSA", _) => Ok(SignatureAlgorithm::RS256),
+            ("OKP", Some("Ed25519")) => Ok(SignatureAlgorithm::EdDSA),
+            (kty, crv) => Err(SecretError::InvalidKey(format!(
+                "unsupported JWK type kty={kty}, crv={crv:?}"
+            ))),
+        }
+    }
+
+    fn verify(&self, message: &[u8], sig: &[u8]) -> Result<()> {
+        let res = match self.algorithm()? {
+            SignatureAlgorithm::ES256 => {
+                let mut point = vec![0x04];
+                point.extend(decode_field(&self.x, "x")?);
+                point.extend(decode_field(&self.y, "y")?);
+                UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, point)
+                    .verify(message, sig)
+            }
+            SignatureAlgorithm::RS256 => RsaPublicKeyComponents {
+                n: decode_field(&self.n, "n")?,
+                e: decode_field(&self.e, "e")?,
+            }
+            .verify(&signature::RSA_PKCS1_2048_8192_SHA256, message, sig),
+            SignatureAlgorithm::EdDSA => {
+                UnparsedPublicKey::new(&signature::ED25519, decode_field(&self.x, "x")?)
+                    .verify(message, sig)
+            }
+        };
+
+        res.map_err(|_| SecretError::SignatureVerificationFailed("bad signature".into()))
+    }
+}
+
+enum KeyPairInner {
+    Ecdsa(EcdsaKeyPair),
+    Rsa(RsaKeyPair),
+    Ed25519(Ed25519KeyPair),
+}
+
+/// A private key used by tooling to sign sealed secrets.
+pub struct SigningKey {
+    inner: KeyPairInner,
+    kid: Option<String>,
+}
+
+impl SigningKey {
+    /// Load a PKCS#8 private key, either PEM or DER encoded. The algorithm
+    /// is detected from the key type: P-256 keys sign with `ES256`, RSA keys
+    /// with `RS256` and Ed25519 keys with `EdDSA`.
+    pub fn from_pkcs8(key: &[u8], kid: Option<String>) -> Result<Self> {
+        let der = pem_to_der(key)?;
+        let rng = SystemRandom::new();
+
+        let inner = if let Ok(k) =
+            EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P256_SHA256_FIXED_SIGNING, &der, &rng)
+        {
+            KeyPairInner::Ecdsa(k)
+        } else if let Ok(k) = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der) {
+            KeyPairInner::Ed25519(k)
+        } else if let Ok(k) = RsaKeyPair::from_pkcs8(&der) {
+            KeyPairInner::Rsa(k)
+        } else {
+            return Err(SecretError::InvalidKey(
+                "signing key must be a PKCS#8 P-256, RSA or Ed25519 private key".into(),
+            ));
+        };
+
+        Ok(Self { inner, kid })
+    }
+
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        match self.inner {
+            KeyPairInner::Ecdsa(_) => SignatureAlgorithm::ES256,
+            KeyPairInner::Rsa(_) => SignatureAlgorithm::RS256,
+            KeyPairInner::Ed25519(_) => SignatureAlgorithm::EdDSA,
+        }
+    }
+
+    /// Export the public part of the key as a JWK, which can be put into
+    /// CDH's `[sealed_secret] trusted_keys`.
+    pub fn public_jwk(&self) -> Jwk {
+        let mut jwk = Jwk {
+            kty: String::new(),
+            kid: self.kid.clone(),
+            alg: Some(self.algorithm().as_ref().to_string()),
+            crv: None,
+            x: None,
+            y: None,
+            n: None,
+            e: None,
+        };
+
+        match &self.inner {
+            KeyPairInner::Ecdsa(k) => {
+                // uncompressed point: 0x04 || x || y
+                let point = k.public_key().as_ref();
+                jwk.kty = "EC".into();
+                jwk.crv = Some("P-256".into());
+                jwk.x = Some(b64.encode(&point[1..33]));
+                jwk.y = Some(b64.encode(&point[33..]));
+            }
+            KeyPairInner::Rsa(k) => {
+                let components = RsaPublicKeyComponents::<Vec<u8>>::from(k.public());
+                jwk.kty = "RSA".into();
+                jwk.n = Some(b64.encode(components.n));
+                jwk.e = Some(b64.encode(components.e));
+            }
+            KeyPairInner::Ed25519(k) => {
+                jwk.kty = "OKP".into();
+                jwk.crv = Some("Ed25519".into());
+                jwk.x = Some(b64.encode(k.public_key().as_ref()));
+            }
+        }
+
+        jwk
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let rng = SystemRandom::new();
+        match &self.inner {
+            KeyPairInner::Ecdsa(k) => k
+                .sign(&rng, message)
+                .map(|s| s.as_ref().to_vec())
+                .map_err(|_| SecretError::SigningFailed),
+            KeyPairInner::Rsa(k) => {
+                let mut sig = vec![0; k.public().modulus_len()];
+                k.sign(&signature::RSA_PKCS1_SHA256, &rng, message, &mut sig)
+                    .map_err(|_| SecretError::SigningFailed)?;
+                Ok(sig)
+            }
+            KeyPairInner::Ed25519(k) => Ok(k.sign(message).as_ref().to_vec()),
+        }
+    }
+}
+
+/// Decode a PEM document into DER. Input that is not PEM is returned as-is.
+fn pem_to_der(input: &[u8]) -> Result<Vec<u8>> {
+    let Ok(text) = std::str::from_utf8(input) else {
+        return Ok(input.to_vec());
+    };
+
+    if !text.trim_start().starts_with("-----BEGIN") {
+        return Ok(input.to_vec());
+    }
+
+    let body: String = text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with("-----"))
+        .collect();
+    STANDARD
+        .decode(body)
+        .map_err(|e| SecretError::InvalidKey(format!("malformed PEM: {e}")))
+}
+
+/// The sections of a sealed secret string.
+struct SealedSections<'a> {
+    header: &'a str,
+    payload: &'a str,
+    signature: &'a str,
+}
+
+impl<'a> SealedSections<'a> {
+    fn parse(secret: &'a str) -> Result<Self> {
+        let sections: Vec<_> = secret.trim().split('.').collect();
+
+        if sections.len() != 4 || sections[0] != SEALED_SECRET_PREFIX {
+            return Err(SecretError::ParseFailed("malformed input sealed secret"));
+        }
+
+        Ok(Self {
+            header: sections[1],
+            payload: sections[2],
+            signature: sections[3],
+        })
+    }
+
+    fn signing_input(&self) -> String {
+        format!("{}.{}", self.header, self.payload)
+    }
+
+    fn secret(&self) -> Result<Secret> {
+        let secret_json = b64
+            .decode(self.payload)
+            .map_err(|_| SecretError::ParseFailed("base64 decode Secret body"))?;
+
+        serde_json::from_slice(&secret_json).map_err(|_| {
+            SecretError::ParseFailed(
+                "malformed input sealed secret format (json deserialization failed)",
+            )
+        })
+    }
+}
+
+/// Verifies the JWS signature of sealed secrets against a set of trusted
+/// public keys.
+#[derive(Clone, Debug, Default)]
+pub struct SecretVerifier {
+    policy: SignaturePolicy,
+
+    /// KBS resource URIs or local paths of the trusted keys, which are
+    /// loaded on the first verification.
+    key_sources: Vec<String>,
+
+    keys: OnceCell<Vec<Jwk>>,
+}
+
+impl SecretVerifier {
+    pub fn new(policy: SignaturePolicy, keys: Vec<Jwk>) -> Self {
+        Self {
+            policy,
+            key_sources: Vec::new(),
+            keys: OnceCell::new_with(Some(keys)),
+        }
+    }
+
+    /// Create a verifier from CDH's `[sealed_secret]` config. The trusted
+    /// keys are loaded either from KBS or from the local filesystem when the
+    /// first sealed secret is verified, thus CDH can start without KBS.
+    pub fn from_config(config: &SealedSecretConfig) -> Self {
+        if config.trusted_keys.is_empty() && config.signature_policy == SignaturePolicy::Reject {
+            warn!("no trusted key is configured for sealed secrets, all of them will be rejected");
+        }
+
+        Self {
+            policy: config.signature_policy,
+            key_sources: config.trusted_keys.clone(),
+            keys: OnceCell::new(),
+        }
+    }
+
+    async fn load_keys(&self) -> Result<Vec<Jwk>> {
+        let mut keys = Vec::new();
+        for uri in &self.key_sources {
+            let content = if uri.starts_with("kbs://") {
+                kms::new_getter("kbs", ProviderSettings::default())
+                    .await
+                    .map_err(|e| SecretError::InvalidKey(format!("create kbs client: {e}")))?
+                    .get_secret(uri, &Annotations::default())
+                    .await
+                    .map_err(|e| SecretError::InvalidKey(format!("get {uri} from kbs: {e}")))?
+            } else {
+                tokio::fs::read(uri)
+                    .await
+                    .map_err(|e| SecretError::InvalidKey(format!("read {uri}: {e}")))?
+            };
+
+            keys.extend(parse_jwks(&content)?);
+        }
+
+        Ok(keys)
+    }
+
+    /// Check the signature of the given sealed secret string and parse the
+    /// secret. Whether a failed check is fatal is due to the [`SignaturePolicy`].
+    /// If the trusted keys cannot be loaded, the check fails and loading is
+    /// retried on the next call.
+    pub async fn verify(&self, secret: &str) -> Result<Secret> {
+        let sections = SealedSections::parse(secret)?;
+
+        let checked = match self.keys.get_or_try_init(|| self.load_keys()).await {
+            Ok(keys) => check_signature(keys, &sections),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = checked {
+            match self.policy {
+                SignaturePolicy::Reject => return Err(e),
+                SignaturePolicy::Warn => {
+                    warn!("sealed secret signature is not verified ({e}), unseal it anyway due to `warn` policy")
+                }
+            }
+        }
+
+        sections.secret()
+    }
+}
+
+fn check_signature(keys: &[Jwk], sections: &SealedSections) -> Result<()> {
+    let header = b64.decode(sections.header).map_err(|_| {
+        SecretError::SignatureVerificationFailed("JWS header is not base64url".into())
+    })?;
+    let header: JwsHeader = serde_json::from_slice(&header).map_err(|_| {
+        SecretError::SignatureVerificationFailed("JWS header is not a JSON object".into())
+    })?;
+
+    let alg: SignatureAlgorithm = header
+        .alg
+        .parse()
+        .map_err(|_| SecretError::UnsupportedAlgorithm(header.alg.clone()))?;
+
+    let sig = b64.decode(sections.signature).map_err(|_| {
+        SecretError::SignatureVerificationFailed("JWS signature is not base64url".into())
+    })?;
+    let message = sections.signing_input();
+
+    let mut candidates = keys
+        .iter()
+        .filter(|k| header.kid.is_none() || k.kid == header.kid)
+        .filter(|k| k.algorithm().ok() == Some(alg))
+        .peekable();
+
+    if candidates.peek().is_none() {
+        return Err(SecretError::SignatureVerificationFailed(format!(
+            "no trusted key for alg {} and kid {:?}",
+            header.alg, header.kid
+        )));
+    }
+
+    if candidates.any(|k| k.verify(message.as_bytes(), &sig).is_ok()) {
+        return Ok(());
+    }
+
+    Err(SecretError::SignatureVerificationFailed(
+        "signature does not match any trusted key".into(),
+    ))
+}
+
+impl Secret {
+    /// Serialize the secret and sign it with the given key. The output is
+    /// a sealed secret string which can be verified by [`SecretVerifier`].
+    pub fn sign(&self, key: &SigningKey) -> Result<String> {
+        let header = JwsHeader {
+            alg: key.algorithm().as_ref().to_string(),
+            kid: key.kid.clone(),
+        };
+        let header = serde_json::to_vec(&header)
+            .map_err(|_| SecretError::ParseFailed("JSON serialization failed"))?;
+        let payload = serde_json::to_vec(&self)
+            .map_err(|_| SecretError::ParseFailed("JSON serialization failed"))?;
+
+        let signing_input = format!("{}.{}", b64.encode(header), b64.encode(payload));
+        let sig = key.sign(signing_input.as_bytes())?;
+
+        Ok(format!(
+            "{SEALED_SECRET_PREFIX}.{signing_input}.{}",
+            b64.encode(sig)
+        ))
+    }
+
+    /// Parse a sealed secret string without checking its signature.
+    pub(crate) fn from_sealed_string_unverified(secret: &str) -> Result<Self> {
+        SealedSections::parse(secret)?.secret()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::{
+        rand::SystemRandom,
+        signature::{EcdsaKeyPair, Ed25519KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING},
+    };
+    use rstest::rstest;
+
+    use crate::secret::{
+        layout::vault::{Annotations, ProviderSettings, VaultSecret},
+        Secret, SecretContent, SecretError,
+    };
+
+    use super::{SecretVerifier, SignaturePolicy, SigningKey};
+    use crate::SealedSecretConfig;
+
+    fn secret() -> Secret {
+        Secret {
+            version: "0.1.0".into(),
+            r#type: SecretContent::Vault(VaultSecret {
+                provider: "kbs".into(),
+                provider_settings: ProviderSettings::default(),
+                annotations: Annotations::default(),
+                name: "kbs:///one/2/trois".into(),
+            }),
+        }
+    }
+
+    /// Two different keys of the given algorithm. `ring` cannot generate RSA
+    /// keys, so the RSA ones are loaded from test fixtures.
+    fn generate_keys(alg: &str) -> [SigningKey; 2] {
+        let rng = SystemRandom::new();
+        let pkcs8 = |i: usize| match alg {
+            "ES256" => EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng)
+                .unwrap()
+                .as_ref()
+                .to_vec(),
+            "EdDSA" => Ed25519KeyPair::generate_pkcs8(&rng)
+                .unwrap()
+                .as_ref()
+                .to_vec(),
+            "RS256" if i == 0 => include_bytes!("./tests/rsa-1.pem").to_vec(),
+            "RS256" => include_bytes!("./tests/rsa-2.pem").to_vec(),
+            _ => unreachable!(),
+        };
+        [0, 1].map(|i| SigningKey::from_pkcs8(&pkcs8(i), Some("key-1".into())).unwrap())
+    }
+
+    #[rstest]
+    #[case("ES256")]
+    #[case("RS256")]
+    #[case("EdDSA")]
+    #[tokio::test]
+    async fn sign_and_verify(#[case] alg: &str) {
+        let [key, other] = generate_keys(alg);
+        assert_eq!(key.algorithm().as_ref(), alg);
+
+        let sealed = secret().sign(&key).unwrap();
+        let verifier = SecretVerifier::new(SignaturePolicy::Reject, vec![key.public_jwk()]);
+        assert_eq!(verifier.verify(&sealed).await.unwrap(), secret());
+
+        // A secret signed by another key must be rejected
+        let verifier = SecretVerifier::new(SignaturePolicy::Reject, vec![other.public_jwk()]);
+        assert!(matches!(
+            verifier.verify(&sealed).await,
+            Err(SecretError::SignatureVerificationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn tampered_payload() {
+        let [key, _] = generate_keys("ES256");
+        let sealed = secret().sign(&key).unwrap();
+
+        let mut forged = secret();
+        let SecretContent::Vault(ref mut v) = forged.r#type else {
+            unreachable!()
+        };
+        v.provider = "aliyun".into();
+        let forged_payload = forged
+            .to_signed_base64_string()
+            .unwrap()
+            .split('.')
+            .nth(2)
+            .unwrap()
+            .to_string();
+
+        let mut sections: Vec<_> = sealed.split('.').collect();
+        sections[2] = &forged_payload;
+        let forged = sections.join(".");
+
+        let verifier = SecretVerifier::new(SignaturePolicy::Reject, vec![key.public_jwk()]);
+        assert!(verifier.verify(&forged).await.is_err());
+    }
+
+    #[rstest]
+    #[case(SignaturePolicy::Reject, false)]
+    #[case(SignaturePolicy::Warn, true)]
+    #[tokio::test]
+    async fn unsigned_secret(#[case] policy: SignaturePolicy, #[case] accepted: bool) {
+        let [key, _] = generate_keys("EdDSA");
+        let unsigned = secret().to_signed_base64_string().unwrap();
+        let verifier = SecretVerifier::new(policy, vec![key.public_jwk()]);
+        assert_eq!(verifier.verify(&unsigned).await.is_ok(), accepted);
+    }
+
+    #[tokio::test]
+    async fn default_config_rejects_unsigned_secret() {
+        let verifier = SecretVerifier::from_config(&SealedSecretConfig::default());
+        let unsigned = secret().to_signed_base64_string().unwrap();
+        assert!(verifier.verify(&unsigned).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn trusted_keys_are_loaded_lazily() {
+        let [key, _] = generate_keys("ES256");
+        let sealed = secret().sign(&key).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pubkey.jwk");
+        let verifier = SecretVerifier::from_config(&SealedSecretConfig {
+            signature_policy: SignaturePolicy::Reject,
+            trusted_keys: vec![path.to_string_lossy().to_string()],
+        });
+
+        // The key cannot be loaded yet, which is retried by the next call
+        assert!(matches!(
+            verifier.verify(&sealed).await,
+            Err(SecretError::InvalidKey(_))
+        ));
+
+        std::fs::write(&path, serde_json::to_vec(&key.public_jwk()).unwrap()).unwrap();
+        assert_eq!(verifier.verify(&sealed).await.unwrap(), secret());
+    }
+}
@@ -21,6 +21,9 @@ pub enum AliyunError {
     #[error("Failed to mount oss")]
     OssfsMountFailed,
 
+    #[error("Failed to unmount oss")]
+    OssfsUmountFailed,
+
     #[error("Serialize/Deserialize failed")]
     SerdeError(#[from] serde_json::Error),
 
@@ -22,7 +22,7 @@ use tokio::{
 use crate::secret;
 use error::{AliyunError, Result};
 
-use super::SecureMount;
+use super::{scrub_path, umount_path, MountHandle, SecureMount};
 
 /// Name of the file that contains ossfs password
 const OSSFS_PASSWD_FILE: &str = "ossfs_passwd";
@@ -97,7 +97,7 @@ impl Oss {
         options: &HashMap<String, String>,
         _flags: &[String],
         mount_point: &str,
-    ) -> Result<()> {
+    ) -> Result<MountHandle> {
         // construct OssParameters
         let parameters = serde_json::to_string(options)?;
 
@@ -109,6 +109,10 @@ impl Oss {
 
         // create temp directory to store metadata for this mount operation
         let tempdir = tempfile::tempdir()?;
+        let mut handle = MountHandle {
+            mount_point: mount_point.to_string(),
+            ..Default::default()
+        };
 
         // create ossfs passwd file
         let mut ossfs_passwd_path = tempdir.path().to_owned();
@@ -139,6 +143,7 @@ impl Oss {
 
         if oss_parameter.encrypted == "gocryptfs" {
             let gocryptfs_dir = create_random_dir().await?;
+            handle.inner_mount_points.push(gocryptfs_dir.clone());
 
             let mut parameters = vec![
                 format!("{}:{}", oss_parameter.bucket, oss_parameter.path),
@@ -241,6 +246,28 @@ impl Oss {
             }
         };
 
+        // ossfs and gocryptfs have read the password files, so scrub them
+        // right away rather than keeping them until the mount is torn down.
+        scrub_path(tempdir.path()).await?;
+        drop(tempdir);
+        Ok(handle)
+    }
+
+    async fn real_umount(&self, handle: &MountHandle) -> Result<()> {
+        umount_path(&handle.mount_point).await.map_err(|e| {
+            error!("umount {} failed: {e:?}", handle.mount_point);
+            AliyunError::OssfsUmountFailed
+        })?;
+
+        // the ossfs mount beneath the gocryptfs mount
+        for inner in &handle.inner_mount_points {
+            umount_path(inner).await.map_err(|e| {
+                error!("umount {inner} failed: {e:?}");
+                AliyunError::OssfsUmountFailed
+            })?;
+            fs::remove_dir(inner).await?;
+        }
+
         Ok(())
     }
 }
@@ -260,9 +287,14 @@ impl SecureMount for Oss {
         options: &HashMap<String, String>,
         flags: &[String],
         mount_point: &str,
-    ) -> super::Result<()> {
+    ) -> super::Result<MountHandle> {
         self.real_mount(options, flags, mount_point)
             .await
             .map_err(|e| e.into())
     }
+
+    /// Unmount the gocryptfs (if any) and the ossfs FUSE mounts.
+    async fn umount(&self, handle: &MountHandle) -> super::Result<()> {
+        self.real_umount(handle).await.map_err(|e| e.into())
+    }
 }
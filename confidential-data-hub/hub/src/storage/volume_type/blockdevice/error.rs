@@ -21,9 +21,44 @@ pub enum BlockDeviceError {
     #[error("Failed to mount block device")]
     BlockDeviceMountFailed,
 
+    #[error("Failed to unmount block device")]
+    BlockDeviceUmountFailed,
+
     #[error("Serialize/Deserialize failed")]
     SerdeError(#[from] serde_json::Error),
 
     #[error("Failed to recognize the storage type")]
     StorageTypeNotRecognized(#[from] strum::ParseError),
+
+    #[error("Block device {0} not found")]
+    DeviceNotFound(String),
+
+    #[error("Invalid LUKS2 header: {0}")]
+    InvalidLuksHeader(String),
+
+    #[error("LUKS2 header checksum mismatch")]
+    LuksHeaderChecksumMismatch,
+
+    #[error("Unsupported LUKS2 parameter: {0}")]
+    UnsupportedLuksParameter(String),
+
+    #[error("No LUKS2 keyslot can be unlocked with the given key")]
+    LuksKeyslotUnlockFailed,
+
+    #[error("Failed to format LUKS2 device: {0}")]
+    LuksFormatFailed(String),
+
+    #[error("Device-mapper operation failed: {0}")]
+    DeviceMapper(String),
+
+    #[error("Failed to create filesystem: {0}")]
+    MkfsFailed(String),
+
+    #[error("Failed to mount {device} to {mount_point}")]
+    MountFailed {
+        device: String,
+        mount_point: String,
+        #[source]
+        source: nix::Error,
+    },
 }
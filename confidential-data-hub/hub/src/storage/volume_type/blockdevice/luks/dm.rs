@@ -0,0 +1,310 @@
+// Copyright (c) 2024 Intel
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Activation of an unlocked LUKS2 data segment through device-mapper.
+//!
+//! Without integrity a single `crypt` target named `<name>` is created on top
+//! of the raw device. With integrity a `<name>_dif` `integrity` target is
+//! stacked between the raw device and the `crypt` target, the same layout
+//! cryptsetup uses.
+
+use std::{fs::File, os::fd::AsRawFd, os::unix::fs::FileExt, path::PathBuf};
+
+use devicemapper::{DevId, Device, DmName, DmOptions, DM};
+use log::{debug, warn};
+use nix::{
+    fcntl::{posix_fadvise, PosixFadviseAdvice},
+    sys::stat::{makedev, mknod, Mode, SFlag},
+};
+use zeroize::Zeroizing;
+
+use super::super::error::{BlockDeviceError, Result};
+use super::luks2::Segment;
+
+const SECTOR_SIZE: u64 = 512;
+
+/// Magic of the dm-integrity superblock.
+const INTEGRITY_SB_MAGIC: &[u8; 8] = b"integrt\0";
+
+/// Length of the temporary integrity device used to let the kernel format
+/// the integrity superblock, see [`provided_data_sectors`].
+const INTEGRITY_FORMAT_SECTORS: u64 = 8;
+
+fn dm_error(e: impl std::fmt::Display) -> BlockDeviceError {
+    BlockDeviceError::DeviceMapper(e.to_string())
+}
+
+/// The path of the activated device `name`.
+pub(crate) fn mapper_path(name: &str) -> PathBuf {
+    PathBuf::from("/dev/mapper").join(name)
+}
+
+fn integrity_name(name: &str) -> String {
+    format!("{name}_dif")
+}
+
+/// Translate the LUKS2 segment encryption into a dm-crypt cipher
+/// specification and the size of the per-sector authentication tag.
+fn cipher_spec(segment: &Segment) -> Result<(String, Option<usize>)> {
+    let mut parts = segment.encryption.splitn(3, '-');
+    let (Some(cipher), Some(mode), Some(iv)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+            "segment encryption {}",
+            segment.encryption
+        )));
+    };
+
+    let Some(integrity) = &segment.integrity else {
+        return Ok((segment.encryption.clone(), None));
+    };
+
+    if integrity.integrity_type != "hmac(sha256)" {
+        return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+            "integrity {}",
+            integrity.integrity_type
+        )));
+    }
+
+    // The tag holds the MAC, followed by the IV when it is random.
+    let tag_size = match iv {
+        "random" => 32 + 16,
+        _ => 32,
+    };
+    Ok((
+        format!("capi:authenc(hmac(sha256),{mode}({cipher}))-{iv}"),
+        Some(tag_size),
+    ))
+}
+
+/// Create the device `name` with a single-target table and resume it.
+fn create(dm: &DM, name: &str, table: (u64, String, String)) -> Result<Device> {
+    let (length, target, params) = table;
+    let dm_name = DmName::new(name).map_err(dm_error)?;
+    let id = DevId::Name(dm_name);
+
+    let info = dm
+        .device_create(dm_name, None, DmOptions::default())
+        .map_err(dm_error)?;
+    let table = [(0, length, target, params)];
+    if let Err(e) = dm
+        .table_load(&id, &table, DmOptions::default())
+        .and_then(|_| dm.device_suspend(&id, DmOptions::default()))
+    {
+        let _ = dm.device_remove(&id, DmOptions::default());
+        return Err(dm_error(e));
+    }
+
+    Ok(info.device())
+}
+
+/// Create the device node if there is no udev to do so.
+fn ensure_node(name: &str, device: Device) -> Result<()> {
+    let path = mapper_path(name);
+    if path.exists() {
+        return Ok(());
+    }
+
+    debug!("creating device node {}", path.display());
+    std::fs::create_dir_all("/dev/mapper")?;
+    mknod(
+        &path,
+        SFlag::S_IFBLK,
+        Mode::from_bits_truncate(0o600),
+        makedev(device.major.into(), device.minor.into()),
+    )
+    .map_err(|e| BlockDeviceError::DeviceMapper(format!("mknod {}: {e}", path.display())))
+}
+
+fn remove(dm: &DM, name: &str) -> Result<()> {
+    let dm_name = DmName::new(name).map_err(dm_error)?;
+    dm.device_remove(&DevId::Name(dm_name), DmOptions::default())
+        .map_err(dm_error)?;
+
+    match std::fs::remove_file(mapper_path(name)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            warn!("failed to remove device node of {name}: {e}")
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Let the kernel format the dm-integrity superblock through a temporary
+/// device and read the number of sectors it can provide.
+fn provided_data_sectors(
+    dm: &DM,
+    name: &str,
+    raw: &File,
+    offset: u64,
+    integrity_params: &str,
+) -> Result<u64> {
+    create(
+        dm,
+        name,
+        (
+            INTEGRITY_FORMAT_SECTORS,
+            "integrity".into(),
+            integrity_params.into(),
+        ),
+    )?;
+    remove(dm, name)?;
+
+    // The superblock was written by the kernel beneath the page cache of the
+    // raw device, so drop any stale cached copy first.
+    posix_fadvise(
+        raw.as_raw_fd(),
+        offset as i64,
+        4096,
+        PosixFadviseAdvice::POSIX_FADV_DONTNEED,
+    )
+    .map_err(|e| BlockDeviceError::DeviceMapper(format!("fadvise: {e}")))?;
+
+    let mut superblock = [0u8; 24];
+    raw.read_exact_at(&mut superblock, offset)?;
+    if &superblock[..8] != INTEGRITY_SB_MAGIC {
+        return Err(BlockDeviceError::DeviceMapper(
+            "dm-integrity superblock was not formatted".into(),
+        ));
+    }
+
+    Ok(u64::from_le_bytes(
+        superblock[16..24].try_into().expect("8 bytes"),
+    ))
+}
+
+/// Activate the data segment of the device `device_id` ("MAJ:MIN", opened
+/// as `raw`) as `/dev/mapper/<name>`. Returns the names of the created
+/// devices, the topmost first.
+pub(crate) fn activate(
+    name: &str,
+    device_id: &str,
+    raw: &File,
+    device_size: u64,
+    segment: &Segment,
+    volume_key: &[u8],
+) -> Result<Vec<String>> {
+    let dm = DM::new().map_err(dm_error)?;
+    let (cipher, tag_size) = cipher_spec(segment)?;
+    let key = Zeroizing::new(hex::encode(volume_key));
+    let offset_sectors = segment.offset / SECTOR_SIZE;
+    let block_sectors = segment.sector_size as u64 / SECTOR_SIZE;
+    let sector_option = match segment.sector_size {
+        512 => vec![],
+        size => vec![format!("sector_size:{size}")],
+    };
+
+    let Some(tag_size) = tag_size else {
+        let length = (device_size.saturating_sub(segment.offset) / SECTOR_SIZE) / block_sectors
+            * block_sectors;
+        if length == 0 {
+            return Err(BlockDeviceError::DeviceMapper(
+                "no space left for the data segment".into(),
+            ));
+        }
+
+        // crypt parameters: <cipher> <key> <iv_offset> <device> <offset> [<#opt> <opts>]
+        let mut params = format!(
+            "{cipher} {} {} {device_id} {offset_sectors}",
+            key.as_str(),
+            segment.iv_tweak
+        );
+        if !sector_option.is_empty() {
+            params.push_str(&format!(" 1 {}", sector_option.join(" ")));
+        }
+        let device = create(&dm, name, (length, "crypt".into(), params))?;
+        ensure_node(name, device)?;
+        return Ok(vec![name.to_string()]);
+    };
+
+    // integrity parameters: <device> <offset> <tag_size> <mode> <#opt> <opts>
+    let dif_name = integrity_name(name);
+    let integrity_params = format!(
+        "{device_id} {offset_sectors} {tag_size} J 1 block_size:{}",
+        segment.sector_size
+    );
+    let length = provided_data_sectors(&dm, &dif_name, raw, segment.offset, &integrity_params)?;
+    let dif = create(
+        &dm,
+        &dif_name,
+        (length, "integrity".into(), integrity_params),
+    )?;
+
+    let mut options = vec![format!("integrity:{tag_size}:aead")];
+    options.extend(sector_option);
+    let params = format!(
+        "{cipher} {} {} {dif} 0 {} {}",
+        key.as_str(),
+        segment.iv_tweak,
+        options.len(),
+        options.join(" ")
+    );
+    let device = match create(&dm, name, (length, "crypt".into(), params)) {
+        Ok(device) => device,
+        Err(e) => {
+            let _ = remove(&dm, &dif_name);
+            return Err(e);
+        }
+    };
+    ensure_node(name, device)?;
+
+    Ok(vec![name.to_string(), dif_name])
+}
+
+/// Remove the devices created by [`activate`], the topmost first.
+pub(crate) fn deactivate(names: &[String]) -> Result<()> {
+    let dm = DM::new().map_err(dm_error)?;
+    for name in names {
+        remove(&dm, name)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::cipher_spec;
+    use crate::storage::volume_type::blockdevice::luks::luks2::{Segment, SegmentIntegrity};
+
+    #[rstest]
+    #[case("aes-xts-plain64", None, "aes-xts-plain64", None)]
+    #[case(
+        "aes-xts-random",
+        Some("hmac(sha256)"),
+        "capi:authenc(hmac(sha256),xts(aes))-random",
+        Some(48)
+    )]
+    #[case(
+        "aes-xts-plain64",
+        Some("hmac(sha256)"),
+        "capi:authenc(hmac(sha256),xts(aes))-plain64",
+        Some(32)
+    )]
+    fn dm_crypt_cipher(
+        #[case] encryption: &str,
+        #[case] integrity: Option<&str>,
+        #[case] expected: &str,
+        #[case] tag_size: Option<usize>,
+    ) {
+        let segment = Segment {
+            segment_type: "crypt".into(),
+            offset: 16 * 1024 * 1024,
+            size: "dynamic".into(),
+            iv_tweak: 0,
+            encryption: encryption.into(),
+            sector_size: 4096,
+            integrity: integrity.map(|t| SegmentIntegrity {
+                integrity_type: t.into(),
+                journal_encryption: "none".into(),
+                journal_integrity: "none".into(),
+            }),
+        };
+
+        let (cipher, tag) = cipher_spec(&segment).unwrap();
+        assert_eq!(cipher, expected);
+        assert_eq!(tag, tag_size);
+    }
+}
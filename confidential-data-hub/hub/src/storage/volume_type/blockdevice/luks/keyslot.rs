@@ -0,0 +1,216 @@
+// Copyright (c) 2024 Intel
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Cryptographic primitives used by LUKS2 keyslots: the key derivation
+//! functions, the anti-forensic splitter and the `aes-xts-plain64`
+//! encryption of the keyslot area.
+//!
+//! See <https://gitlab.com/cryptsetup/LUKS2-docs> for the specification.
+
+use aes::{cipher::KeyInit, Aes128, Aes256};
+use argon2::{Algorithm, Argon2, Params, Version};
+use pbkdf2::pbkdf2_hmac;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use xts_mode::{get_tweak_default, Xts128};
+use zeroize::Zeroizing;
+
+use super::super::error::{BlockDeviceError, Result};
+use super::luks2::Kdf;
+
+/// The sector size used to encrypt keyslot areas.
+pub(crate) const KEYSLOT_SECTOR_SIZE: usize = 512;
+
+/// Derive a key of `key_len` bytes from the passphrase due to the keyslot's
+/// KDF parameters.
+pub(crate) fn derive_key(
+    kdf: &Kdf,
+    passphrase: &[u8],
+    key_len: usize,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let mut key = Zeroizing::new(vec![0u8; key_len]);
+    match kdf {
+        Kdf::Pbkdf2 {
+            hash,
+            iterations,
+            salt,
+        } => {
+            check_hash(hash)?;
+            pbkdf2_hmac::<Sha256>(passphrase, salt, *iterations, &mut key);
+        }
+        Kdf::Argon2i {
+            time,
+            memory,
+            cpus,
+            salt,
+        }
+        | Kdf::Argon2id {
+            time,
+            memory,
+            cpus,
+            salt,
+        } => {
+            let algorithm = match kdf {
+                Kdf::Argon2i { .. } => Algorithm::Argon2i,
+                _ => Algorithm::Argon2id,
+            };
+            let params = Params::new(*memory, *time, *cpus, Some(key_len))
+                .map_err(|e| BlockDeviceError::InvalidLuksHeader(format!("argon2 params: {e}")))?;
+            Argon2::new(algorithm, Version::V0x13, params)
+                .hash_password_into(passphrase, salt, &mut key)
+                .map_err(|e| BlockDeviceError::InvalidLuksHeader(format!("argon2: {e}")))?;
+        }
+    }
+
+    Ok(key)
+}
+
+/// Only `sha256` is supported for the AF splitter, PBKDF2 and digests.
+pub(crate) fn check_hash(hash: &str) -> Result<()> {
+    if hash != "sha256" {
+        return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+            "hash {hash}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// The LUKS diffusion function. The block is hashed in chunks of the digest
+/// size, each prefixed by its big-endian chunk index.
+fn diffuse(block: &mut [u8]) {
+    let digest_size = Sha256::output_size();
+    for (i, chunk) in block.chunks_mut(digest_size).enumerate() {
+        let mut hasher = Sha256::new();
+        hasher.update((i as u32).to_be_bytes());
+        hasher.update(&chunk[..]);
+        let hash = hasher.finalize();
+        chunk.copy_from_slice(&hash[..chunk.len()]);
+    }
+}
+
+/// Split `key` into `stripes` stripes with the anti-forensic splitter.
+pub(crate) fn af_split(key: &[u8], stripes: usize) -> Zeroizing<Vec<u8>> {
+    let key_size = key.len();
+    let mut material = Zeroizing::new(vec![0u8; key_size * stripes]);
+    let mut block = Zeroizing::new(vec![0u8; key_size]);
+    let mut rng = rand::rng();
+
+    for stripe in material.chunks_mut(key_size).take(stripes - 1) {
+        rng.fill(stripe);
+        block
+            .iter_mut()
+            .zip(stripe.iter())
+            .for_each(|(b, s)| *b ^= s);
+        diffuse(&mut block);
+    }
+
+    let last = &mut material[(stripes - 1) * key_size..];
+    last.iter_mut()
+        .zip(block.iter().zip(key))
+        .for_each(|(l, (b, k))| *l = b ^ k);
+    material
+}
+
+/// Recover the key from the anti-forensic split `material`.
+pub(crate) fn af_merge(material: &[u8], key_size: usize, stripes: usize) -> Zeroizing<Vec<u8>> {
+    let mut block = Zeroizing::new(vec![0u8; key_size]);
+    for stripe in material.chunks(key_size).take(stripes - 1) {
+        block.iter_mut().zip(stripe).for_each(|(b, s)| *b ^= s);
+        diffuse(&mut block);
+    }
+
+    let last = &material[(stripes - 1) * key_size..stripes * key_size];
+    block.iter_mut().zip(last).for_each(|(b, l)| *b ^= l);
+    block
+}
+
+/// Size of the keyslot area needed to store the split key, aligned to 4096
+/// bytes as cryptsetup does.
+pub(crate) fn area_size(key_size: usize, stripes: usize) -> u64 {
+    let size = (key_size * stripes) as u64;
+    size.div_ceil(4096) * 4096
+}
+
+enum XtsCipher {
+    Aes128(Xts128<Aes128>),
+    Aes256(Xts128<Aes256>),
+}
+
+impl XtsCipher {
+    fn new(encryption: &str, key: &[u8]) -> Result<Self> {
+        if encryption != "aes-xts-plain64" {
+            return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+                "keyslot encryption {encryption}"
+            )));
+        }
+
+        let half = key.len() / 2;
+        let (k1, k2) = key.split_at(half);
+        match key.len() {
+            32 => Ok(Self::Aes128(Xts128::new(
+                Aes128::new(k1.into()),
+                Aes128::new(k2.into()),
+            ))),
+            64 => Ok(Self::Aes256(Xts128::new(
+                Aes256::new(k1.into()),
+                Aes256::new(k2.into()),
+            ))),
+            others => Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+                "keyslot area key size {others}"
+            ))),
+        }
+    }
+}
+
+/// Encrypt the keyslot area in place with `aes-xts-plain64`. The IV of each
+/// 512-byte sector is its index inside the area.
+pub(crate) fn encrypt_area(encryption: &str, key: &[u8], area: &mut [u8]) -> Result<()> {
+    match XtsCipher::new(encryption, key)? {
+        XtsCipher::Aes128(c) => c.encrypt_area(area, KEYSLOT_SECTOR_SIZE, 0, get_tweak_default),
+        XtsCipher::Aes256(c) => c.encrypt_area(area, KEYSLOT_SECTOR_SIZE, 0, get_tweak_default),
+    }
+    Ok(())
+}
+
+/// Decrypt the keyslot area in place, see [`encrypt_area`].
+pub(crate) fn decrypt_area(encryption: &str, key: &[u8], area: &mut [u8]) -> Result<()> {
+    match XtsCipher::new(encryption, key)? {
+        XtsCipher::Aes128(c) => c.decrypt_area(area, KEYSLOT_SECTOR_SIZE, 0, get_tweak_default),
+        XtsCipher::Aes256(c) => c.decrypt_area(area, KEYSLOT_SECTOR_SIZE, 0, get_tweak_default),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{af_merge, af_split, decrypt_area, encrypt_area};
+
+    #[rstest]
+    #[case(64, 4000)]
+    #[case(96, 4000)]
+    #[case(32, 2)]
+    fn af_roundtrip(#[case] key_size: usize, #[case] stripes: usize) {
+        let key: Vec<u8> = (0..key_size).map(|i| i as u8).collect();
+        let material = af_split(&key, stripes);
+        assert_eq!(material.len(), key_size * stripes);
+        assert_eq!(&af_merge(&material, key_size, stripes)[..], &key[..]);
+    }
+
+    #[test]
+    fn area_roundtrip() {
+        let key = [7u8; 64];
+        let plain: Vec<u8> = (0..4096).map(|i| i as u8).collect();
+        let mut area = plain.clone();
+        encrypt_area("aes-xts-plain64", &key, &mut area).unwrap();
+        assert_ne!(area, plain);
+        decrypt_area("aes-xts-plain64", &key, &mut area).unwrap();
+        assert_eq!(area, plain);
+
+        assert!(encrypt_area("serpent-xts-plain64", &key, &mut area).is_err());
+    }
+}
@@ -0,0 +1,674 @@
+// Copyright (c) 2024 Intel
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! On-disk LUKS2 format: reading and writing the binary headers with their
+//! JSON metadata, formatting a device with a single keyslot and recovering
+//! the volume key from a passphrase.
+//!
+//! Only the subset of LUKS2 that CDH needs is implemented: one `crypt`
+//! segment encrypted with `aes-xts-plain64` (optionally authenticated with
+//! `hmac(sha256)` through dm-integrity), `luks2` keyslots with PBKDF2 or
+//! Argon2 KDFs and `pbkdf2` digests. Devices formatted by cryptsetup with
+//! these parameters can be opened as well.
+//!
+//! See <https://gitlab.com/cryptsetup/LUKS2-docs> for the specification.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{Seek, SeekFrom},
+    os::unix::fs::FileExt,
+};
+
+use pbkdf2::pbkdf2_hmac;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use zeroize::Zeroizing;
+
+use super::super::error::{BlockDeviceError, Result};
+use super::keyslot;
+
+/// Magic of the primary binary header.
+const LUKS2_MAGIC_PRIMARY: &[u8; 6] = b"LUKS\xba\xbe";
+
+/// Magic of the secondary binary header.
+const LUKS2_MAGIC_SECONDARY: &[u8; 6] = b"SKUL\xba\xbe";
+
+/// Size of the binary part of a header.
+const BINARY_HEADER_SIZE: usize = 4096;
+
+/// Size of one header (binary part plus JSON area).
+pub(crate) const HEADER_SIZE: u64 = 16 * 1024;
+
+/// Keyslot areas start right after the two headers.
+const KEYSLOTS_OFFSET: u64 = 2 * HEADER_SIZE;
+
+/// The data segment starts at 16 MiB, which is the cryptsetup default.
+pub(crate) const DATA_OFFSET: u64 = 16 * 1024 * 1024;
+
+/// Number of stripes of the anti-forensic splitter.
+const AF_STRIPES: u32 = 4000;
+
+/// Key size used to encrypt the keyslot area (`aes-xts-plain64` with AES-256).
+const KEYSLOT_KEY_SIZE: usize = 64;
+
+/// Offsets of the checksum fields inside the binary header.
+const CSUM_OFFSET: usize = 0x1c0;
+const CSUM_SIZE: usize = 64;
+
+/// Parameters used to format a new LUKS2 device.
+#[derive(Debug, Clone)]
+pub(crate) struct FormatParams {
+    /// Enable authenticated encryption with dm-integrity.
+    pub integrity: bool,
+
+    /// Iterations of PBKDF2 used for the keyslot and the digest.
+    pub pbkdf2_iterations: u32,
+
+    /// Encryption sector size of the data segment.
+    pub sector_size: u32,
+}
+
+impl Default for FormatParams {
+    fn default() -> Self {
+        Self {
+            integrity: true,
+            pbkdf2_iterations: 1_000_000,
+            sector_size: 4096,
+        }
+    }
+}
+
+mod as_string {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+mod as_base64 {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(D::Error::custom)
+    }
+}
+
+/// JSON metadata of a LUKS2 header.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct Metadata {
+    pub keyslots: BTreeMap<String, Keyslot>,
+    #[serde(default)]
+    pub tokens: BTreeMap<String, serde_json::Value>,
+    pub segments: BTreeMap<String, Segment>,
+    pub digests: BTreeMap<String, LuksDigest>,
+    pub config: Config,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct Keyslot {
+    #[serde(rename = "type")]
+    pub keyslot_type: String,
+    pub key_size: usize,
+    pub af: AntiForensic,
+    pub area: KeyslotArea,
+    pub kdf: Kdf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct AntiForensic {
+    #[serde(rename = "type")]
+    pub af_type: String,
+    pub stripes: u32,
+    pub hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct KeyslotArea {
+    #[serde(rename = "type")]
+    pub area_type: String,
+    #[serde(with = "as_string")]
+    pub offset: u64,
+    #[serde(with = "as_string")]
+    pub size: u64,
+    pub encryption: String,
+    pub key_size: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub(crate) enum Kdf {
+    Pbkdf2 {
+        hash: String,
+        iterations: u32,
+        #[serde(with = "as_base64")]
+        salt: Vec<u8>,
+    },
+    Argon2i {
+        time: u32,
+        memory: u32,
+        cpus: u32,
+        #[serde(with = "as_base64")]
+        salt: Vec<u8>,
+    },
+    Argon2id {
+        time: u32,
+        memory: u32,
+        cpus: u32,
+        #[serde(with = "as_base64")]
+        salt: Vec<u8>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct Segment {
+    #[serde(rename = "type")]
+    pub segment_type: String,
+    #[serde(with = "as_string")]
+    pub offset: u64,
+    /// Either `dynamic` or the size in bytes.
+    pub size: String,
+    #[serde(with = "as_string")]
+    pub iv_tweak: u64,
+    pub encryption: String,
+    pub sector_size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<SegmentIntegrity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct SegmentIntegrity {
+    #[serde(rename = "type")]
+    pub integrity_type: String,
+    pub journal_encryption: String,
+    pub journal_integrity: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct LuksDigest {
+    #[serde(rename = "type")]
+    pub digest_type: String,
+    pub keyslots: Vec<String>,
+    pub segments: Vec<String>,
+    pub hash: String,
+    pub iterations: u32,
+    #[serde(with = "as_base64")]
+    pub salt: Vec<u8>,
+    #[serde(with = "as_base64")]
+    pub digest: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct Config {
+    #[serde(with = "as_string")]
+    pub json_size: u64,
+    #[serde(with = "as_string")]
+    pub keyslots_size: u64,
+}
+
+/// A parsed LUKS2 header.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Luks2Header {
+    pub uuid: String,
+    pub seqid: u64,
+    pub metadata: Metadata,
+}
+
+/// Offsets of the fields inside the binary header.
+struct Field;
+
+impl Field {
+    const MAGIC: std::ops::Range<usize> = 0..6;
+    const VERSION: std::ops::Range<usize> = 6..8;
+    const HDR_SIZE: std::ops::Range<usize> = 8..16;
+    const SEQID: std::ops::Range<usize> = 16..24;
+    const CSUM_ALG: std::ops::Range<usize> = 0x48..0x68;
+    const SALT: std::ops::Range<usize> = 0x68..0xa8;
+    const UUID: std::ops::Range<usize> = 0xa8..0xd0;
+    const HDR_OFFSET: std::ops::Range<usize> = 0x100..0x108;
+}
+
+fn invalid(msg: impl Into<String>) -> BlockDeviceError {
+    BlockDeviceError::InvalidLuksHeader(msg.into())
+}
+
+fn header_checksum(area: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(&area[..CSUM_OFFSET]);
+    hasher.update([0u8; CSUM_SIZE]);
+    hasher.update(&area[CSUM_OFFSET + CSUM_SIZE..]);
+    hasher.finalize().into()
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).to_string()
+}
+
+impl Luks2Header {
+    /// Parse one header copy located at `offset`.
+    fn read_at(device: &File, offset: u64) -> Result<Self> {
+        let mut binary = vec![0u8; BINARY_HEADER_SIZE];
+        device.read_exact_at(&mut binary, offset)?;
+
+        let magic = if offset == 0 {
+            LUKS2_MAGIC_PRIMARY
+        } else {
+            LUKS2_MAGIC_SECONDARY
+        };
+        if &binary[Field::MAGIC] != magic {
+            return Err(invalid("bad magic"));
+        }
+
+        let version = u16::from_be_bytes(binary[Field::VERSION].try_into().expect("2 bytes"));
+        if version != 2 {
+            return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+                "LUKS version {version}"
+            )));
+        }
+
+        let hdr_size = u64::from_be_bytes(binary[Field::HDR_SIZE].try_into().expect("8 bytes"));
+        if hdr_size < BINARY_HEADER_SIZE as u64 || hdr_size > 4 * 1024 * 1024 {
+            return Err(invalid(format!("bad header size {hdr_size}")));
+        }
+        let hdr_offset = u64::from_be_bytes(binary[Field::HDR_OFFSET].try_into().expect("8 bytes"));
+        if hdr_offset != offset {
+            return Err(invalid(format!("header offset {hdr_offset} mismatches")));
+        }
+
+        let csum_alg = c_string(&binary[Field::CSUM_ALG]);
+        keyslot::check_hash(&csum_alg)?;
+
+        let mut area = vec![0u8; hdr_size as usize];
+        area[..BINARY_HEADER_SIZE].copy_from_slice(&binary);
+        device.read_exact_at(
+            &mut area[BINARY_HEADER_SIZE..],
+            offset + BINARY_HEADER_SIZE as u64,
+        )?;
+        if area[CSUM_OFFSET..CSUM_OFFSET + 32] != header_checksum(&area) {
+            return Err(BlockDeviceError::LuksHeaderChecksumMismatch);
+        }
+
+        let json = &area[BINARY_HEADER_SIZE..];
+        let end = json.iter().position(|b| *b == 0).unwrap_or(json.len());
+        let metadata: Metadata = serde_json::from_slice(&json[..end])?;
+
+        Ok(Self {
+            uuid: c_string(&binary[Field::UUID]),
+            seqid: u64::from_be_bytes(binary[Field::SEQID].try_into().expect("8 bytes")),
+            metadata,
+        })
+    }
+
+    /// Read the header from the device. The copy with the higher sequence id
+    /// wins; if one copy is damaged the other one is used.
+    pub fn read(device: &File) -> Result<Self> {
+        let primary = Self::read_at(device, 0);
+        let secondary = match &primary {
+            Ok(p) => Self::read_at(
+                device,
+                p.metadata.config.json_size + BINARY_HEADER_SIZE as u64,
+            ),
+            Err(_) => Self::read_at(device, HEADER_SIZE),
+        };
+
+        match (primary, secondary) {
+            (Ok(p), Ok(s)) if s.seqid > p.seqid => Ok(s),
+            (Ok(p), _) => Ok(p),
+            (Err(_), Ok(s)) => Ok(s),
+            (Err(e), Err(_)) => Err(e),
+        }
+    }
+
+    fn binary_header(&self, offset: u64, json: &[u8]) -> Result<Vec<u8>> {
+        let hdr_size = self.metadata.config.json_size + BINARY_HEADER_SIZE as u64;
+        if json.len() as u64 >= self.metadata.config.json_size {
+            return Err(invalid("metadata is larger than the JSON area"));
+        }
+
+        let mut area = vec![0u8; hdr_size as usize];
+        let magic = if offset == 0 {
+            LUKS2_MAGIC_PRIMARY
+        } else {
+            LUKS2_MAGIC_SECONDARY
+        };
+        area[Field::MAGIC].copy_from_slice(magic);
+        area[Field::VERSION].copy_from_slice(&2u16.to_be_bytes());
+        area[Field::HDR_SIZE].copy_from_slice(&hdr_size.to_be_bytes());
+        area[Field::SEQID].copy_from_slice(&self.seqid.to_be_bytes());
+        area[Field::CSUM_ALG][..6].copy_from_slice(b"sha256");
+        rand::rng().fill(&mut area[Field::SALT]);
+        area[Field::UUID][..self.uuid.len()].copy_from_slice(self.uuid.as_bytes());
+        area[Field::HDR_OFFSET].copy_from_slice(&offset.to_be_bytes());
+        area[BINARY_HEADER_SIZE..BINARY_HEADER_SIZE + json.len()].copy_from_slice(json);
+
+        let csum = header_checksum(&area);
+        area[CSUM_OFFSET..CSUM_OFFSET + csum.len()].copy_from_slice(&csum);
+        Ok(area)
+    }
+
+    /// Write both header copies to the device.
+    pub fn write(&self, device: &File) -> Result<()> {
+        let json = serde_json::to_vec(&self.metadata)?;
+        let hdr_size = self.metadata.config.json_size + BINARY_HEADER_SIZE as u64;
+        for offset in [0, hdr_size] {
+            device.write_all_at(&self.binary_header(offset, &json)?, offset)?;
+        }
+
+        device.sync_all()?;
+        Ok(())
+    }
+
+    /// The data segment.
+    pub fn segment(&self) -> Result<&Segment> {
+        let segment = self
+            .metadata
+            .segments
+            .get("0")
+            .ok_or_else(|| invalid("no data segment"))?;
+        if segment.segment_type != "crypt" {
+            return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+                "segment type {}",
+                segment.segment_type
+            )));
+        }
+
+        Ok(segment)
+    }
+}
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    rand::rng().fill(&mut bytes[..]);
+    bytes
+}
+
+/// A random (version 4) UUID in its textual form.
+fn random_uuid() -> String {
+    let mut bytes = random_bytes(16);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn volume_key_digest(volume_key: &[u8], salt: &[u8], iterations: u32) -> Vec<u8> {
+    let mut digest = vec![0u8; 32];
+    pbkdf2_hmac::<Sha256>(volume_key, salt, iterations, &mut digest);
+    digest
+}
+
+/// Format the device as LUKS2 with a single keyslot protected by
+/// `passphrase`. Returns the header and the generated volume key.
+pub(crate) fn format(
+    device: &mut File,
+    passphrase: &[u8],
+    params: &FormatParams,
+) -> Result<(Luks2Header, Zeroizing<Vec<u8>>)> {
+    let device_size = device.seek(SeekFrom::End(0))?;
+    if device_size <= DATA_OFFSET {
+        return Err(BlockDeviceError::LuksFormatFailed(format!(
+            "device of {device_size} bytes is too small"
+        )));
+    }
+
+    // With integrity the volume key carries the HMAC key after the XTS key.
+    let (volume_key_size, encryption, integrity) = if params.integrity {
+        (
+            96,
+            "aes-xts-random",
+            Some(SegmentIntegrity {
+                integrity_type: "hmac(sha256)".into(),
+                journal_encryption: "none".into(),
+                journal_integrity: "none".into(),
+            }),
+        )
+    } else {
+        (64, "aes-xts-plain64", None)
+    };
+    let volume_key = Zeroizing::new(random_bytes(volume_key_size));
+
+    let kdf = Kdf::Pbkdf2 {
+        hash: "sha256".into(),
+        iterations: params.pbkdf2_iterations,
+        salt: random_bytes(32),
+    };
+    let area_size = keyslot::area_size(volume_key_size, AF_STRIPES as usize);
+    let keyslot = Keyslot {
+        keyslot_type: "luks2".into(),
+        key_size: volume_key_size,
+        af: AntiForensic {
+            af_type: "luks1".into(),
+            stripes: AF_STRIPES,
+            hash: "sha256".into(),
+        },
+        area: KeyslotArea {
+            area_type: "raw".into(),
+            offset: KEYSLOTS_OFFSET,
+            size: area_size,
+            encryption: "aes-xts-plain64".into(),
+            key_size: KEYSLOT_KEY_SIZE,
+        },
+        kdf,
+        priority: None,
+    };
+
+    let mut area = Zeroizing::new(vec![0u8; area_size as usize]);
+    let material = keyslot::af_split(&volume_key, AF_STRIPES as usize);
+    area[..material.len()].copy_from_slice(&material);
+    let slot_key = keyslot::derive_key(&keyslot.kdf, passphrase, KEYSLOT_KEY_SIZE)?;
+    keyslot::encrypt_area(&keyslot.area.encryption, &slot_key, &mut area)?;
+
+    let digest_salt = random_bytes(32);
+    let digest = LuksDigest {
+        digest_type: "pbkdf2".into(),
+        keyslots: vec!["0".into()],
+        segments: vec!["0".into()],
+        hash: "sha256".into(),
+        iterations: params.pbkdf2_iterations,
+        digest: volume_key_digest(&volume_key, &digest_salt, params.pbkdf2_iterations),
+        salt: digest_salt,
+    };
+
+    let segment = Segment {
+        segment_type: "crypt".into(),
+        offset: DATA_OFFSET,
+        size: "dynamic".into(),
+        iv_tweak: 0,
+        encryption: encryption.into(),
+        sector_size: params.sector_size,
+        integrity,
+    };
+
+    let header = Luks2Header {
+        uuid: random_uuid(),
+        seqid: 1,
+        metadata: Metadata {
+            keyslots: BTreeMap::from([("0".into(), keyslot)]),
+            tokens: BTreeMap::new(),
+            segments: BTreeMap::from([("0".into(), segment)]),
+            digests: BTreeMap::from([("0".into(), digest)]),
+            config: Config {
+                json_size: HEADER_SIZE - BINARY_HEADER_SIZE as u64,
+                keyslots_size: DATA_OFFSET - KEYSLOTS_OFFSET,
+            },
+        },
+    };
+
+    device.write_all_at(&area, KEYSLOTS_OFFSET)?;
+    header.write(device)?;
+
+    // Wipe the start of the data segment, so that no stale dm-integrity
+    // superblock is picked up and the kernel formats a fresh one.
+    device.write_all_at(&vec![0u8; 4096], DATA_OFFSET)?;
+    device.sync_all()?;
+
+    Ok((header, volume_key))
+}
+
+/// Try to recover the volume key from the keyslot `id`.
+fn open_keyslot(
+    device: &File,
+    header: &Luks2Header,
+    id: &str,
+    keyslot: &Keyslot,
+    passphrase: &[u8],
+) -> Result<Option<Zeroizing<Vec<u8>>>> {
+    if keyslot.keyslot_type != "luks2" || keyslot.area.area_type != "raw" {
+        return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+            "keyslot type {}",
+            keyslot.keyslot_type
+        )));
+    }
+    keyslot::check_hash(&keyslot.af.hash)?;
+
+    let stripes = keyslot.af.stripes as usize;
+    let material_size = keyslot.key_size * stripes;
+    if material_size as u64 > keyslot.area.size {
+        return Err(invalid(format!("keyslot {id} area is too small")));
+    }
+
+    let mut area = Zeroizing::new(vec![0u8; keyslot.area.size as usize]);
+    device.read_exact_at(&mut area, keyslot.area.offset)?;
+    let slot_key = keyslot::derive_key(&keyslot.kdf, passphrase, keyslot.area.key_size)?;
+    keyslot::decrypt_area(&keyslot.area.encryption, &slot_key, &mut area)?;
+    let volume_key = keyslot::af_merge(&area[..material_size], keyslot.key_size, stripes);
+
+    for digest in header.metadata.digests.values() {
+        if !digest.keyslots.iter().any(|k| k == id) {
+            continue;
+        }
+        if digest.digest_type != "pbkdf2" {
+            return Err(BlockDeviceError::UnsupportedLuksParameter(format!(
+                "digest type {}",
+                digest.digest_type
+            )));
+        }
+        keyslot::check_hash(&digest.hash)?;
+
+        let computed = volume_key_digest(&volume_key, &digest.salt, digest.iterations);
+        if ring::constant_time::verify_slices_are_equal(&computed, &digest.digest).is_ok() {
+            return Ok(Some(volume_key));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read the header of `device` and recover the volume key with `passphrase`.
+pub(crate) fn unlock(
+    device: &File,
+    passphrase: &[u8],
+) -> Result<(Luks2Header, Zeroizing<Vec<u8>>)> {
+    let header = Luks2Header::read(device)?;
+    for (id, keyslot) in &header.metadata.keyslots {
+        if let Some(volume_key) = open_keyslot(device, &header, id, keyslot, passphrase)? {
+            return Ok((header, volume_key));
+        }
+    }
+
+    Err(BlockDeviceError::LuksKeyslotUnlockFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{File, OpenOptions},
+        os::unix::fs::FileExt,
+    };
+
+    use rstest::rstest;
+
+    use super::{format, unlock, FormatParams, Luks2Header, DATA_OFFSET};
+    use crate::storage::volume_type::blockdevice::error::BlockDeviceError;
+
+    fn image() -> (tempfile::TempDir, File) {
+        let dir = tempfile::tempdir().unwrap();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(dir.path().join("disk.img"))
+            .unwrap();
+        file.set_len(DATA_OFFSET + 1024 * 1024).unwrap();
+        (dir, file)
+    }
+
+    fn params(integrity: bool) -> FormatParams {
+        FormatParams {
+            integrity,
+            pbkdf2_iterations: 1000,
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(true, 96)]
+    #[case(false, 64)]
+    fn format_and_unlock(#[case] integrity: bool, #[case] key_size: usize) {
+        let (_dir, mut file) = image();
+        let (header, volume_key) = format(&mut file, b"passphrase", &params(integrity)).unwrap();
+        assert_eq!(volume_key.len(), key_size);
+        assert_eq!(header.segment().unwrap().integrity.is_some(), integrity);
+
+        let (read, unlocked) = unlock(&file, b"passphrase").unwrap();
+        assert_eq!(read, header);
+        assert_eq!(unlocked, volume_key);
+
+        assert!(matches!(
+            unlock(&file, b"wrong passphrase"),
+            Err(BlockDeviceError::LuksKeyslotUnlockFailed)
+        ));
+    }
+
+    #[test]
+    fn secondary_header_fallback() {
+        let (_dir, mut file) = image();
+        let (header, _) = format(&mut file, b"passphrase", &params(false)).unwrap();
+
+        // Corrupt the JSON area of the primary header.
+        file.write_all_at(b"garbage", 4096).unwrap();
+        assert_eq!(Luks2Header::read(&file).unwrap(), header);
+        assert!(unlock(&file, b"passphrase").is_ok());
+
+        // Corrupt the secondary header as well.
+        file.write_all_at(b"garbage", 16384 + 4096).unwrap();
+        assert!(Luks2Header::read(&file).is_err());
+    }
+
+    #[test]
+    fn too_small_device() {
+        let (_dir, mut file) = image();
+        file.set_len(DATA_OFFSET).unwrap();
+        assert!(matches!(
+            format(&mut file, b"passphrase", &params(false)),
+            Err(BlockDeviceError::LuksFormatFailed(_))
+        ));
+    }
+}
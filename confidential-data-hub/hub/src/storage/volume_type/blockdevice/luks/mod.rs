@@ -2,19 +2,38 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 //
+
+//! LUKS2 encrypted block devices. The LUKS2 header is formatted and opened
+//! in [`luks2`] and the data segment is activated with dm-crypt (and
+//! dm-integrity) in [`dm`], so neither cryptsetup nor a shell is needed in
+//! the guest. Only the filesystem is still created with `mkfs.ext4`.
+
+mod dm;
+mod keyslot;
+mod luks2;
+
 use super::Interpreter;
 use super::{get_plaintext_key, BlockDeviceError, BlockDeviceParameters, Result};
+use crate::storage::volume_type::{umount_path, MountHandle};
 use async_trait::async_trait;
-use log::error;
+use log::{debug, error, info};
+use luks2::FormatParams;
+use nix::mount::MsFlags;
 use rand::{distr::Alphanumeric, Rng};
-use tokio::{
-    fs,
-    io::{AsyncReadExt, AsyncWriteExt},
-    process::Command,
+use std::{
+    fs::OpenOptions,
+    io::{Seek, SeekFrom},
+    os::unix::fs::FileExt,
+    path::{Path, PathBuf},
 };
+use tokio::{fs, process::Command};
+use zeroize::Zeroizing;
 
-/// LUKS encrypt storage binary
-const LUKS_ENCRYPT_STORAGE_BIN: &str = "/usr/local/bin/luks-encrypt-storage";
+/// mkfs.ext4 binary, used to create the filesystem on the opened device
+const MKFS_EXT4_BIN: &str = "mkfs.ext4";
+
+/// Block size of the filesystem and of the LUKS2 data segment
+const BLOCK_SIZE: u64 = 4096;
 
 async fn random_encrypt_key() -> Vec<u8> {
     let mut buffer = vec![0u8; 4096];
@@ -22,19 +41,124 @@ async fn random_encrypt_key() -> Vec<u8> {
     buffer
 }
 
-async fn create_storage_key_file(
-    storage_key_path: &str,
-    encrypt_key: Option<String>,
-) -> anyhow::Result<()> {
-    let mut storage_key_file = fs::File::create(storage_key_path).await?;
+/// Resolve the device node of the block device "MAJ:MIN".
+async fn device_path(device_id: &str) -> Result<PathBuf> {
+    let uevent = fs::read_to_string(format!("/sys/dev/block/{device_id}/uevent"))
+        .await
+        .map_err(|_| BlockDeviceError::DeviceNotFound(device_id.to_string()))?;
+    let name = uevent
+        .lines()
+        .find_map(|line| line.strip_prefix("DEVNAME="))
+        .ok_or_else(|| BlockDeviceError::DeviceNotFound(device_id.to_string()))?;
+
+    Ok(PathBuf::from("/dev").join(name))
+}
+
+/// Format (when `format` is set) or unlock the LUKS2 device and activate its
+/// data segment as `/dev/mapper/<name>`. Returns the created device-mapper
+/// devices, the topmost first.
+fn open_device(
+    device: &Path,
+    device_id: &str,
+    name: &str,
+    passphrase: &[u8],
+    format: Option<FormatParams>,
+) -> Result<Vec<String>> {
+    let mut raw = OpenOptions::new().read(true).write(true).open(device)?;
+    let (header, volume_key) = match format {
+        Some(params) => {
+            info!("formatting {} as LUKS2", device.display());
+            luks2::format(&mut raw, passphrase, &params)?
+        }
+        None => luks2::unlock(&raw, passphrase)?,
+    };
+
+    let device_size = raw.seek(SeekFrom::End(0))?;
+    dm::activate(
+        name,
+        device_id,
+        &raw,
+        device_size,
+        header.segment()?,
+        &volume_key,
+    )
+}
 
-    let plain_key = match encrypt_key {
-        Some(encrypt_key) => get_plaintext_key(&encrypt_key).await?,
-        None => random_encrypt_key().await,
+/// Parse the superblock backup locations from the output of `mkfs.ext4 -n`,
+/// e.g.
+///
+/// ```text
+/// Superblock backups stored on blocks:
+///         32768, 98304, 163840, 229376, 294912, 819200, 884736, 1605632, 2654208,
+///         4096000, 7962624, 11239424, 20480000, 23887872, 71663616, 78675968,
+///         102400000, 214990848
+/// ```
+fn superblock_backups(output: &str) -> Vec<u64> {
+    let Some((_, blocks)) = output.split_once("Superblock backups stored on blocks:") else {
+        return Vec::new();
     };
 
-    storage_key_file.write_all(&plain_key).await?;
-    storage_key_file.flush().await?;
+    let mut blocks: Vec<u64> = blocks
+        .lines()
+        .take_while(|line| line.trim().is_empty() || line.starts_with(char::is_whitespace))
+        .flat_map(|line| line.split(|c: char| !c.is_ascii_digit()))
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    blocks.sort_unstable();
+    blocks
+}
+
+async fn run_mkfs(device: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new(MKFS_EXT4_BIN)
+        .arg(device)
+        .args(args)
+        .output()
+        .await?;
+    if !output.status.success() {
+        return Err(BlockDeviceError::MkfsFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Create an ext4 filesystem on the opened device.
+///
+/// An integrity device is not wiped when formatted, so its sectors carry no
+/// valid tags until they are written. mkfs.ext4 reads some blocks before it
+/// writes them, so the blocks it touches (block 0 and the superblock backups,
+/// found through a dry run) are zeroed first to give them valid tags. See
+/// <https://gitlab.com/cryptsetup/cryptsetup/-/issues/525>.
+async fn mkfs(device: &Path, integrity: bool) -> Result<()> {
+    if integrity {
+        let output = run_mkfs(device, &["-F", "-n"]).await?;
+        let backups = superblock_backups(&output);
+        if backups.is_empty() {
+            return Err(BlockDeviceError::MkfsFailed(
+                "superblock backup blocks not found".into(),
+            ));
+        }
+
+        let device = device.to_path_buf();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let file = OpenOptions::new().write(true).open(&device)?;
+            let zeroes = vec![0u8; BLOCK_SIZE as usize];
+            for block in std::iter::once(0).chain(backups) {
+                debug!("clearing block {block} of {}", device.display());
+                file.write_all_at(&zeroes, block * BLOCK_SIZE)?;
+            }
+            file.sync_all()?;
+            Ok(())
+        })
+        .await
+        .map_err(std::io::Error::other)??;
+    }
+
+    // Use lazy_journal_init so that the journal is initialized on demand.
+    // This is safe for ephemeral storage since we don't expect ephemeral
+    // storage to survive a power cycle.
+    run_mkfs(device, &["-E", "lazy_journal_init"]).await?;
     Ok(())
 }
 
@@ -46,46 +170,116 @@ impl Interpreter for LuksInterpreter {
         &self,
         parameters: BlockDeviceParameters,
         mount_point: &str,
-    ) -> Result<()> {
+    ) -> Result<MountHandle> {
         let random_string: String = rand::rng()
             .sample_iter(&Alphanumeric)
             .take(5)
             .map(char::from)
             .collect();
-        let storage_key_path = format!("/tmp/encrypted_storage_key_{}", random_string);
-        create_storage_key_file(&storage_key_path, parameters.encryption_key.clone()).await?;
-
-        let parameters = vec![
-            parameters.device_id,
-            match parameters.encryption_key {
-                None => "false".to_string(),
-                Some(_) => "true".to_string(),
-            },
-            mount_point.to_string(),
-            storage_key_path,
-            parameters.data_integrity.clone(),
-        ];
-
-        let mut encrypt_device = Command::new(LUKS_ENCRYPT_STORAGE_BIN)
-            .args(parameters)
-            .spawn()
-            .map_err(|e| {
-                error!("luks-encrypt-storage cmd fork failed: {:?}", e);
-                BlockDeviceError::BlockDeviceMountFailed
-            })?;
-
-        let bd_res = encrypt_device.wait().await?;
-        if !bd_res.success() {
-            let mut stderr = String::new();
-            if let Some(mut err) = encrypt_device.stderr.take() {
-                err.read_to_string(&mut stderr).await?;
-                error!("BlockDevice mount failed with stderr: {:?}", stderr);
-            } else {
-                error!("BlockDevice mount failed");
-            }
+        let opened_device_name = format!("encrypted_disk_{}", random_string);
+        let enable_integrity = !parameters.data_integrity.eq_ignore_ascii_case("false");
+
+        // Only a device without a given key is formatted; a given key
+        // unlocks the existing LUKS2 header.
+        let (passphrase, format) = match &parameters.encryption_key {
+            Some(encrypt_key) => (Zeroizing::new(get_plaintext_key(encrypt_key).await?), None),
+            None => (
+                Zeroizing::new(random_encrypt_key().await),
+                Some(FormatParams {
+                    integrity: enable_integrity,
+                    ..Default::default()
+                }),
+            ),
+        };
+
+        let device = device_path(&parameters.device_id).await?;
+        let mapped_devices = {
+            let device_id = parameters.device_id.clone();
+            let name = opened_device_name.clone();
+            tokio::task::spawn_blocking(move || {
+                open_device(&device, &device_id, &name, &passphrase, format)
+            })
+            .await
+            .map_err(std::io::Error::other)??
+        };
 
-            return Err(BlockDeviceError::BlockDeviceMountFailed);
+        // An existing device is opened due to its own header, which decides
+        // whether a dm-integrity device lies beneath the crypt device.
+        let integrity = mapped_devices.len() > 1;
+
+        let handle = MountHandle {
+            mount_point: mount_point.to_string(),
+            mapped_devices,
+            ..Default::default()
+        };
+
+        if let Err(e) = mount_opened_device(&opened_device_name, integrity, mount_point).await {
+            error!("mount {opened_device_name} failed: {e:?}");
+            if let Err(e) = deactivate(&handle).await {
+                error!("failed to close {opened_device_name}: {e:?}");
+            }
+            return Err(e);
         }
-        Ok(())
+
+        Ok(handle)
+    }
+
+    async fn secure_device_umount(&self, handle: &MountHandle) -> Result<()> {
+        umount_path(&handle.mount_point).await.map_err(|e| {
+            error!("umount {} failed: {e:?}", handle.mount_point);
+            BlockDeviceError::BlockDeviceUmountFailed
+        })?;
+
+        deactivate(handle).await
+    }
+}
+
+async fn mount_opened_device(name: &str, integrity: bool, mount_point: &str) -> Result<()> {
+    let device = dm::mapper_path(name);
+    mkfs(&device, integrity).await?;
+
+    fs::create_dir_all(mount_point).await?;
+    nix::mount::mount(
+        Some(&device),
+        mount_point,
+        Some("ext4"),
+        MsFlags::empty(),
+        None::<&str>,
+    )
+    .map_err(|source| BlockDeviceError::MountFailed {
+        device: device.display().to_string(),
+        mount_point: mount_point.to_string(),
+        source,
+    })
+}
+
+async fn deactivate(handle: &MountHandle) -> Result<()> {
+    let devices = handle.mapped_devices.clone();
+    tokio::task::spawn_blocking(move || dm::deactivate(&devices))
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::superblock_backups;
+
+    #[test]
+    fn parse_superblock_backups() {
+        let output = "mke2fs 1.46.5 (30-Dec-2021)
+Creating filesystem with 268435456 4k blocks and 67108864 inodes
+Filesystem UUID: 4a5ff012-91c0-47d9-b4bb-8f83e830825f
+Superblock backups stored on blocks:
+	32768, 98304, 163840, 229376, 294912, 819200, 884736, 1605632, 2654208,
+	4096000, 7962624, 11239424, 20480000, 23887872, 71663616, 78675968,
+	102400000, 214990848
+
+";
+        let blocks = superblock_backups(output);
+        assert_eq!(blocks.len(), 18);
+        assert_eq!(blocks.first(), Some(&32768));
+        assert_eq!(blocks.last(), Some(&214990848));
+
+        assert!(superblock_backups("mke2fs 1.46.5 (30-Dec-2021)").is_empty());
     }
 }
@@ -5,7 +5,7 @@
 pub mod error;
 pub mod luks;
 
-use super::SecureMount;
+use super::{MountHandle, SecureMount};
 use async_trait::async_trait;
 use error::{BlockDeviceError, Result};
 use log::{debug, error};
@@ -49,7 +49,9 @@ pub trait Interpreter {
         &self,
         parameters: BlockDeviceParameters,
         mount_point: &str,
-    ) -> Result<()>;
+    ) -> Result<MountHandle>;
+
+    async fn secure_device_umount(&self, handle: &MountHandle) -> Result<()>;
 }
 
 async fn get_plaintext_key(resource: &str) -> anyhow::Result<Vec<u8>> {
@@ -80,7 +82,7 @@ impl BlockDevice {
         options: &HashMap<String, String>,
         _flags: &[String],
         mount_point: &str,
-    ) -> Result<()> {
+    ) -> Result<MountHandle> {
         // construct BlockDeviceParameters
         let parameters = serde_json::to_string(options)?;
         let bd_parameter: BlockDeviceParameters = serde_json::from_str(&parameters)?;
@@ -89,10 +91,9 @@ impl BlockDevice {
             BlockDeviceEncryptType::LUKS => {
                 luks::LuksInterpreter
                     .secure_device_mount(bd_parameter, mount_point)
-                    .await?;
+                    .await
             }
         }
-        Ok(())
     }
 }
 
@@ -100,8 +101,8 @@ impl BlockDevice {
 impl SecureMount for BlockDevice {
     /// Mount the block device to the given `mount_point``.
     ///
-    /// If `bd.encrypt_type` is set to `LUKS`, the device will be formated as a LUKS-encrypted device.
-    /// Then use cryptsetup open the device and mount it to `mount_point` as plaintext.
+    /// If `bd.encrypt_type` is set to `LUKS`, the device will be formated as a LUKS2-encrypted device.
+    /// Then the device is opened with dm-crypt and mounted to `mount_point` as plaintext.
     ///
     /// This is a wrapper for inner function to convert error type.
     async fn mount(
@@ -109,9 +110,18 @@ impl SecureMount for BlockDevice {
         options: &HashMap<String, String>,
         flags: &[String],
         mount_point: &str,
-    ) -> super::Result<()> {
+    ) -> super::Result<MountHandle> {
         self.real_mount(options, flags, mount_point)
             .await
             .map_err(|e| e.into())
     }
+
+    /// Unmount the block device, close the opened LUKS device (with the
+    /// dm-integrity device beneath it if any).
+    async fn umount(&self, handle: &MountHandle) -> super::Result<()> {
+        luks::LuksInterpreter
+            .secure_device_umount(handle)
+            .await
+            .map_err(|e| e.into())
+    }
 }
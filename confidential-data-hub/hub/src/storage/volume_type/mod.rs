@@ -5,21 +5,33 @@
 
 #[cfg(feature = "aliyun")]
 pub mod aliyun;
+#[cfg(feature = "block_device")]
 pub mod blockdevice;
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use super::error::Result;
 
 use async_trait::async_trait;
 
+use log::warn;
+use nix::{
+    errno::Errno,
+    mount::{umount2, MntFlags},
+};
 use serde::Deserialize;
 use strum::EnumString;
+use tokio::{fs, io::AsyncWriteExt};
 
 #[derive(EnumString, PartialEq, Debug)]
 pub enum Volume {
     #[cfg(feature = "aliyun")]
     #[strum(serialize = "alibaba-cloud-oss")]
     AliOss,
+    #[cfg(feature = "block_device")]
     BlockDevice,
 }
 
@@ -40,34 +52,174 @@ pub struct Storage {
     pub mount_point: String,
 }
 
+/// Everything that is needed to tear down an active secure mount.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct MountHandle {
+    /// Driver name of the mount plugin that created the mount.
+    pub volume_type: String,
+
+    /// The target mount point.
+    pub mount_point: String,
+
+    /// Intermediate mount points that `mount_point` is stacked on, e.g. the
+    /// ossfs mount under a gocryptfs mount. They are unmounted after
+    /// `mount_point`, in order.
+    pub inner_mount_points: Vec<String>,
+
+    /// Names of the device-mapper devices opened for the mount, e.g. the
+    /// dm-crypt mapping of a LUKS device.
+    pub mapped_devices: Vec<String>,
+
+    /// Files or directories which contain key material of the mount. They
+    /// are scrubbed and removed on teardown.
+    pub key_files: Vec<PathBuf>,
+}
+
 #[async_trait]
 pub trait SecureMount {
-    /// Mount the volume to `mount_point` due to the given options.
+    /// Mount the volume to `mount_point` due to the given options. The
+    /// returned [`MountHandle`] is used to tear the mount down later.
     async fn mount(
         &self,
         options: &HashMap<String, String>,
         flags: &[String],
         mount_point: &str,
-    ) -> Result<()>;
+    ) -> Result<MountHandle>;
+
+    /// Unmount the volume created by [`SecureMount::mount`], close all the
+    /// devices it opened and scrub the key material it left.
+    async fn umount(&self, handle: &MountHandle) -> Result<()>;
 }
 
 impl Storage {
-    pub async fn mount(&self) -> Result<String> {
+    pub async fn mount(&self) -> Result<MountHandle> {
         let volume_type = Volume::from_str(&self.volume_type)?;
-        match volume_type {
+        let mut handle: MountHandle = match volume_type {
             #[cfg(feature = "aliyun")]
             Volume::AliOss => {
                 let oss = aliyun::Oss {};
                 oss.mount(&self.options, &self.flags, &self.mount_point)
-                    .await?;
-                Ok(self.mount_point.clone())
+                    .await?
             }
+            #[cfg(feature = "block_device")]
             Volume::BlockDevice => {
                 let bd = blockdevice::BlockDevice {};
                 bd.mount(&self.options, &self.flags, &self.mount_point)
-                    .await?;
-                Ok(self.mount_point.clone())
+                    .await?
+            }
+        };
+
+        handle.volume_type = self.volume_type.clone();
+        Ok(handle)
+    }
+
+    /// Tear down the mount created by [`Storage::mount`].
+    pub async fn umount(handle: &MountHandle) -> Result<()> {
+        let volume_type = Volume::from_str(&handle.volume_type)?;
+        match volume_type {
+            #[cfg(feature = "aliyun")]
+            Volume::AliOss => aliyun::Oss {}.umount(handle).await,
+            #[cfg(feature = "block_device")]
+            Volume::BlockDevice => blockdevice::BlockDevice {}.umount(handle).await,
+        }
+    }
+}
+
+/// Unmount the given mount point. It is not an error if the path is not
+/// mounted, which makes the teardown idempotent.
+pub(crate) async fn umount_path(mount_point: &str) -> std::io::Result<()> {
+    let target = mount_point.to_string();
+    let res = tokio::task::spawn_blocking(move || umount2(target.as_str(), MntFlags::empty()))
+        .await
+        .map_err(std::io::Error::other)?;
+
+    match res {
+        Ok(()) => Ok(()),
+        Err(Errno::EINVAL | Errno::ENOENT) => {
+            warn!("{mount_point} is not mounted, skip");
+            Ok(())
+        }
+        Err(e) => Err(std::io::Error::other(format!(
+            "umount {mount_point} failed: {e}"
+        ))),
+    }
+}
+
+/// Overwrite the content of the given file with zeros and then delete it.
+/// If `path` is a directory, all the files inside will be scrubbed. It is
+/// not an error if the path does not exist.
+pub(crate) async fn scrub_path(path: &Path) -> std::io::Result<()> {
+    let metadata = match fs::symlink_metadata(path).await {
+        Ok(m) => m,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if !metadata.is_dir() {
+        scrub_file(path, &metadata).await?;
+        return fs::remove_file(path).await;
+    }
+
+    let mut dirs = vec![path.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = fs::symlink_metadata(entry.path()).await?;
+            if metadata.is_dir() {
+                dirs.push(entry.path());
+            } else {
+                scrub_file(&entry.path(), &metadata).await?;
             }
         }
     }
+
+    fs::remove_dir_all(path).await
+}
+
+async fn scrub_file(path: &Path, metadata: &std::fs::Metadata) -> std::io::Result<()> {
+    if !metadata.is_file() {
+        return Ok(());
+    }
+
+    let mut file = fs::OpenOptions::new().write(true).open(path).await?;
+    file.write_all(&vec![0u8; metadata.len() as usize]).await?;
+    file.sync_all().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scrub_path, umount_path};
+
+    #[tokio::test]
+    async fn scrub_key_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let keys = dir.path().join("keys");
+        tokio::fs::create_dir_all(keys.join("inner")).await.unwrap();
+        tokio::fs::write(keys.join("passwd"), b"secret")
+            .await
+            .unwrap();
+        tokio::fs::write(keys.join("inner").join("key"), b"secret")
+            .await
+            .unwrap();
+
+        scrub_path(&keys).await.unwrap();
+        assert!(!keys.exists());
+
+        // scrubbing a path that does not exist is a no-op
+        scrub_path(&keys).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn umount_not_mounted_path() {
+        if !nix::unistd::Uid::effective().is_root() {
+            println!("INFO: skipping umount test, which needs root");
+            return;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        umount_path(dir.path().to_str().unwrap()).await.unwrap();
+        umount_path(dir.path().join("missing").to_str().unwrap())
+            .await
+            .unwrap();
+    }
 }
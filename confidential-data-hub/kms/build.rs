@@ -0,0 +1,12 @@
+// Copyright (c) 2023 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+fn main() {
+    #[cfg(feature = "aliyun")]
+    tonic_build::compile_protos(
+        "./src/plugins/aliyun/client/client_key_client/protobuf/dkms_api.proto",
+    )
+    .expect("Generate aliyun protocol code failed.");
+}
@@ -3,7 +3,6 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use attestation_agent::config::aa_kbc_params;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -23,7 +22,4 @@ pub enum Error {
 
     #[error("Unsupported provider: {0}")]
     UnsupportedProvider(String),
-
-    #[error("aa_kbc_params error")]
-    AaKbcParamsError(#[from] aa_kbc_params::ParamError),
 }
@@ -0,0 +1,18 @@
+// Copyright (c) 2023 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! KMS/Vault drivers of Confidential Data Hub.
+//!
+//! The api and the KMS plugins live in their own crate so that userside
+//! tools (e.g. coco_keyprovider) can use the [`Encrypter`]s without
+//! depending on the whole CDH.
+
+pub mod api;
+pub use api::*;
+
+pub mod error;
+pub use error::*;
+
+pub mod plugins;
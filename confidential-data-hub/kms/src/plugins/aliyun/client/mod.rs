@@ -14,9 +14,9 @@ mod ecs_ram_role_client;
 pub mod oidc_with_ram;
 mod sts_token_client;
 
-use crate::kms::plugins::_IN_GUEST_DEFAULT_KEY_PATH;
-use crate::kms::{Annotations, Decrypter, Encrypter, Getter, ProviderSettings};
-use crate::kms::{Error, Result};
+use crate::plugins::_IN_GUEST_DEFAULT_KEY_PATH;
+use crate::{Annotations, Decrypter, Encrypter, Getter, ProviderSettings};
+use crate::{Error, Result};
 
 use client_key_client::ClientKeyClient;
 use ecs_ram_role_client::EcsRamRoleClient;
@@ -246,7 +246,7 @@ mod tests {
     #[case(b"this is a another test plaintext")]
     #[tokio::test]
     async fn key_lifetime(#[case] plaintext: &[u8]) {
-        use crate::kms::{plugins::aliyun::AliyunKmsClient, Decrypter, Encrypter};
+        use crate::{plugins::aliyun::AliyunKmsClient, Decrypter, Encrypter};
 
         let kid = "alias/test_key_id";
         let provider_settings = json!({
@@ -286,7 +286,7 @@ mod tests {
     #[case("ecs_ram_role")]
     #[tokio::test]
     async fn get_secret(#[case] client_type: &str) {
-        use crate::kms::{plugins::aliyun::AliyunKmsClient, Annotations, Getter};
+        use crate::{plugins::aliyun::AliyunKmsClient, Annotations, Getter};
 
         let secret_name = "test_secret";
         let provider_settings = json!({
@@ -14,7 +14,7 @@ use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::kms::{Annotations, ProviderSettings};
+use crate::{Annotations, ProviderSettings};
 
 use super::sts_token_client::{credential::StsCredential, StsTokenClient};
 
@@ -15,7 +15,7 @@ use log::info;
 use serde_json::Value;
 use tokio::fs;
 
-use crate::kms::{
+use crate::{
     plugins::_IN_GUEST_DEFAULT_KEY_PATH, Annotations, Decrypter, Encrypter, Error,
     ProviderSettings, Result,
 };
@@ -158,7 +158,7 @@ mod tests {
     use rstest::rstest;
     use serde_json::json;
 
-    use crate::kms::{plugins::ehsm::client::EhsmKmsClient, Decrypter, Encrypter};
+    use crate::{plugins::ehsm::client::EhsmKmsClient, Decrypter, Encrypter};
 
     #[ignore]
     #[tokio::test]
@@ -0,0 +1,12 @@
+// Copyright (c) 2023 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+const _IN_GUEST_DEFAULT_KEY_PATH: &str = "/run/confidential-containers/cdh/kms-credential";
+
+#[cfg(feature = "aliyun")]
+pub mod aliyun;
+
+#[cfg(feature = "ehsm")]
+pub mod ehsm;
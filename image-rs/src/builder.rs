@@ -13,9 +13,11 @@ use crate::{
     config::{ImageConfig, NydusConfig},
     image::ImageClient,
     meta_store::{MetaStore, METAFILE},
+    platform::Platform,
     resource::ResourceProvider,
     signature::SignatureValidator,
     snapshots::SnapshotType,
+    unpack::UnpackMode,
 };
 
 use anyhow::Result;
@@ -48,6 +50,7 @@ impl ClientBuilder {
         Some(image_security_policy_uri),
         String
     );
+    __impl_config!(cache_signature_verification, bool);
     __impl_config!(sigstore_config_uri, Some(sigstore_config_uri), String);
     __impl_config!(
         authenticated_registry_credentials_uri,
@@ -55,6 +58,12 @@ impl ClientBuilder {
         String
     );
     __impl_config!(max_concurrent_layer_downloads_per_image, usize);
+    __impl_config!(layer_download_retries, u32);
+    __impl_config!(layer_download_retry_delay_ms, u64);
+    __impl_config!(stage_layer_downloads, bool);
+    __impl_config!(platform, Some(platform), Platform);
+    __impl_config!(strict_platform, bool);
+    __impl_config!(unpack_mode, UnpackMode);
     __impl_config!(nydus_config, Some(nydus_config), NydusConfig);
 
     #[cfg(feature = "keywrap-native")]
@@ -2,12 +2,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, Result};
-use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ffi::OsString;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
 
 use oci_spec::image::ImageConfiguration;
-use oci_spec::runtime::{Mount, Process, Spec};
+use oci_spec::runtime::{Mount, Process, Spec, User};
 
 pub const BUNDLE_CONFIG: &str = "config.json";
 pub const BUNDLE_ROOTFS: &str = "rootfs";
@@ -23,6 +26,37 @@ const ANNOTATION_CREATED: &str = "org.opencontainers.image.created";
 const ANNOTATION_STOP_SIGNAL: &str = "org.opencontainers.image.stopSignal";
 const ANNOTATION_EXPOSED_PORTS: &str = "org.opencontainers.image.exposedPorts";
 
+const PASSWD_FILE: &str = "/etc/passwd";
+const GROUP_FILE: &str = "/etc/group";
+
+/// Max number of symlinks followed to resolve a path in the rootfs, the same
+/// as the `MAXSYMLINKS` of Linux.
+const MAX_SYMLINKS: usize = 40;
+
+/// An entry of `/etc/passwd`.
+struct PasswdEntry {
+    name: String,
+    uid: u32,
+    gid: u32,
+    home: String,
+}
+
+/// An entry of `/etc/group`.
+struct GroupEntry {
+    name: String,
+    gid: u32,
+    members: Vec<String>,
+}
+
+/// The user of the container process resolved from the image config.
+#[derive(Debug, PartialEq)]
+struct ResolvedUser {
+    uid: u32,
+    gid: u32,
+    additional_gids: Vec<u32>,
+    home: Option<String>,
+}
+
 /// Convert an `application/vnd.oci.image.config.v1+json` object into an OCI runtime configuration
 /// blob and write to `config.json`.
 ///
@@ -33,6 +67,10 @@ const ANNOTATION_EXPOSED_PORTS: &str = "org.opencontainers.image.exposedPorts";
 /// provided inputs from the caller. In addition, a converter MAY have its own
 /// implementation-defined defaults and extensions which MAY be combined with the "default generated
 /// runtime configuration".
+///
+/// The `User` of the image config is resolved against `/etc/passwd` and
+/// `/etc/group` of the rootfs in the bundle, which is thus to be mounted
+/// before.
 pub fn create_runtime_config(
     image_config: &ImageConfiguration,
     bundle_path: &Path,
@@ -46,6 +84,7 @@ pub fn create_runtime_config(
 
     if let Some(config) = image_config.config() {
         let mut process = Process::default();
+        let rootfs = bundle_path.join(BUNDLE_ROOTFS);
 
         // Verbatim Fields:
         //
@@ -56,13 +95,33 @@ pub fn create_runtime_config(
         // - EntryPoint
         // - Cmd
         if let Some(working_dir) = config.working_dir() {
-            process.set_cwd(PathBuf::from(working_dir));
+            if !working_dir.is_empty() {
+                process.set_cwd(Path::new("/").join(working_dir));
+            }
         }
+
+        // Parsed Fields:
+        //
+        // Certain image configuration fields have a counterpart that must first be translated.
+        // A compliant configuration converter SHOULD parse all of these fields and set the
+        // corresponding fields in the generated runtime configuration:
+        // - User
+        let user = resolve_user(&rootfs, config.user().as_deref().unwrap_or_default())
+            .context("failed to resolve the user of the image")?;
+        let mut process_user = User::default();
+        process_user.set_uid(user.uid).set_gid(user.gid);
+        if !user.additional_gids.is_empty() {
+            process_user.set_additional_gids(Some(user.additional_gids.clone()));
+        }
+        process.set_user(process_user);
+
         // The converter MAY add additional entries to process.env but it SHOULD NOT add entries
         // that have variable names present in Config.Env.
-        if let Some(env) = config.env() {
-            process.set_env(Some(env.to_vec()));
-        }
+        let mut defaults = process.env().clone().unwrap_or_default();
+        defaults.push(format!("HOME={}", user.home.as_deref().unwrap_or("/")));
+        let image_env = config.env().clone().unwrap_or_default();
+        process.set_env(Some(merge_env(&image_env, &defaults)));
+
         // If both Config.Entrypoint and Config.Cmd are specified, the converter MUST append the
         // value of Config.Cmd to the value of Config.Entrypoint and set process.args to that
         // combined value.
@@ -103,15 +162,6 @@ pub fn create_runtime_config(
             }
         }
 
-        // Parsed Fields:
-        //
-        // Certain image configuration fields have a counterpart that must first be translated.
-        // A compliant configuration converter SHOULD parse all of these fields and set the
-        // corresponding fields in the generated runtime configuration:
-        // - User
-        // TODO: parse image config user info and extract uid from rootfs passwd file
-        // github issue: https://github.com/confidential-containers/image-rs/issues/8
-
         // Optional Fields:
         //
         // Certain image configuration fields are not applicable to all conversion use cases, and
@@ -141,11 +191,21 @@ pub fn create_runtime_config(
         //
         // Note that the implementation of Config.Volumes need not use mountpoints, as it is
         // effectively a mask of the filesystem.
+        //
+        // The volumes are mounted after the default mounts, and volumes at the destination of a
+        // default mount are left to the default mount.
         if let Some(volumes) = config.volumes() {
-            let mut mounts: Vec<Mount> = vec![];
-            for v in volumes.iter() {
+            let mut mounts = spec.mounts().clone().unwrap_or_default();
+            let defaults: HashSet<PathBuf> =
+                mounts.iter().map(|m| m.destination().clone()).collect();
+            let volumes: BTreeSet<PathBuf> = volumes
+                .iter()
+                .map(|v| Path::new("/").join(v))
+                .filter(|v| !defaults.contains(v))
+                .collect();
+            for v in volumes {
                 let mut m = Mount::default();
-                m.set_destination(PathBuf::from(v))
+                m.set_destination(v)
                     .set_typ("tmpfs".to_string().into())
                     .set_source(None)
                     .set_options(Some(vec![
@@ -155,14 +215,9 @@ pub fn create_runtime_config(
                         "relatime".into(),
                         "rw".into(),
                     ]));
-                mounts.push(m.clone());
-            }
-            if !mounts.is_empty() {
-                if let Some(default_mounts) = spec.mounts() {
-                    mounts.extend(default_mounts.clone());
-                }
-                spec.set_mounts(Some(mounts));
+                mounts.push(m);
             }
+            spec.set_mounts(Some(mounts));
         }
     }
 
@@ -215,9 +270,187 @@ pub fn create_runtime_config(
     Ok(bundle_config)
 }
 
+/// Merge the environment of the image with the default environment. The
+/// defaults of the variables set by the image are dropped.
+fn merge_env(image_env: &[String], defaults: &[String]) -> Vec<String> {
+    let name = |var: &str| {
+        var.split_once('=')
+            .map_or(var, |(name, _)| name)
+            .to_string()
+    };
+    let names: HashSet<String> = image_env.iter().map(|var| name(var)).collect();
+
+    let mut env = image_env.to_vec();
+    env.extend(
+        defaults
+            .iter()
+            .filter(|var| !names.contains(&name(var)))
+            .cloned(),
+    );
+    env
+}
+
+/// Resolve `path` in `rootfs` as if `rootfs` were `/`, so that symlinks in
+/// the image cannot point out of it.
+fn resolve_in_rootfs(rootfs: &Path, path: &Path) -> Result<PathBuf> {
+    let mut resolved = PathBuf::new();
+    let mut pending: Vec<OsString> = path
+        .components()
+        .rev()
+        .map(|c| c.as_os_str().to_owned())
+        .collect();
+    let mut links = 0;
+
+    while let Some(component) = pending.pop() {
+        match Path::new(&component).components().next() {
+            Some(Component::RootDir) => resolved = PathBuf::new(),
+            Some(Component::ParentDir) => {
+                resolved.pop();
+            }
+            Some(Component::Normal(name)) => {
+                let candidate = resolved.join(name);
+                let full = rootfs.join(&candidate);
+                match fs::symlink_metadata(&full) {
+                    Ok(metadata) if metadata.file_type().is_symlink() => {
+                        links += 1;
+                        if links > MAX_SYMLINKS {
+                            bail!("too many levels of symlinks resolving {path:?}");
+                        }
+                        let target = fs::read_link(&full)?;
+                        pending.extend(target.components().rev().map(|c| c.as_os_str().to_owned()));
+                    }
+                    _ => resolved = candidate,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(rootfs.join(resolved))
+}
+
+/// Read a file of the rootfs, or `None` if it does not exist.
+fn read_rootfs_file(rootfs: &Path, path: &str) -> Result<Option<String>> {
+    let path = resolve_in_rootfs(rootfs, Path::new(path))?;
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).context(format!("failed to read {path:?}")),
+    }
+}
+
+fn parse_passwd(content: &str) -> Vec<PasswdEntry> {
+    content
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(':').collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            Some(PasswdEntry {
+                name: fields[0].to_string(),
+                uid: fields[2].parse().ok()?,
+                gid: fields[3].parse().ok()?,
+                home: fields[5].to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_group(content: &str) -> Vec<GroupEntry> {
+    content
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(':').collect();
+            if fields.len() < 4 {
+                return None;
+            }
+            Some(GroupEntry {
+                name: fields[0].to_string(),
+                gid: fields[2].parse().ok()?,
+                members: fields[3]
+                    .split(',')
+                    .filter(|member| !member.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Resolve the `User` of the image config, i.e. `user[:group]` where both
+/// can be a name or an id, against the passwd and group files of the rootfs.
+/// Ids are used verbatim, and the user defaults to root.
+///
+/// Without a group, the primary group of the user is used, or gid 0 if the
+/// user is not in the passwd file. The groups listing the user as a member
+/// are the additional groups.
+fn resolve_user(rootfs: &Path, user: &str) -> Result<ResolvedUser> {
+    let (user, group) = match user.split_once(':') {
+        Some((user, group)) => (user, Some(group)),
+        None => (user, None),
+    };
+    let user = if user.is_empty() { "0" } else { user };
+
+    let passwd = read_rootfs_file(rootfs, PASSWD_FILE)?
+        .map(|content| parse_passwd(&content))
+        .unwrap_or_default();
+    let entry = match user.parse::<u32>() {
+        Ok(uid) => passwd.iter().find(|entry| entry.uid == uid),
+        Err(_) => Some(
+            passwd
+                .iter()
+                .find(|entry| entry.name == user)
+                .ok_or(anyhow!("user {user} not found in {PASSWD_FILE}"))?,
+        ),
+    };
+    let uid = match entry {
+        Some(entry) => entry.uid,
+        None => user.parse()?,
+    };
+
+    let groups = read_rootfs_file(rootfs, GROUP_FILE)?
+        .map(|content| parse_group(&content))
+        .unwrap_or_default();
+    let gid = match group {
+        Some(group) => match group.parse::<u32>() {
+            Ok(gid) => gid,
+            Err(_) => {
+                groups
+                    .iter()
+                    .find(|entry| entry.name == group)
+                    .ok_or(anyhow!("group {group} not found in {GROUP_FILE}"))?
+                    .gid
+            }
+        },
+        None => entry.map_or(0, |entry| entry.gid),
+    };
+
+    let additional_gids = match entry {
+        Some(entry) => groups
+            .iter()
+            .filter(|group| group.gid != gid && group.members.contains(&entry.name))
+            .map(|group| group.gid)
+            .collect::<BTreeSet<u32>>()
+            .into_iter()
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Ok(ResolvedUser {
+        uid,
+        gid,
+        additional_gids,
+        home: entry.map(|entry| entry.home.clone()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_bundle_create_config() {
@@ -234,4 +467,187 @@ mod tests {
         assert!(create_runtime_config(&image_config, tempdir.path()).is_err());
         assert!(filename.exists());
     }
+
+    const PASSWD: &str = "\
+root:x:0:0:root:/root:/bin/sh
+# comment
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+app:x:1000:1000:app:/home/app:/bin/sh
+malformed:x:1001
+";
+
+    const GROUP: &str = "\
+root:x:0:
+wheel:x:10:root,app
+app:x:1000:
+staff:x:50:app
+audio:x:29:daemon
+";
+
+    fn rootfs() -> tempfile::TempDir {
+        let tempdir = tempfile::tempdir().unwrap();
+        let etc = tempdir.path().join(BUNDLE_ROOTFS).join("etc");
+        fs::create_dir_all(&etc).unwrap();
+        fs::write(etc.join("passwd"), PASSWD).unwrap();
+        fs::write(etc.join("group"), GROUP).unwrap();
+        tempdir
+    }
+
+    #[rstest]
+    #[case("", 0, 0, &[10], Some("/root"))]
+    #[case("root", 0, 0, &[10], Some("/root"))]
+    #[case("app", 1000, 1000, &[10, 50], Some("/home/app"))]
+    #[case("1000", 1000, 1000, &[10, 50], Some("/home/app"))]
+    #[case("app:staff", 1000, 50, &[10], Some("/home/app"))]
+    #[case("app:29", 1000, 29, &[10, 50], Some("/home/app"))]
+    #[case("daemon", 1, 1, &[29], Some("/usr/sbin"))]
+    #[case("2000", 2000, 0, &[], None)]
+    #[case("2000:3000", 2000, 3000, &[], None)]
+    #[case("2000:wheel", 2000, 10, &[], None)]
+    fn test_resolve_user(
+        #[case] user: &str,
+        #[case] uid: u32,
+        #[case] gid: u32,
+        #[case] additional_gids: &[u32],
+        #[case] home: Option<&str>,
+    ) {
+        let tempdir = rootfs();
+        let resolved = resolve_user(&tempdir.path().join(BUNDLE_ROOTFS), user).unwrap();
+        assert_eq!(
+            resolved,
+            ResolvedUser {
+                uid,
+                gid,
+                additional_gids: additional_gids.to_vec(),
+                home: home.map(str::to_string),
+            },
+            "{user}"
+        );
+    }
+
+    #[rstest]
+    #[case("nobody")]
+    #[case("malformed")]
+    #[case("app:nogroup")]
+    #[case("1000:nogroup")]
+    fn test_resolve_unknown_user(#[case] user: &str) {
+        let tempdir = rootfs();
+        assert!(resolve_user(&tempdir.path().join(BUNDLE_ROOTFS), user).is_err());
+    }
+
+    #[test]
+    fn test_resolve_user_without_passwd() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let resolved = resolve_user(tempdir.path(), "").unwrap();
+        assert_eq!((resolved.uid, resolved.gid), (0, 0));
+        assert!(resolve_user(tempdir.path(), "app").is_err());
+    }
+
+    #[test]
+    fn test_resolve_in_rootfs() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let rootfs = tempdir.path().join(BUNDLE_ROOTFS);
+        fs::create_dir_all(rootfs.join("etc")).unwrap();
+        fs::create_dir_all(rootfs.join("usr/lib")).unwrap();
+        fs::write(
+            rootfs.join("usr/lib/passwd"),
+            "app:x:1000:1000::/:/bin/sh\n",
+        )
+        .unwrap();
+        fs::write(tempdir.path().join("passwd"), "host:x:0:0::/:/bin/sh\n").unwrap();
+
+        // Absolute and escaping symlinks stay in the rootfs.
+        std::os::unix::fs::symlink("/../../usr/lib/passwd", rootfs.join("etc/passwd")).unwrap();
+        assert_eq!(
+            resolve_in_rootfs(&rootfs, Path::new("/etc/passwd")).unwrap(),
+            rootfs.join("usr/lib/passwd")
+        );
+        assert_eq!(resolve_user(&rootfs, "app").unwrap().uid, 1000);
+
+        std::os::unix::fs::symlink("../../passwd", rootfs.join("etc/group")).unwrap();
+        assert_eq!(
+            resolve_in_rootfs(&rootfs, Path::new("/etc/group")).unwrap(),
+            rootfs.join("passwd")
+        );
+
+        std::os::unix::fs::symlink("loop", rootfs.join("loop")).unwrap();
+        assert!(resolve_in_rootfs(&rootfs, Path::new("/loop")).is_err());
+    }
+
+    #[test]
+    fn test_merge_env() {
+        let image_env = vec!["PATH=/app/bin".to_string(), "FOO=bar=baz".to_string()];
+        let defaults = vec![
+            "PATH=/usr/bin:/bin".to_string(),
+            "TERM=xterm".to_string(),
+            "HOME=/root".to_string(),
+        ];
+        assert_eq!(
+            merge_env(&image_env, &defaults),
+            vec!["PATH=/app/bin", "FOO=bar=baz", "TERM=xterm", "HOME=/root"]
+        );
+    }
+
+    #[test]
+    fn test_bundle_create_config_from_image_config() {
+        let tempdir = rootfs();
+        let image_config: ImageConfiguration = serde_json::from_value(serde_json::json!({
+            "architecture": "amd64",
+            "os": "linux",
+            "config": {
+                "User": "app",
+                "Env": ["PATH=/app/bin"],
+                "Entrypoint": ["/app/bin/server"],
+                "Cmd": ["--port", "8080"],
+                "WorkingDir": "app",
+                "Volumes": { "/data": {}, "/dev": {}, "cache": {} },
+                "ExposedPorts": { "8080/tcp": {} },
+                "StopSignal": "SIGINT",
+                "Labels": { "org.opencontainers.image.os": "custom" }
+            },
+            "rootfs": { "type": "layers", "diff_ids": [] }
+        }))
+        .unwrap();
+
+        let config = create_runtime_config(&image_config, tempdir.path()).unwrap();
+        let spec = Spec::load(config).unwrap();
+
+        let process = spec.process().as_ref().unwrap();
+        assert_eq!(process.user().uid(), 1000);
+        assert_eq!(process.user().gid(), 1000);
+        assert_eq!(process.user().additional_gids(), &Some(vec![10, 50]));
+        assert_eq!(process.cwd(), &PathBuf::from("/app"));
+        assert_eq!(
+            process.args(),
+            &Some(vec![
+                "/app/bin/server".to_string(),
+                "--port".to_string(),
+                "8080".to_string()
+            ])
+        );
+        let env = process.env().as_ref().unwrap();
+        assert_eq!(env[0], "PATH=/app/bin");
+        assert!(!env.iter().skip(1).any(|var| var.starts_with("PATH=")));
+        assert!(env.contains(&"HOME=/home/app".to_string()));
+
+        let mounts = spec.mounts().as_ref().unwrap();
+        let destinations: Vec<&Path> = mounts.iter().map(|m| m.destination().as_path()).collect();
+        assert_eq!(
+            destinations
+                .iter()
+                .filter(|d| **d == Path::new("/dev"))
+                .count(),
+            1
+        );
+        assert_eq!(
+            destinations[destinations.len() - 2..],
+            [Path::new("/cache"), Path::new("/data")]
+        );
+
+        let annotations = spec.annotations().as_ref().unwrap();
+        assert_eq!(annotations[ANNOTATION_STOP_SIGNAL], "SIGINT");
+        assert_eq!(annotations[ANNOTATION_EXPOSED_PORTS], "8080/tcp");
+        assert_eq!(annotations[ANNOTATION_OS], "custom");
+        assert_eq!(annotations[ANNOTATION_ARCH], "amd64");
+    }
 }
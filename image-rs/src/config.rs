@@ -7,7 +7,9 @@ use serde::Deserialize;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 
+use crate::platform::Platform;
 use crate::snapshots::SnapshotType;
+use crate::unpack::UnpackMode;
 
 /// By default use a work dir in `/run` because for confidential guests `/run`
 /// is typically in a `tmpfs` which is backed by encrypted memory.
@@ -25,6 +27,13 @@ pub const AUTH_FILE_PATH: &str = "kbs:///default/credential/test";
 /// Default max concurrent downloads
 pub const DEFAULT_MAX_CONCURRENT_DOWNLOAD: usize = 3;
 
+/// Default number of retries of a failed layer download
+pub const DEFAULT_LAYER_DOWNLOAD_RETRIES: u32 = 3;
+
+/// Default delay in milliseconds before the first retry of a failed layer
+/// download
+pub const DEFAULT_LAYER_DOWNLOAD_RETRY_DELAY_MS: u64 = 1000;
+
 /// Path to the configuration file to generate ImageConfiguration
 pub const CONFIGURATION_FILE_NAME: &str = "config.json";
 
@@ -63,6 +72,15 @@ pub struct ImageConfig {
     #[serde(default = "Option::default")]
     pub image_security_policy_uri: Option<String>,
 
+    /// The image security policy is evaluated on every pull, including the
+    /// pulls of images that are already stored. If this is set, a successful
+    /// signature verification is reused when the same image reference is
+    /// pulled again under the same policy.
+    ///
+    /// This value defaults to `false`.
+    #[serde(default = "bool::default")]
+    pub cache_signature_verification: bool,
+
     /// Sigstore config file URI for simple signing scheme.
     ///
     /// When `image_security_policy_uri` is set and `SimpleSigning` (signedBy) is
@@ -90,6 +108,63 @@ pub struct ImageConfig {
     #[serde(default = "default_max_concurrent_layer_downloads_per_image")]
     pub max_concurrent_layer_downloads_per_image: usize,
 
+    /// The number of times a failed layer download is retried. The delay
+    /// before a retry starts from `layer_download_retry_delay_ms` and is
+    /// doubled on each further retry.
+    ///
+    /// This defaults to [`DEFAULT_LAYER_DOWNLOAD_RETRIES`].
+    #[serde(default = "default_layer_download_retries")]
+    pub layer_download_retries: u32,
+
+    /// The delay in milliseconds before the first retry of a failed layer
+    /// download.
+    ///
+    /// This defaults to [`DEFAULT_LAYER_DOWNLOAD_RETRY_DELAY_MS`].
+    #[serde(default = "default_layer_download_retry_delay_ms")]
+    pub layer_download_retry_delay_ms: u64,
+
+    /// If set, the compressed layers are downloaded into `work_dir/staging`
+    /// before being unpacked, rather than being unpacked while downloaded.
+    /// A retried download resumes from where the failed one stopped with
+    /// HTTP range requests, and a layer is only unpacked after its
+    /// compressed digest is verified. This needs extra disk space for the
+    /// compressed layers being pulled.
+    ///
+    /// This value defaults to `false`.
+    #[serde(default = "bool::default")]
+    pub stage_layer_downloads: bool,
+
+    /// The platform to pull from a multi-platform image, i.e. an OCI image
+    /// index or a docker manifest list, in the form of
+    /// `os/architecture[/variant][@manifest-digest]`, e.g. `linux/arm64/v8`.
+    /// The optional manifest digest pins the manifest to select from the
+    /// image index.
+    ///
+    /// This value defaults to `None`, which selects the platform image-rs
+    /// runs on.
+    #[serde(default = "Option::default")]
+    pub platform: Option<Platform>,
+
+    /// If set, a pull fails when the image has no manifest of the
+    /// `platform`, rather than falling back to the platform image-rs runs
+    /// on. Single-platform images are checked against the `platform` too.
+    ///
+    /// This value defaults to `false`.
+    #[serde(default = "bool::default")]
+    pub strict_platform: bool,
+
+    /// The mode layers are unpacked in, i.e. the mapping of the uids and
+    /// gids of the layer tarball entries and the representation of
+    /// whiteouts. Unpacking in a user namespace without `CAP_SYS_ADMIN`
+    /// needs the ids mapped into the namespace and `device_nodes` or `files`
+    /// whiteouts.
+    ///
+    /// Layers stored in another mode are unpacked again.
+    ///
+    /// This value defaults to ids applied verbatim and overlayfs whiteouts.
+    #[serde(default = "UnpackMode::default")]
+    pub unpack_mode: UnpackMode,
+
     /// Proxy that will be used to pull image
     ///
     /// If a registry is not accessible to the guest, you can try
@@ -141,6 +216,18 @@ __default_deserialization_value!(
     DEFAULT_MAX_CONCURRENT_DOWNLOAD
 );
 
+__default_deserialization_value!(
+    default_layer_download_retries,
+    u32,
+    DEFAULT_LAYER_DOWNLOAD_RETRIES
+);
+
+__default_deserialization_value!(
+    default_layer_download_retry_delay_ms,
+    u64,
+    DEFAULT_LAYER_DOWNLOAD_RETRY_DELAY_MS
+);
+
 __default_deserialization_value!(default_work_dir, PathBuf, PathBuf::from(DEFAULT_WORK_DIR));
 
 #[cfg(feature = "keywrap-native")]
@@ -159,11 +246,18 @@ impl Default for ImageConfig {
             #[cfg(not(feature = "snapshot-overlayfs"))]
             default_snapshot: SnapshotType::Unknown,
             max_concurrent_layer_downloads_per_image: DEFAULT_MAX_CONCURRENT_DOWNLOAD,
+            layer_download_retries: DEFAULT_LAYER_DOWNLOAD_RETRIES,
+            layer_download_retry_delay_ms: DEFAULT_LAYER_DOWNLOAD_RETRY_DELAY_MS,
+            stage_layer_downloads: false,
+            platform: None,
+            strict_platform: false,
+            unpack_mode: UnpackMode::default(),
             #[cfg(feature = "nydus")]
             nydus_config: Some(NydusConfig::default()),
             #[cfg(not(feature = "nydus"))]
             nydus_config: None,
             image_security_policy_uri: None,
+            cache_signature_verification: false,
             sigstore_config_uri: None,
             authenticated_registry_credentials_uri: None,
             image_pull_proxy: None,
@@ -359,6 +453,7 @@ impl Default for FscacheConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::unpack::WhiteoutMode;
     use std::io::prelude::*;
 
     #[test]
@@ -372,6 +467,12 @@ mod tests {
             config.max_concurrent_layer_downloads_per_image,
             DEFAULT_MAX_CONCURRENT_DOWNLOAD
         );
+        assert_eq!(
+            config.layer_download_retries,
+            DEFAULT_LAYER_DOWNLOAD_RETRIES
+        );
+        assert!(!config.stage_layer_downloads);
+        assert_eq!(config.unpack_mode, UnpackMode::default());
 
         let env_work_dir = "/tmp";
         let config = ImageConfig::new(PathBuf::from(env_work_dir));
@@ -386,7 +487,13 @@ mod tests {
             "default_snapshot": "overlay",
             "image_security_policy_uri": "file:///etc/image-policy.json",
             "authenticated_registry_credentials_uri": "file:///etc/image-auth.json",
-	        "max_concurrent_layer_downloads_per_image": 1
+	        "max_concurrent_layer_downloads_per_image": 1,
+            "platform": "linux/arm64/v8",
+            "strict_platform": true,
+            "unpack_mode": {
+                "uid_mappings": [{ "container_id": 0, "host_id": 100000, "size": 65536 }],
+                "whiteout": "device_nodes"
+            }
         }"#;
 
         let tempdir = tempfile::tempdir().unwrap();
@@ -411,6 +518,11 @@ mod tests {
             config.authenticated_registry_credentials_uri,
             Some("file:///etc/image-auth.json".to_string())
         );
+        assert_eq!(config.platform, Some("linux/arm64/v8".parse().unwrap()));
+        assert!(config.strict_platform);
+        assert_eq!(config.unpack_mode.uid_mappings[0].host_id, 100000);
+        assert!(config.unpack_mode.gid_mappings.is_empty());
+        assert_eq!(config.unpack_mode.whiteout, WhiteoutMode::DeviceNodes);
 
         let invalid_config_file = tempdir.path().join("does-not-exist");
         assert!(!invalid_config_file.exists());
@@ -6,14 +6,30 @@ use std::fmt;
 use std::io;
 
 use anyhow::{bail, Result};
-use oci_client::manifest;
+use oci_client::manifest::{self, OciDescriptor};
 use oci_spec::image::MediaType;
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncRead, BufReader};
 
+pub mod toc;
+
 /// Error message for unhandled media type.
 pub const ERR_BAD_MEDIA_TYPE: &str = "unhandled media type";
 
+/// Media type of uncompressed docker layers.
+pub const DOCKER_LAYER_MEDIA_TYPE: &str = "application/vnd.docker.image.rootfs.diff.tar";
+
+/// Media type of zstd compressed docker layers.
+pub const DOCKER_LAYER_ZSTD_MEDIA_TYPE: &str = "application/vnd.docker.image.rootfs.diff.tar.zstd";
+
+/// Media type of uncompressed foreign (non-distributable) docker layers.
+pub const DOCKER_FOREIGN_LAYER_MEDIA_TYPE: &str =
+    "application/vnd.docker.image.rootfs.foreign.diff.tar";
+
+/// Media type of gzip compressed foreign (non-distributable) docker layers.
+pub const DOCKER_FOREIGN_LAYER_GZIP_MEDIA_TYPE: &str =
+    "application/vnd.docker.image.rootfs.foreign.diff.tar.gzip";
+
 /// Represents the layer compression algorithm type,
 /// and allows to decompress corresponding compressed data.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -22,6 +38,10 @@ pub enum Compression {
     #[default]
     Gzip,
     Zstd,
+    /// zstd:chunked, i.e. zstd with a TOC in a skippable frame.
+    ZstdChunked,
+    /// eStargz, i.e. gzip with a TOC entry and footer.
+    Estargz,
 }
 
 impl fmt::Display for Compression {
@@ -30,6 +50,8 @@ impl fmt::Display for Compression {
             Compression::Uncompressed => "uncompressed",
             Compression::Gzip => "gzip",
             Compression::Zstd => "zstd",
+            Compression::ZstdChunked => "zstd:chunked",
+            Compression::Estargz => "estargz",
         };
 
         write!(f, "{output}")
@@ -46,7 +68,8 @@ impl Compression {
     {
         match self {
             Self::Gzip => gzip_decode(input, output),
-            Self::Zstd => zstd_decode(input, output),
+            Self::Estargz => multi_gzip_decode(input, output),
+            Self::Zstd | Self::ZstdChunked => zstd_decode(input, output),
             Self::Uncompressed => Err(io::Error::new(
                 io::ErrorKind::Other,
                 "uncompressed input data".to_string(),
@@ -60,19 +83,54 @@ impl Compression {
         input: (impl AsyncRead + Unpin + 'a + Send),
     ) -> Box<dyn AsyncRead + Unpin + 'a + Send> {
         match self {
-            Self::Gzip => {
+            Self::Gzip | Self::Estargz => {
                 let mut gzip =
                     async_compression::tokio::bufread::GzipDecoder::new(BufReader::new(input));
                 gzip.multiple_members(true);
                 Box::new(gzip)
             }
-            Self::Zstd => Box::new(async_compression::tokio::bufread::ZstdDecoder::new(
-                BufReader::new(input),
-            )),
+            // zstd:chunked compresses each file into its own frame.
+            Self::Zstd | Self::ZstdChunked => {
+                let mut zstd =
+                    async_compression::tokio::bufread::ZstdDecoder::new(BufReader::new(input));
+                zstd.multiple_members(true);
+                Box::new(zstd)
+            }
             Self::Uncompressed => Box::new(input),
         }
     }
 
+    /// The compression of a layer. zstd:chunked and eStargz layers are told
+    /// from plain zstd and gzip layers by their TOC annotations.
+    pub fn from_descriptor(descriptor: &OciDescriptor) -> Result<Self> {
+        let compression = Self::try_from(descriptor.media_type.as_str())?;
+        let compression = match compression {
+            Self::Zstd if toc::is_zstd_chunked(descriptor) => Self::ZstdChunked,
+            Self::Gzip if toc::is_estargz(descriptor) => Self::Estargz,
+            compression => compression,
+        };
+
+        Ok(compression)
+    }
+
+    /// The TOC of a zstd:chunked or eStargz layer.
+    pub fn toc(&self, descriptor: &OciDescriptor) -> Result<Option<toc::LayerToc>> {
+        match self {
+            Self::ZstdChunked => toc::LayerToc::from_zstd_chunked(descriptor),
+            Self::Estargz => Ok(toc::LayerToc::from_estargz(descriptor)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The entries at the root of the layer tarball that are metadata of the
+    /// layer format rather than part of the rootfs.
+    pub fn metadata_entries(&self) -> &'static [&'static str] {
+        match self {
+            Self::Estargz => toc::ESTARGZ_METADATA_ENTRIES,
+            _ => &[],
+        }
+    }
+
     /// Create an `AsyncRead` to decode input gzip stream.
     pub fn async_gzip_decompress(input: (impl AsyncRead + Unpin)) -> impl AsyncRead + Unpin {
         async_compression::tokio::bufread::GzipDecoder::new(BufReader::new(input))
@@ -95,6 +153,17 @@ where
     Ok(())
 }
 
+// Decompress a gzip encoded data of multiple members with flate2 crate.
+fn multi_gzip_decode<R, W>(input: R, output: &mut W) -> std::io::Result<()>
+where
+    R: io::Read,
+    W: io::Write,
+{
+    let mut decoder = flate2::read::MultiGzDecoder::new(input);
+    io::copy(&mut decoder, output)?;
+    Ok(())
+}
+
 // Decompress a zstd encoded data with zstd crate.
 fn zstd_decode<R, W>(input: R, output: &mut W) -> std::io::Result<()>
 where
@@ -110,12 +179,19 @@ impl TryFrom<&str> for Compression {
     type Error = anyhow::Error;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let mut media_type_str = s;
-
-        // convert docker layer media type to oci format
-        if media_type_str == manifest::IMAGE_DOCKER_LAYER_GZIP_MEDIA_TYPE {
-            media_type_str = manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE;
-        }
+        // convert docker layer media types to oci format
+        let media_type_str = match s {
+            manifest::IMAGE_DOCKER_LAYER_GZIP_MEDIA_TYPE => manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE,
+            DOCKER_LAYER_MEDIA_TYPE => manifest::IMAGE_LAYER_MEDIA_TYPE,
+            DOCKER_FOREIGN_LAYER_MEDIA_TYPE => {
+                "application/vnd.oci.image.layer.nondistributable.v1.tar"
+            }
+            DOCKER_FOREIGN_LAYER_GZIP_MEDIA_TYPE => {
+                "application/vnd.oci.image.layer.nondistributable.v1.tar+gzip"
+            }
+            DOCKER_LAYER_ZSTD_MEDIA_TYPE => "application/vnd.oci.image.layer.v1.tar+zstd",
+            s => s,
+        };
 
         let media_type = MediaType::from(media_type_str);
 
@@ -296,6 +372,22 @@ mod tests {
                 media_type_str: "application/vnd.oci.image.layer.nondistributable.v1.tar+zstd",
                 result: Ok(Compression::Zstd),
             },
+            TestData {
+                media_type_str: DOCKER_LAYER_MEDIA_TYPE,
+                result: Ok(Compression::Uncompressed),
+            },
+            TestData {
+                media_type_str: DOCKER_FOREIGN_LAYER_MEDIA_TYPE,
+                result: Ok(Compression::Uncompressed),
+            },
+            TestData {
+                media_type_str: DOCKER_FOREIGN_LAYER_GZIP_MEDIA_TYPE,
+                result: Ok(Compression::Gzip),
+            },
+            TestData {
+                media_type_str: DOCKER_LAYER_ZSTD_MEDIA_TYPE,
+                result: Ok(Compression::Zstd),
+            },
         ];
 
         for (i, d) in tests.iter().enumerate() {
@@ -308,4 +400,76 @@ mod tests {
             assert_result!(d.result, result, msg);
         }
     }
+
+    #[test]
+    fn test_from_descriptor() {
+        let descriptor = |media_type: &str, annotation: Option<&str>| OciDescriptor {
+            media_type: media_type.to_string(),
+            annotations: annotation.map(|key| {
+                [(key.to_string(), "sha256:1234".to_string())]
+                    .into_iter()
+                    .collect()
+            }),
+            ..Default::default()
+        };
+
+        let zstd = "application/vnd.oci.image.layer.v1.tar+zstd";
+        let layer = descriptor(zstd, None);
+        assert_eq!(
+            Compression::from_descriptor(&layer).unwrap(),
+            Compression::Zstd
+        );
+        assert!(Compression::Zstd.toc(&layer).unwrap().is_none());
+
+        let layer = descriptor(zstd, Some(toc::ZSTD_CHUNKED_MANIFEST_CHECKSUM));
+        let compression = Compression::from_descriptor(&layer).unwrap();
+        assert_eq!(compression, Compression::ZstdChunked);
+        assert_eq!(
+            compression.toc(&layer).unwrap().unwrap().digest,
+            "sha256:1234"
+        );
+
+        let gzip = manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE;
+        let layer = descriptor(gzip, Some(toc::ESTARGZ_TOC_DIGEST));
+        let compression = Compression::from_descriptor(&layer).unwrap();
+        assert_eq!(compression, Compression::Estargz);
+        assert_eq!(
+            compression.metadata_entries(),
+            toc::ESTARGZ_METADATA_ENTRIES
+        );
+
+        // The annotation of another format is ignored.
+        let layer = descriptor(gzip, Some(toc::ZSTD_CHUNKED_MANIFEST_CHECKSUM));
+        assert_eq!(
+            Compression::from_descriptor(&layer).unwrap(),
+            Compression::Gzip
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_multi_frame_decode() {
+        let data: Vec<u8> = b"This is some text!".to_vec();
+        let mut bytes = zstd::encode_all(&data[..9], 1).unwrap();
+        bytes.extend(zstd::encode_all(&data[9..], 1).unwrap());
+
+        let mut output = Vec::new();
+        let mut reader = Compression::ZstdChunked.async_decompress(bytes.as_slice());
+        tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut output)
+            .await
+            .unwrap();
+        assert_eq!(data, output);
+
+        let mut bytes = Vec::new();
+        for chunk in [&data[..9], &data[9..]] {
+            let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(chunk).unwrap();
+            bytes.extend(encoder.finish().unwrap());
+        }
+
+        let mut output = Vec::new();
+        Compression::Estargz
+            .decompress(bytes.as_slice(), &mut output)
+            .unwrap();
+        assert_eq!(data, output);
+    }
 }
@@ -0,0 +1,159 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Table of contents (TOC) annotations of the seekable layer formats
+//! zstd:chunked and eStargz. Both formats are a valid zstd or gzip stream of
+//! the layer tarball, so they are unpacked like plain zstd or gzip layers.
+
+use anyhow::{bail, Context, Result};
+use oci_client::manifest::OciDescriptor;
+use serde::{Deserialize, Serialize};
+
+/// Annotation of a zstd:chunked layer carrying the digest of its TOC.
+pub const ZSTD_CHUNKED_MANIFEST_CHECKSUM: &str =
+    "io.github.containers.zstd-chunked.manifest-checksum";
+
+/// Annotation of a zstd:chunked layer carrying the position of its TOC, in
+/// the form of `offset:length:uncompressed-length:type`.
+pub const ZSTD_CHUNKED_MANIFEST_POSITION: &str =
+    "io.github.containers.zstd-chunked.manifest-position";
+
+/// Annotation of an eStargz layer carrying the digest of its TOC.
+pub const ESTARGZ_TOC_DIGEST: &str = "containerd.io/snapshot/stargz/toc.digest";
+
+/// Entries eStargz adds to the root of the layer tarball, which are not part
+/// of the container rootfs.
+pub const ESTARGZ_METADATA_ENTRIES: &[&str] = &[
+    "stargz.index.json",
+    ".prefetch.landmark",
+    ".no.prefetch.landmark",
+];
+
+/// The TOC of a zstd:chunked or eStargz layer.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerToc {
+    /// Digest of the TOC.
+    pub digest: String,
+
+    /// Offset of the TOC in the compressed layer, if annotated.
+    pub offset: Option<u64>,
+
+    /// Length of the compressed TOC, if annotated.
+    pub length: Option<u64>,
+}
+
+fn annotation<'a>(descriptor: &'a OciDescriptor, key: &str) -> Option<&'a str> {
+    descriptor
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(key))
+        .map(String::as_str)
+}
+
+/// Whether the layer is annotated with a zstd:chunked TOC.
+pub fn is_zstd_chunked(descriptor: &OciDescriptor) -> bool {
+    annotation(descriptor, ZSTD_CHUNKED_MANIFEST_CHECKSUM).is_some()
+}
+
+/// Whether the layer is annotated with an eStargz TOC.
+pub fn is_estargz(descriptor: &OciDescriptor) -> bool {
+    annotation(descriptor, ESTARGZ_TOC_DIGEST).is_some()
+}
+
+impl LayerToc {
+    /// Read the TOC from the zstd:chunked annotations of the layer.
+    pub fn from_zstd_chunked(descriptor: &OciDescriptor) -> Result<Option<Self>> {
+        let Some(digest) = annotation(descriptor, ZSTD_CHUNKED_MANIFEST_CHECKSUM) else {
+            return Ok(None);
+        };
+
+        let (offset, length) = match annotation(descriptor, ZSTD_CHUNKED_MANIFEST_POSITION) {
+            Some(position) => {
+                let mut fields = position.split(':');
+                let (Some(offset), Some(length)) = (fields.next(), fields.next()) else {
+                    bail!("invalid zstd:chunked manifest position {position}");
+                };
+                let offset = offset
+                    .parse()
+                    .with_context(|| format!("invalid zstd:chunked manifest offset {offset}"))?;
+                let length = length
+                    .parse()
+                    .with_context(|| format!("invalid zstd:chunked manifest length {length}"))?;
+                (Some(offset), Some(length))
+            }
+            None => (None, None),
+        };
+
+        Ok(Some(Self {
+            digest: digest.to_string(),
+            offset,
+            length,
+        }))
+    }
+
+    /// Read the TOC from the eStargz annotations of the layer. The position
+    /// of an eStargz TOC is only recorded in the footer of the layer.
+    pub fn from_estargz(descriptor: &OciDescriptor) -> Option<Self> {
+        annotation(descriptor, ESTARGZ_TOC_DIGEST).map(|digest| Self {
+            digest: digest.to_string(),
+            offset: None,
+            length: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn descriptor(annotations: &[(&str, &str)]) -> OciDescriptor {
+        OciDescriptor {
+            annotations: Some(
+                annotations
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&[(ZSTD_CHUNKED_MANIFEST_CHECKSUM, "sha256:1234")], Some((None, None)))]
+    #[case(
+        &[(ZSTD_CHUNKED_MANIFEST_CHECKSUM, "sha256:1234"), (ZSTD_CHUNKED_MANIFEST_POSITION, "100:20:80:1")],
+        Some((Some(100), Some(20)))
+    )]
+    fn zstd_chunked_toc(
+        #[case] annotations: &[(&str, &str)],
+        #[case] position: Option<(Option<u64>, Option<u64>)>,
+    ) {
+        let toc = LayerToc::from_zstd_chunked(&descriptor(annotations)).unwrap();
+        assert_eq!(
+            toc.map(|toc| (toc.offset, toc.length)),
+            position,
+            "{annotations:?}"
+        );
+    }
+
+    #[rstest]
+    #[case("100")]
+    #[case("a:20:80:1")]
+    fn invalid_zstd_chunked_position(#[case] position: &str) {
+        let descriptor = descriptor(&[
+            (ZSTD_CHUNKED_MANIFEST_CHECKSUM, "sha256:1234"),
+            (ZSTD_CHUNKED_MANIFEST_POSITION, position),
+        ]);
+        assert!(LayerToc::from_zstd_chunked(&descriptor).is_err());
+    }
+
+    #[test]
+    fn estargz_toc() {
+        assert!(LayerToc::from_estargz(&descriptor(&[])).is_none());
+        let toc = LayerToc::from_estargz(&descriptor(&[(ESTARGZ_TOC_DIGEST, "sha256:1234")]));
+        assert_eq!(toc.unwrap().digest, "sha256:1234");
+    }
+}
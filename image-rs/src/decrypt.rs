@@ -6,6 +6,9 @@ use anyhow::{bail, Result};
 use oci_client::manifest::{self, OciDescriptor};
 use tokio::io::AsyncRead;
 
+/// The media type of zstd compressed layers, which oci-client has no constant for.
+const IMAGE_LAYER_ZSTD_MEDIA_TYPE: &str = "application/vnd.oci.image.layer.v1.tar+zstd";
+
 /// Image layer encryption type information and associated methods to decrypt image layers.
 #[derive(Default, Clone, Debug)]
 pub struct Decryptor {
@@ -29,12 +32,14 @@ mod encryption {
     use anyhow::anyhow;
     use ocicrypt_rs::config::CryptoConfig;
     use ocicrypt_rs::encryption::{
-        async_decrypt_layer, decrypt_layer, decrypt_layer_key_opts_data,
+        async_decrypt_layer, async_decrypt_layer_key_opts_data, decrypt_layer,
+        decrypt_layer_key_opts_data,
     };
     use ocicrypt_rs::helpers::create_decrypt_config;
     use ocicrypt_rs::spec::{
         MEDIA_TYPE_LAYER_ENC, MEDIA_TYPE_LAYER_GZIP_ENC, MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ENC,
-        MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_GZIP_ENC,
+        MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_GZIP_ENC, MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ZSTD_ENC,
+        MEDIA_TYPE_LAYER_ZSTD_ENC,
     };
     use std::io::Read;
 
@@ -51,6 +56,9 @@ mod encryption {
                 MEDIA_TYPE_LAYER_GZIP_ENC | MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_GZIP_ENC => {
                     (manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE.to_string(), true)
                 }
+                MEDIA_TYPE_LAYER_ZSTD_ENC | MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ZSTD_ENC => {
+                    (IMAGE_LAYER_ZSTD_MEDIA_TYPE.to_string(), true)
+                }
                 _ => ("".to_string(), false),
             };
 
@@ -112,6 +120,31 @@ mod encryption {
             }
         }
 
+        /// This is an async version of [`Decryptor::get_decrypt_key`], which does not block
+        /// on the key providers.
+        pub async fn async_get_decrypt_key(
+            &self,
+            descriptor: &OciDescriptor,
+            decrypt_config: &Option<&str>,
+        ) -> Result<Vec<u8>> {
+            if !self.is_encrypted() {
+                bail!("unencrypted media type: {}", self.media_type);
+            }
+
+            let keys = match decrypt_config {
+                Some(decrypt_config) => vec![decrypt_config.to_string()],
+                None => Vec::new(),
+            };
+
+            let cc = create_decrypt_config(keys, vec![])?;
+            if let Some(decrypt_config) = cc.decrypt_config {
+                async_decrypt_layer_key_opts_data(&decrypt_config, descriptor.annotations.as_ref())
+                    .await
+            } else {
+                Err(anyhow!("failed to retrieve decrypt key!"))
+            }
+        }
+
         pub fn async_get_plaintext_layer(
             &self,
             encrypted_layer: impl AsyncRead + Send,
@@ -214,6 +247,20 @@ mod encryption {
                         encrypted: true,
                     },
                 },
+                TestData {
+                    media_type: MEDIA_TYPE_LAYER_ZSTD_ENC,
+                    result: Decryptor {
+                        media_type: IMAGE_LAYER_ZSTD_MEDIA_TYPE.to_string(),
+                        encrypted: true,
+                    },
+                },
+                TestData {
+                    media_type: MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ZSTD_ENC,
+                    result: Decryptor {
+                        media_type: IMAGE_LAYER_ZSTD_MEDIA_TYPE.to_string(),
+                        encrypted: true,
+                    },
+                },
             ];
 
             for (i, d) in tests.iter().enumerate() {
@@ -337,6 +384,10 @@ impl Decryptor {
             | "application/vnd.oci.image.layer.nondistributable.v1.tar+gzip+encrypted" => {
                 (manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE.to_string(), true)
             }
+            "application/vnd.oci.image.layer.v1.tar+zstd+encrypted"
+            | "application/vnd.oci.image.layer.nondistributable.v1.tar+zstd+encrypted" => {
+                (IMAGE_LAYER_ZSTD_MEDIA_TYPE.to_string(), true)
+            }
             _ => ("".to_string(), false),
         };
 
@@ -369,6 +420,17 @@ impl Decryptor {
         );
     }
 
+    pub async fn async_get_decrypt_key(
+        &self,
+        _descriptor: &OciDescriptor,
+        _decrypt_config: &Option<&str>,
+    ) -> Result<Vec<u8>> {
+        bail!(
+            "no support of encryption, can't handle '{}'",
+            self.media_type
+        );
+    }
+
     pub fn async_get_plaintext_layer(
         &self,
         encrypted_layer: impl AsyncRead,
@@ -18,6 +18,20 @@ pub enum LayerDigestHasher {
     Sha512(sha2::Sha512),
 }
 
+impl LayerDigestHasher {
+    /// Create a hasher for the algorithm of `digest`, or `None` if the
+    /// algorithm is not supported.
+    pub fn new(digest: &str) -> Option<Self> {
+        if digest.starts_with(DIGEST_SHA256_PREFIX) {
+            Some(LayerDigestHasher::Sha256(sha2::Sha256::new()))
+        } else if digest.starts_with(DIGEST_SHA512_PREFIX) {
+            Some(LayerDigestHasher::Sha512(sha2::Sha512::new()))
+        } else {
+            None
+        }
+    }
+}
+
 impl DigestHasher for LayerDigestHasher {
     fn digest_update(&mut self, buf: &[u8]) {
         match self {
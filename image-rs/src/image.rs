@@ -2,8 +2,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, Context, Result};
-use log::info;
+use anyhow::{anyhow, bail, Context, Result};
+use log::{info, warn};
 use oci_client::manifest::{OciDescriptor, OciImageManifest};
 use oci_client::secrets::RegistryAuth;
 use oci_client::Reference;
@@ -12,17 +12,20 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::sync::RwLock;
 
 use crate::auth::Auth;
 use crate::bundle::{create_runtime_config, BUNDLE_ROOTFS};
 use crate::config::{ImageConfig, CONFIGURATION_FILE_NAME, DEFAULT_WORK_DIR};
-use crate::decoder::Compression;
+use crate::decoder::{toc::LayerToc, Compression};
 use crate::meta_store::{MetaStore, METAFILE};
+use crate::platform::Platform;
 use crate::pull::PullClient;
 use crate::signature::SignatureValidator;
-use crate::snapshots::{SnapshotType, Snapshotter};
+use crate::snapshots::{MountPoint, SnapshotType, Snapshotter};
+use crate::unpack::UnpackMode;
 
 #[cfg(feature = "snapshot-unionfs")]
 use crate::snapshots::occlum::unionfs::Unionfs;
@@ -32,6 +35,10 @@ use crate::snapshots::overlay::OverlayFs;
 #[cfg(feature = "nydus")]
 use crate::nydus::{service, utils};
 
+/// How long the leftovers of a pull, e.g. a partially unpacked layer, are
+/// kept before the garbage collection deletes them.
+pub const LEFTOVER_MIN_AGE: Duration = Duration::from_secs(60 * 60);
+
 /// The metadata info for container image layer.
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct LayerMeta {
@@ -49,6 +56,14 @@ pub struct LayerMeta {
 
     /// The image layer storage path.
     pub store_path: String,
+
+    /// The TOC of a zstd:chunked or eStargz layer.
+    #[serde(default)]
+    pub toc: Option<LayerToc>,
+
+    /// The mode the layer is unpacked in.
+    #[serde(default)]
+    pub unpack_mode: UnpackMode,
 }
 
 /// The metadata info for container image.
@@ -71,6 +86,86 @@ pub struct ImageMeta {
 
     /// The metadata of image layers.
     pub layer_metas: Vec<LayerMeta>,
+
+    /// The successful signature verifications of the image, keyed by the
+    /// image reference that was verified.
+    #[serde(default)]
+    pub verifications: HashMap<String, SignatureVerification>,
+
+    /// The rootfs mounts of the bundles created from the image. `None` for
+    /// images stored before the bundles were tracked, whose bundles are
+    /// unknown. Such images are never pruned as unused.
+    #[serde(default)]
+    pub bundles: Option<Vec<MountPoint>>,
+
+    /// The platform of the image, selected from the image index for
+    /// multi-platform images.
+    #[serde(default)]
+    pub platform: Option<Platform>,
+
+    /// The mode the layers of the image are unpacked in.
+    #[serde(default)]
+    pub unpack_mode: UnpackMode,
+}
+
+impl LayerMeta {
+    /// The key of the layer in the layer db.
+    pub fn key(&self) -> String {
+        self.unpack_mode.key_of(&self.compressed_digest)
+    }
+}
+
+impl ImageMeta {
+    /// The key of the image in the image db.
+    pub fn key(&self) -> String {
+        self.unpack_mode.key_of(&self.id)
+    }
+
+    /// Whether `reference_or_id` is the ID of the image, with or without the
+    /// `sha256:` prefix, or a reference the image was pulled by.
+    pub(crate) fn matches(&self, reference_or_id: &str) -> bool {
+        if self.id == reference_or_id || self.id.strip_prefix("sha256:") == Some(reference_or_id) {
+            return true;
+        }
+
+        let Ok(wanted) = Reference::try_from(reference_or_id) else {
+            return false;
+        };
+        std::iter::once(&self.reference)
+            .chain(self.verifications.keys())
+            .any(|reference| {
+                Reference::try_from(reference.as_str())
+                    .is_ok_and(|reference| reference.whole() == wanted.whole())
+            })
+    }
+
+    /// Whether the rootfs of a bundle created from the image may still exist.
+    fn in_use(&self) -> bool {
+        match &self.bundles {
+            Some(bundles) => bundles.iter().any(|bundle| bundle.mount_path.exists()),
+            None => true,
+        }
+    }
+
+    /// Record the rootfs mount of a bundle created from the image. The
+    /// bundles of images stored before the bundles were tracked stay unknown,
+    /// as tracking only the new ones would make the image look unused once
+    /// they are gone.
+    pub(crate) fn add_bundle(&mut self, mount_point: MountPoint) {
+        if let Some(bundles) = &mut self.bundles {
+            bundles.push(mount_point);
+        }
+    }
+}
+
+/// A successful check of an image reference against the image security policy.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SignatureVerification {
+    /// The digest of the image manifest that was verified.
+    pub image_digest: String,
+
+    /// The digest of the image security policy that was used.
+    pub policy_digest: String,
 }
 
 /// The`image-rs` client will support OCI image
@@ -182,8 +277,24 @@ impl ImageClient {
         bundle_dir: &Path,
         auth_info: &Option<&str>,
         decrypt_config: &Option<&str>,
+    ) -> Result<String> {
+        self.pull_image_for_platform(image_url, bundle_dir, auth_info, decrypt_config, None)
+            .await
+    }
+
+    /// Like [`ImageClient::pull_image`], but selects the manifest of
+    /// `platform` from multi-platform images instead of the `platform` in
+    /// self.config.
+    pub async fn pull_image_for_platform(
+        &mut self,
+        image_url: &str,
+        bundle_dir: &Path,
+        auth_info: &Option<&str>,
+        decrypt_config: &Option<&str>,
+        platform: Option<&Platform>,
     ) -> Result<String> {
         let reference = Reference::try_from(image_url)?;
+        let platform = platform.or(self.config.platform.as_ref());
 
         // Try to find a valid registry auth. Logic order
         // 1. the input parameter
@@ -213,10 +324,41 @@ impl ImageClient {
             self.config.skip_proxy_ips.as_deref(),
             self.config.image_pull_proxy.as_deref(),
             self.config.extra_root_certificates.clone(),
-        )?;
-        let (image_manifest, image_digest, image_config) = client.pull_manifest().await?;
+            platform,
+            self.config.strict_platform,
+        )?
+        .with_retries(
+            self.config.layer_download_retries,
+            Duration::from_millis(self.config.layer_download_retry_delay_ms),
+        )
+        .with_unpack_mode(self.config.unpack_mode.clone());
+        if self.config.stage_layer_downloads {
+            client = client.with_staging_dir(self.config.work_dir.join("staging"));
+        }
+        let (image_manifest, image_digest, image_config) = match platform {
+            Some(platform) => client
+                .pull_manifest()
+                .await
+                .with_context(|| format!("failed to pull manifest of platform {platform}"))?,
+            None => client.pull_manifest().await?,
+        };
+
+        if let Some(platform) = platform.filter(|_| self.config.strict_platform) {
+            let config = ImageConfiguration::from_reader(image_config.as_bytes())?;
+            platform.check_pulled_image(&image_digest, &config)?;
+        }
 
         let id = image_manifest.config.digest.clone();
+        // An image is stored once per unpack mode, as its layers are.
+        let key = self.config.unpack_mode.key_of(&id);
+        let meta_file = self.meta_file();
+
+        // The policy is evaluated before an image already stored is reused,
+        // as the policy may have changed, or the image may have been pulled
+        // by another reference.
+        let verification = self
+            .check_image_signature(image_url, &image_digest, &auth, &key)
+            .await?;
 
         let snapshot = match self.snapshots.get_mut(&self.config.default_snapshot) {
             Some(s) => s,
@@ -231,27 +373,42 @@ impl ImageClient {
         #[cfg(feature = "nydus")]
         if utils::is_nydus_image(&image_manifest) {
             {
-                let m = self.meta_store.read().await;
-                if let Some(image_data) = &m.image_db.get(&id) {
-                    return service::create_nydus_bundle(image_data, bundle_dir, snapshot);
+                let mut m = self.meta_store.write().await;
+                if let Some(image_data) = m
+                    .image_db
+                    .get_mut(&key)
+                    .filter(|image| image.unpack_mode == self.config.unpack_mode)
+                {
+                    // The new bundle is only recorded for images whose bundles are tracked.
+                    let mut changed = image_data.bundles.is_some();
+                    let image_id = service::create_nydus_bundle(image_data, bundle_dir, snapshot)?;
+                    if let Some(verification) = verification {
+                        image_data
+                            .verifications
+                            .insert(image_url.to_string(), verification);
+                        changed = true;
+                    }
+                    if changed {
+                        m.write_to_file(&meta_file)
+                            .context("update meta store failed")?;
+                    }
+                    return Ok(image_id);
                 }
             }
 
-            #[cfg(feature = "signature")]
-            if let Some(signature_validator) = &self.signature_validator {
-                signature_validator
-                    .check_image_signature(image_url, &image_digest, &auth)
-                    .await
-                    .context("image security validation failed")?;
-            }
-
             let (mut image_data, _, _) = create_image_meta(
                 &id,
                 image_url,
                 &image_manifest,
                 &image_digest,
                 &image_config,
+                &self.config.unpack_mode,
             )?;
+            if let Some(verification) = verification {
+                image_data
+                    .verifications
+                    .insert(image_url.to_string(), verification);
+            }
 
             return self
                 .do_pull_image_with_nydus(
@@ -266,27 +423,42 @@ impl ImageClient {
 
         // If image has already been populated, just create the bundle.
         {
-            let m = self.meta_store.read().await;
-            if let Some(image_data) = &m.image_db.get(&id) {
-                return create_bundle(image_data, bundle_dir, snapshot);
+            let mut m = self.meta_store.write().await;
+            if let Some(image_data) = m
+                .image_db
+                .get_mut(&key)
+                .filter(|image| image.unpack_mode == self.config.unpack_mode)
+            {
+                // The new bundle is only recorded for images whose bundles are tracked.
+                let mut changed = image_data.bundles.is_some();
+                let image_id = create_bundle(image_data, bundle_dir, snapshot)?;
+                if let Some(verification) = verification {
+                    image_data
+                        .verifications
+                        .insert(image_url.to_string(), verification);
+                    changed = true;
+                }
+                if changed {
+                    m.write_to_file(&meta_file)
+                        .context("update meta store failed")?;
+                }
+                return Ok(image_id);
             }
         }
 
-        #[cfg(feature = "signature")]
-        if let Some(signature_validator) = &self.signature_validator {
-            signature_validator
-                .check_image_signature(image_url, &image_digest, &auth)
-                .await
-                .context("image security validation failed")?;
-        }
-
         let (mut image_data, unique_layers, unique_diff_ids) = create_image_meta(
             &id,
             image_url,
             &image_manifest,
             &image_digest,
             &image_config,
+            &self.config.unpack_mode,
         )?;
+        if let Some(verification) = verification {
+            image_data
+                .verifications
+                .insert(image_url.to_string(), verification);
+        }
 
         let unique_layers_len = unique_layers.len();
         let layer_metas = client
@@ -302,7 +474,7 @@ impl ImageClient {
         let layer_db: HashMap<String, LayerMeta> = image_data
             .layer_metas
             .iter()
-            .map(|layer| (layer.compressed_digest.clone(), layer.clone()))
+            .map(|layer| (layer.key(), layer.clone()))
             .collect();
 
         self.meta_store.write().await.layer_db.extend(layer_db);
@@ -313,20 +485,14 @@ impl ImageClient {
             );
         }
 
-        let image_id = create_bundle(&image_data, bundle_dir, snapshot)?;
+        let image_id = create_bundle(&mut image_data, bundle_dir, snapshot)?;
 
         self.meta_store
             .write()
             .await
             .image_db
-            .insert(image_data.id.clone(), image_data.clone());
+            .insert(image_data.key(), image_data.clone());
 
-        let meta_file = self
-            .config
-            .work_dir
-            .join(METAFILE)
-            .to_string_lossy()
-            .to_string();
         self.meta_store
             .write()
             .await
@@ -335,6 +501,221 @@ impl ImageClient {
         Ok(image_id)
     }
 
+    /// The metadata of all the stored images.
+    pub async fn list_images(&self) -> Vec<ImageMeta> {
+        self.meta_store.read().await.images()
+    }
+
+    /// The metadata of the stored image given by its reference or ID.
+    pub async fn inspect_image(&self, reference_or_id: &str) -> Result<ImageMeta> {
+        self.meta_store.read().await.find_image(reference_or_id)
+    }
+
+    /// The meta store of the client. Reading it does not need exclusive
+    /// access to the client, so it is not blocked by a pull in progress.
+    pub fn meta_store(&self) -> Arc<RwLock<MetaStore>> {
+        self.meta_store.clone()
+    }
+
+    /// Remove the image given by its reference or ID, in all the unpack
+    /// modes it is stored in. The rootfs of the bundles created from the
+    /// image are unmounted first, and the layers no longer used by any image
+    /// are deleted afterwards.
+    pub async fn remove_image(&mut self, reference_or_id: &str) -> Result<()> {
+        let snapshot = self.default_snapshot()?;
+        let mut m = self.meta_store.write().await;
+        let id = m
+            .image_db
+            .values()
+            .find(|image| image.matches(reference_or_id))
+            .map(|image| image.id.clone())
+            .ok_or_else(|| anyhow!("image {reference_or_id} not found"))?;
+        let keys: Vec<String> = m
+            .image_db
+            .iter()
+            .filter(|(_, image)| image.id == id)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut res = Ok(());
+        for key in keys {
+            let image_data = m.image_db.get_mut(&key).expect("image just found");
+            match unmount_bundles(image_data, snapshot) {
+                Ok(()) => {
+                    m.image_db.remove(&key);
+                    info!("Image {key} removed");
+                }
+                Err(e) => {
+                    res = Err(e.context(format!("failed to tear down the bundles of image {key}")))
+                }
+            }
+        }
+        m.write_to_file(&self.meta_file())
+            .context("update meta store failed")?;
+        drop(m);
+        res?;
+
+        self.prune_layers().await?;
+        Ok(())
+    }
+
+    /// Remove the images of which no bundle rootfs exists anymore, as well as
+    /// the layers no longer used. Returns the IDs of the removed images.
+    pub async fn prune_unused_images(&mut self) -> Result<Vec<String>> {
+        let snapshot = self.default_snapshot()?;
+        let mut m = self.meta_store.write().await;
+        let mut removed = Vec::new();
+        m.image_db.retain(|id, image_data| {
+            if image_data.in_use() {
+                return true;
+            }
+
+            if let Err(e) = unmount_bundles(image_data, snapshot) {
+                warn!("Failed to tear down the bundles of image {id}: {e:#}");
+                return true;
+            }
+
+            removed.push(id.clone());
+            false
+        });
+        m.write_to_file(&self.meta_file())
+            .context("update meta store failed")?;
+        drop(m);
+
+        if !removed.is_empty() {
+            info!("Unused images removed: {removed:?}");
+        }
+        self.prune_layers().await?;
+        Ok(removed)
+    }
+
+    /// Delete the layers not referenced by any stored image, as well as the
+    /// leftovers of failed pulls, i.e. the layer dirs not recorded in the meta
+    /// store and the staged layer downloads. Leftovers are only deleted once
+    /// untouched for [`LEFTOVER_MIN_AGE`], as they may belong to a pull in
+    /// progress. Returns the number of deleted layers.
+    pub async fn prune_layers(&mut self) -> Result<usize> {
+        let mut m = self.meta_store.write().await;
+        let ref_counts = m.layer_ref_counts();
+        let unused: Vec<String> = m
+            .layer_db
+            .keys()
+            .filter(|digest| !ref_counts.contains_key(digest.as_str()))
+            .cloned()
+            .collect();
+
+        for digest in &unused {
+            let layer = m.layer_db.remove(digest).expect("layer just listed");
+            remove_dir(Path::new(&layer.store_path))?;
+        }
+
+        let layers_dir = self.config.work_dir.join("layers");
+        let mut pruned = unused.len();
+        if layers_dir.exists() {
+            let stored: BTreeSet<PathBuf> = m
+                .layer_db
+                .values()
+                .map(|layer| PathBuf::from(&layer.store_path))
+                .collect();
+            for entry in std::fs::read_dir(&layers_dir)? {
+                let path = entry?.path();
+                if path.is_dir() && !stored.contains(&path) && is_stale(&path) {
+                    remove_dir(&path)?;
+                    pruned += 1;
+                }
+            }
+        }
+
+        let staging_dir = self.config.work_dir.join("staging");
+        if staging_dir.exists() {
+            for entry in std::fs::read_dir(&staging_dir)? {
+                let path = entry?.path();
+                if path.is_file() && is_stale(&path) {
+                    info!("Delete stale staged layer {}", path.display());
+                    std::fs::remove_file(&path)
+                        .with_context(|| format!("remove {}", path.display()))?;
+                }
+            }
+        }
+
+        m.write_to_file(&self.meta_file())
+            .context("update meta store failed")?;
+        if pruned != 0 {
+            info!("{pruned} unused layers deleted");
+        }
+        Ok(pruned)
+    }
+
+    fn default_snapshot(&self) -> Result<&dyn Snapshotter> {
+        match self.snapshots.get(&self.config.default_snapshot) {
+            Some(s) => Ok(s.as_ref()),
+            None => bail!(
+                "default snapshot {} not found",
+                &self.config.default_snapshot
+            ),
+        }
+    }
+
+    fn meta_file(&self) -> String {
+        self.config
+            .work_dir
+            .join(METAFILE)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// Check the image against the image security policy, if any. A
+    /// verification recorded for the same reference, manifest and policy is
+    /// only reused if `cache_signature_verification` is set.
+    ///
+    /// Returns the verification to record for `image_url`.
+    #[cfg(feature = "signature")]
+    async fn check_image_signature(
+        &self,
+        image_url: &str,
+        image_digest: &str,
+        auth: &RegistryAuth,
+        key: &str,
+    ) -> Result<Option<SignatureVerification>> {
+        let Some(signature_validator) = &self.signature_validator else {
+            return Ok(None);
+        };
+
+        let verification = SignatureVerification {
+            image_digest: image_digest.to_string(),
+            policy_digest: signature_validator.policy_digest().to_string(),
+        };
+
+        if self.config.cache_signature_verification {
+            let m = self.meta_store.read().await;
+            let cached = m
+                .image_db
+                .get(key)
+                .and_then(|image| image.verifications.get(image_url));
+            if cached == Some(&verification) {
+                info!("Reuse the signature verification of {image_url}");
+                return Ok(None);
+            }
+        }
+
+        signature_validator
+            .check_image_signature(image_url, image_digest, auth)
+            .await
+            .context("image security validation failed")?;
+        Ok(Some(verification))
+    }
+
+    #[cfg(not(feature = "signature"))]
+    async fn check_image_signature(
+        &self,
+        _image_url: &str,
+        _image_digest: &str,
+        _auth: &RegistryAuth,
+        _key: &str,
+    ) -> Result<Option<SignatureVerification>> {
+        Ok(None)
+    }
+
     #[cfg(feature = "nydus")]
     async fn do_pull_image_with_nydus<'a>(
         &mut self,
@@ -365,7 +746,7 @@ impl ImageClient {
         let layer_db: HashMap<String, LayerMeta> = image_data
             .layer_metas
             .iter()
-            .map(|layer| (layer.compressed_digest.clone(), layer.clone()))
+            .map(|layer| (layer.key(), layer.clone()))
             .collect();
 
         self.meta_store.write().await.layer_db.extend(layer_db);
@@ -403,12 +784,54 @@ impl ImageClient {
             .write()
             .await
             .image_db
-            .insert(image_data.id.clone(), image_data.clone());
+            .insert(image_data.key(), image_data.clone());
 
         Ok(image_id)
     }
 }
 
+/// Unmount the bundles of the image. The bundles that fail to be unmounted
+/// are kept in the image meta.
+fn unmount_bundles(image_data: &mut ImageMeta, snapshot: &dyn Snapshotter) -> Result<()> {
+    let Some(bundles) = &mut image_data.bundles else {
+        warn!(
+            "The bundles of image {} are unknown and not unmounted",
+            image_data.id
+        );
+        return Ok(());
+    };
+
+    let mut res = Ok(());
+    bundles.retain(|bundle| match snapshot.unmount(bundle) {
+        Ok(()) => false,
+        Err(e) => {
+            res = Err(e.context(format!("unmount {}", bundle.mount_path.display())));
+            true
+        }
+    });
+    res
+}
+
+/// Whether the leftover of a pull at `path` has not been touched for
+/// [`LEFTOVER_MIN_AGE`].
+fn is_stale(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age >= LEFTOVER_MIN_AGE)
+}
+
+/// Remove a layer directory, which may be gone already.
+fn remove_dir(path: &Path) -> Result<()> {
+    match std::fs::remove_dir_all(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(e).with_context(|| format!("remove {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Create image meta object with the image info
 /// Return the image meta object, oci descriptors of the unique layers, and unique diff ids.
 fn create_image_meta(
@@ -417,12 +840,17 @@ fn create_image_meta(
     image_manifest: &OciImageManifest,
     image_digest: &str,
     image_config: &str,
+    unpack_mode: &UnpackMode,
 ) -> Result<(ImageMeta, Vec<OciDescriptor>, Vec<String>)> {
+    let image_config = ImageConfiguration::from_reader(image_config.as_bytes())?;
     let image_data = ImageMeta {
         id: id.to_string(),
         digest: image_digest.to_string(),
         reference: image_url.to_string(),
-        image_config: ImageConfiguration::from_reader(image_config.as_bytes())?,
+        platform: Some(Platform::from_image_config(&image_config)),
+        image_config,
+        bundles: Some(Vec::new()),
+        unpack_mode: unpack_mode.clone(),
         ..Default::default()
     };
 
@@ -459,7 +887,7 @@ fn create_image_meta(
 }
 
 fn create_bundle(
-    image_data: &ImageMeta,
+    image_data: &mut ImageMeta,
     bundle_dir: &Path,
     snapshot: &mut Box<dyn Snapshotter>,
 ) -> Result<String> {
@@ -470,14 +898,26 @@ fn create_bundle(
         .map(|l| l.store_path.as_str())
         .collect::<Vec<&str>>();
 
-    snapshot.mount(&layer_path, &bundle_dir.join(BUNDLE_ROOTFS))?;
-
     let image_config = image_data.image_config.clone();
     if image_config.os() != &Os::Linux {
         bail!("unsupport OS image {:?}", image_config.os());
     }
 
-    create_runtime_config(&image_config, bundle_dir)?;
+    let mount_point = snapshot.mount(
+        &layer_path,
+        &bundle_dir.join(BUNDLE_ROOTFS),
+        &image_data.unpack_mode,
+    )?;
+
+    // The rootfs is not recorded yet, so it would be leaked if kept mounted.
+    if let Err(e) = create_runtime_config(&image_config, bundle_dir) {
+        if let Err(e) = snapshot.unmount(&mount_point) {
+            warn!("Failed to unmount the bundle rootfs: {e:#}");
+        }
+        return Err(e);
+    }
+    image_data.add_bundle(mount_point);
+
     let image_id = image_data.id.clone();
     Ok(image_id)
 }
@@ -661,4 +1101,454 @@ mod tests {
         // This confirms that the second image client reused the meta store and layers from the first image client
         assert!(!work_dir_2.path().join("layers").exists());
     }
+
+    #[tokio::test]
+    async fn test_pull_image_in_two_unpack_modes() {
+        if !live_image_pull_tests_enabled() {
+            eprintln!(
+                "skipping live image pull test; set {}=1 to run it",
+                LIVE_IMAGE_PULL_TESTS_ENV
+            );
+            return;
+        }
+
+        let work_dir = tempfile::tempdir().unwrap();
+        let image = "docker.io/library/busybox:latest";
+        let mut image_client = ImageClient::new(work_dir.path().to_path_buf());
+        let mapping = crate::unpack::IdMapping {
+            container_id: 0,
+            host_id: 100000,
+            size: 65536,
+        };
+        let mapped = UnpackMode {
+            uid_mappings: vec![mapping.clone()],
+            gid_mappings: vec![mapping],
+            ..Default::default()
+        };
+
+        let mut bundle_dirs = Vec::new();
+        for unpack_mode in [UnpackMode::default(), mapped, UnpackMode::default()] {
+            image_client.config.unpack_mode = unpack_mode;
+            let bundle_dir = tempfile::tempdir().unwrap();
+            image_client
+                .pull_image(image, bundle_dir.path(), &None, &None)
+                .await
+                .unwrap();
+            bundle_dirs.push(bundle_dir);
+        }
+
+        // The image and its layers are stored once per mode, and the last
+        // pull reuses the image stored in the default mode.
+        let m = image_client.meta_store.read().await;
+        assert_eq!(m.image_db.len(), 2);
+        let stored = m
+            .image_db
+            .values()
+            .find(|image| image.unpack_mode == UnpackMode::default())
+            .unwrap();
+        assert_eq!(stored.bundles.as_ref().unwrap().len(), 2);
+        assert_eq!(m.layer_db.len(), 2 * stored.layer_metas.len());
+        for layer in m.layer_db.values() {
+            assert!(Path::new(&layer.store_path).exists());
+        }
+    }
+
+    /// A snapshotter that only records the rootfs it has mounted.
+    struct FakeSnapshotter {
+        mounted: Arc<std::sync::Mutex<Vec<PathBuf>>>,
+    }
+
+    impl Snapshotter for FakeSnapshotter {
+        fn mount(
+            &mut self,
+            _layer_path: &[&str],
+            mount_path: &Path,
+            _unpack_mode: &UnpackMode,
+        ) -> Result<MountPoint> {
+            fs::create_dir_all(mount_path)?;
+            self.mounted.lock().unwrap().push(mount_path.to_path_buf());
+            Ok(MountPoint {
+                r#type: "fake".to_string(),
+                mount_path: mount_path.to_path_buf(),
+                work_dir: PathBuf::new(),
+            })
+        }
+
+        fn unmount(&self, mount_point: &MountPoint) -> Result<()> {
+            self.mounted
+                .lock()
+                .unwrap()
+                .retain(|path| *path != mount_point.mount_path);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn create_bundle_unmounts_rootfs_on_failure() {
+        let image_config = ImageConfiguration::from_reader(
+            r#"{"architecture":"amd64","os":"linux","config":{"User":"app"},"rootfs":{"type":"layers","diff_ids":[]}}"#
+                .as_bytes(),
+        )
+        .unwrap();
+        let mut image_data = ImageMeta {
+            image_config,
+            bundles: Some(Vec::new()),
+            ..Default::default()
+        };
+        let mounted = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut snapshot: Box<dyn Snapshotter> = Box::new(FakeSnapshotter {
+            mounted: mounted.clone(),
+        });
+        let bundle_dir = tempfile::tempdir().unwrap();
+
+        // The user is not in the /etc/passwd of the rootfs
+        assert!(create_bundle(&mut image_data, bundle_dir.path(), &mut snapshot).is_err());
+        assert!(mounted.lock().unwrap().is_empty());
+        assert!(image_data.bundles.as_ref().unwrap().is_empty());
+
+        let rootfs = bundle_dir.path().join(BUNDLE_ROOTFS);
+        fs::create_dir_all(rootfs.join("etc")).unwrap();
+        fs::write(
+            rootfs.join("etc/passwd"),
+            "app:x:1000:1000:app:/home/app:/bin/sh\n",
+        )
+        .unwrap();
+        create_bundle(&mut image_data, bundle_dir.path(), &mut snapshot).unwrap();
+        assert_eq!(*mounted.lock().unwrap(), vec![rootfs]);
+        assert_eq!(image_data.bundles.as_ref().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_and_inspect_image() {
+        let work_dir = tempfile::tempdir().unwrap();
+        let image_client = ImageClient::new(work_dir.path().to_path_buf());
+        let image = ImageMeta {
+            id: "sha256:1234".to_string(),
+            reference: "docker.io/library/busybox:latest".to_string(),
+            ..Default::default()
+        };
+        image_client
+            .meta_store
+            .write()
+            .await
+            .image_db
+            .insert(image.id.clone(), image);
+
+        assert_eq!(image_client.list_images().await.len(), 1);
+        for wanted in [
+            "sha256:1234",
+            "1234",
+            "busybox",
+            "docker.io/library/busybox",
+        ] {
+            let image = image_client.inspect_image(wanted).await.unwrap();
+            assert_eq!(image.id, "sha256:1234");
+        }
+        assert!(image_client.inspect_image("alpine").await.is_err());
+    }
+
+    /// Store an image of ID `sha256:<name>` and reference `<name>`, made of
+    /// `layers`, with its layers unpacked under the work dir.
+    async fn store_image(
+        image_client: &ImageClient,
+        name: &str,
+        layers: &[&str],
+        bundles: Option<Vec<MountPoint>>,
+    ) {
+        let mut m = image_client.meta_store.write().await;
+        let mut image = ImageMeta {
+            id: format!("sha256:{name}"),
+            reference: name.to_string(),
+            bundles,
+            ..Default::default()
+        };
+        for digest in layers {
+            let layer = store_layer(image_client, &mut m, digest);
+            image.layer_metas.push(layer);
+        }
+        m.image_db.insert(image.id.clone(), image);
+    }
+
+    fn store_layer(image_client: &ImageClient, m: &mut MetaStore, digest: &str) -> LayerMeta {
+        let store_path = image_client.config.work_dir.join("layers").join(digest);
+        fs::create_dir_all(&store_path).unwrap();
+        let layer = LayerMeta {
+            compressed_digest: digest.to_string(),
+            store_path: store_path.display().to_string(),
+            ..Default::default()
+        };
+        m.layer_db.insert(digest.to_string(), layer.clone());
+        layer
+    }
+
+    fn make_stale(path: &Path) {
+        let modified = std::time::SystemTime::now() - LEFTOVER_MIN_AGE * 2;
+        fs::File::open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_remove_image() {
+        let work_dir = tempfile::tempdir().unwrap();
+        let mut image_client = ImageClient::new(work_dir.path().to_path_buf());
+        store_image(&image_client, "busybox", &["l1", "l2"], Some(vec![])).await;
+        store_image(&image_client, "alpine", &["l2"], Some(vec![])).await;
+
+        image_client.remove_image("busybox").await.unwrap();
+        assert!(image_client.remove_image("busybox").await.is_err());
+
+        let m = image_client.meta_store.read().await;
+        assert_eq!(m.image_db.keys().collect::<Vec<_>>(), vec!["sha256:alpine"]);
+        assert_eq!(m.layer_db.keys().collect::<Vec<_>>(), vec!["l2"]);
+        let layers_dir = work_dir.path().join("layers");
+        assert!(!layers_dir.join("l1").exists());
+        assert!(layers_dir.join("l2").exists());
+
+        // The removal is persisted
+        let stored = MetaStore::try_from(work_dir.path().join(METAFILE).as_path()).unwrap();
+        assert!(!stored.image_db.contains_key("sha256:busybox"));
+    }
+
+    #[tokio::test]
+    async fn prune_unused_images_keeps_legacy_images() {
+        let work_dir = tempfile::tempdir().unwrap();
+        let rootfs = tempfile::tempdir().unwrap();
+        let mut image_client = ImageClient::new(work_dir.path().to_path_buf());
+
+        // An image stored before the bundles were tracked
+        let mut legacy = serde_json::to_value(ImageMeta {
+            id: "sha256:legacy".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        legacy.as_object_mut().unwrap().remove("bundles");
+        let legacy: ImageMeta = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.bundles.is_none());
+        image_client
+            .meta_store
+            .write()
+            .await
+            .image_db
+            .insert(legacy.id.clone(), legacy);
+
+        let bundle = MountPoint {
+            r#type: "overlay".to_string(),
+            mount_path: rootfs.path().to_path_buf(),
+            work_dir: work_dir.path().join("overlay/1"),
+        };
+        store_image(&image_client, "in-use", &["l1"], Some(vec![bundle])).await;
+        store_image(&image_client, "unused", &["l2"], Some(vec![])).await;
+
+        let removed = image_client.prune_unused_images().await.unwrap();
+        assert_eq!(removed, vec!["sha256:unused"]);
+
+        let m = image_client.meta_store.read().await;
+        assert!(m.image_db.contains_key("sha256:legacy"));
+        assert!(m.image_db.contains_key("sha256:in-use"));
+        assert!(m.layer_db.contains_key("l1"));
+        assert!(!m.layer_db.contains_key("l2"));
+    }
+
+    #[tokio::test]
+    async fn test_prune_layers() {
+        let work_dir = tempfile::tempdir().unwrap();
+        let mut image_client = ImageClient::new(work_dir.path().to_path_buf());
+        store_image(&image_client, "busybox", &["used"], Some(vec![])).await;
+        store_layer(
+            &image_client,
+            &mut *image_client.meta_store.write().await,
+            "unused",
+        );
+
+        // Leftovers of failed pulls
+        let layers_dir = work_dir.path().join("layers");
+        let staging_dir = work_dir.path().join("staging");
+        fs::create_dir_all(layers_dir.join("stale")).unwrap();
+        fs::create_dir_all(layers_dir.join("in-progress")).unwrap();
+        fs::create_dir_all(&staging_dir).unwrap();
+        fs::write(staging_dir.join("stale"), b"partial").unwrap();
+        fs::write(staging_dir.join("in-progress"), b"partial").unwrap();
+        make_stale(&layers_dir.join("stale"));
+        make_stale(&staging_dir.join("stale"));
+
+        assert_eq!(image_client.prune_layers().await.unwrap(), 2);
+        assert!(layers_dir.join("used").exists());
+        assert!(!layers_dir.join("unused").exists());
+        assert!(!layers_dir.join("stale").exists());
+        assert!(layers_dir.join("in-progress").exists());
+        assert!(!staging_dir.join("stale").exists());
+        assert!(staging_dir.join("in-progress").exists());
+        assert_eq!(
+            image_client
+                .meta_store
+                .read()
+                .await
+                .layer_db
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["used"]
+        );
+    }
+
+    #[cfg(feature = "signature")]
+    const ACCEPT_POLICY: &str =
+        r#"{"default": [{"type": "insecureAcceptAnything"}], "transports": {}}"#;
+
+    #[cfg(feature = "signature")]
+    const REJECT_POLICY: &str = r#"{"default": [{"type": "reject"}], "transports": {}}"#;
+
+    #[cfg(feature = "signature")]
+    const BUSYBOX_DIGEST: &str =
+        "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    /// An image client under the given image security policy, with an image
+    /// of ID `sha256:1234` stored.
+    #[cfg(feature = "signature")]
+    async fn client_with_policy(
+        work_dir: &Path,
+        policy: &str,
+        cache_signature_verification: bool,
+    ) -> ImageClient {
+        let policy_path = work_dir.join("policy.json");
+        fs::write(&policy_path, policy).unwrap();
+        let image_client = crate::builder::ClientBuilder::default()
+            .work_dir(work_dir.to_path_buf())
+            .image_security_policy_uri(format!("file://{}", policy_path.display()))
+            .cache_signature_verification(cache_signature_verification)
+            .build()
+            .await
+            .unwrap();
+
+        let image = ImageMeta {
+            id: "sha256:1234".to_string(),
+            reference: "docker.io/library/busybox:latest".to_string(),
+            ..Default::default()
+        };
+        image_client
+            .meta_store
+            .write()
+            .await
+            .image_db
+            .insert(image.id.clone(), image);
+        image_client
+    }
+
+    #[cfg(feature = "signature")]
+    #[tokio::test]
+    async fn cached_image_is_checked_against_stricter_policy() {
+        let image = "docker.io/library/busybox:latest";
+
+        let work_dir = tempfile::tempdir().unwrap();
+        let image_client = client_with_policy(work_dir.path(), ACCEPT_POLICY, true).await;
+        let verification = image_client
+            .check_image_signature(
+                image,
+                BUSYBOX_DIGEST,
+                &RegistryAuth::Anonymous,
+                "sha256:1234",
+            )
+            .await
+            .unwrap()
+            .expect("a new verification is recorded");
+
+        // The image is stored together with the verification under the old
+        // policy, but the new policy rejects it.
+        let work_dir = tempfile::tempdir().unwrap();
+        let image_client = client_with_policy(work_dir.path(), REJECT_POLICY, true).await;
+        image_client
+            .meta_store
+            .write()
+            .await
+            .image_db
+            .get_mut("sha256:1234")
+            .unwrap()
+            .verifications
+            .insert(image.to_string(), verification);
+        assert!(image_client
+            .check_image_signature(
+                image,
+                BUSYBOX_DIGEST,
+                &RegistryAuth::Anonymous,
+                "sha256:1234"
+            )
+            .await
+            .is_err());
+    }
+
+    #[cfg(feature = "signature")]
+    #[rstest::rstest]
+    #[case::same_reference_and_policy(
+        "docker.io/library/busybox:latest",
+        BUSYBOX_DIGEST,
+        None,
+        true,
+        true
+    )]
+    #[case::other_reference("docker.io/library/busybox:1.36", BUSYBOX_DIGEST, None, true, false)]
+    #[case::other_manifest("docker.io/library/busybox:latest", "sha256:5678", None, true, false)]
+    #[case::other_policy(
+        "docker.io/library/busybox:latest",
+        BUSYBOX_DIGEST,
+        Some("sha256:5678"),
+        true,
+        false
+    )]
+    #[case::caching_disabled(
+        "docker.io/library/busybox:latest",
+        BUSYBOX_DIGEST,
+        None,
+        false,
+        false
+    )]
+    #[tokio::test]
+    async fn cached_verification_is_reused(
+        #[case] image: &str,
+        #[case] verified_image_digest: &str,
+        #[case] verified_policy_digest: Option<&str>,
+        #[case] cache_signature_verification: bool,
+        #[case] reused: bool,
+    ) {
+        // Under a policy that rejects every image, only a reused verification
+        // lets the check pass.
+        let work_dir = tempfile::tempdir().unwrap();
+        let image_client =
+            client_with_policy(work_dir.path(), REJECT_POLICY, cache_signature_verification).await;
+        let policy_digest = image_client
+            .signature_validator
+            .as_ref()
+            .unwrap()
+            .policy_digest()
+            .to_string();
+        let verification = SignatureVerification {
+            image_digest: verified_image_digest.to_string(),
+            policy_digest: verified_policy_digest
+                .map(String::from)
+                .unwrap_or(policy_digest),
+        };
+        image_client
+            .meta_store
+            .write()
+            .await
+            .image_db
+            .get_mut("sha256:1234")
+            .unwrap()
+            .verifications
+            .insert("docker.io/library/busybox:latest".to_string(), verification);
+
+        let res = image_client
+            .check_image_signature(
+                image,
+                BUSYBOX_DIGEST,
+                &RegistryAuth::Anonymous,
+                "sha256:1234",
+            )
+            .await;
+        if reused {
+            assert!(res.unwrap().is_none());
+        } else {
+            assert!(res.is_err());
+        }
+    }
 }
@@ -15,6 +15,7 @@ pub mod image;
 pub mod meta_store;
 #[cfg(feature = "nydus")]
 pub mod nydus;
+pub mod platform;
 pub mod pull;
 pub mod resource;
 #[cfg(feature = "signature")]
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::path::Path;
 
@@ -33,6 +33,33 @@ impl TryFrom<&Path> for MetaStore {
 }
 
 impl MetaStore {
+    /// The metadata of all the stored images.
+    pub fn images(&self) -> Vec<ImageMeta> {
+        self.image_db.values().cloned().collect()
+    }
+
+    /// The metadata of the stored image given by its reference or ID.
+    pub fn find_image(&self, reference_or_id: &str) -> Result<ImageMeta> {
+        self.image_db
+            .values()
+            .find(|image| image.matches(reference_or_id))
+            .cloned()
+            .ok_or_else(|| anyhow!("image {reference_or_id} not found"))
+    }
+
+    /// The number of stored images using each layer, keyed by the key of the
+    /// layer in `layer_db`. Layers not used by any image are absent.
+    pub fn layer_ref_counts(&self) -> HashMap<String, usize> {
+        let mut ref_counts = HashMap::new();
+        for image in self.image_db.values() {
+            let keys: HashSet<String> = image.layer_metas.iter().map(LayerMeta::key).collect();
+            for key in keys {
+                *ref_counts.entry(key).or_insert(0) += 1;
+            }
+        }
+        ref_counts
+    }
+
     pub fn write_to_file(&self, path: &str) -> Result<()> {
         let file = File::create(path)
             .map_err(|e| anyhow!("failed to create metastore file: {}", e.to_string()))?;
@@ -40,3 +67,37 @@ impl MetaStore {
             .map_err(|e| anyhow!("failed to write metastore to file: {}", e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MetaStore;
+    use crate::image::{ImageMeta, LayerMeta};
+
+    fn image(id: &str, layers: &[&str]) -> ImageMeta {
+        ImageMeta {
+            id: id.to_string(),
+            layer_metas: layers
+                .iter()
+                .map(|digest| LayerMeta {
+                    compressed_digest: digest.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn layer_ref_counts() {
+        let mut meta_store = MetaStore::default();
+        for image in [image("a", &["l1", "l2"]), image("b", &["l2", "l3", "l3"])] {
+            meta_store.image_db.insert(image.id.clone(), image);
+        }
+
+        let ref_counts = meta_store.layer_ref_counts();
+        assert_eq!(ref_counts.len(), 3);
+        assert_eq!(ref_counts["l1"], 1);
+        assert_eq!(ref_counts["l2"], 2);
+        assert_eq!(ref_counts["l3"], 1);
+    }
+}
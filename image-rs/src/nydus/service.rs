@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{anyhow, bail, Result};
-use log::{error, info};
+use log::{error, info, warn};
 use nix::mount::MsFlags;
 use oci_client::Reference;
 use oci_spec::image::Os;
@@ -24,6 +24,7 @@ use crate::bundle::{create_runtime_config, BUNDLE_ROOTFS};
 use crate::config::{FscacheConfig, FuseConfig, NydusConfig};
 use crate::image::ImageMeta;
 use crate::snapshots::Snapshotter;
+use crate::unpack::UnpackMode;
 
 pub const NYDUS_ROOTFS: &str = "nydus_rootfs";
 
@@ -33,7 +34,7 @@ lazy_static::lazy_static! {
 }
 
 pub async fn start_nydus_service(
-    image_data: &ImageMeta,
+    image_data: &mut ImageMeta,
     reference: Reference,
     nydus_config: &NydusConfig,
     work_dir: &Path,
@@ -327,7 +328,7 @@ pub fn process_fscache_daemon(
 }
 
 pub fn create_nydus_bundle(
-    image_data: &ImageMeta,
+    image_data: &mut ImageMeta,
     bundle_dir: &Path,
     snapshot: &mut Box<dyn Snapshotter>,
 ) -> Result<String> {
@@ -337,12 +338,22 @@ pub fn create_nydus_bundle(
     }
 
     let nydus_rootfs = &bundle_dir.join(NYDUS_ROOTFS);
-    snapshot.mount(
+    // The nydus rootfs is not unpacked by image-rs, so it has no whiteouts
+    // in another format.
+    let mount_point = snapshot.mount(
         &[&nydus_rootfs.to_string_lossy()],
         &bundle_dir.join(BUNDLE_ROOTFS),
+        &UnpackMode::default(),
     )?;
 
-    create_runtime_config(&image_config, bundle_dir)?;
+    // The rootfs is not recorded yet, so it would be leaked if kept mounted.
+    if let Err(e) = create_runtime_config(&image_config, bundle_dir) {
+        if let Err(e) = snapshot.unmount(&mount_point) {
+            warn!("Failed to unmount the bundle rootfs: {e:#}");
+        }
+        return Err(e);
+    }
+    image_data.add_bundle(mount_point);
     let image_id = image_data.id.clone();
     Ok(image_id)
 }
@@ -0,0 +1,247 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Selection of the image to pull from a multi-platform image, i.e. an OCI
+//! image index or a docker manifest list.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{bail, Result};
+use oci_client::{client::current_platform_resolver, manifest::ImageIndexEntry};
+use oci_spec::image::ImageConfiguration;
+use serde::{Deserialize, Serialize};
+
+/// The platform of an image, in the form of
+/// `os/architecture[/variant][@manifest-digest]`, e.g. `linux/arm64/v8`.
+///
+/// The manifest digest optionally pins the manifest to select from an image
+/// index.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Platform {
+    pub os: String,
+    pub architecture: String,
+    pub variant: Option<String>,
+    pub manifest_digest: Option<String>,
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (platform, manifest_digest) = match s.split_once('@') {
+            Some((platform, digest)) => {
+                if !digest.contains(':') {
+                    bail!("invalid manifest digest {digest} in platform {s}");
+                }
+                (platform, Some(digest.to_string()))
+            }
+            None => (s, None),
+        };
+
+        let mut parts = platform.split('/');
+        let (Some(os), Some(architecture)) = (parts.next(), parts.next()) else {
+            bail!("platform {s} is not in the form of os/architecture[/variant]");
+        };
+        let variant = parts.next().map(str::to_string);
+        if os.is_empty() || architecture.is_empty() || parts.next().is_some() {
+            bail!("platform {s} is not in the form of os/architecture[/variant]");
+        }
+
+        Ok(Self {
+            os: os.to_string(),
+            architecture: architecture.to_string(),
+            variant,
+            manifest_digest,
+        })
+    }
+}
+
+impl TryFrom<String> for Platform {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.os, self.architecture)?;
+        if let Some(variant) = &self.variant {
+            write!(f, "/{variant}")?;
+        }
+        if let Some(digest) = &self.manifest_digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Platform> for String {
+    fn from(platform: Platform) -> Self {
+        platform.to_string()
+    }
+}
+
+impl Platform {
+    /// The platform of the given image config.
+    pub fn from_image_config(image_config: &ImageConfiguration) -> Self {
+        Self {
+            os: image_config.os().to_string(),
+            architecture: image_config.architecture().to_string(),
+            variant: image_config.variant().clone(),
+            manifest_digest: None,
+        }
+    }
+
+    /// Whether the entry of an image index is the manifest of the platform.
+    /// An entry without platform only matches a pinned manifest digest.
+    fn matches_entry(&self, entry: &ImageIndexEntry) -> bool {
+        if let Some(digest) = &self.manifest_digest {
+            if *digest != entry.digest {
+                return false;
+            }
+        }
+
+        let Some(platform) = &entry.platform else {
+            return self.manifest_digest.is_some();
+        };
+        platform.os == self.os
+            && platform.architecture == self.architecture
+            && (self.variant.is_none() || platform.variant == self.variant)
+    }
+
+    /// Check that the pulled image is of the platform, as a single-platform
+    /// image is pulled without any selection.
+    pub fn check_pulled_image(
+        &self,
+        image_digest: &str,
+        image_config: &ImageConfiguration,
+    ) -> Result<()> {
+        if let Some(digest) = &self.manifest_digest {
+            if digest != image_digest {
+                bail!("pulled image manifest {image_digest} is not the required {digest}");
+            }
+        }
+
+        let pulled = Self::from_image_config(image_config);
+        if pulled.os != self.os
+            || pulled.architecture != self.architecture
+            || (self.variant.is_some() && pulled.variant != self.variant)
+        {
+            bail!("pulled image is of platform {pulled} rather than {self}");
+        }
+
+        Ok(())
+    }
+}
+
+/// Select the manifest of `platform` from the entries of an image index. If
+/// none matches, the manifest of the platform image-rs runs on is selected
+/// unless `strict` is set.
+pub fn resolve_platform(
+    entries: &[ImageIndexEntry],
+    platform: &Platform,
+    strict: bool,
+) -> Option<String> {
+    if let Some(entry) = entries.iter().find(|entry| platform.matches_entry(entry)) {
+        return Some(entry.digest.clone());
+    }
+
+    if strict {
+        return None;
+    }
+
+    current_platform_resolver(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oci_client::manifest::Platform as IndexPlatform;
+    use rstest::rstest;
+
+    fn entry(digest: &str, os: &str, architecture: &str, variant: Option<&str>) -> ImageIndexEntry {
+        ImageIndexEntry {
+            media_type: "application/vnd.oci.image.manifest.v1+json".to_string(),
+            digest: digest.to_string(),
+            size: 0,
+            platform: Some(IndexPlatform {
+                architecture: architecture.to_string(),
+                os: os.to_string(),
+                os_version: None,
+                os_features: None,
+                variant: variant.map(str::to_string),
+                features: None,
+            }),
+            annotations: None,
+        }
+    }
+
+    #[rstest]
+    #[case("linux/amd64", "linux", "amd64", None, None)]
+    #[case("linux/arm64/v8", "linux", "arm64", Some("v8"), None)]
+    #[case("linux/s390x@sha256:1234", "linux", "s390x", None, Some("sha256:1234"))]
+    fn parse_platform(
+        #[case] s: &str,
+        #[case] os: &str,
+        #[case] architecture: &str,
+        #[case] variant: Option<&str>,
+        #[case] manifest_digest: Option<&str>,
+    ) {
+        let platform: Platform = s.parse().unwrap();
+        assert_eq!(platform.os, os);
+        assert_eq!(platform.architecture, architecture);
+        assert_eq!(platform.variant.as_deref(), variant);
+        assert_eq!(platform.manifest_digest.as_deref(), manifest_digest);
+        assert_eq!(platform.to_string(), s);
+    }
+
+    #[rstest]
+    #[case("linux")]
+    #[case("linux/")]
+    #[case("linux/arm64/v8/extra")]
+    #[case("linux/amd64@1234")]
+    fn parse_invalid_platform(#[case] s: &str) {
+        assert!(s.parse::<Platform>().is_err());
+    }
+
+    #[rstest]
+    #[case("linux/arm64", Some("sha256:arm64"))]
+    #[case("linux/arm64/v8", Some("sha256:arm64"))]
+    #[case("linux/arm/v7", Some("sha256:armv7"))]
+    #[case("linux/arm/v6", None)]
+    #[case("linux/amd64@sha256:amd64", Some("sha256:amd64"))]
+    #[case("linux/arm64@sha256:amd64", None)]
+    #[case("linux/riscv64", None)]
+    fn resolve_strict(#[case] platform: &str, #[case] expected: Option<&str>) {
+        let entries = vec![
+            entry("sha256:amd64", "linux", "amd64", None),
+            entry("sha256:arm64", "linux", "arm64", Some("v8")),
+            entry("sha256:armv7", "linux", "arm", Some("v7")),
+        ];
+        let platform = platform.parse().unwrap();
+        assert_eq!(
+            resolve_platform(&entries, &platform, true).as_deref(),
+            expected
+        );
+    }
+
+    #[test]
+    fn resolve_falls_back_to_current_platform() {
+        let entries = vec![entry(
+            "sha256:current",
+            "linux",
+            std::env::consts::ARCH,
+            None,
+        )];
+        let platform = "linux/riscv64".parse().unwrap();
+        assert!(resolve_platform(&entries, &platform, true).is_none());
+        assert_eq!(
+            resolve_platform(&entries, &platform, false).as_deref(),
+            current_platform_resolver(&entries).as_deref()
+        );
+    }
+}
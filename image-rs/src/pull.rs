@@ -4,20 +4,31 @@
 
 use anyhow::{anyhow, bail, Context, Result};
 use futures_util::stream::{self, StreamExt, TryStreamExt};
-use oci_client::client::{Certificate, CertificateEncoding, ClientConfig};
+use log::warn;
+use oci_client::client::{BlobResponse, Certificate, CertificateEncoding, ClientConfig};
 use oci_client::manifest::{OciDescriptor, OciImageManifest};
 use oci_client::{secrets::RegistryAuth, Client, Reference};
 use std::collections::BTreeMap;
+use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::RwLock;
 use tokio_util::io::StreamReader;
 
 use crate::decoder::Compression;
 use crate::decrypt::Decryptor;
+use crate::digest::{DigestHasher, LayerDigestHasher};
 use crate::image::LayerMeta;
 use crate::meta_store::MetaStore;
+use crate::platform::{resolve_platform, Platform};
 use crate::stream::stream_processing;
+use crate::unpack::UnpackMode;
+
+/// The longest delay between two retries of a layer download.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
 
 /// The PullClient connects to remote OCI registry, pulls the container image,
 /// and save the image layers under data_dir and return the layer meta info.
@@ -36,11 +47,29 @@ pub struct PullClient<'a> {
 
     /// Max number of concurrent downloads.
     pub max_concurrent_download: usize,
+
+    /// Number of retries of a failed layer download.
+    pub download_retries: u32,
+
+    /// Delay before the first retry of a failed layer download, doubled on
+    /// each further retry.
+    pub download_retry_delay: Duration,
+
+    /// Directory to download the compressed layers into before unpacking
+    /// them. Layers are unpacked while downloaded if not set.
+    pub staging_dir: Option<PathBuf>,
+
+    /// The mode layers are unpacked in.
+    pub unpack_mode: UnpackMode,
 }
 
 impl<'a> PullClient<'a> {
     /// Constructs a new PullClient struct with provided image info,
     /// data store dir and optional remote registry auth info.
+    ///
+    /// If `platform` is given, its manifest is selected from multi-platform
+    /// images, see [`resolve_platform`].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         reference: Reference,
         data_dir: &Path,
@@ -49,6 +78,8 @@ impl<'a> PullClient<'a> {
         no_proxy: Option<&str>,
         https_proxy: Option<&str>,
         extra_root_certificates: Vec<String>,
+        platform: Option<&Platform>,
+        strict_platform: bool,
     ) -> Result<PullClient<'a>> {
         let mut client_config = ClientConfig::default();
         if let Some(no_proxy) = no_proxy {
@@ -67,6 +98,13 @@ impl<'a> PullClient<'a> {
                 data,
             });
         client_config.extra_root_certificates.extend(certs);
+
+        if let Some(platform) = platform {
+            let platform = platform.clone();
+            client_config.platform_resolver = Some(Box::new(move |entries| {
+                resolve_platform(entries, &platform, strict_platform)
+            }));
+        }
         let client = Client::try_from(client_config)?;
 
         Ok(PullClient {
@@ -75,9 +113,29 @@ impl<'a> PullClient<'a> {
             reference,
             data_dir: data_dir.to_path_buf(),
             max_concurrent_download,
+            download_retries: 0,
+            download_retry_delay: Duration::ZERO,
+            staging_dir: None,
+            unpack_mode: UnpackMode::default(),
         })
     }
 
+    /// Retry a failed layer download up to `retries` times, waiting `delay`
+    /// before the first retry and doubling it on each further retry.
+    pub fn with_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.download_retries = retries;
+        self.download_retry_delay = delay;
+        self
+    }
+
+    /// Download the compressed layers into `staging_dir` before unpacking
+    /// them, so that a retried download resumes from where the failed one
+    /// stopped.
+    pub fn with_staging_dir(mut self, staging_dir: PathBuf) -> Self {
+        self.staging_dir = Some(staging_dir);
+        self
+    }
+
     /// pull_manifest pulls an image manifest and config data.
     pub async fn pull_manifest(&mut self) -> Result<(OciImageManifest, String, String)> {
         self.client
@@ -116,22 +174,9 @@ impl<'a> PullClient<'a> {
         let layer_metas: Vec<(usize, LayerMeta)> = stream::iter(layer_descs)
             .enumerate()
             .map(|(i, layer)| async move {
-                let layer_stream = self
-                    .client
-                    .pull_blob_stream(&self.reference, &layer)
+                self.pull_layer(layer, diff_ids[i].clone(), decrypt_config, meta_store)
                     .await
-                    .map_err(|e| anyhow!("failed to async pull blob stream {}", e.to_string()))?;
-                let layer_reader = StreamReader::new(layer_stream.stream);
-                self.async_handle_layer(
-                    layer,
-                    diff_ids[i].clone(),
-                    decrypt_config,
-                    layer_reader,
-                    meta_store.clone(),
-                )
-                .await
-                .map_err(|e| anyhow!("failed to handle layer: {:?}", e))
-                .map(|layer_meta| (i, layer_meta))
+                    .map(|layer_meta| (i, layer_meta))
             })
             .buffer_unordered(self.max_concurrent_download)
             .try_collect()
@@ -141,6 +186,183 @@ impl<'a> PullClient<'a> {
         Ok(sorted_layer_metas)
     }
 
+    /// Unpack the layers in the given mode. Stored layers unpacked in another
+    /// mode are unpacked again.
+    pub fn with_unpack_mode(mut self, unpack_mode: UnpackMode) -> Self {
+        self.unpack_mode = unpack_mode;
+        self
+    }
+
+    /// The stored layer of the given digest, if unpacked in the mode of the
+    /// client.
+    async fn stored_layer(
+        &self,
+        digest: &str,
+        meta_store: &Arc<RwLock<MetaStore>>,
+    ) -> Option<LayerMeta> {
+        meta_store
+            .read()
+            .await
+            .layer_db
+            .get(&self.unpack_mode.key_of(digest))
+            .filter(|layer_meta| layer_meta.unpack_mode == self.unpack_mode)
+            .cloned()
+    }
+
+    /// Pull a layer, retrying a failed download. With a staging dir, the
+    /// compressed layer is downloaded and verified first, so a retry only
+    /// fetches the remaining part. Otherwise a retry pulls and unpacks the
+    /// whole layer again.
+    async fn pull_layer(
+        &self,
+        layer: OciDescriptor,
+        diff_id: String,
+        decrypt_config: &Option<&str>,
+        meta_store: &Arc<RwLock<MetaStore>>,
+    ) -> Result<LayerMeta> {
+        // Do not download the layers already stored.
+        if let Some(layer_meta) = self.stored_layer(&layer.digest, meta_store).await {
+            return Ok(layer_meta);
+        }
+
+        let (layer_ref, diff_id_ref) = (&layer, &diff_id);
+        match &self.staging_dir {
+            Some(staging_dir) => {
+                let blob = match self
+                    .retry(layer_ref, || self.stage_layer(layer_ref, staging_dir))
+                    .await
+                {
+                    Ok(blob) => blob,
+                    Err(e) => {
+                        // The partial download is only resumed by the retries.
+                        remove_staged_layer(&staged_layer_path(staging_dir, layer_ref)).await;
+                        return Err(e);
+                    }
+                };
+                let layer_reader = fs::File::open(&blob)
+                    .await
+                    .with_context(|| format!("failed to open staged layer {}", blob.display()))?;
+                let res = self
+                    .async_handle_layer(
+                        layer,
+                        diff_id,
+                        decrypt_config,
+                        layer_reader,
+                        meta_store.clone(),
+                    )
+                    .await
+                    .map_err(|e| anyhow!("failed to handle layer: {:?}", e));
+                remove_staged_layer(&blob).await;
+                res
+            }
+            None => {
+                self.retry(layer_ref, || async move {
+                    let layer_stream = self
+                        .client
+                        .pull_blob_stream(&self.reference, layer_ref)
+                        .await
+                        .map_err(|e| {
+                            anyhow!("failed to async pull blob stream {}", e.to_string())
+                        })?;
+                    let layer_reader = StreamReader::new(layer_stream.stream);
+                    self.async_handle_layer(
+                        layer_ref.clone(),
+                        diff_id_ref.clone(),
+                        decrypt_config,
+                        layer_reader,
+                        meta_store.clone(),
+                    )
+                    .await
+                    .map_err(|e| anyhow!("failed to handle layer: {:?}", e))
+                })
+                .await
+            }
+        }
+    }
+
+    /// Run `pull` until it succeeds or the retries are exhausted, with an
+    /// exponential backoff in between.
+    async fn retry<T, F, Fut>(&self, layer: &OciDescriptor, mut pull: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut delay = self.download_retry_delay;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match pull().await {
+                Ok(res) => return Ok(res),
+                Err(e) if attempt <= self.download_retries => {
+                    warn!(
+                        "failed to pull layer {} (attempt {attempt}), retrying in {delay:?}: {e:?}",
+                        layer.digest
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!(
+                            "failed to pull layer {} after {attempt} attempts",
+                            layer.digest
+                        )
+                    })
+                }
+            }
+        }
+    }
+
+    /// Download the compressed layer into the staging dir, resuming a
+    /// previous partial download with a HTTP range request, and verify it
+    /// against the layer digest. A layer failing the verification is
+    /// removed, so that it is downloaded from the start on retry.
+    async fn stage_layer(&self, layer: &OciDescriptor, staging_dir: &Path) -> Result<PathBuf> {
+        fs::create_dir_all(staging_dir)
+            .await
+            .with_context(|| format!("failed to create staging dir {}", staging_dir.display()))?;
+        let blob = staged_layer_path(staging_dir, layer);
+        let size = u64::try_from(layer.size).unwrap_or_default();
+        let offset = match fs::metadata(&blob).await {
+            Ok(metadata) if metadata.len() <= size => metadata.len(),
+            _ => 0,
+        };
+
+        if offset < size || size == 0 {
+            let response = self
+                .client
+                .pull_blob_stream_partial(&self.reference, layer, offset, None)
+                .await
+                .map_err(|e| anyhow!("failed to async pull blob stream {}", e.to_string()))?;
+
+            // The registry may ignore the range and send the whole blob.
+            let (layer_stream, resumed) = match response {
+                BlobResponse::Partial(stream) => (stream, offset > 0),
+                BlobResponse::Full(stream) => (stream, false),
+            };
+            let mut file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(resumed)
+                .truncate(!resumed)
+                .open(&blob)
+                .await
+                .with_context(|| format!("failed to open staged layer {}", blob.display()))?;
+            let mut layer_reader = StreamReader::new(layer_stream.stream);
+            tokio::io::copy(&mut layer_reader, &mut file)
+                .await
+                .context("failed to download layer")?;
+            file.flush().await?;
+        }
+
+        if let Err(e) = verify_blob(&blob, &layer.digest).await {
+            fs::remove_file(&blob).await?;
+            return Err(e);
+        }
+
+        Ok(blob)
+    }
+
     async fn async_handle_layer(
         &self,
         layer: OciDescriptor,
@@ -149,15 +371,16 @@ impl<'a> PullClient<'a> {
         layer_reader: (impl tokio::io::AsyncRead + Unpin + Send),
         ms: Arc<RwLock<MetaStore>>,
     ) -> Result<LayerMeta> {
-        if let Some(layer_meta) = ms.read().await.layer_db.get(&layer.digest) {
-            return Ok(layer_meta.clone());
+        if let Some(layer_meta) = self.stored_layer(&layer.digest, &ms).await {
+            return Ok(layer_meta);
         }
 
-        let blob_id = layer.digest.to_string().replace(':', "_");
+        let blob_id = self.unpack_mode.key_of(&layer.digest).replace(':', "_");
         let destination = self.data_dir.join(blob_id);
         let mut layer_meta = LayerMeta {
             compressed_digest: layer.digest.clone(),
             store_path: destination.display().to_string(),
+            unpack_mode: self.unpack_mode.clone(),
             ..Default::default()
         };
 
@@ -167,39 +390,30 @@ impl<'a> PullClient<'a> {
         // 1. Compressed layer = Compress(Layer Data)
         // 2. Encrypted+Compressed layer = Compress(Encrypt(Layer Data))
         if decryptor.is_encrypted() {
-            let decrypt_key = tokio::task::spawn_blocking({
-                let decryptor = decryptor.clone();
-                let layer = layer.clone();
-                let decrypt_config = decrypt_config.as_ref().map(|inner| inner.to_string());
-                move || {
-                    decryptor
-                        .get_decrypt_key(&layer, &decrypt_config.as_deref())
-                        .context("failed to get decrypt key")
-                }
-            })
-            .await
-            .context("decryptor thread failed to execute")??;
+            let decrypt_key = decryptor
+                .async_get_decrypt_key(&layer, decrypt_config)
+                .await
+                .context("failed to get decrypt key")?;
             let plaintext_layer = decryptor
                 .async_get_plaintext_layer(layer_reader, &layer, &decrypt_key)
                 .map_err(|e| anyhow!("failed to async_get_plaintext_layer: {:?}", e))?;
+            let decoder = Compression::from_descriptor(&OciDescriptor {
+                media_type: decryptor.media_type.clone(),
+                ..layer.clone()
+            })?;
+            layer_meta.toc = decoder.toc(&layer)?;
             layer_meta.uncompressed_digest = self
-                .async_decompress_unpack_layer(
-                    plaintext_layer,
-                    &diff_id,
-                    &decryptor.media_type,
-                    &destination,
-                )
+                .async_decompress_unpack_layer(plaintext_layer, &diff_id, decoder, &destination)
                 .await?;
+            layer_meta.decoder = decoder;
             layer_meta.encrypted = true;
         } else {
+            let decoder = Compression::from_descriptor(&layer)?;
+            layer_meta.toc = decoder.toc(&layer)?;
             layer_meta.uncompressed_digest = self
-                .async_decompress_unpack_layer(
-                    layer_reader,
-                    &diff_id,
-                    &layer.media_type,
-                    &destination,
-                )
+                .async_decompress_unpack_layer(layer_reader, &diff_id, decoder, &destination)
                 .await?;
+            layer_meta.decoder = decoder;
         }
 
         // uncompressed digest should equal to the diff_ids in image_config.
@@ -220,25 +434,75 @@ impl<'a> PullClient<'a> {
         &self,
         input_reader: (impl tokio::io::AsyncRead + Unpin + Send),
         diff_id: &str,
-        media_type: &str,
+        decoder: Compression,
         destination: &Path,
     ) -> Result<String> {
-        let decoder = Compression::try_from(media_type)?;
         let async_decoder = decoder.async_decompress(input_reader);
-        stream_processing(async_decoder, diff_id, destination).await
+        stream_processing(
+            async_decoder,
+            diff_id,
+            destination,
+            decoder.metadata_entries(),
+            &self.unpack_mode,
+        )
+        .await
+    }
+}
+
+/// The path that the compressed `layer` is downloaded to in the staging dir.
+fn staged_layer_path(staging_dir: &Path, layer: &OciDescriptor) -> PathBuf {
+    staging_dir.join(layer.digest.replace(':', "_"))
+}
+
+/// Remove a staged layer, which may not exist.
+async fn remove_staged_layer(blob: &Path) {
+    match fs::remove_file(blob).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            warn!("failed to remove staged layer {}: {e}", blob.display());
+        }
+        _ => {}
     }
 }
 
+/// Check that the content of the file at `path` has the given digest.
+async fn verify_blob(path: &Path, digest: &str) -> Result<()> {
+    let Some(mut hasher) = LayerDigestHasher::new(digest) else {
+        bail!("unsupported layer digest {digest}");
+    };
+
+    let mut file = fs::File::open(path).await?;
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.digest_update(&buf[..n]);
+    }
+
+    let actual = hasher.digest_finalize();
+    if actual != digest {
+        bail!("downloaded layer digest {actual} does not match {digest}");
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::DEFAULT_MAX_CONCURRENT_DOWNLOAD;
     use crate::decoder::ERR_BAD_MEDIA_TYPE;
+    use crate::digest::{DIGEST_SHA256_PREFIX, DIGEST_SHA512_PREFIX};
+    use crate::unpack::IdMapping;
     use crate::ERR_BAD_UNCOMPRESSED_DIGEST;
     use flate2::write::GzEncoder;
-    use oci_client::manifest::IMAGE_CONFIG_MEDIA_TYPE;
+    use oci_client::manifest::{IMAGE_CONFIG_MEDIA_TYPE, IMAGE_LAYER_GZIP_MEDIA_TYPE};
     use oci_spec::image::{ImageConfiguration, MediaType};
+    use sha2::Digest;
     use std::io::Write;
+    use std::os::unix::fs::MetadataExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     use test_utils::{assert_result, assert_retry};
 
@@ -263,6 +527,8 @@ mod tests {
             None,
             None,
             vec![],
+            None,
+            false,
         )
         .unwrap();
         let (image_manifest, _image_digest, image_config) = client.pull_manifest().await.unwrap();
@@ -322,6 +588,8 @@ mod tests {
                 None,
                 None,
                 vec![],
+                None,
+                false,
             )
             .unwrap();
             let (image_manifest, _image_digest, image_config) =
@@ -369,6 +637,8 @@ mod tests {
                 None,
                 None,
                 vec![],
+                None,
+                false,
             )
             .unwrap();
             let (image_manifest, _image_digest, image_config) =
@@ -399,6 +669,194 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_retry() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let client = PullClient::new(
+            Reference::try_from("busybox").unwrap(),
+            tempdir.path(),
+            &RegistryAuth::Anonymous,
+            DEFAULT_MAX_CONCURRENT_DOWNLOAD,
+            None,
+            None,
+            vec![],
+            None,
+            false,
+        )
+        .unwrap()
+        .with_retries(2, Duration::ZERO);
+        let layer = OciDescriptor::default();
+
+        let attempts = &AtomicU32::new(0);
+        let res = client
+            .retry(&layer, || async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    bail!("connection reset");
+                }
+                Ok(())
+            })
+            .await;
+        assert!(res.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        attempts.store(0, Ordering::SeqCst);
+        let res: Result<()> = client
+            .retry(&layer, || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                bail!("connection reset")
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn staged_layer_is_removed_after_failed_pull() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let staging_dir = tempdir.path().join("staging");
+        // Nothing listens on the port, so every download attempt fails.
+        let client = PullClient::new(
+            Reference::try_from("127.0.0.1:1/busybox").unwrap(),
+            &tempdir.path().join("layers"),
+            &RegistryAuth::Anonymous,
+            DEFAULT_MAX_CONCURRENT_DOWNLOAD,
+            None,
+            None,
+            vec![],
+            None,
+            false,
+        )
+        .unwrap()
+        .with_retries(1, Duration::ZERO)
+        .with_staging_dir(staging_dir.clone());
+
+        let layer = OciDescriptor {
+            media_type: IMAGE_LAYER_GZIP_MEDIA_TYPE.to_string(),
+            digest: format!("{DIGEST_SHA256_PREFIX}{}", "a".repeat(64)),
+            size: 1024,
+            ..Default::default()
+        };
+        let blob = staged_layer_path(&staging_dir, &layer);
+        std::fs::create_dir_all(&staging_dir).unwrap();
+        std::fs::write(&blob, b"partial").unwrap();
+
+        let res = client
+            .pull_layer(
+                layer,
+                String::new(),
+                &None,
+                &Arc::new(RwLock::new(MetaStore::default())),
+            )
+            .await;
+        assert!(res.is_err());
+        assert!(!blob.exists());
+    }
+
+    #[tokio::test]
+    async fn layer_is_stored_per_unpack_mode() {
+        let mut ar = tokio_tar::Builder::new(Vec::new());
+        let mut header = tokio_tar::Header::new_gnu();
+        header.set_size(4);
+        header.set_mode(0o644);
+        header.set_uid(10);
+        header.set_gid(10);
+        header.set_cksum();
+        ar.append_data(&mut header, "file.txt", &b"data"[..])
+            .await
+            .unwrap();
+        let data = ar.into_inner().await.unwrap();
+        let digest = format!("{DIGEST_SHA256_PREFIX}{:x}", sha2::Sha256::digest(&data));
+        let layer = OciDescriptor {
+            media_type: MediaType::ImageLayer.to_string(),
+            digest: digest.clone(),
+            size: data.len() as i64,
+            ..Default::default()
+        };
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut client = PullClient::new(
+            Reference::try_from("busybox").unwrap(),
+            tempdir.path(),
+            &RegistryAuth::Anonymous,
+            DEFAULT_MAX_CONCURRENT_DOWNLOAD,
+            None,
+            None,
+            vec![],
+            None,
+            false,
+        )
+        .unwrap();
+        let ms = Arc::new(RwLock::new(MetaStore::default()));
+        let mapped = UnpackMode {
+            uid_mappings: vec![IdMapping {
+                container_id: 0,
+                host_id: 1000,
+                size: 100,
+            }],
+            ..Default::default()
+        };
+
+        let mut layer_metas = Vec::new();
+        for unpack_mode in [UnpackMode::default(), mapped.clone()] {
+            client.unpack_mode = unpack_mode;
+            let layer_meta = client
+                .async_handle_layer(
+                    layer.clone(),
+                    digest.clone(),
+                    &None,
+                    data.as_slice(),
+                    ms.clone(),
+                )
+                .await
+                .unwrap();
+            ms.write()
+                .await
+                .layer_db
+                .insert(layer_meta.key(), layer_meta.clone());
+            layer_metas.push(layer_meta);
+        }
+
+        // Unpacking in the second mode keeps the layer of the first one.
+        assert_ne!(layer_metas[0].store_path, layer_metas[1].store_path);
+        assert_eq!(ms.read().await.layer_db.len(), 2);
+        let uid = |layer_meta: &LayerMeta| {
+            std::fs::metadata(Path::new(&layer_meta.store_path).join("file.txt"))
+                .unwrap()
+                .uid()
+        };
+        assert_eq!(uid(&layer_metas[0]), 10);
+        assert_eq!(uid(&layer_metas[1]), 1010);
+
+        for (unpack_mode, layer_meta) in [UnpackMode::default(), mapped]
+            .into_iter()
+            .zip(&layer_metas)
+        {
+            client.unpack_mode = unpack_mode;
+            assert_eq!(
+                client.stored_layer(&digest, &ms).await.as_ref(),
+                Some(layer_meta)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_blob() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let blob = tempdir.path().join("blob");
+        let data = b"This is some text!";
+        std::fs::write(&blob, data).unwrap();
+
+        let digest = format!("{}{:x}", DIGEST_SHA256_PREFIX, sha2::Sha256::digest(data));
+        verify_blob(&blob, &digest).await.unwrap();
+
+        let digest = format!("{}{:x}", DIGEST_SHA512_PREFIX, sha2::Sha512::digest(data));
+        verify_blob(&blob, &digest).await.unwrap();
+
+        std::fs::write(&blob, b"This is some other text!").unwrap();
+        assert!(verify_blob(&blob, &digest).await.is_err());
+        assert!(verify_blob(&blob, "md5:1234").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_async_handle_layer() {
         let oci_image = Reference::try_from(
@@ -437,6 +895,8 @@ mod tests {
             None,
             None,
             vec![],
+            None,
+            false,
         )
         .unwrap();
 
@@ -529,6 +989,8 @@ mod tests {
                 None,
                 None,
                 vec![],
+                None,
+                false,
             )
             .unwrap();
             let (image_manifest, _image_digest, image_config) =
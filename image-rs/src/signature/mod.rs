@@ -15,6 +15,7 @@ pub use policy::Policy;
 
 use anyhow::{bail, Context, Result};
 use oci_client::secrets::RegistryAuth;
+use sha2::{Digest, Sha256};
 
 use crate::resource::ResourceProvider;
 
@@ -25,6 +26,9 @@ pub const IMAGE_SECURITY_CONFIG_SUBDIR: &str = "image-security";
 pub struct SignatureValidator {
     policy: Policy,
 
+    /// `sha256:` digest of the policy file.
+    policy_digest: String,
+
     resource_provider: Arc<ResourceProvider>,
 
     no_proxy: Option<String>,
@@ -58,6 +62,26 @@ impl SignatureValidator {
         bail!("feature \"signature-cosign\" not enabled.")
     }
 
+    #[cfg(not(feature = "signature-cosign"))]
+    async fn cosign_keyless_allows_image(
+        &self,
+        _parameter: &crate::signature::policy::CosignKeylessParameters,
+        _image: &Image,
+        _auth: &RegistryAuth,
+    ) -> Result<()> {
+        bail!("feature \"signature-cosign\" not enabled.")
+    }
+
+    #[cfg(not(feature = "signature-cosign"))]
+    async fn cosign_attestation_allows_image(
+        &self,
+        _parameter: &crate::signature::policy::AttestationParameters,
+        _image: &Image,
+        _auth: &RegistryAuth,
+    ) -> Result<()> {
+        bail!("feature \"signature-cosign\" not enabled.")
+    }
+
     async fn check_image_requirement(
         &self,
         req: &PolicyReqType,
@@ -74,6 +98,13 @@ impl SignatureValidator {
                 self.simple_signing_allows_image(inner, image, auth).await
             }
             PolicyReqType::Cosign(inner) => self.cosign_allows_image(inner, image, auth).await,
+            PolicyReqType::CosignKeyless(inner) => {
+                self.cosign_keyless_allows_image(inner, image, auth).await
+            }
+            PolicyReqType::CosignAttestation(inner) => {
+                self.cosign_attestation_allows_image(inner, image, auth)
+                    .await
+            }
         }
     }
 
@@ -108,6 +139,12 @@ impl SignatureValidator {
         Ok(())
     }
 
+    /// The digest of the image security policy, which tells whether a
+    /// verification was done under the same policy.
+    pub fn policy_digest(&self) -> &str {
+        &self.policy_digest
+    }
+
     pub(crate) async fn new(
         policy: &[u8],
         _simple_signing_sigstore_config: Option<Vec<u8>>,
@@ -117,6 +154,7 @@ impl SignatureValidator {
         certificates: Vec<String>,
         resource_provider: Arc<ResourceProvider>,
     ) -> Result<Self> {
+        let policy_digest = format!("sha256:{}", hex::encode(Sha256::digest(policy)));
         let policy: Policy = serde_json::from_slice(policy).context("parse image policy")?;
         tokio::fs::create_dir_all(workdir.join(IMAGE_SECURITY_CONFIG_SUBDIR)).await?;
 
@@ -149,6 +187,7 @@ impl SignatureValidator {
 
         Ok(Self {
             policy,
+            policy_digest,
             resource_provider,
             no_proxy,
             https_proxy,
@@ -0,0 +1,177 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! [in-toto](https://github.com/in-toto/attestation/blob/main/spec/README.md)
+//! statements in [DSSE](https://github.com/secure-systems-lab/dsse/blob/master/envelope.md)
+//! envelopes, the format of the attestations cosign attaches to images.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::signature::image::digest::Digest;
+
+/// Media type of the layers of a cosign attestation image.
+pub const DSSE_MEDIA_TYPE: &str = "application/vnd.dsse.envelope.v1+json";
+
+/// Payload type of a DSSE envelope carrying an in-toto statement.
+pub const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// Prefix of the `_type` of in-toto statements of all versions.
+const IN_TOTO_STATEMENT_TYPE_PREFIX: &str = "https://in-toto.io/Statement/";
+
+/// A DSSE envelope.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Envelope {
+    pub payload_type: String,
+
+    /// The payload in base64.
+    pub payload: String,
+
+    pub signatures: Vec<EnvelopeSignature>,
+}
+
+/// A signature of a DSSE envelope.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EnvelopeSignature {
+    #[serde(default)]
+    pub keyid: String,
+
+    /// The signature in base64.
+    pub sig: String,
+}
+
+impl Envelope {
+    /// The decoded payload.
+    pub fn payload(&self) -> Result<Vec<u8>> {
+        STANDARD
+            .decode(&self.payload)
+            .context("invalid base64 DSSE payload")
+    }
+
+    /// The pre-authentication encoding (PAE) of the payload, which is what
+    /// the signatures are made over.
+    pub fn pae(&self) -> Result<Vec<u8>> {
+        let payload = self.payload()?;
+        let mut pae = format!(
+            "DSSEv1 {} {} {} ",
+            self.payload_type.len(),
+            self.payload_type,
+            payload.len()
+        )
+        .into_bytes();
+        pae.extend(payload);
+        Ok(pae)
+    }
+
+    /// The decoded signatures. Signatures that are not valid base64 are
+    /// skipped.
+    pub fn signatures(&self) -> Vec<Vec<u8>> {
+        self.signatures
+            .iter()
+            .filter_map(|signature| STANDARD.decode(&signature.sig).ok())
+            .collect()
+    }
+}
+
+/// An in-toto statement, i.e. a predicate about the subjects.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Statement {
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+
+    pub subject: Vec<Subject>,
+
+    #[serde(default)]
+    pub predicate: serde_json::Value,
+}
+
+/// A subject of an in-toto statement.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Subject {
+    #[serde(default)]
+    pub name: String,
+
+    /// Digests of the subject keyed by algorithm.
+    pub digest: HashMap<String, String>,
+}
+
+impl TryFrom<&serde_json::Value> for Statement {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self> {
+        let statement = Statement::deserialize(value).context("invalid in-toto statement")?;
+        if !statement
+            .statement_type
+            .starts_with(IN_TOTO_STATEMENT_TYPE_PREFIX)
+        {
+            bail!(
+                "unknown in-toto statement type {}",
+                statement.statement_type
+            );
+        }
+
+        Ok(statement)
+    }
+}
+
+impl Statement {
+    /// Whether the statement is about the image of the given manifest digest.
+    pub fn is_about(&self, digest: &Digest) -> bool {
+        self.subject.iter().any(|subject| {
+            subject
+                .digest
+                .get(&digest.algorithm())
+                .is_some_and(|value| *value == digest.value())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pae() {
+        // Test vector of the DSSE spec.
+        let envelope = Envelope {
+            payload_type: "http://example.com/HelloWorld".into(),
+            payload: STANDARD.encode("hello world"),
+            signatures: vec![],
+        };
+        assert_eq!(
+            envelope.pae().unwrap(),
+            b"DSSEv1 29 http://example.com/HelloWorld 11 hello world"
+        );
+    }
+
+    #[test]
+    fn test_statement() {
+        let digest = "sha256:10e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8";
+        let value = serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://slsa.dev/provenance/v1",
+            "subject": [{
+                "name": "example.com/app",
+                "digest": { "sha256": digest.strip_prefix("sha256:").unwrap() }
+            }],
+            "predicate": {}
+        });
+        let statement = Statement::try_from(&value).unwrap();
+        assert!(statement.is_about(&Digest::try_from(digest).unwrap()));
+
+        let other = "sha256:20e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8";
+        assert!(!statement.is_about(&Digest::try_from(other).unwrap()));
+
+        let mut value = value;
+        value["_type"] = "https://example.com/Statement/v1".into();
+        assert!(Statement::try_from(&value).is_err());
+    }
+}
@@ -10,5 +10,8 @@
 //!
 //! Now support the following payload formats:
 //! * [SimpleSigning](https://github.com/containers/image/blob/main/docs/containers-signature.5.md#json-data-format)
+//! * [in-toto](https://github.com/in-toto/attestation/blob/main/spec/README.md) statements
+//!   in DSSE envelopes, as cosign attestations
 
+pub mod in_toto;
 pub mod simple_signing;
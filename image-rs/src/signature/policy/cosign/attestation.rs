@@ -0,0 +1,361 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Verification of cosign attestations, i.e. in-toto statements about the
+//! image signed with a key, e.g. SLSA provenances or vulnerability scans.
+//!
+//! Cosign attaches the attestations of an image as DSSE envelope layers of
+//! the image tagged `<algorithm>-<digest>.att` in the same repository.
+
+use anyhow::{bail, Context, Result};
+use log::debug;
+use oci_client::{
+    client::{
+        Certificate as OciCertificate, CertificateEncoding as OciCertificateEncoding, ClientConfig,
+    },
+    secrets::RegistryAuth,
+    Client, Reference,
+};
+use sigstore::{
+    crypto::{CosignVerificationKey, Signature, SigningScheme},
+    registry::{Certificate, CertificateEncoding},
+};
+
+use crate::signature::{
+    image::{digest::Digest, Image},
+    payload::in_toto::{Envelope, Statement, DSSE_MEDIA_TYPE, IN_TOTO_PAYLOAD_TYPE},
+    policy::AttestationParameters,
+    SignatureValidator,
+};
+
+use super::get_public_key;
+
+impl SignatureValidator {
+    /// Judge whether an image is allowed by the cosign attestation
+    /// requirement.
+    pub(crate) async fn cosign_attestation_allows_image(
+        &self,
+        parameter: &AttestationParameters,
+        image: &Image,
+        auth: &RegistryAuth,
+    ) -> Result<()> {
+        let key = get_public_key(
+            &self.resource_provider,
+            &parameter.key_data,
+            &parameter.key_path,
+        )
+        .await?;
+        let key = CosignVerificationKey::from_pem(&key, &SigningScheme::ECDSA_P256_SHA256_ASN1)
+            .context("invalid attestation public key")?;
+
+        // Verification, will access the network
+        let envelopes = fetch_attestations(
+            image,
+            auth,
+            self.certificates.iter().collect(),
+            self.no_proxy.as_ref(),
+            self.https_proxy.as_ref(),
+        )
+        .await?;
+
+        let mut reasons = Vec::new();
+        for envelope in &envelopes {
+            match parameter.verify_envelope(envelope, &key, &image.manifest_digest) {
+                Ok(()) => return Ok(()),
+                Err(e) => reasons.push(format!("{e:#}")),
+            }
+        }
+
+        bail!(
+            "no attestation of predicate type {} satisfying the policy: {reasons:?}",
+            parameter.predicate_type
+        )
+    }
+}
+
+impl AttestationParameters {
+    /// Verify the DSSE envelope is signed by the key and carries a statement
+    /// of the predicate type about the image satisfying all the constraints.
+    fn verify_envelope(
+        &self,
+        envelope: &Envelope,
+        key: &CosignVerificationKey,
+        manifest_digest: &Digest,
+    ) -> Result<()> {
+        if envelope.payload_type != IN_TOTO_PAYLOAD_TYPE {
+            bail!("unknown DSSE payload type {}", envelope.payload_type);
+        }
+
+        let pae = envelope.pae()?;
+        if !envelope.signatures().iter().any(|signature| {
+            key.verify_signature(Signature::Raw(signature), &pae)
+                .is_ok()
+        }) {
+            bail!("no DSSE signature verified by the public key");
+        }
+
+        let statement: serde_json::Value =
+            serde_json::from_slice(&envelope.payload()?).context("invalid in-toto statement")?;
+        let parsed = Statement::try_from(&statement)?;
+        if parsed.predicate_type != self.predicate_type {
+            bail!("predicate type {} not required", parsed.predicate_type);
+        }
+
+        if !parsed.is_about(manifest_digest) {
+            bail!("statement is not about image {manifest_digest}");
+        }
+
+        for constraint in &self.constraints {
+            constraint.check(&statement)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Download the cosign attestation image of the image and gather its DSSE
+/// envelopes. The envelopes are not verified.
+async fn fetch_attestations(
+    image: &Image,
+    auth: &RegistryAuth,
+    certificates: Vec<&Certificate>,
+    no_proxy: Option<&String>,
+    https_proxy: Option<&String>,
+) -> Result<Vec<Envelope>> {
+    let config = ClientConfig {
+        no_proxy: no_proxy.cloned(),
+        https_proxy: https_proxy.cloned(),
+        extra_root_certificates: certificates
+            .into_iter()
+            .map(|certificate| OciCertificate {
+                encoding: match certificate.encoding {
+                    CertificateEncoding::Der => OciCertificateEncoding::Der,
+                    CertificateEncoding::Pem => OciCertificateEncoding::Pem,
+                },
+                data: certificate.data.clone(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let client = Client::try_from(config)?;
+
+    let attestation_image = attestation_reference(image);
+    let (manifest, _) = client
+        .pull_image_manifest(&attestation_image, auth)
+        .await
+        .with_context(|| format!("failed to pull attestations {attestation_image}"))?;
+
+    let mut envelopes = Vec::new();
+    for layer in &manifest.layers {
+        if layer.media_type != DSSE_MEDIA_TYPE {
+            debug!("skip attestation layer of media type {}", layer.media_type);
+            continue;
+        }
+
+        let mut data = Vec::new();
+        client
+            .pull_blob(&attestation_image, layer, &mut data)
+            .await
+            .with_context(|| format!("failed to pull attestation {}", layer.digest))?;
+        match serde_json::from_slice(&data) {
+            Ok(envelope) => envelopes.push(envelope),
+            Err(e) => debug!("skip invalid DSSE envelope {}: {e}", layer.digest),
+        }
+    }
+
+    Ok(envelopes)
+}
+
+/// The reference of the cosign attestation image of the image.
+fn attestation_reference(image: &Image) -> Reference {
+    Reference::with_tag(
+        image.reference.registry().to_string(),
+        image.reference.repository().to_string(),
+        format!(
+            "{}-{}.att",
+            image.manifest_digest.algorithm(),
+            image.manifest_digest.value()
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use ring::{
+        rand::SystemRandom,
+        signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING},
+    };
+    use rstest::rstest;
+
+    use crate::signature::payload::in_toto::EnvelopeSignature;
+
+    const IMAGE_DIGEST: &str =
+        "sha256:10e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8";
+
+    /// SPKI DER prefix of an uncompressed P-256 public key.
+    const P256_SPKI_PREFIX: &str = "3059301306072a8648ce3d020106082a8648ce3d030107034200";
+
+    fn key_pair() -> EcdsaKeyPair {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng).unwrap()
+    }
+
+    fn public_key_pem(key_pair: &EcdsaKeyPair) -> String {
+        let mut der = hex::decode(P256_SPKI_PREFIX).unwrap();
+        der.extend_from_slice(key_pair.public_key().as_ref());
+        let base64 = STANDARD.encode(der);
+        let lines: Vec<&str> = base64
+            .as_bytes()
+            .chunks(64)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect();
+        format!(
+            "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+            lines.join("\n")
+        )
+    }
+
+    fn envelope(
+        key_pair: &EcdsaKeyPair,
+        payload_type: &str,
+        statement: &serde_json::Value,
+    ) -> Envelope {
+        let mut envelope = Envelope {
+            payload_type: payload_type.into(),
+            payload: STANDARD.encode(statement.to_string()),
+            signatures: vec![],
+        };
+        let signature = key_pair
+            .sign(&SystemRandom::new(), &envelope.pae().unwrap())
+            .unwrap();
+        envelope.signatures.push(EnvelopeSignature {
+            keyid: String::new(),
+            sig: STANDARD.encode(signature.as_ref()),
+        });
+        envelope
+    }
+
+    fn statement(digest: &str, builder: &str) -> serde_json::Value {
+        serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v0.1",
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "subject": [{
+                "name": "ghcr.io/confidential-containers/test-container",
+                "digest": { "sha256": digest.strip_prefix("sha256:").unwrap() }
+            }],
+            "predicate": { "builder": { "id": builder } }
+        })
+    }
+
+    fn parameters() -> AttestationParameters {
+        serde_json::from_str(
+            r#"{
+                "keyData": "",
+                "predicateType": "https://slsa.dev/provenance/v0.2",
+                "constraints": [
+                    { "path": "/predicate/builder/id", "equals": "https://github.com/actions/runner" }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        IN_TOTO_PAYLOAD_TYPE,
+        IMAGE_DIGEST,
+        "https://github.com/actions/runner",
+        true
+    )]
+    #[case(
+        "application/json",
+        IMAGE_DIGEST,
+        "https://github.com/actions/runner",
+        false
+    )]
+    #[case(
+        IN_TOTO_PAYLOAD_TYPE,
+        "sha256:20e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8",
+        "https://github.com/actions/runner",
+        false
+    )]
+    #[case(
+        IN_TOTO_PAYLOAD_TYPE,
+        IMAGE_DIGEST,
+        "https://example.com/builder",
+        false
+    )]
+    fn test_verify_envelope(
+        #[case] payload_type: &str,
+        #[case] subject_digest: &str,
+        #[case] builder: &str,
+        #[case] verified: bool,
+    ) {
+        let key_pair = key_pair();
+        let key = CosignVerificationKey::from_pem(
+            public_key_pem(&key_pair).as_bytes(),
+            &SigningScheme::ECDSA_P256_SHA256_ASN1,
+        )
+        .unwrap();
+        let envelope = envelope(&key_pair, payload_type, &statement(subject_digest, builder));
+        let digest = Digest::try_from(IMAGE_DIGEST).unwrap();
+
+        assert_eq!(
+            parameters()
+                .verify_envelope(&envelope, &key, &digest)
+                .is_ok(),
+            verified
+        );
+    }
+
+    #[test]
+    fn test_verify_envelope_signatures() {
+        let key_pair = key_pair();
+        let statement = statement(IMAGE_DIGEST, "https://github.com/actions/runner");
+        let digest = Digest::try_from(IMAGE_DIGEST).unwrap();
+
+        // Signed by another key
+        let other = CosignVerificationKey::from_pem(
+            public_key_pem(&self::key_pair()).as_bytes(),
+            &SigningScheme::ECDSA_P256_SHA256_ASN1,
+        )
+        .unwrap();
+        let envelope = envelope(&key_pair, IN_TOTO_PAYLOAD_TYPE, &statement);
+        assert!(parameters()
+            .verify_envelope(&envelope, &other, &digest)
+            .is_err());
+
+        // Payload changed after signing
+        let key = CosignVerificationKey::from_pem(
+            public_key_pem(&key_pair).as_bytes(),
+            &SigningScheme::ECDSA_P256_SHA256_ASN1,
+        )
+        .unwrap();
+        let mut tampered = envelope.clone();
+        tampered.payload = STANDARD
+            .encode(self::statement(IMAGE_DIGEST, "https://example.com/builder").to_string());
+        assert!(parameters()
+            .verify_envelope(&tampered, &key, &digest)
+            .is_err());
+        assert!(parameters()
+            .verify_envelope(&envelope, &key, &digest)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_attestation_reference() {
+        let mut image = Image::default_with_reference(
+            Reference::try_from("ghcr.io/confidential-containers/test-container:latest").unwrap(),
+        );
+        image.set_manifest_digest(IMAGE_DIGEST).unwrap();
+        assert_eq!(
+            attestation_reference(&image).whole(),
+            "ghcr.io/confidential-containers/test-container:sha256-10e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8.att"
+        );
+    }
+}
@@ -0,0 +1,617 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Keyless cosign verification, i.e. signatures made with short-lived
+//! Fulcio certificates and logged in Rekor.
+//!
+//! The trust root is given in the format of the sigstore `trusted_root.json`
+//! rather than fetched from the public sigstore TUF repository, so that
+//! verification works offline.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::debug;
+use oci_client::secrets::RegistryAuth;
+use regex::Regex;
+use rustls_pki_types::CertificateDer;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use sigstore::{
+    cosign::{
+        signature_layers::CertificateSubject,
+        verification_constraint::{
+            CertSubjectEmailVerifier, CertSubjectUrlVerifier, VerificationConstraint,
+            VerificationConstraintVec,
+        },
+        verify_constraints, SignatureLayer,
+    },
+    crypto::Signature,
+    errors::{Result as SigstoreResult, SigstoreVerifyConstraintsError},
+    registry::Certificate,
+    trust::ManualTrustRoot,
+};
+use std::sync::Arc;
+
+use crate::signature::{image::Image, payload::simple_signing::SigPayload};
+use crate::{resource::ResourceProvider, signature::SignatureValidator};
+
+use super::{check_reference_rule_types, fetch_signature_layers};
+use crate::signature::policy::CosignKeylessParameters;
+
+/// The subset of the sigstore `trusted_root.json` used to verify keyless
+/// signatures.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrustedRoot {
+    #[serde(default)]
+    tlogs: Vec<TransparencyLog>,
+    #[serde(default)]
+    certificate_authorities: Vec<CertificateAuthority>,
+    #[serde(default)]
+    ctlogs: Vec<TransparencyLog>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransparencyLog {
+    public_key: RawBytes,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateAuthority {
+    cert_chain: CertChain,
+}
+
+#[derive(Deserialize)]
+struct CertChain {
+    certificates: Vec<RawBytes>,
+}
+
+/// DER encoded data in base64.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBytes {
+    raw_bytes: String,
+}
+
+impl RawBytes {
+    fn decode(&self) -> Result<Vec<u8>> {
+        STANDARD
+            .decode(&self.raw_bytes)
+            .context("invalid base64 in trust root")
+    }
+}
+
+/// Parse a sigstore `trusted_root.json`. The Fulcio CAs and Rekor keys are
+/// required to verify keyless signatures.
+fn parse_trust_root(data: &[u8]) -> Result<ManualTrustRoot<'static>> {
+    let trusted_root: TrustedRoot =
+        serde_json::from_slice(data).context("failed to parse sigstore trust root")?;
+
+    let fulcio_certs = trusted_root
+        .certificate_authorities
+        .iter()
+        .flat_map(|ca| ca.cert_chain.certificates.iter())
+        .map(|cert| cert.decode().map(CertificateDer::from))
+        .collect::<Result<Vec<_>>>()?;
+    if fulcio_certs.is_empty() {
+        bail!("no Fulcio certificate authority in trust root");
+    }
+
+    let rekor_keys = trusted_root
+        .tlogs
+        .iter()
+        .map(|tlog| tlog.public_key.decode())
+        .collect::<Result<Vec<_>>>()?;
+    if rekor_keys.is_empty() {
+        bail!("no Rekor public key in trust root");
+    }
+
+    let ctfe_keys = trusted_root
+        .ctlogs
+        .iter()
+        .map(|ctlog| ctlog.public_key.decode())
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ManualTrustRoot {
+        fulcio_certs,
+        rekor_keys,
+        ctfe_keys,
+    })
+}
+
+/// Compile the subject regex, which must match the whole subject.
+fn subject_regex(subject_regex: &str) -> Result<Regex> {
+    Regex::new(&format!("^(?:{subject_regex})$"))
+        .with_context(|| format!("invalid subjectRegex {subject_regex}"))
+}
+
+impl SignatureValidator {
+    /// Judge whether an image is allowed by the keyless cosign requirement.
+    pub(crate) async fn cosign_keyless_allows_image(
+        &self,
+        parameter: &CosignKeylessParameters,
+        image: &Image,
+        auth: &RegistryAuth,
+    ) -> Result<()> {
+        parameter
+            .check_image_signature(
+                self.resource_provider.clone(),
+                image,
+                auth,
+                self.certificates.iter().collect(),
+                self.no_proxy.as_ref(),
+                self.https_proxy.as_ref(),
+            )
+            .await
+    }
+}
+
+impl CosignKeylessParameters {
+    async fn check_image_signature(
+        &self,
+        resource_provider: Arc<ResourceProvider>,
+        image: &Image,
+        auth: &RegistryAuth,
+        certificates: Vec<&Certificate>,
+        no_proxy: Option<&String>,
+        https_proxy: Option<&String>,
+    ) -> Result<()> {
+        // Check before we access the network
+        check_reference_rule_types(&self.signed_identity)?;
+        let subject = subject_regex(&self.subject_regex)?;
+
+        let trust_root = resource_provider
+            .get_resource(&self.trust_root_path)
+            .await
+            .context("failed to get sigstore trust root")?;
+        let trust_root = parse_trust_root(&trust_root)?;
+
+        // Verification, will access the network
+        let signature_layers = fetch_signature_layers(
+            image,
+            auth,
+            certificates,
+            no_proxy,
+            https_proxy,
+            Some(&trust_root),
+        )
+        .await?;
+
+        let identity = KeylessIdentityVerifier {
+            issuer: self.oidc_issuer.clone(),
+            subject,
+        };
+        let payloads: Vec<SigPayload> = signature_layers
+            .iter()
+            .filter(|layer| identity.verify(layer).unwrap_or(false))
+            .map(|layer| SigPayload::from(layer.simple_signing.clone()))
+            .collect();
+
+        let verification_constraints: VerificationConstraintVec = vec![Box::new(identity)];
+        if let Err(SigstoreVerifyConstraintsError {
+            unsatisfied_constraints,
+        }) = verify_constraints(&signature_layers, verification_constraints.iter())
+        {
+            bail!(
+                "no keyless signature of issuer {} and subject matching {}: {:?}",
+                self.oidc_issuer,
+                self.subject_regex,
+                unsatisfied_constraints
+            );
+        }
+
+        // check the reference rules (signed identity)
+        for payload in payloads {
+            if let Some(rule) = &self.signed_identity {
+                payload.validate_signed_docker_reference(&image.reference, rule)?;
+            }
+
+            payload.validate_signed_docker_manifest_digest(&image.manifest_digest.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Verification constraint satisfied by the signature layers signed by a
+/// Fulcio certificate of the keyless identity.
+///
+/// The certificate and the Rekor bundle of a layer are only set if its
+/// chain is verified against the Fulcio CAs and its SET is verified
+/// against the Rekor keys. On top of that, the signature of the layer
+/// must be made by the key of the certificate, and the Rekor entry of the
+/// bundle must record that signature over the payload of the layer.
+#[derive(Debug)]
+struct KeylessIdentityVerifier {
+    issuer: String,
+    subject: Regex,
+}
+
+impl VerificationConstraint for KeylessIdentityVerifier {
+    fn verify(&self, layer: &SignatureLayer) -> SigstoreResult<bool> {
+        let Some(certificate) = &layer.certificate_signature else {
+            debug!(
+                "skip signature layer {} without trusted certificate",
+                layer.oci_digest
+            );
+            return Ok(false);
+        };
+        let Some(bundle) = &layer.bundle else {
+            debug!(
+                "skip signature layer {} without Rekor bundle",
+                layer.oci_digest
+            );
+            return Ok(false);
+        };
+        let Some(signature) = &layer.signature else {
+            debug!(
+                "skip signature layer {} without signature",
+                layer.oci_digest
+            );
+            return Ok(false);
+        };
+
+        // sigstore only matches exact subjects, so the subject of the
+        // certificate is matched against the regex first.
+        let identity_matches = match &certificate.subject {
+            CertificateSubject::Email(email) => {
+                self.subject.is_match(email)
+                    && CertSubjectEmailVerifier {
+                        email: email.clone(),
+                        issuer: Some(self.issuer.clone()),
+                    }
+                    .verify(layer)?
+            }
+            CertificateSubject::Uri(uri) => {
+                self.subject.is_match(uri)
+                    && CertSubjectUrlVerifier {
+                        url: uri.clone(),
+                        issuer: self.issuer.clone(),
+                    }
+                    .verify(layer)?
+            }
+        };
+        if !identity_matches {
+            return Ok(false);
+        }
+
+        if let Err(e) = certificate.verification_key.verify_signature(
+            Signature::Base64Encoded(signature.as_bytes()),
+            &layer.raw_data,
+        ) {
+            debug!(
+                "signature of layer {} is not made by its certificate: {e}",
+                layer.oci_digest
+            );
+            return Ok(false);
+        }
+
+        if let Err(e) = check_rekor_entry(&bundle.payload.body, signature, &layer.raw_data) {
+            debug!(
+                "Rekor bundle of layer {} does not match: {e:#}",
+                layer.oci_digest
+            );
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+}
+
+/// Check that the Rekor entry, i.e. the base64 encoded body of the bundle
+/// payload, is the `hashedrekord` of the signature over the payload.
+fn check_rekor_entry(body: &str, signature: &str, payload: &[u8]) -> Result<()> {
+    let entry = STANDARD
+        .decode(body)
+        .context("invalid base64 in Rekor entry")?;
+    let entry: serde_json::Value =
+        serde_json::from_slice(&entry).context("failed to parse Rekor entry")?;
+
+    if entry["kind"] != "hashedrekord" {
+        bail!("unsupported Rekor entry kind {}", entry["kind"]);
+    }
+    if entry.pointer("/spec/signature/content") != Some(&signature.into()) {
+        bail!("Rekor entry is not of the signature");
+    }
+    let digest = hex::encode(Sha256::digest(payload));
+    if entry.pointer("/spec/data/hash/algorithm") != Some(&"sha256".into())
+        || entry.pointer("/spec/data/hash/value") != Some(&digest.into())
+    {
+        bail!("Rekor entry is not of the payload");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::{
+        rand::SystemRandom,
+        signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING},
+    };
+    use rstest::rstest;
+    use sigstore::{
+        cosign::signature_layers::CertificateSignature,
+        crypto::{CosignVerificationKey, SigningScheme},
+    };
+
+    const ISSUER: &str = "https://token.actions.githubusercontent.com";
+
+    const SUBJECT: &str =
+        "https://github.com/example/repo/.github/workflows/release.yml@refs/heads/main";
+
+    /// SPKI DER prefix of an uncompressed P-256 public key.
+    const P256_SPKI_PREFIX: &str = "3059301306072a8648ce3d020106082a8648ce3d030107034200";
+
+    fn key_pair() -> EcdsaKeyPair {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng).unwrap()
+    }
+
+    fn verification_key(key_pair: &EcdsaKeyPair) -> CosignVerificationKey {
+        let mut der = hex::decode(P256_SPKI_PREFIX).unwrap();
+        der.extend_from_slice(key_pair.public_key().as_ref());
+        CosignVerificationKey::from_der(&der, &SigningScheme::ECDSA_P256_SHA256_ASN1).unwrap()
+    }
+
+    fn sign(key_pair: &EcdsaKeyPair, payload: &[u8]) -> String {
+        let signature = key_pair.sign(&SystemRandom::new(), payload).unwrap();
+        STANDARD.encode(signature.as_ref())
+    }
+
+    fn simple_signing(digest: &str) -> Vec<u8> {
+        serde_json::json!({
+            "critical": {
+                "identity": { "docker-reference": "ghcr.io/confidential-containers/test-container" },
+                "image": { "docker-manifest-digest": digest },
+                "type": "cosign container image signature"
+            },
+            "optional": null
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    /// The cosign bundle of the `hashedrekord` Rekor entry of the signature
+    /// over the payload.
+    fn bundle(signature: &str, payload: &[u8]) -> serde_json::Value {
+        let entry = serde_json::json!({
+            "apiVersion": "0.0.1",
+            "kind": "hashedrekord",
+            "spec": {
+                "data": {
+                    "hash": { "algorithm": "sha256", "value": hex::encode(Sha256::digest(payload)) }
+                },
+                "signature": {
+                    "content": signature,
+                    "publicKey": { "content": "" }
+                }
+            }
+        });
+        serde_json::json!({
+            "SignedEntryTimestamp": "",
+            "Payload": {
+                "body": STANDARD.encode(entry.to_string()),
+                "integratedTime": 1700000000,
+                "logIndex": 1,
+                "logID": "c0d23d6ad406973f9559f3ba2d1ca01f84147d8ffc5b8445c224f98b9591801d"
+            }
+        })
+    }
+
+    /// A signature layer as handed out by sigstore after its certificate
+    /// chain and Rekor SET are verified against the trust root. The
+    /// certificate certifies `certified`, and the payload is signed by
+    /// `signer`.
+    fn signature_layer(
+        certified: &EcdsaKeyPair,
+        signer: &EcdsaKeyPair,
+        issuer: &str,
+        subject: CertificateSubject,
+    ) -> SignatureLayer {
+        let raw_data = simple_signing(
+            "sha256:10e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8",
+        );
+        let signature = sign(signer, &raw_data);
+        SignatureLayer {
+            simple_signing: serde_json::from_slice(&raw_data).unwrap(),
+            oci_digest: "sha256:5f481572d088dc4023afb35fced9530ced3d9b03bf7299c6f492163cb9f0452e"
+                .into(),
+            certificate_signature: Some(CertificateSignature {
+                verification_key: verification_key(certified),
+                issuer: Some(issuer.into()),
+                github_workflow_trigger: None,
+                github_workflow_sha: None,
+                github_workflow_name: None,
+                github_workflow_repository: None,
+                github_workflow_ref: None,
+                subject,
+            }),
+            bundle: Some(serde_json::from_value(bundle(&signature, &raw_data)).unwrap()),
+            signature: Some(signature),
+            raw_data,
+        }
+    }
+
+    fn identity() -> KeylessIdentityVerifier {
+        KeylessIdentityVerifier {
+            issuer: ISSUER.into(),
+            subject: subject_regex(r"https://github\.com/example/repo/\.github/workflows/.+")
+                .unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case(ISSUER, CertificateSubject::Uri(SUBJECT.into()), true)]
+    #[case("https://accounts.google.com", CertificateSubject::Uri(SUBJECT.into()), false)]
+    #[case(
+        ISSUER,
+        CertificateSubject::Uri("https://github.com/example/fork/.github/workflows/release.yml@refs/heads/main".into()),
+        false
+    )]
+    #[case(ISSUER, CertificateSubject::Email("ci@example.com".into()), false)]
+    fn test_keyless_identity(
+        #[case] issuer: &str,
+        #[case] subject: CertificateSubject,
+        #[case] verified: bool,
+    ) {
+        let key_pair = key_pair();
+        let layer = signature_layer(&key_pair, &key_pair, issuer, subject);
+        assert_eq!(identity().verify(&layer).unwrap(), verified);
+    }
+
+    #[test]
+    fn test_keyless_email_identity() {
+        let key_pair = key_pair();
+        let identity = KeylessIdentityVerifier {
+            issuer: ISSUER.into(),
+            subject: subject_regex(r".+@example\.com").unwrap(),
+        };
+        let layer = signature_layer(
+            &key_pair,
+            &key_pair,
+            ISSUER,
+            CertificateSubject::Email("ci@example.com".into()),
+        );
+        assert!(identity.verify(&layer).unwrap());
+    }
+
+    #[test]
+    fn test_keyless_signature_and_bundle() {
+        let key_pair = key_pair();
+        let subject = || CertificateSubject::Uri(SUBJECT.into());
+
+        // Signed by another key than the certified one
+        let layer = signature_layer(&key_pair, &self::key_pair(), ISSUER, subject());
+        assert!(!identity().verify(&layer).unwrap());
+
+        // Without certificate, bundle or signature
+        let mut layer = signature_layer(&key_pair, &key_pair, ISSUER, subject());
+        layer.certificate_signature = None;
+        assert!(!identity().verify(&layer).unwrap());
+        let mut layer = signature_layer(&key_pair, &key_pair, ISSUER, subject());
+        layer.bundle = None;
+        assert!(!identity().verify(&layer).unwrap());
+        let mut layer = signature_layer(&key_pair, &key_pair, ISSUER, subject());
+        layer.signature = None;
+        assert!(!identity().verify(&layer).unwrap());
+
+        // Rekor entry of another signature over the same payload
+        let mut layer = signature_layer(&key_pair, &key_pair, ISSUER, subject());
+        let other = sign(&key_pair, &layer.raw_data);
+        layer.bundle = Some(serde_json::from_value(bundle(&other, &layer.raw_data)).unwrap());
+        assert!(!identity().verify(&layer).unwrap());
+
+        // Rekor entry of the signature over another payload
+        let mut layer = signature_layer(&key_pair, &key_pair, ISSUER, subject());
+        let signature = layer.signature.clone().unwrap();
+        let other = simple_signing(
+            "sha256:20e0ec4c7663b5f9be6efd16d8ceec760efe5377b9a0762ef3f51101ac08b7e8",
+        );
+        layer.bundle = Some(serde_json::from_value(bundle(&signature, &other)).unwrap());
+        assert!(!identity().verify(&layer).unwrap());
+    }
+
+    #[test]
+    fn test_keyless_verify_constraints() {
+        let key_pair = key_pair();
+        let layers = vec![
+            signature_layer(
+                &key_pair,
+                &key_pair,
+                ISSUER,
+                CertificateSubject::Email("ci@example.com".into()),
+            ),
+            signature_layer(
+                &key_pair,
+                &key_pair,
+                ISSUER,
+                CertificateSubject::Uri(SUBJECT.into()),
+            ),
+        ];
+        let constraints: VerificationConstraintVec = vec![Box::new(identity())];
+        assert!(verify_constraints(&layers, constraints.iter()).is_ok());
+        assert!(verify_constraints(&layers[..1], constraints.iter()).is_err());
+    }
+
+    fn trust_root(cas: &[&[u8]], tlogs: &[&[u8]], ctlogs: &[&[u8]]) -> Vec<u8> {
+        let key =
+            |key: &&[u8]| serde_json::json!({ "publicKey": { "rawBytes": STANDARD.encode(key) } });
+        serde_json::json!({
+            "mediaType": "application/vnd.dev.sigstore.trustedroot+json;version=0.1",
+            "tlogs": tlogs.iter().map(key).collect::<Vec<_>>(),
+            "certificateAuthorities": [{
+                "uri": "https://fulcio.example.com",
+                "certChain": {
+                    "certificates": cas
+                        .iter()
+                        .map(|ca| serde_json::json!({ "rawBytes": STANDARD.encode(ca) }))
+                        .collect::<Vec<_>>()
+                }
+            }],
+            "ctlogs": ctlogs.iter().map(key).collect::<Vec<_>>(),
+            "timestampAuthorities": []
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_parse_trust_root() {
+        let data = trust_root(&[b"intermediate", b"root"], &[b"rekor"], &[b"ctfe"]);
+        let trust_root = parse_trust_root(&data).unwrap();
+        assert_eq!(trust_root.fulcio_certs.len(), 2);
+        assert_eq!(trust_root.fulcio_certs[1].as_ref(), b"root");
+        assert_eq!(trust_root.rekor_keys, vec![b"rekor".to_vec()]);
+        assert_eq!(trust_root.ctfe_keys, vec![b"ctfe".to_vec()]);
+
+        // Without CT log keys
+        let data = trust_root(&[b"root"], &[b"rekor"], &[]);
+        assert!(parse_trust_root(&data).unwrap().ctfe_keys.is_empty());
+    }
+
+    #[rstest]
+    #[case(&[], &[b"rekor"])]
+    #[case(&[b"root"], &[])]
+    fn test_parse_incomplete_trust_root(#[case] cas: &[&[u8]], #[case] tlogs: &[&[u8]]) {
+        let data = trust_root(cas, tlogs, &[]);
+        assert!(parse_trust_root(&data).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_trust_root() {
+        assert!(parse_trust_root(b"not json").is_err());
+        let data = br#"{"tlogs": [{"publicKey": {"rawBytes": "!!"}}]}"#;
+        assert!(parse_trust_root(data).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        r"https://github\.com/example/repo/\.github/workflows/.+@refs/heads/main",
+        "https://github.com/example/repo/.github/workflows/release.yml@refs/heads/main",
+        true
+    )]
+    #[case(
+        r"https://github\.com/example/repo/\.github/workflows/.+@refs/heads/main",
+        "https://github.com/example/repo/.github/workflows/release.yml@refs/heads/main-fork",
+        false
+    )]
+    #[case(r".+@example\.com", "ci@example.com", true)]
+    #[case(r".+@example\.com", "ci@example.com.evil.org", false)]
+    #[case(r"ci@example\.com|release@example\.com", "release@example.com", true)]
+    #[case(r"ci@example\.com|release@example\.com", "xrelease@example.com", false)]
+    fn test_subject_regex(#[case] regex: &str, #[case] subject: &str, #[case] matches: bool) {
+        assert_eq!(subject_regex(regex).unwrap().is_match(subject), matches);
+    }
+
+    #[test]
+    fn test_invalid_subject_regex() {
+        assert!(subject_regex("(").is_err());
+    }
+}
@@ -13,11 +13,12 @@ use sigstore::registry::{Certificate, ClientConfig};
 use sigstore::{
     cosign::{
         verification_constraint::{PublicKeyVerifier, VerificationConstraintVec},
-        verify_constraints, ClientBuilder, CosignCapabilities,
+        verify_constraints, ClientBuilder, CosignCapabilities, SignatureLayer,
     },
     crypto::SigningScheme,
     errors::SigstoreVerifyConstraintsError,
     registry::{Auth, OciReference},
+    trust::ManualTrustRoot,
 };
 use std::{str::FromStr, sync::Arc};
 
@@ -28,6 +29,9 @@ use crate::{resource::ResourceProvider, signature::SignatureValidator};
 
 use super::CosignParameters;
 
+pub mod attestation;
+pub mod keyless;
+
 impl SignatureValidator {
     /// Judge whether an image is allowed by this SignScheme.
     pub(crate) async fn cosign_allows_image(
@@ -63,12 +67,7 @@ impl CosignParameters {
         self.check_reference_rule_types()?;
 
         // Get the public key
-        let key = match (&self.key_data, &self.key_path) {
-            (None, None) => bail!("Neither keyPath nor keyData is specified."),
-            (None, Some(key_path)) => resource_provider.get_resource(key_path).await?,
-            (Some(key_data), None) => key_data.as_bytes().to_vec(),
-            (Some(_), Some(_)) => bail!("Both keyPath and keyData are specified."),
-        };
+        let key = get_public_key(&resource_provider, &self.key_data, &self.key_path).await?;
 
         // Verification, will access the network
         let payloads = self
@@ -94,14 +93,7 @@ impl CosignParameters {
     /// Other types are all to be denied.
     /// If it is neither of them, return `Error`. Otherwise, return `Ok()`
     fn check_reference_rule_types(&self) -> Result<()> {
-        match &self.signed_identity {
-            Some(rule) => match rule {
-                PolicyReqMatchType::MatchRepository
-                | PolicyReqMatchType::ExactRepository { .. } => Ok(()),
-                p => Err(anyhow!("Denied by {:?}", p)),
-            },
-            None => Ok(()),
-        }
+        check_reference_rule_types(&self.signed_identity)
     }
 
     /// Verify the cosign-signed image. There will be three steps:
@@ -121,28 +113,8 @@ impl CosignParameters {
         no_proxy: Option<&String>,
         https_proxy: Option<&String>,
     ) -> Result<Vec<SigPayload>> {
-        let image_ref = OciReference::from_str(&image.reference.whole())?;
-        let auth = match auth {
-            RegistryAuth::Anonymous => Auth::Anonymous,
-            RegistryAuth::Basic(username, pass) => Auth::Basic(username.clone(), pass.clone()),
-        };
-
-        let config = ClientConfig {
-            no_proxy: no_proxy.cloned(),
-            https_proxy: https_proxy.cloned(),
-            extra_root_certificates: certificates.into_iter().cloned().collect(),
-            ..Default::default()
-        };
-        let mut client = ClientBuilder::default()
-            .with_oci_client_config(config)
-            .build()?;
-
-        // Get the cosign signature "image"'s uri and the signed image's digest
-        let (cosign_image, source_image_digest) = client.triangulate(&image_ref, &auth).await?;
-
-        let signature_layers = client
-            .trusted_signature_layers(&auth, &source_image_digest, &cosign_image)
-            .await?;
+        let signature_layers =
+            fetch_signature_layers(image, auth, certificates, no_proxy, https_proxy, None).await?;
 
         // By default, the hashing algorithm is SHA256
         let pub_key_verifier =
@@ -168,6 +140,74 @@ impl CosignParameters {
     }
 }
 
+/// Get the public key given by exactly one of keyData and keyPath.
+async fn get_public_key(
+    resource_provider: &ResourceProvider,
+    key_data: &Option<String>,
+    key_path: &Option<String>,
+) -> Result<Vec<u8>> {
+    match (key_data, key_path) {
+        (None, None) => bail!("Neither keyPath nor keyData is specified."),
+        (None, Some(key_path)) => resource_provider.get_resource(key_path).await,
+        (Some(key_data), None) => Ok(key_data.as_bytes().to_vec()),
+        (Some(_), Some(_)) => bail!("Both keyPath and keyData are specified."),
+    }
+}
+
+/// Because cosign-created signatures only contain a repository, only
+/// MatchRepository and ExactRepository can be used to accept them.
+fn check_reference_rule_types(signed_identity: &Option<PolicyReqMatchType>) -> Result<()> {
+    match signed_identity {
+        Some(rule) => match rule {
+            PolicyReqMatchType::MatchRepository | PolicyReqMatchType::ExactRepository { .. } => {
+                Ok(())
+            }
+            p => Err(anyhow!("Denied by {:?}", p)),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Download the cosign signature image of the image and gather its
+/// signature layers. With a trust root, the certificates and Rekor bundles
+/// of the layers are verified against it, and the layers failing the
+/// verification carry no certificate or bundle.
+async fn fetch_signature_layers(
+    image: &Image,
+    auth: &RegistryAuth,
+    certificates: Vec<&Certificate>,
+    no_proxy: Option<&String>,
+    https_proxy: Option<&String>,
+    trust_root: Option<&ManualTrustRoot<'_>>,
+) -> Result<Vec<SignatureLayer>> {
+    let image_ref = OciReference::from_str(&image.reference.whole())?;
+    let auth = match auth {
+        RegistryAuth::Anonymous => Auth::Anonymous,
+        RegistryAuth::Basic(username, pass) => Auth::Basic(username.clone(), pass.clone()),
+    };
+
+    let config = ClientConfig {
+        no_proxy: no_proxy.cloned(),
+        https_proxy: https_proxy.cloned(),
+        extra_root_certificates: certificates.into_iter().cloned().collect(),
+        ..Default::default()
+    };
+    let mut builder = ClientBuilder::default().with_oci_client_config(config);
+    if let Some(trust_root) = trust_root {
+        builder = builder.with_trust_repository(trust_root)?;
+    }
+    let mut client = builder.build()?;
+
+    // Get the cosign signature "image"'s uri and the signed image's digest
+    let (cosign_image, source_image_digest) = client.triangulate(&image_ref, &auth).await?;
+
+    let signature_layers = client
+        .trusted_signature_layers(&auth, &source_image_digest, &cosign_image)
+        .await?;
+
+    Ok(signature_layers)
+}
+
 #[cfg(feature = "signature-cosign")]
 #[cfg(test)]
 mod tests {
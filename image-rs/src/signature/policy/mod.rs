@@ -9,10 +9,12 @@ use std::collections::HashMap;
 use strum_macros::{Display, EnumString};
 
 use self::policy_requirement::PolicyReqType;
+use self::predicate::PredicateConstraint;
 
 use super::image;
 
 pub mod policy_requirement;
+pub mod predicate;
 pub mod ref_match;
 
 #[cfg(feature = "signature-cosign")]
@@ -47,6 +49,60 @@ pub struct CosignParameters {
     pub signed_identity: Option<PolicyReqMatchType>,
 }
 
+#[derive(Deserialize, Debug, Eq, PartialEq, Serialize, Default)]
+pub struct CosignKeylessParameters {
+    // TrustRootPath is the resource URI of the sigstore trust root, i.e. a
+    // `trusted_root.json` with the Fulcio CAs, Rekor keys and CT log keys.
+    // The public sigstore infrastructure is not accessed.
+    #[serde(rename = "trustRootPath")]
+    pub trust_root_path: String,
+
+    // OidcIssuer is the OIDC issuer the Fulcio certificate must be issued by,
+    // e.g. `https://token.actions.githubusercontent.com`.
+    #[serde(rename = "oidcIssuer")]
+    pub oidc_issuer: String,
+
+    // SubjectRegex must match the whole subject of the Fulcio certificate,
+    // i.e. the email or the URI of the signer.
+    #[serde(rename = "subjectRegex")]
+    pub subject_regex: String,
+
+    // SignedIdentity specifies what image identity the signature must be claiming about the image.
+    // Defaults to "match-exact" if not specified.
+    //
+    // This field is optional.
+    #[serde(default, rename = "signedIdentity")]
+    pub signed_identity: Option<PolicyReqMatchType>,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq, Serialize, Default)]
+pub struct AttestationParameters {
+    // KeyPath is a pathname to a local file containing the trusted key(s).
+    // Exactly one of KeyPath and KeyData can be specified.
+    //
+    // This field is optional.
+    #[serde(rename = "keyPath")]
+    pub key_path: Option<String>,
+    // KeyData contains the trusted key(s), base64-encoded.
+    // Exactly one of KeyPath and KeyData can be specified.
+    //
+    // This field is optional.
+    #[serde(rename = "keyData")]
+    pub key_data: Option<String>,
+
+    // PredicateType is the in-toto predicate type the attestation must have,
+    // e.g. `https://slsa.dev/provenance/v0.2`.
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+
+    // Constraints must all be satisfied by the in-toto statement of the
+    // attestation.
+    //
+    // This field is optional.
+    #[serde(default)]
+    pub constraints: Vec<PredicateConstraint>,
+}
+
 #[derive(Deserialize, Debug, PartialEq, Eq, Serialize, Default)]
 pub struct SimpleParameters {
     // KeyType specifies what kind of the public key to verify the signatures.
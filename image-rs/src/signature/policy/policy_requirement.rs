@@ -5,13 +5,19 @@
 
 use serde::*;
 
-use super::{CosignParameters, SimpleParameters};
+use super::{AttestationParameters, CosignKeylessParameters, CosignParameters, SimpleParameters};
 
 /// Policy Requirement Types.
 /// * `Accept`: s.t. `insecureAcceptAnything`, skip signature verification, accept the image unconditionally.
 /// * `Reject`: s.t. `reject`, reject the image directly.
 /// * `SignedBy`: s.t. `signBy`, means that the image is signed by `Simple Signing`,
 ///   and the related parameters are inside the enum.
+/// * `Cosign`: s.t. `sigstoreSigned`, means that the image is signed by `cosign` with a key.
+/// * `CosignKeyless`: s.t. `sigstoreKeyless`, means that the image is signed by `cosign`
+///   with a Fulcio certificate of the given identity, logged in Rekor.
+/// * `CosignAttestation`: s.t. `sigstoreAttested`, means that the image has an in-toto
+///   attestation of the given predicate type signed by `cosign` with a key, satisfying
+///   the constraints.
 #[derive(Deserialize, Debug, PartialEq, Eq, Serialize)]
 #[serde(tag = "type")]
 pub enum PolicyReqType {
@@ -30,6 +36,14 @@ pub enum PolicyReqType {
     /// Signed by Cosign
     #[serde(rename = "sigstoreSigned")]
     Cosign(CosignParameters),
+
+    /// Signed by Cosign keylessly
+    #[serde(rename = "sigstoreKeyless")]
+    CosignKeyless(CosignKeylessParameters),
+
+    /// Attested by Cosign
+    #[serde(rename = "sigstoreAttested")]
+    CosignAttestation(AttestationParameters),
     // TODO: Add more signature mechanism.
     //
     // Refer to issue: https://github.com/confidential-containers/image-rs/issues/7
@@ -150,4 +164,72 @@ mod tests {
             assert_eq!(policies[i], policy_parsed[i]);
         }
     }
+
+    #[test]
+    fn deserialize_sigstore_keyless_policy() {
+        use crate::signature::policy::{ref_match::PolicyReqMatchType, CosignKeylessParameters};
+
+        let json = r#"{
+            "type": "sigstoreKeyless",
+            "trustRootPath": "kbs:///default/sigstore-trust-root/test",
+            "oidcIssuer": "https://token.actions.githubusercontent.com",
+            "subjectRegex": "https://github\\.com/example/.+",
+            "signedIdentity": {
+                "type": "matchRepository"
+            }
+        }"#;
+        let policy_parsed: PolicyReqType = serde_json::from_str(json).unwrap();
+        let policy = PolicyReqType::CosignKeyless(CosignKeylessParameters {
+            trust_root_path: "kbs:///default/sigstore-trust-root/test".into(),
+            oidc_issuer: "https://token.actions.githubusercontent.com".into(),
+            subject_regex: r"https://github\.com/example/.+".into(),
+            signed_identity: Some(PolicyReqMatchType::MatchRepository),
+        });
+        assert_eq!(policy, policy_parsed);
+
+        // The identity is required.
+        let json = r#"{
+            "type": "sigstoreKeyless",
+            "trustRootPath": "kbs:///default/sigstore-trust-root/test"
+        }"#;
+        assert!(serde_json::from_str::<PolicyReqType>(json).is_err());
+    }
+
+    #[test]
+    fn deserialize_sigstore_attested_policy() {
+        use crate::signature::policy::{
+            predicate::{ConstraintRule, PredicateConstraint},
+            AttestationParameters,
+        };
+
+        let json = r#"{
+            "type": "sigstoreAttested",
+            "keyPath": "kbs:///default/cosign-public-key/test",
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "constraints": [
+                {
+                    "path": "/predicate/builder/id",
+                    "equals": "https://github.com/actions/runner"
+                }
+            ]
+        }"#;
+        let policy_parsed: PolicyReqType = serde_json::from_str(json).unwrap();
+        let policy = PolicyReqType::CosignAttestation(AttestationParameters {
+            key_path: Some("kbs:///default/cosign-public-key/test".into()),
+            key_data: None,
+            predicate_type: "https://slsa.dev/provenance/v0.2".into(),
+            constraints: vec![PredicateConstraint {
+                path: "/predicate/builder/id".into(),
+                rule: ConstraintRule::Equals("https://github.com/actions/runner".into()),
+            }],
+        });
+        assert_eq!(policy, policy_parsed);
+
+        // The predicate type is required.
+        let json = r#"{
+            "type": "sigstoreAttested",
+            "keyPath": "kbs:///default/cosign-public-key/test"
+        }"#;
+        assert!(serde_json::from_str::<PolicyReqType>(json).is_err());
+    }
 }
@@ -0,0 +1,247 @@
+// Copyright (c) 2024 Alibaba Cloud
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+//! Constraints on the in-toto statements of attestations, e.g. the builder
+//! of a SLSA provenance or the findings of a vulnerability scan.
+
+use anyhow::{anyhow, bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+/// A constraint on the in-toto statement of an attestation.
+///
+/// The `path` is a JSON pointer (RFC 6901) into the statement, where a `*`
+/// token selects all the elements of an array or object, e.g.
+/// `/predicate/builder/id` or
+/// `/predicate/scanner/result/Results/*/Vulnerabilities/*/Severity`.
+/// The rule must hold for all the selected values.
+#[derive(Deserialize, Debug, PartialEq, Eq, Serialize)]
+pub struct PredicateConstraint {
+    pub path: String,
+
+    #[serde(flatten)]
+    pub rule: ConstraintRule,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConstraintRule {
+    /// The value must equal the given JSON value.
+    Equals(Value),
+
+    /// The value must not equal the given JSON value. This also holds if no
+    /// value is selected, e.g. a scan without any vulnerabilities, as long
+    /// as the path before its first `*` token, or the parent of the value if
+    /// there is none, is found. A mistyped path does not pass.
+    NotEquals(Value),
+
+    /// The value must be a string the given regex matches as a whole.
+    Matches(String),
+
+    /// The value must be a number not greater than the given one.
+    AtMost(Number),
+}
+
+impl PredicateConstraint {
+    /// Check the constraint against the statement. The rules except
+    /// `notEquals` need at least one value selected.
+    pub fn check(&self, statement: &Value) -> Result<()> {
+        let values = select(statement, &self.path)?;
+        if values.is_empty() {
+            let required = match self.rule {
+                ConstraintRule::NotEquals(_) => required_prefix(&self.path),
+                _ => &self.path,
+            };
+            if select(statement, required)?.is_empty() {
+                bail!("{required} not found in attestation");
+            }
+        }
+
+        let regex = match &self.rule {
+            ConstraintRule::Matches(regex) => Some(
+                Regex::new(&format!("^(?:{regex})$"))
+                    .with_context(|| format!("invalid regex {regex} of {}", self.path))?,
+            ),
+            _ => None,
+        };
+
+        for value in values {
+            let satisfied = match &self.rule {
+                ConstraintRule::Equals(expected) => value == expected,
+                ConstraintRule::NotEquals(unexpected) => value != unexpected,
+                ConstraintRule::Matches(_) => value.as_str().is_some_and(|value| {
+                    regex
+                        .as_ref()
+                        .expect("regex compiled above")
+                        .is_match(value)
+                }),
+                ConstraintRule::AtMost(max) => {
+                    let max = max.as_f64().ok_or(anyhow!("invalid number {max}"))?;
+                    value.as_f64().is_some_and(|value| value <= max)
+                }
+            };
+            if !satisfied {
+                bail!("{} is {value}, violating {:?}", self.path, self.rule);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The part of the path that must be found for `notEquals` to hold without
+/// any value selected: the path before the first `*` token, or the parent of
+/// the value if there is none.
+fn required_prefix(path: &str) -> &str {
+    let mut end = path.rfind('/').unwrap_or_default();
+    let mut start = 0;
+    for token in path.split('/').skip(1) {
+        if token == "*" {
+            end = end.min(start);
+            break;
+        }
+        start += token.len() + 1;
+    }
+
+    &path[..end]
+}
+
+/// Select the values of the JSON pointer, with `*` tokens selecting all the
+/// elements.
+fn select<'a>(value: &'a Value, path: &str) -> Result<Vec<&'a Value>> {
+    if path.is_empty() {
+        return Ok(vec![value]);
+    }
+
+    let Some(path) = path.strip_prefix('/') else {
+        bail!("invalid JSON pointer {path}");
+    };
+
+    let mut values = vec![value];
+    for token in path.split('/') {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        values = values
+            .into_iter()
+            .flat_map(|value| match (value, token.as_str()) {
+                (Value::Array(array), "*") => array.iter().collect(),
+                (Value::Object(object), "*") => object.values().collect(),
+                (Value::Array(array), index) => index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| array.get(index))
+                    .into_iter()
+                    .collect(),
+                (Value::Object(object), key) => object.get(key).into_iter().collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn statement() -> Value {
+        serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v0.1",
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "subject": [{ "name": "example.com/app", "digest": { "sha256": "1234" } }],
+            "predicate": {
+                "builder": { "id": "https://github.com/actions/runner" },
+                "invocation": {
+                    "configSource": { "uri": "git+https://github.com/example/app@refs/heads/main" }
+                },
+                "scanner": {
+                    "result": {
+                        "Results": [
+                            { "Vulnerabilities": [{ "Severity": "LOW" }, { "Severity": "HIGH" }] },
+                            { "Vulnerabilities": [] }
+                        ],
+                        "Summary": { "CRITICAL": 0, "HIGH": 1 }
+                    }
+                },
+                "a/b": { "~c": true }
+            }
+        })
+    }
+
+    fn constraint(json: &str) -> PredicateConstraint {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"path": "/predicate/builder/id", "equals": "https://github.com/actions/runner"}"#,
+        true
+    )]
+    #[case(
+        r#"{"path": "/predicate/builder/id", "equals": "https://example.com/builder"}"#,
+        false
+    )]
+    #[case(r#"{"path": "/predicate/builder/missing", "equals": "x"}"#, false)]
+    #[case(r#"{"path": "/predicate/invocation/configSource/uri", "matches": "git\\+https://github\\.com/example/.+"}"#, true)]
+    #[case(r#"{"path": "/predicate/invocation/configSource/uri", "matches": "https://github\\.com/example/.+"}"#, false)]
+    #[case(
+        r#"{"path": "/predicate/scanner/result/Summary/CRITICAL", "atMost": 0}"#,
+        true
+    )]
+    #[case(
+        r#"{"path": "/predicate/scanner/result/Summary/HIGH", "atMost": 0}"#,
+        false
+    )]
+    #[case(r#"{"path": "/predicate/scanner/result/Results/*/Vulnerabilities/*/Severity", "notEquals": "CRITICAL"}"#, true)]
+    #[case(r#"{"path": "/predicate/scanner/result/Results/*/Vulnerabilities/*/Severity", "notEquals": "HIGH"}"#, false)]
+    #[case(r#"{"path": "/predicate/scanner/result/Results/1/Vulnerabilities/*/Severity", "notEquals": "HIGH"}"#, true)]
+    #[case(r#"{"path": "/predicate/scaner/result/Results/*/Vulnerabilities/*/Severity", "notEquals": "CRITICAL"}"#, false)]
+    #[case(r#"{"path": "/predicate/scanner/result/Results/2/Vulnerabilities/*/Severity", "notEquals": "HIGH"}"#, false)]
+    #[case(
+        r#"{"path": "/predicate/scanner/result/Summary/MEDIUM", "notEquals": 1}"#,
+        true
+    )]
+    #[case(
+        r#"{"path": "/predicate/scanner/result/Sumary/CRITICAL", "notEquals": 1}"#,
+        false
+    )]
+    #[case(r#"{"path": "/predicate/a~1b/~0c", "equals": true}"#, true)]
+    #[case(r#"{"path": "/subject/0/digest/sha256", "equals": "1234"}"#, true)]
+    #[case(r#"{"path": "predicate", "equals": {}}"#, false)]
+    fn test_check_constraint(#[case] json: &str, #[case] satisfied: bool) {
+        assert_eq!(
+            constraint(json).check(&statement()).is_ok(),
+            satisfied,
+            "{json}"
+        );
+    }
+
+    #[rstest]
+    #[case("/a/b/c", "/a/b")]
+    #[case("/a", "")]
+    #[case("/a/*/b/*/c", "/a")]
+    #[case("/*/b", "")]
+    #[case("/a/b*/c", "/a/b*")]
+    fn test_required_prefix(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(required_prefix(path), expected);
+    }
+
+    #[test]
+    fn test_deserialize_constraint() {
+        assert_eq!(
+            constraint(r#"{"path": "/predicate/builder/id", "equals": "x"}"#),
+            PredicateConstraint {
+                path: "/predicate/builder/id".into(),
+                rule: ConstraintRule::Equals("x".into()),
+            }
+        );
+        assert!(serde_json::from_str::<PredicateConstraint>(r#"{"path": "/a"}"#).is_err());
+        assert!(
+            serde_json::from_str::<PredicateConstraint>(r#"{"path": "/a", "below": 1}"#).is_err()
+        );
+    }
+}
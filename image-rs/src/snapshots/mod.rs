@@ -3,9 +3,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+use crate::unpack::UnpackMode;
+
 #[cfg(feature = "snapshot-unionfs")]
 pub mod occlum;
 #[cfg(feature = "snapshot-overlayfs")]
@@ -49,7 +51,7 @@ impl std::fmt::Display for SnapshotType {
 }
 
 /// A MountPoint contains the info to represents a mount point.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct MountPoint {
     /// The filesystem type of mount point.
     pub r#type: String,
@@ -63,8 +65,13 @@ pub struct MountPoint {
 
 /// Trait to mount/umount image snapshots.
 pub trait Snapshotter: Send + Sync {
-    // mount the OCI image layers to destination mount path.
-    fn mount(&mut self, layer_path: &[&str], mount_path: &Path) -> Result<MountPoint>;
+    // mount the OCI image layers unpacked in `unpack_mode` to destination mount path.
+    fn mount(
+        &mut self,
+        layer_path: &[&str],
+        mount_path: &Path,
+        unpack_mode: &UnpackMode,
+    ) -> Result<MountPoint>;
 
     // unmount the mount_point and cleanup snapshot work dir.
     fn unmount(&self, mount_point: &MountPoint) -> Result<()>;
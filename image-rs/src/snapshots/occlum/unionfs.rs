@@ -17,6 +17,7 @@ use nix::mount::MsFlags;
 use ocicrypt_rs::blockcipher::rand::rand_bytes;
 
 use crate::snapshots::{MountPoint, Snapshotter};
+use crate::unpack::UnpackMode;
 
 const LD_LIB: &str = "ld-linux-x86-64.so.2";
 
@@ -125,7 +126,12 @@ fn create_environment(mount_path: &Path) -> Result<()> {
 }
 
 impl Snapshotter for Unionfs {
-    fn mount(&mut self, layer_path: &[&str], mount_path: &Path) -> Result<MountPoint> {
+    fn mount(
+        &mut self,
+        layer_path: &[&str],
+        mount_path: &Path,
+        _unpack_mode: &UnpackMode,
+    ) -> Result<MountPoint> {
         let fs_type = String::from("sefs");
         let source = Path::new(&fs_type);
         let flags = MsFlags::empty();
@@ -295,6 +301,8 @@ mod tests {
             path_2.path().to_str().unwrap(),
         ];
 
-        assert!(occlum_unionfs.mount(layer_path, mnt_path.as_ref()).is_err());
+        assert!(occlum_unionfs
+            .mount(layer_path, mnt_path.as_ref(), &UnpackMode::default())
+            .is_err());
     }
 }
@@ -2,7 +2,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
+use nix::errno::Errno;
 use nix::mount::MsFlags;
 use sha2::{Digest, Sha256};
 use std::fs;
@@ -11,6 +12,7 @@ use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 use crate::snapshots::{MountPoint, SnapshotType, Snapshotter};
+use crate::unpack::{UnpackMode, WhiteoutMode};
 
 #[derive(Debug)]
 pub struct OverlayFs {
@@ -25,7 +27,20 @@ impl OverlayFs {
 }
 
 impl Snapshotter for OverlayFs {
-    fn mount(&mut self, layer_path: &[&str], mount_path: &Path) -> Result<MountPoint> {
+    fn mount(
+        &mut self,
+        layer_path: &[&str],
+        mount_path: &Path,
+        unpack_mode: &UnpackMode,
+    ) -> Result<MountPoint> {
+        let xattr_option = match unpack_mode.whiteout {
+            WhiteoutMode::Overlay => "",
+            // Opaque directories are marked with `user.overlay.opaque`.
+            WhiteoutMode::DeviceNodes => ",userxattr",
+            WhiteoutMode::Files => {
+                bail!("overlayfs does not support whiteouts kept as `.wh.` files")
+            }
+        };
         let fs_type = SnapshotType::Overlay.to_string();
         let overlay_lowerdir = layer_path.join(":");
 
@@ -67,10 +82,11 @@ impl Snapshotter for OverlayFs {
         let source = Path::new(&fs_type);
         let flags = MsFlags::empty();
         let options = format!(
-            "lowerdir={},upperdir={},workdir={}",
+            "lowerdir={},upperdir={},workdir={}{}",
             overlay_lowerdir,
             overlay_upperdir.display(),
-            overlay_workdir.display()
+            overlay_workdir.display(),
+            xattr_option
         );
 
         nix::mount::mount(
@@ -97,8 +113,108 @@ impl Snapshotter for OverlayFs {
     }
 
     fn unmount(&self, mount_point: &MountPoint) -> Result<()> {
-        nix::mount::umount(mount_point.mount_path.as_path())?;
+        match nix::mount::umount(mount_point.mount_path.as_path()) {
+            // Already unmounted, or the bundle has been removed.
+            Ok(()) | Err(Errno::EINVAL) | Err(Errno::ENOENT) => {}
+            Err(e) => {
+                return Err(anyhow!(
+                    "failed to unmount {:?}, with error: {}",
+                    mount_point.mount_path,
+                    e
+                ))
+            }
+        }
+
+        if mount_point.work_dir.exists() {
+            fs::remove_dir_all(&mount_point.work_dir)?;
+        }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unpack::unpack;
+    use tokio_tar::{Builder, EntryType, Header};
+
+    /// A layer tarball of the given files, and of the dirs ending with `/`.
+    async fn layer_tar(entries: &[&str]) -> Vec<u8> {
+        let mut ar = Builder::new(Vec::new());
+        for name in entries {
+            let mut header = Header::new_gnu();
+            let data: &[u8] = if name.ends_with('/') {
+                header.set_entry_type(EntryType::Directory);
+                header.set_mode(0o755);
+                b""
+            } else {
+                header.set_mode(0o644);
+                b"data"
+            };
+            header.set_size(data.len() as u64);
+            header.set_uid(0);
+            header.set_gid(0);
+            header.set_cksum();
+            ar.append_data(&mut header, name, data).await.unwrap();
+        }
+        ar.into_inner().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mount_device_nodes_whiteouts() {
+        if !nix::unistd::Uid::effective().is_root() {
+            println!("INFO: skipping {} which needs root", module_path!());
+            return;
+        }
+
+        let unpack_mode = UnpackMode {
+            whiteout: WhiteoutMode::DeviceNodes,
+            ..Default::default()
+        };
+        let tempdir = tempfile::tempdir().unwrap();
+        let lower = tempdir.path().join("lower");
+        let upper = tempdir.path().join("upper");
+        let data = layer_tar(&["file.txt", "dir/", "dir/old.txt"]).await;
+        unpack(data.as_slice(), &lower, &[], &unpack_mode)
+            .await
+            .unwrap();
+        let data = layer_tar(&[".wh.file.txt", "dir/", "dir/.wh..wh..opq", "dir/new.txt"]).await;
+        unpack(data.as_slice(), &upper, &[], &unpack_mode)
+            .await
+            .unwrap();
+        let opaque = xattr::get(upper.join("dir"), "user.overlay.opaque")
+            .unwrap()
+            .is_some();
+
+        let mut overlayfs = OverlayFs::new(tempdir.path().join("overlay"));
+        let rootfs = tempdir.path().join("rootfs");
+        let layer_path = [upper.to_str().unwrap(), lower.to_str().unwrap()];
+        let mount_point = overlayfs.mount(&layer_path, &rootfs, &unpack_mode).unwrap();
+
+        assert!(!rootfs.join("file.txt").exists());
+        assert!(rootfs.join("dir/new.txt").exists());
+        if opaque {
+            assert!(!rootfs.join("dir/old.txt").exists());
+        }
+
+        overlayfs.unmount(&mount_point).unwrap();
+    }
+
+    #[test]
+    fn test_mount_rejects_whiteout_files() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut overlayfs = OverlayFs::new(tempdir.path().join("overlay"));
+        let unpack_mode = UnpackMode {
+            whiteout: WhiteoutMode::Files,
+            ..Default::default()
+        };
+
+        let layer = tempdir.path().join("layer");
+        let rootfs = tempdir.path().join("rootfs");
+        assert!(overlayfs
+            .mount(&[layer.to_str().unwrap()], &rootfs, &unpack_mode)
+            .is_err());
+        assert!(!rootfs.exists());
+    }
+}
@@ -4,7 +4,6 @@
 
 use anyhow::{anyhow, bail, Context, Result};
 use log::error;
-use sha2::Digest;
 use std::{
     path::{Path, PathBuf},
     pin::Pin,
@@ -12,8 +11,8 @@ use std::{
 };
 use tokio::io::{AsyncRead, ReadBuf};
 
-use crate::digest::{DigestHasher, LayerDigestHasher, DIGEST_SHA256_PREFIX, DIGEST_SHA512_PREFIX};
-use crate::unpack::unpack;
+use crate::digest::{DigestHasher, LayerDigestHasher, DIGEST_SHA256_PREFIX};
+use crate::unpack::{unpack, UnpackMode};
 use crate::ERR_BAD_UNCOMPRESSED_DIGEST;
 
 struct HashReader<R, H> {
@@ -58,21 +57,20 @@ where
 
 /// stream_processing will handle async uncompressed layer data and
 /// unpack to the destination, returns layer digest for verification.
+/// The skipped entries are still part of the digest.
 pub async fn stream_processing(
     layer_reader: impl AsyncRead + Unpin,
     diff_id: &str,
     destination: &Path,
+    skip_entries: &[&str],
+    unpack_mode: &UnpackMode,
 ) -> Result<String> {
     let dest = destination.to_path_buf();
-    let hasher = if diff_id.starts_with(DIGEST_SHA256_PREFIX) {
-        LayerDigestHasher::Sha256(sha2::Sha256::new())
-    } else if diff_id.starts_with(DIGEST_SHA512_PREFIX) {
-        LayerDigestHasher::Sha512(sha2::Sha512::new())
-    } else {
+    let Some(hasher) = LayerDigestHasher::new(diff_id) else {
         bail!("{}: {:?}", ERR_BAD_UNCOMPRESSED_DIGEST, diff_id);
     };
 
-    async_processing(layer_reader, hasher, dest)
+    async_processing(layer_reader, hasher, dest, skip_entries, unpack_mode)
         .await
         .map_err(|e| anyhow!("hasher {} {:?}", DIGEST_SHA256_PREFIX, e))
 }
@@ -81,9 +79,18 @@ async fn async_processing(
     layer_reader: (impl AsyncRead + Unpin),
     hasher: LayerDigestHasher,
     destination: PathBuf,
+    skip_entries: &[&str],
+    unpack_mode: &UnpackMode,
 ) -> Result<String> {
     let mut hash_reader = HashReader::new(layer_reader, hasher);
-    if let Err(e) = unpack(&mut hash_reader, destination.as_path()).await {
+    if let Err(e) = unpack(
+        &mut hash_reader,
+        destination.as_path(),
+        skip_entries,
+        unpack_mode,
+    )
+    .await
+    {
         error!("failed to unpack layer: {e:?}");
         tokio::fs::remove_dir_all(destination.as_path())
             .await
@@ -97,7 +104,9 @@ async fn async_processing(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::digest::DIGEST_SHA512_PREFIX;
     use ring::rand::SecureRandom;
+    use sha2::Digest;
     use tokio::{
         fs::File,
         io::{AsyncReadExt, BufReader},
@@ -133,10 +142,15 @@ mod tests {
 
         let hasher = LayerDigestHasher::Sha256(sha2::Sha256::new());
 
-        let layer_digest_new =
-            async_processing(layer_data.as_slice(), hasher, file_path.to_path_buf())
-                .await
-                .unwrap();
+        let layer_digest_new = async_processing(
+            layer_data.as_slice(),
+            hasher,
+            file_path.to_path_buf(),
+            &[],
+            &UnpackMode::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(layer_digest, layer_digest_new);
 
         let file = File::open(file_path.join("file.txt")).await.unwrap();
@@ -174,9 +188,15 @@ mod tests {
         let tempdir = tempfile::tempdir().unwrap();
         let file_path = tempdir.path().join("layer0");
 
-        let layer_digest_new = stream_processing(layer_data.as_slice(), &layer_digest, &file_path)
-            .await
-            .unwrap();
+        let layer_digest_new = stream_processing(
+            layer_data.as_slice(),
+            &layer_digest,
+            &file_path,
+            &[],
+            &UnpackMode::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(layer_digest, layer_digest_new);
 
         let tempdir = tempfile::tempdir().unwrap();
@@ -187,9 +207,15 @@ mod tests {
             sha2::Sha512::digest(layer_data.as_slice())
         );
 
-        let layer_digest_new = stream_processing(layer_data.as_slice(), &layer_digest, &file_path)
-            .await
-            .unwrap();
+        let layer_digest_new = stream_processing(
+            layer_data.as_slice(),
+            &layer_digest,
+            &file_path,
+            &[],
+            &UnpackMode::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(layer_digest, layer_digest_new);
     }
 }
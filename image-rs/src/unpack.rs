@@ -8,6 +8,8 @@ use futures::StreamExt;
 use log::{debug, warn};
 use nix::libc::timeval;
 use nix::sys::stat::{mknod, Mode, SFlag};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use std::{
     collections::HashMap,
@@ -44,6 +46,115 @@ fn is_attr_available(path: &Path) -> Result<bool> {
 const WHITEOUT_PREFIX: &str = ".wh.";
 const WHITEOUT_OPAQUE_DIR: &str = ".wh..wh..opq";
 
+/// A range of ids of the tarball entries mapped to ids on the host, in the
+/// form of a line of `/proc/<pid>/uid_map`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdMapping {
+    /// First id of the range in the tarball.
+    pub container_id: u32,
+
+    /// First id of the range on the host.
+    pub host_id: u32,
+
+    /// Length of the range.
+    pub size: u32,
+}
+
+/// How whiteouts of the tarball are represented in the unpacked layer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WhiteoutMode {
+    /// Whiteouts are converted to the format of overlayfs, i.e. character
+    /// devices and `trusted.overlay.opaque` xattrs, if xattrs are available.
+    /// Otherwise they are kept as `.wh.` files.
+    #[default]
+    Overlay,
+
+    /// Whiteout files are converted to character devices, and opaque
+    /// directories are marked with `user.overlay.opaque` xattrs, as read by
+    /// overlayfs mounted with `userxattr`, e.g. in a user namespace. Opaque
+    /// markers are kept as `.wh..wh..opq` files if xattrs are unavailable.
+    DeviceNodes,
+
+    /// Whiteouts are kept as `.wh.` files, i.e. the AUFS format. Overlayfs
+    /// does not interpret them, so the overlay snapshotter rejects layers
+    /// unpacked in this mode.
+    Files,
+}
+
+impl WhiteoutMode {
+    fn opaque_xattr(&self) -> &'static str {
+        match self {
+            Self::DeviceNodes => "user.overlay.opaque",
+            _ => "trusted.overlay.opaque",
+        }
+    }
+
+    /// Whether the whiteout entry is converted rather than kept as a file.
+    fn converts(&self, name: &str, attr_available: bool) -> bool {
+        match self {
+            Self::Overlay => attr_available,
+            Self::DeviceNodes => name != WHITEOUT_OPAQUE_DIR || attr_available,
+            Self::Files => false,
+        }
+    }
+}
+
+/// The mode layers are unpacked in. The default mode applies the ownership
+/// of the tarball entries verbatim, which needs root on the host. Unpacking
+/// in a user namespace without `CAP_SYS_ADMIN` needs the ids mapped into the
+/// namespace and whiteouts without trusted xattrs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnpackMode {
+    /// Mappings of the uids of the tarball entries. Uids are kept as is if
+    /// empty, and an unmapped uid fails the unpack otherwise.
+    #[serde(default)]
+    pub uid_mappings: Vec<IdMapping>,
+
+    /// Mappings of the gids of the tarball entries, like `uid_mappings`.
+    #[serde(default)]
+    pub gid_mappings: Vec<IdMapping>,
+
+    /// How whiteouts are represented.
+    #[serde(default)]
+    pub whiteout: WhiteoutMode,
+}
+
+impl UnpackMode {
+    /// The key of the layer or image of the given digest unpacked in this
+    /// mode, which also names the layer dir. Layers and images are stored
+    /// once per mode. The default mode keeps the digest, so that the ones
+    /// stored before the mode was recorded are still found.
+    pub fn key_of(&self, digest: &str) -> String {
+        if *self == Self::default() {
+            return digest.to_string();
+        }
+
+        let mode = serde_json::to_vec(self).expect("serialize unpack mode");
+        let mode = format!("{:x}", Sha256::digest(mode));
+        format!("{digest}-{}", &mode[..16])
+    }
+
+    fn map_uid(&self, uid: u32) -> Result<u32> {
+        map_id(&self.uid_mappings, uid).ok_or(anyhow!("uid {uid} is not mapped"))
+    }
+
+    fn map_gid(&self, gid: u32) -> Result<u32> {
+        map_id(&self.gid_mappings, gid).ok_or(anyhow!("gid {gid} is not mapped"))
+    }
+}
+
+fn map_id(mappings: &[IdMapping], id: u32) -> Option<u32> {
+    if mappings.is_empty() {
+        return Some(id);
+    }
+
+    mappings
+        .iter()
+        .find(|m| id >= m.container_id && id - m.container_id < m.size)
+        .and_then(|m| m.host_id.checked_add(id - m.container_id))
+}
+
 /// Returns whether the file name is a whiteout file
 fn is_whiteout(name: &str) -> bool {
     name.starts_with(WHITEOUT_PREFIX)
@@ -59,6 +170,7 @@ async fn convert_whiteout(
     gid: u32,
     mode: Option<u32>,
     destination: &Path,
+    whiteout_mode: WhiteoutMode,
 ) -> Result<()> {
     let parent = path
         .parent()
@@ -67,7 +179,7 @@ async fn convert_whiteout(
     // Handle opaque directories
     if name == WHITEOUT_OPAQUE_DIR {
         let destination_parent = destination.join(parent);
-        xattr::set(destination_parent, "trusted.overlay.opaque", b"y")?;
+        xattr::set(destination_parent, whiteout_mode.opaque_xattr(), b"y")?;
         return Ok(());
     }
 
@@ -87,8 +199,15 @@ async fn convert_whiteout(
     set_perms_ownerships(&path, ChownType::LChown, uid, gid, mode).await
 }
 
-/// Unpack the contents of tarball to the destination path
-pub async fn unpack<R: AsyncRead + Unpin>(input: R, destination: &Path) -> Result<()> {
+/// Unpack the contents of tarball to the destination path in the given
+/// mode. Entries at the root of the tarball named in `skip_entries` are not
+/// unpacked.
+pub async fn unpack<R: AsyncRead + Unpin>(
+    input: R,
+    destination: &Path,
+    skip_entries: &[&str],
+    unpack_mode: &UnpackMode,
+) -> Result<()> {
     if destination.exists() {
         warn!(
             "unpack destination {:?} already exists, will delete and rerwrite the layer",
@@ -117,6 +236,15 @@ pub async fn unpack<R: AsyncRead + Unpin>(input: R, destination: &Path) -> Resul
         let mut file = file?;
 
         let entry_path = file.path()?;
+        let root_entry = entry_path.strip_prefix("./").unwrap_or(&entry_path);
+        if skip_entries
+            .iter()
+            .any(|skip| root_entry == Path::new(skip))
+        {
+            debug!("skip layer metadata entry {entry_path:?}");
+            continue;
+        }
+
         let entry_name = entry_path
             .file_name()
             .unwrap_or_default()
@@ -127,15 +255,26 @@ pub async fn unpack<R: AsyncRead + Unpin>(input: R, destination: &Path) -> Resul
             .uid()?
             .try_into()
             .context("UID is too large!")?;
+        let uid = unpack_mode.map_uid(uid)?;
         let gid = file
             .header()
             .gid()?
             .try_into()
             .context("GID is too large!")?;
+        let gid = unpack_mode.map_gid(gid)?;
         let mode = file.header().mode().ok();
 
-        if attr_available && is_whiteout(entry_name) {
-            convert_whiteout(entry_name, &entry_path, uid, gid, mode, destination).await?;
+        if is_whiteout(entry_name) && unpack_mode.whiteout.converts(entry_name, attr_available) {
+            convert_whiteout(
+                entry_name,
+                &entry_path,
+                uid,
+                gid,
+                mode,
+                destination,
+                unpack_mode.whiteout,
+            )
+            .await?;
             continue;
         }
 
@@ -255,6 +394,7 @@ async fn set_perms_ownerships(
 mod tests {
     use std::os::unix::fs::{chown, lchown, MetadataExt};
 
+    use rstest::rstest;
     use std::os::unix::fs::FileTypeExt;
     use tokio::{
         fs::{self, File},
@@ -332,7 +472,11 @@ mod tests {
             fs::remove_dir_all(destination).await.unwrap();
         }
 
-        assert!(unpack(data.as_slice(), destination).await.is_ok());
+        assert!(
+            unpack(data.as_slice(), destination, &[], &UnpackMode::default())
+                .await
+                .is_ok()
+        );
 
         let path = destination.join("file.txt");
         let metadata = fs::metadata(path).await.unwrap();
@@ -368,6 +512,143 @@ mod tests {
 
         // though destination already exists, it will be deleted
         // and rewrite
-        assert!(unpack(data.as_slice(), destination).await.is_ok());
+        assert!(
+            unpack(data.as_slice(), destination, &[], &UnpackMode::default())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unpack_skip_entries() {
+        let mut ar = Builder::new(Vec::new());
+        for name in ["./stargz.index.json", "file.txt", "dir/stargz.index.json"] {
+            let mut header = tokio_tar::Header::new_gnu();
+            header.set_size(4);
+            header.set_mode(0o644);
+            header.set_uid(0);
+            header.set_gid(0);
+            header.set_cksum();
+            ar.append_data(&mut header, name, &b"data"[..])
+                .await
+                .unwrap();
+        }
+        let data = ar.into_inner().await.unwrap();
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let destination = tempdir.path().join("layer");
+        unpack(
+            data.as_slice(),
+            &destination,
+            &["stargz.index.json"],
+            &UnpackMode::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(!destination.join("stargz.index.json").exists());
+        assert!(destination.join("file.txt").exists());
+        assert!(destination.join("dir/stargz.index.json").exists());
+    }
+
+    #[rstest]
+    #[case(&[], 1000, Some(1000))]
+    #[case(&[(0, 100000, 65536)], 0, Some(100000))]
+    #[case(&[(0, 100000, 65536)], 1000, Some(101000))]
+    #[case(&[(0, 100000, 65536)], 65536, None)]
+    #[case(&[(0, 1000, 1), (1, 100000, 65535)], 0, Some(1000))]
+    #[case(&[(0, 1000, 1), (1, 100000, 65535)], 2, Some(100001))]
+    #[case(&[(0, u32::MAX, 2)], 1, None)]
+    fn test_map_id(
+        #[case] mappings: &[(u32, u32, u32)],
+        #[case] id: u32,
+        #[case] expected: Option<u32>,
+    ) {
+        let mappings: Vec<IdMapping> = mappings
+            .iter()
+            .map(|&(container_id, host_id, size)| IdMapping {
+                container_id,
+                host_id,
+                size,
+            })
+            .collect();
+        assert_eq!(map_id(&mappings, id), expected);
+    }
+
+    #[test]
+    fn test_unpack_mode_key() {
+        let digest = "sha256:1234";
+        assert_eq!(UnpackMode::default().key_of(digest), digest);
+
+        let files = UnpackMode {
+            whiteout: WhiteoutMode::Files,
+            ..Default::default()
+        };
+        let device_nodes = UnpackMode {
+            whiteout: WhiteoutMode::DeviceNodes,
+            ..Default::default()
+        };
+        assert!(files.key_of(digest).starts_with("sha256:1234-"));
+        assert_eq!(files.key_of(digest), files.clone().key_of(digest));
+        assert_ne!(files.key_of(digest), device_nodes.key_of(digest));
+    }
+
+    #[tokio::test]
+    async fn test_unpack_mode() {
+        let mut ar = Builder::new(Vec::new());
+        for name in [
+            "file.txt".to_string(),
+            WHITEOUT_PREFIX.to_owned() + "whiteout_file.txt",
+        ] {
+            let mut header = tokio_tar::Header::new_gnu();
+            header.set_size(4);
+            header.set_mode(0o644);
+            header.set_uid(10);
+            header.set_gid(20);
+            header.set_cksum();
+            ar.append_data(&mut header, name, &b"data"[..])
+                .await
+                .unwrap();
+        }
+        let data = ar.into_inner().await.unwrap();
+
+        let unpack_mode = UnpackMode {
+            uid_mappings: vec![IdMapping {
+                container_id: 0,
+                host_id: 1000,
+                size: 100,
+            }],
+            gid_mappings: vec![IdMapping {
+                container_id: 0,
+                host_id: 2000,
+                size: 100,
+            }],
+            whiteout: WhiteoutMode::Files,
+        };
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let destination = tempdir.path().join("layer");
+        unpack(data.as_slice(), &destination, &[], &unpack_mode)
+            .await
+            .unwrap();
+
+        let metadata = fs::metadata(destination.join("file.txt")).await.unwrap();
+        assert_eq!(metadata.uid(), 1010);
+        assert_eq!(metadata.gid(), 2020);
+
+        let whiteout = destination.join(WHITEOUT_PREFIX.to_owned() + "whiteout_file.txt");
+        assert!(fs::metadata(whiteout).await.unwrap().is_file());
+
+        let unpack_mode = UnpackMode {
+            uid_mappings: vec![IdMapping {
+                container_id: 0,
+                host_id: 1000,
+                size: 10,
+            }],
+            ..Default::default()
+        };
+        assert!(unpack(data.as_slice(), &destination, &[], &unpack_mode)
+            .await
+            .is_err());
     }
 }
@@ -0,0 +1,475 @@
+// Copyright The ocicrypt Authors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Authenticated streaming encryption of image layers with AES-256-GCM.
+//!
+//! The layer is split into chunks of `chunksize` bytes, and each chunk is sealed on its own with
+//! a 16 bytes tag appended. The nonce of a chunk is a random 7 bytes prefix, the 32 bits big
+//! endian chunk counter and a byte marking the final chunk (the STREAM construction), so that
+//! reordered, dropped or truncated chunks fail authentication. Only the final chunk may be
+//! shorter than `chunksize`, and it is empty only for an empty layer.
+
+use std::io::{Error, ErrorKind, Read};
+
+use aes_gcm::aead::{AeadInPlace, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce, Tag};
+use anyhow::{anyhow, Result};
+
+use crate::blockcipher::{EncryptionFinalizer, LayerBlockCipher, LayerBlockCipherOptions};
+
+use super::rand::rand_bytes;
+
+const AES256_KEY_SIZE: usize = 32;
+const NONCE_PREFIX_SIZE: usize = 7;
+const TAG_SIZE: usize = 16;
+const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// The default size of the plaintext of a chunk.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The chunking state of the stream, separated from the reader to be shared by the blocking and
+/// the async readers.
+struct ChunkState {
+    encrypt: bool,
+    cipher: Aes256Gcm,
+    nonce_prefix: Vec<u8>,
+    counter: u32,
+    // One input chunk and a byte of lookahead to know whether the chunk is the final one.
+    input: Vec<u8>,
+    filled: usize,
+    eof: bool,
+    output: Vec<u8>,
+    output_pos: usize,
+    done: bool,
+}
+
+impl ChunkState {
+    fn wants_input(&self) -> bool {
+        !self.eof && self.filled < self.input.len()
+    }
+
+    fn spare(&mut self) -> &mut [u8] {
+        &mut self.input[self.filled..]
+    }
+
+    fn fill(&mut self, read_len: usize) {
+        if read_len == 0 {
+            self.eof = true;
+        }
+        self.filled += read_len;
+    }
+
+    fn pending(&self) -> &[u8] {
+        &self.output[self.output_pos..]
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.output_pos += len;
+    }
+
+    fn finished(&self) -> bool {
+        self.done && self.pending().is_empty()
+    }
+
+    fn nonce(&self, last: bool) -> Vec<u8> {
+        let mut nonce = self.nonce_prefix.clone();
+        nonce.extend_from_slice(&self.counter.to_be_bytes());
+        nonce.push(last as u8);
+        nonce
+    }
+
+    /// Seal or open the next chunk once the input holds a full chunk and the lookahead byte, or
+    /// the rest of the stream.
+    fn process(&mut self) -> std::io::Result<()> {
+        let chunk_len = self.input.len() - 1;
+        let last = self.eof && self.filled <= chunk_len;
+        let len = if last { self.filled } else { chunk_len };
+        let nonce = self.nonce(last);
+        let nonce = Nonce::from_slice(&nonce);
+
+        let mut output = self.input[..len].to_vec();
+        if self.encrypt {
+            let tag = self
+                .cipher
+                .encrypt_in_place_detached(nonce, b"", &mut output)
+                .map_err(|_| Error::other(format!("failed to encrypt chunk {}", self.counter)))?;
+            output.extend_from_slice(&tag);
+        } else {
+            if len < TAG_SIZE {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    format!("chunk {} is truncated", self.counter),
+                ));
+            }
+            let tag = output.split_off(len - TAG_SIZE);
+            self.cipher
+                .decrypt_in_place_detached(nonce, b"", &mut output, Tag::from_slice(&tag))
+                .map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("failed to authenticate chunk {}", self.counter),
+                    )
+                })?;
+        }
+
+        if last {
+            self.done = true;
+        } else {
+            self.counter = self
+                .counter
+                .checked_add(1)
+                .ok_or_else(|| Error::other("too many chunks in the byte stream"))?;
+        }
+        self.input.copy_within(len..self.filled, 0);
+        self.filled -= len;
+        self.output = output;
+        self.output_pos = 0;
+
+        Ok(())
+    }
+}
+
+pin_project_lite::pin_project! {
+    struct AESGCMChunkedBlockCipherState<R> {
+        chunks: ChunkState,
+        #[pin]
+        reader: R,
+    }
+}
+
+/// Implementation of the chunked AES GCM stream cipher.
+pub struct AESGCMChunkedBlockCipher<R> {
+    key_len: usize,
+    state: Option<AESGCMChunkedBlockCipherState<R>>,
+}
+
+impl<R> AESGCMChunkedBlockCipher<R> {
+    /// Create a new instance of `AESGCMChunkedBlockCipher`.
+    pub fn new(bits: usize) -> Result<AESGCMChunkedBlockCipher<R>> {
+        if bits != AES256_KEY_SIZE * 8 {
+            return Err(anyhow!("AES GCM bit count not supported"));
+        }
+
+        Ok(AESGCMChunkedBlockCipher {
+            key_len: AES256_KEY_SIZE,
+            state: None,
+        })
+    }
+
+    // init initializes an instance
+    fn init(&mut self, encrypt: bool, reader: R, opts: &mut LayerBlockCipherOptions) -> Result<()> {
+        let symmetric_key = &opts.private.symmetric_key;
+        if symmetric_key.len() != AES256_KEY_SIZE {
+            return Err(anyhow!(
+                "invalid key length of {} bytes; expect {} bytes",
+                symmetric_key.len(),
+                AES256_KEY_SIZE
+            ));
+        }
+
+        let chunk_size = match opts.get_opt("chunksize") {
+            Some(v) => {
+                let v: [u8; 4] = v
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid chunk size encoding"))?;
+                u32::from_be_bytes(v) as usize
+            }
+            None => DEFAULT_CHUNK_SIZE,
+        };
+        if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+            return Err(anyhow!(
+                "invalid chunk size of {} bytes; need at most {} bytes",
+                chunk_size,
+                MAX_CHUNK_SIZE
+            ));
+        }
+
+        let mut nonce_prefix = vec![0u8; NONCE_PREFIX_SIZE];
+        match opts.get_opt("nonce") {
+            Some(v) => {
+                if v.len() != NONCE_PREFIX_SIZE {
+                    return Err(anyhow!(
+                        "invalid nonce length of {} bytes; need {} bytes",
+                        v.len(),
+                        NONCE_PREFIX_SIZE
+                    ));
+                }
+                nonce_prefix = v;
+            }
+            None => rand_bytes(&mut nonce_prefix[..])?,
+        }
+
+        let cipher = Aes256Gcm::new_from_slice(symmetric_key)
+            .map_err(|_| anyhow!("Failed to create AES GCM cipher"))?;
+        let input_len = if encrypt {
+            chunk_size
+        } else {
+            chunk_size + TAG_SIZE
+        };
+
+        self.state = Some(AESGCMChunkedBlockCipherState {
+            chunks: ChunkState {
+                encrypt,
+                cipher,
+                nonce_prefix: nonce_prefix.clone(),
+                counter: 0,
+                input: vec![0u8; input_len + 1],
+                filled: 0,
+                eof: false,
+                output: Vec::new(),
+                output_pos: 0,
+                done: false,
+            },
+            reader,
+        });
+
+        opts.private
+            .cipher_options
+            .entry("nonce".to_string())
+            .or_insert(nonce_prefix);
+        opts.public
+            .cipher_options
+            .entry("chunksize".to_string())
+            .or_insert((chunk_size as u32).to_be_bytes().to_vec());
+
+        Ok(())
+    }
+}
+
+impl<R> LayerBlockCipher<R> for AESGCMChunkedBlockCipher<R> {
+    fn generate_key(&self) -> Result<Vec<u8>> {
+        let mut key = vec![0; self.key_len];
+        rand_bytes(&mut key[..])?;
+        Ok(key)
+    }
+
+    fn encrypt(&mut self, input: R, opts: &mut LayerBlockCipherOptions) -> Result<()> {
+        self.init(true, input, opts)
+    }
+
+    fn decrypt(&mut self, input: R, opts: &mut LayerBlockCipherOptions) -> Result<()> {
+        self.init(false, input, opts)
+    }
+}
+
+impl<R> EncryptionFinalizer for AESGCMChunkedBlockCipher<R> {
+    fn finalized_lbco(&self, opts: &mut LayerBlockCipherOptions) -> Result<()> {
+        let state = self.state.as_ref().ok_or_else(|| {
+            anyhow!("The AESGCMChunkedBlockCipher object hasn't been initialized yet")
+        })?;
+        if !state.chunks.done {
+            Err(anyhow!("Read()ing not complete, unable to finalize"))
+        } else {
+            // Every chunk carries its own tag, no HMAC over the whole layer is needed.
+            opts.public.hmac = Vec::new();
+            Ok(())
+        }
+    }
+}
+
+impl<R: Read> Read for AESGCMChunkedBlockCipher<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let state = self
+            .state
+            .as_mut()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::Unsupported))?;
+        let chunks = &mut state.chunks;
+
+        loop {
+            let len = chunks.pending().len().min(buf.len());
+            buf[..len].copy_from_slice(&chunks.pending()[..len]);
+            chunks.consume(len);
+            if len > 0 || buf.is_empty() || chunks.finished() {
+                return Ok(len);
+            }
+
+            while chunks.wants_input() {
+                let read_len = state.reader.read(chunks.spare())?;
+                chunks.fill(read_len);
+            }
+            chunks.process()?;
+        }
+    }
+}
+
+#[cfg(feature = "async-io")]
+impl<R: tokio::io::AsyncRead> tokio::io::AsyncRead for AESGCMChunkedBlockCipher<R> {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        use std::task::Poll;
+
+        if self.state.is_none() {
+            return Poll::Ready(Err(std::io::Error::from(std::io::ErrorKind::Unsupported)));
+        }
+
+        // This is okay because `state` is pinned when `self` is.
+        let state = unsafe { self.map_unchecked_mut(|v| v.state.as_mut().unwrap()) };
+        let pinned_state = state.project();
+        let chunks = pinned_state.chunks;
+        let mut reader = pinned_state.reader;
+
+        loop {
+            let len = chunks.pending().len().min(buf.remaining());
+            buf.put_slice(&chunks.pending()[..len]);
+            chunks.consume(len);
+            if len > 0 || buf.remaining() == 0 || chunks.finished() {
+                return Poll::Ready(Ok(()));
+            }
+
+            // The partially read chunk is kept in `chunks` while the reader is pending.
+            while chunks.wants_input() {
+                let mut read_buf = tokio::io::ReadBuf::new(chunks.spare());
+                match reader.as_mut().poll_read(cx, &mut read_buf) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(res) => res?,
+                }
+                let read_len = read_buf.filled().len();
+                chunks.fill(read_len);
+            }
+            chunks.process()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt(layer_data: &[u8], lbco: &mut LayerBlockCipherOptions) -> Vec<u8> {
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        lbco.private.symmetric_key = block_cipher.generate_key().unwrap();
+        block_cipher.encrypt(layer_data, lbco).unwrap();
+
+        let mut encrypted_data = Vec::new();
+        block_cipher.read_to_end(&mut encrypted_data).unwrap();
+        block_cipher.finalized_lbco(lbco).unwrap();
+        encrypted_data
+    }
+
+    fn decrypt(encrypted_data: &[u8], lbco: &mut LayerBlockCipherOptions) -> Result<Vec<u8>> {
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        block_cipher.decrypt(encrypted_data, lbco)?;
+
+        let mut plaintxt_data = Vec::new();
+        block_cipher.read_to_end(&mut plaintxt_data)?;
+        Ok(plaintxt_data)
+    }
+
+    #[test]
+    fn test_aes_gcm_chunked_block_cipher() {
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        let mut lbco = LayerBlockCipherOptions::default();
+
+        // Error due to LayerBlockCipherOptions without symmetric key
+        assert!(block_cipher
+            .encrypt(b"this is some data".as_slice(), &mut lbco)
+            .is_err());
+        assert!(AESGCMChunkedBlockCipher::<&[u8]>::new(128).is_err());
+
+        for len in [0usize, 1, 15, 16, 17, 32, 33, 100] {
+            let layer_data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut lbco = LayerBlockCipherOptions::default();
+            lbco.public
+                .cipher_options
+                .insert("chunksize".to_string(), 16u32.to_be_bytes().to_vec());
+
+            let encrypted_data = encrypt(&layer_data, &mut lbco);
+            let chunks = len.div_ceil(16).max(1);
+            assert_eq!(
+                encrypted_data.len(),
+                len + chunks * TAG_SIZE,
+                "layer of {len} bytes"
+            );
+
+            let mut lbco: LayerBlockCipherOptions =
+                serde_json::from_str(&serde_json::to_string(&lbco).unwrap()).unwrap();
+            assert_eq!(decrypt(&encrypted_data, &mut lbco).unwrap(), layer_data);
+
+            // Invalid key
+            let mut invalid = lbco.clone();
+            invalid.private.symmetric_key = vec![0; 32];
+            assert!(decrypt(&encrypted_data, &mut invalid).is_err());
+
+            // Truncated at the boundary of the final chunk
+            if chunks > 1 {
+                let truncated = &encrypted_data[..(chunks - 1) * (16 + TAG_SIZE)];
+                assert!(decrypt(truncated, &mut lbco).is_err());
+            }
+
+            // Trailing data after the final chunk
+            let mut appended = encrypted_data.clone();
+            appended.extend_from_slice(&[0; 16 + TAG_SIZE]);
+            assert!(decrypt(&appended, &mut lbco).is_err());
+        }
+    }
+
+    #[test]
+    fn test_aes_gcm_chunked_fail_at_first_bad_chunk() {
+        let layer_data = vec![7u8; 100];
+        let mut lbco = LayerBlockCipherOptions::default();
+        lbco.public
+            .cipher_options
+            .insert("chunksize".to_string(), 16u32.to_be_bytes().to_vec());
+        let mut encrypted_data = encrypt(&layer_data, &mut lbco);
+
+        // Tamper the third chunk
+        encrypted_data[2 * (16 + TAG_SIZE) + 3] ^= 1;
+
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        block_cipher
+            .decrypt(encrypted_data.as_slice(), &mut lbco)
+            .unwrap();
+        let mut plaintxt_data = vec![0u8; 16];
+        block_cipher.read_exact(&mut plaintxt_data).unwrap();
+        block_cipher.read_exact(&mut plaintxt_data).unwrap();
+        let err = block_cipher.read(&mut plaintxt_data).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // Swapped chunks
+        let mut encrypted_data = encrypt(&layer_data, &mut lbco);
+        let (first, second) = encrypted_data.split_at_mut(16 + TAG_SIZE);
+        first.swap_with_slice(&mut second[..16 + TAG_SIZE]);
+        assert!(decrypt(&encrypted_data, &mut lbco).is_err());
+    }
+
+    #[cfg(feature = "async-io")]
+    #[tokio::test]
+    async fn test_async_aes_gcm_chunked_block_cipher() {
+        let layer_data: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let mut lbco = LayerBlockCipherOptions::default();
+        lbco.public
+            .cipher_options
+            .insert("chunksize".to_string(), 64u32.to_be_bytes().to_vec());
+        let mut encrypted_data = encrypt(&layer_data, &mut lbco);
+
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        block_cipher
+            .decrypt(encrypted_data.as_slice(), &mut lbco)
+            .unwrap();
+        let mut plaintxt_data = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut block_cipher, &mut plaintxt_data)
+            .await
+            .unwrap();
+        assert_eq!(layer_data, plaintxt_data);
+
+        // Tamper the last chunk, the chunks before it are still returned
+        let len = encrypted_data.len();
+        encrypted_data[len - 1] ^= 1;
+        let mut block_cipher = AESGCMChunkedBlockCipher::new(256).unwrap();
+        block_cipher
+            .decrypt(encrypted_data.as_slice(), &mut lbco)
+            .unwrap();
+        let mut plaintxt_data = vec![0u8; 960];
+        tokio::io::AsyncReadExt::read_exact(&mut block_cipher, &mut plaintxt_data)
+            .await
+            .unwrap();
+        assert!(
+            tokio::io::AsyncReadExt::read(&mut block_cipher, &mut plaintxt_data)
+                .await
+                .is_err()
+        );
+    }
+}
@@ -12,6 +12,9 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 mod aes_ctr;
 use aes_ctr::AESCTRBlockCipher;
 
+mod aes_gcm_chunked;
+use aes_gcm_chunked::AESGCMChunkedBlockCipher;
+
 pub mod rand;
 
 /// Type of the cipher algorithm used to encrypt/decrypt image layers.
@@ -21,6 +24,10 @@ pub type LayerCipherType = String;
 /// The default cipher algorithm for image layer encryption/decryption.
 pub const AES256CTR: &str = "AES_256_CTR_HMAC_SHA256";
 
+/// The authenticated streaming cipher algorithm, AES-256-GCM over fixed-size chunks, for image
+/// layer encryption/decryption.
+pub const AES256GCM_CHUNKED: &str = "AES_256_GCM_CHUNKED";
+
 base64_serde_type!(Base64Vec, base64::engine::general_purpose::STANDARD);
 
 fn base64_hashmap_s<S>(value: &HashMap<String, Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
@@ -151,6 +158,8 @@ pub trait EncryptionFinalizer {
 pub enum LayerBlockCipherHandler<R> {
     /// AES_256_CTR_HMAC_SHA256
     Aes256Ctr(AESCTRBlockCipher<R>),
+    /// AES_256_GCM_CHUNKED
+    Aes256GcmChunked(AESGCMChunkedBlockCipher<R>),
 }
 
 impl<R> LayerBlockCipherHandler<R> {
@@ -159,6 +168,20 @@ impl<R> LayerBlockCipherHandler<R> {
         let aes_ctr_block_cipher = AESCTRBlockCipher::new(256)?;
         Ok(LayerBlockCipherHandler::Aes256Ctr(aes_ctr_block_cipher))
     }
+
+    /// Create a [`LayerBlockCipherHandler`] object with the block cipher of the cipher type
+    pub fn from_cipher_type(typ: &str) -> Result<LayerBlockCipherHandler<R>> {
+        match typ {
+            AES256CTR => Self::new(),
+            AES256GCM_CHUNKED => {
+                let aes_gcm_block_cipher = AESGCMChunkedBlockCipher::new(256)?;
+                Ok(LayerBlockCipherHandler::Aes256GcmChunked(
+                    aes_gcm_block_cipher,
+                ))
+            }
+            _ => Err(anyhow!("unsupported cipher type {}", typ)),
+        }
+    }
 }
 
 impl<R> LayerBlockCipherHandler<R> {
@@ -178,6 +201,14 @@ impl<R> LayerBlockCipherHandler<R> {
                 opts.public.cipher_type = AES256CTR.to_string();
                 block_cipher.encrypt(plain_data_reader, opts)?;
             }
+            LayerBlockCipherHandler::Aes256GcmChunked(block_cipher) => {
+                if typ != AES256GCM_CHUNKED {
+                    return Err(anyhow!("unsupported cipher type {}", typ));
+                }
+                opts.private.symmetric_key = block_cipher.generate_key()?;
+                opts.public.cipher_type = AES256GCM_CHUNKED.to_string();
+                block_cipher.encrypt(plain_data_reader, opts)?;
+            }
         }
 
         Ok(())
@@ -198,6 +229,12 @@ impl<R> LayerBlockCipherHandler<R> {
                 }
                 block_cipher.decrypt(enc_data_reader, opts)?;
             }
+            LayerBlockCipherHandler::Aes256GcmChunked(block_cipher) => {
+                if typ != AES256GCM_CHUNKED {
+                    return Err(anyhow!("unsupported cipher type {}", typ));
+                }
+                block_cipher.decrypt(enc_data_reader, opts)?;
+            }
         }
 
         Ok(())
@@ -208,6 +245,9 @@ impl<R> EncryptionFinalizer for LayerBlockCipherHandler<R> {
     fn finalized_lbco(&self, opts: &mut LayerBlockCipherOptions) -> Result<()> {
         match self {
             LayerBlockCipherHandler::Aes256Ctr(block_cipher) => block_cipher.finalized_lbco(opts),
+            LayerBlockCipherHandler::Aes256GcmChunked(block_cipher) => {
+                block_cipher.finalized_lbco(opts)
+            }
         }
     }
 }
@@ -216,6 +256,7 @@ impl<R: Read> Read for LayerBlockCipherHandler<R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         match self {
             LayerBlockCipherHandler::Aes256Ctr(block_cipher) => block_cipher.read(buf),
+            LayerBlockCipherHandler::Aes256GcmChunked(block_cipher) => block_cipher.read(buf),
         }
     }
 }
@@ -228,12 +269,14 @@ impl<R: tokio::io::AsyncRead> tokio::io::AsyncRead for LayerBlockCipherHandler<R
         buf: &mut tokio::io::ReadBuf<'_>,
     ) -> std::task::Poll<std::io::Result<()>> {
         // This is okay because `block_cipher` is pinned when `self` is.
-        let aes_ctr_256 = unsafe {
-            self.map_unchecked_mut(|v| match v {
-                LayerBlockCipherHandler::Aes256Ctr(block_cipher) => block_cipher,
-            })
-        };
-        aes_ctr_256.poll_read(cx, buf)
+        match unsafe { self.get_unchecked_mut() } {
+            LayerBlockCipherHandler::Aes256Ctr(block_cipher) => {
+                unsafe { std::pin::Pin::new_unchecked(block_cipher) }.poll_read(cx, buf)
+            }
+            LayerBlockCipherHandler::Aes256GcmChunked(block_cipher) => {
+                unsafe { std::pin::Pin::new_unchecked(block_cipher) }.poll_read(cx, buf)
+            }
+        }
     }
 }
 
@@ -255,9 +298,8 @@ mod tests {
         assert!(lbch
             .encrypt(layer_data.as_slice(), AES256CTR, &mut lbco)
             .is_ok());
-        let LayerBlockCipherHandler::Aes256Ctr(mut encryptor) = lbch;
-        assert!(encryptor.read_to_end(&mut encrypted_data).is_ok());
-        assert!(encryptor.finalized_lbco(&mut lbco).is_ok());
+        assert!(lbch.read_to_end(&mut encrypted_data).is_ok());
+        assert!(lbch.finalized_lbco(&mut lbco).is_ok());
 
         let serialized_json = serde_json::to_string(&lbco).unwrap();
 
@@ -267,9 +309,8 @@ mod tests {
             serde_json::from_str(&serialized_json).unwrap_or_default();
 
         assert!(lbch.decrypt(encrypted_data.as_slice(), &mut lbco).is_ok());
-        let LayerBlockCipherHandler::Aes256Ctr(mut decryptor) = lbch;
         let mut plaintxt_data: Vec<u8> = Vec::new();
-        assert!(decryptor.read_to_end(&mut plaintxt_data).is_ok());
+        assert!(lbch.read_to_end(&mut plaintxt_data).is_ok());
 
         // Decrypted data should equal to original data
         assert_eq!(layer_data, plaintxt_data);
@@ -278,8 +319,40 @@ mod tests {
         let mut lbch = LayerBlockCipherHandler::new().unwrap();
         lbco.private.symmetric_key = vec![0; 32];
         assert!(lbch.decrypt(encrypted_data.as_slice(), &mut lbco).is_ok());
-        let LayerBlockCipherHandler::Aes256Ctr(mut decryptor) = lbch;
         let mut plaintxt_data: Vec<u8> = Vec::new();
-        assert!(decryptor.read_to_end(&mut plaintxt_data).is_err());
+        assert!(lbch.read_to_end(&mut plaintxt_data).is_err());
+    }
+
+    #[test]
+    fn test_layer_block_cipher_handler_cipher_type() {
+        let layer_data: Vec<u8> = b"this is some data".to_vec();
+
+        assert!(LayerBlockCipherHandler::<&[u8]>::from_cipher_type("AES_128_CBC").is_err());
+
+        let mut lbco = LayerBlockCipherOptions::default();
+        let mut lbch = LayerBlockCipherHandler::from_cipher_type(AES256GCM_CHUNKED).unwrap();
+        assert!(lbch
+            .encrypt(layer_data.as_slice(), AES256CTR, &mut lbco)
+            .is_err());
+        assert!(lbch
+            .encrypt(layer_data.as_slice(), AES256GCM_CHUNKED, &mut lbco)
+            .is_ok());
+        let mut encrypted_data: Vec<u8> = Vec::new();
+        assert!(lbch.read_to_end(&mut encrypted_data).is_ok());
+        assert!(lbch.finalized_lbco(&mut lbco).is_ok());
+        assert_eq!(lbco.public.cipher_type, AES256GCM_CHUNKED);
+
+        let serialized_json = serde_json::to_string(&lbco).unwrap();
+        let mut lbco: LayerBlockCipherOptions = serde_json::from_str(&serialized_json).unwrap();
+
+        // The default handler does not decrypt the chunked cipher
+        let mut lbch = LayerBlockCipherHandler::new().unwrap();
+        assert!(lbch.decrypt(encrypted_data.as_slice(), &mut lbco).is_err());
+
+        let mut lbch = LayerBlockCipherHandler::from_cipher_type(&lbco.public.cipher_type).unwrap();
+        assert!(lbch.decrypt(encrypted_data.as_slice(), &mut lbco).is_ok());
+        let mut plaintxt_data: Vec<u8> = Vec::new();
+        assert!(lbch.read_to_end(&mut plaintxt_data).is_ok());
+        assert_eq!(layer_data, plaintxt_data);
     }
 }
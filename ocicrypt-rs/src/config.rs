@@ -186,6 +186,14 @@ pub struct EncryptConfig {
     /// Allow for adding wrapped keys to an encrypted layer
     #[serde(rename = "DecryptConfig")]
     pub decrypt_config: Option<DecryptConfig>,
+
+    /// The cipher to encrypt the layers with, AES_256_CTR_HMAC_SHA256 if not set
+    #[serde(
+        rename = "LayerCipherType",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub layer_cipher_type: Option<String>,
 }
 
 impl EncryptConfig {
@@ -244,6 +252,11 @@ impl EncryptConfig {
         Ok(())
     }
 
+    /// Set the cipher to encrypt the layers with, e.g. AES_256_GCM_CHUNKED
+    pub fn encrypt_with_layer_cipher(&mut self, cipher_type: &str) {
+        self.layer_cipher_type = Some(cipher_type.to_string());
+    }
+
     /// Add EncryptConfig with configured keyprovider parameters for encryption
     pub fn encrypt_with_key_provider(&mut self, key_providers: Vec<Vec<u8>>) -> Result<()> {
         for val in key_providers.iter().map(|v| String::from_utf8_lossy(v)) {
@@ -388,6 +401,10 @@ mod tests {
         assert_eq!(vec![b"abc".to_vec()], ec.param["key_p2"]);
         assert_eq!(vec![b"abc:abc".to_vec()], ec.param["key_p3"]);
 
+        assert!(ec.layer_cipher_type.is_none());
+        ec.encrypt_with_layer_cipher("AES_256_GCM_CHUNKED");
+        assert_eq!(ec.layer_cipher_type.as_deref(), Some("AES_256_GCM_CHUNKED"));
+
         println!("final encrypt config is: {ec:?}");
     }
 
@@ -33,13 +33,10 @@ impl EncLayerFinalizer {
         annotations: Option<&BTreeMap<String, String>>,
         finalizer: Option<&mut impl EncryptionFinalizer>,
     ) -> Result<BTreeMap<String, String>> {
-        let mut priv_opts = vec![];
-        let mut pub_opts = vec![];
-        if let Some(finalizer) = finalizer {
-            finalizer.finalized_lbco(&mut self.lbco)?;
-            priv_opts = serde_json::to_vec(&self.lbco.private)?;
-            pub_opts = serde_json::to_vec(&self.lbco.public)?;
-        }
+        let (priv_opts, pub_opts) = match finalizer {
+            Some(finalizer) => self.finalize_opts(&*finalizer)?,
+            None => (vec![], vec![]),
+        };
 
         let mut new_annotations = BTreeMap::new();
         let mut keys_wrapped = false;
@@ -73,10 +70,25 @@ impl EncLayerFinalizer {
 
         Ok(new_annotations)
     }
+
+    /// Finalize the layer block cipher options and return the serialized
+    /// (private, public) options. This is useful for callers that wrap the
+    /// private options with their own key provider instead of the registered
+    /// key wrappers.
+    pub fn finalize_opts(
+        &mut self,
+        finalizer: &impl EncryptionFinalizer,
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        finalizer.finalized_lbco(&mut self.lbco)?;
+        let priv_opts = serde_json::to_vec(&self.lbco.private)?;
+        let pub_opts = serde_json::to_vec(&self.lbco.public)?;
+        Ok((priv_opts, pub_opts))
+    }
 }
 
 // pre_wrap_keys calls wrap_keys and handles the base64 encoding and
-// concatenation of the annotation data.
+// concatenation of the annotation data. A keywrapper without recipients in
+// the encrypt config wraps nothing, and the annotation data is kept.
 fn pre_wrap_key(
     keywrapper: &dyn KeyWrapper,
     ec: &EncryptConfig,
@@ -85,7 +97,7 @@ fn pre_wrap_key(
 ) -> Result<String> {
     let new_annotation = keywrapper.wrap_keys(ec, opts_data)?;
     if new_annotation.is_empty() {
-        return Err(anyhow!("new annotations is empty!"));
+        return Ok(b64_annotations);
     }
 
     let b64_new_annotation = base64::engine::general_purpose::STANDARD.encode(new_annotation);
@@ -205,6 +217,76 @@ pub fn decrypt_layer_key_opts_data(
     ))
 }
 
+/// This is an async version of [`pre_unwrap_key`].
+#[cfg(feature = "async-io")]
+async fn async_pre_unwrap_key(
+    keywrapper: &dyn KeyWrapper,
+    dc: &DecryptConfig,
+    b64_annotations: &str,
+) -> Result<Vec<u8>> {
+    if b64_annotations.is_empty() {
+        return Err(anyhow!("annotations is empty!"));
+    }
+
+    let mut errs = String::new();
+    for b64_annotation in b64_annotations.split(',') {
+        let annotation = base64::engine::general_purpose::STANDARD.decode(b64_annotation)?;
+
+        match keywrapper.async_unwrap_keys(dc, &annotation).await {
+            Err(e) => {
+                errs.push_str(&e.to_string());
+                continue;
+            }
+            Ok(opts_data) => {
+                return Ok(opts_data);
+            }
+        };
+    }
+
+    Err(anyhow!(
+        "no suitable key found for decrypting layer key:\n {}",
+        errs
+    ))
+}
+
+/// This is an async version of [`decrypt_layer_key_opts_data`], which calls the key wrappers
+/// without blocking, e.g. the keyprovider grpc/ttrpc servers.
+#[cfg(feature = "async-io")]
+pub async fn async_decrypt_layer_key_opts_data(
+    dc: &DecryptConfig,
+    annotations: Option<&BTreeMap<String, String>>,
+) -> Result<Vec<u8>> {
+    let mut priv_key_given = false;
+    let annotations = annotations.unwrap_or(&DEFAULT_ANNOTATION_MAP);
+
+    for (annotations_id, scheme) in KEY_WRAPPERS_ANNOTATIONS.iter() {
+        if let Some(b64_annotation) = get_layer_key_opts(annotations_id, annotations) {
+            let keywrapper = get_key_wrapper(scheme)?;
+            if !keywrapper.probe(&dc.param) {
+                continue;
+            }
+
+            if keywrapper.private_keys(&dc.param).is_some() {
+                priv_key_given = true;
+            }
+
+            let opts_data = async_pre_unwrap_key(keywrapper, dc, &b64_annotation).await?;
+            if !opts_data.is_empty() {
+                return Ok(opts_data);
+            }
+            // try next keywrapper
+        }
+    }
+
+    if !priv_key_given {
+        return Err(anyhow!("missing private key needed for decryption"));
+    }
+
+    Err(anyhow!(
+        "no suitable key unwrapper found or none of the private keys could be used for decryption"
+    ))
+}
+
 /// encrypt_layer encrypts the layer by running one encryptor after the other
 pub fn encrypt_layer<'a, R: 'a + Read>(
     ec: &EncryptConfig,
@@ -234,10 +316,11 @@ pub fn encrypt_layer<'a, R: 'a + Read>(
     }
 
     if !encrypted {
-        let mut lbch = LayerBlockCipherHandler::new()?;
+        let cipher_type = ec.layer_cipher_type.as_deref().unwrap_or(AES256CTR);
+        let mut lbch = LayerBlockCipherHandler::from_cipher_type(cipher_type)?;
         let mut lbco = LayerBlockCipherOptions::default();
 
-        lbch.encrypt(layer_reader, AES256CTR, &mut lbco)?;
+        lbch.encrypt(layer_reader, cipher_type, &mut lbco)?;
         lbco.private.digest = digest.to_string();
         let enc_layer_finalizer = EncLayerFinalizer { lbco };
 
@@ -270,7 +353,7 @@ pub fn decrypt_layer<R: Read>(
         public: pub_opts,
         private: priv_opts,
     };
-    let mut lbch = LayerBlockCipherHandler::new()?;
+    let mut lbch = LayerBlockCipherHandler::from_cipher_type(&opts.public.cipher_type)?;
 
     lbch.decrypt(layer_reader, &mut opts)?;
 
@@ -294,7 +377,7 @@ pub fn async_decrypt_layer<R: tokio::io::AsyncRead + Send>(
         public: pub_opts,
         private: priv_opts,
     };
-    let mut lbch = LayerBlockCipherHandler::new()?;
+    let mut lbch = LayerBlockCipherHandler::from_cipher_type(&opts.public.cipher_type)?;
 
     lbch.decrypt(layer_reader, &mut opts)?;
 
@@ -358,6 +441,51 @@ mod tests {
         }
     }
 
+    // The layer and its annotations are encrypted as Go ocicrypt does for a
+    // pkcs7 recipient, see data/generate_keys.md
+    #[cfg(feature = "keywrap-pkcs7")]
+    #[test]
+    fn test_decrypt_go_pkcs7_layer() {
+        let path = load_data_path();
+        let annotations: BTreeMap<String, String> = serde_json::from_slice(
+            &fs::read(format!("{}/{}", path, "go_pkcs7_annotations.json")).unwrap(),
+        )
+        .unwrap();
+        let encrypted_data = fs::read(format!("{}/{}", path, "go_pkcs7_layer.enc")).unwrap();
+
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_priv_keys(
+                vec![fs::read(format!("{}/{}", path, "private_key.pem")).unwrap()],
+                vec![vec![]]
+            )
+            .is_ok());
+        assert!(dc
+            .decrypt_with_x509s(vec![fs::read(format!(
+                "{}/{}",
+                path, "public_certificate.pem"
+            ))
+            .unwrap()])
+            .is_ok());
+
+        let (layer_decryptor, dec_digest) =
+            decrypt_layer(&dc, encrypted_data.as_slice(), Some(&annotations), false).unwrap();
+        let mut plaintxt_data: Vec<u8> = Vec::new();
+        assert!(layer_decryptor
+            .unwrap()
+            .read_to_end(&mut plaintxt_data)
+            .is_ok());
+
+        assert_eq!(
+            plaintxt_data,
+            b"This is a layer encrypted by Go ocicrypt with a pkcs7 recipient.\n"
+        );
+        assert_eq!(
+            dec_digest,
+            format!("sha256:{:x}", Sha256::digest(&plaintxt_data))
+        );
+    }
+
     #[cfg(feature = "async-io")]
     #[tokio::test]
     async fn test_async_decrypt_layer() {
@@ -392,6 +520,10 @@ mod tests {
 
         if let Ok(new_annotations) = elf.finalize_annotations(&ec, None, Some(&mut encryptor)) {
             let key_opts = decrypt_layer_key_opts_data(&dc, Some(&new_annotations)).unwrap();
+            let async_key_opts = async_decrypt_layer_key_opts_data(&dc, Some(&new_annotations))
+                .await
+                .unwrap();
+            assert_eq!(key_opts, async_key_opts);
 
             let (mut async_reader, dec_digest) =
                 async_decrypt_layer(encrypted_data.as_slice(), Some(&new_annotations), &key_opts)
@@ -407,6 +539,63 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "async-io")]
+    #[tokio::test]
+    async fn test_async_decrypt_chunked_layer() {
+        use crate::blockcipher::AES256GCM_CHUNKED;
+
+        let path = load_data_path();
+        let pub_key = fs::read(format!("{}/{}", path, "public_key.pem")).unwrap();
+
+        let mut ec = EncryptConfig::default();
+        assert!(ec.encrypt_with_jwe(vec![pub_key]).is_ok());
+        ec.encrypt_with_layer_cipher(AES256GCM_CHUNKED);
+
+        let layer_data: Vec<u8> = (0..200 * 1024).map(|i| i as u8).collect();
+        let digest = format!("sha256:{:x}", Sha256::digest(&layer_data));
+
+        let (layer_encryptor, mut elf) =
+            encrypt_layer(&ec, layer_data.as_slice(), None, &digest).unwrap();
+        let mut encrypted_data: Vec<u8> = Vec::new();
+        let mut encryptor = layer_encryptor.unwrap();
+        assert!(encryptor.read_to_end(&mut encrypted_data).is_ok());
+
+        // The options are not wrapped, as the key providers registered by other tests would
+        // fail to wrap them
+        let (key_opts, pub_opts) = elf.finalize_opts(&encryptor).unwrap();
+        let new_annotations = BTreeMap::from([(
+            "org.opencontainers.image.enc.pubopts".to_string(),
+            base64::engine::general_purpose::STANDARD.encode(pub_opts),
+        )]);
+
+        let (mut async_reader, dec_digest) =
+            async_decrypt_layer(encrypted_data.as_slice(), Some(&new_annotations), &key_opts)
+                .unwrap();
+        let mut plaintxt_data: Vec<u8> = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut async_reader, &mut plaintxt_data)
+            .await
+            .unwrap();
+        assert_eq!(layer_data, plaintxt_data);
+        assert_eq!(digest, dec_digest);
+        drop(async_reader);
+
+        // Tamper the second chunk, decryption fails once the first chunk is returned
+        encrypted_data[64 * 1024 + 100] ^= 1;
+        let (mut async_reader, _) =
+            async_decrypt_layer(encrypted_data.as_slice(), Some(&new_annotations), &key_opts)
+                .unwrap();
+        let mut plaintxt_data = vec![0u8; 64 * 1024];
+        tokio::io::AsyncReadExt::read_exact(&mut async_reader, &mut plaintxt_data)
+            .await
+            .unwrap();
+        assert_eq!(&layer_data[..64 * 1024], plaintxt_data.as_slice());
+        assert!(
+            tokio::io::AsyncReadExt::read(&mut async_reader, &mut plaintxt_data)
+                .await
+                .is_err()
+        );
+    }
+
     fn load_data_path() -> String {
         let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         path.push("data");
@@ -11,6 +11,8 @@ use josekit::jwe::{
 use josekit::jwk::{Jwk, KeyAlg, KeyFormat, KeyInfo};
 
 use crate::config::{DecryptConfig, EncryptConfig};
+#[cfg(feature = "async-io")]
+use crate::keywrap::spawn_blocking;
 use crate::keywrap::KeyWrapper;
 
 /// A Jwe keywrapper
@@ -77,6 +79,7 @@ fn decrypter(priv_key: &[u8]) -> Result<Box<dyn JweDecrypter>> {
     }
 }
 
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
 impl KeyWrapper for JweKeyWrapper {
     fn wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
         let pubkeys = ec
@@ -129,6 +132,18 @@ impl KeyWrapper for JweKeyWrapper {
     fn private_keys(&self, dc_param: &HashMap<String, Vec<Vec<u8>>>) -> Option<Vec<Vec<u8>>> {
         dc_param.get("privkeys").cloned()
     }
+
+    #[cfg(feature = "async-io")]
+    async fn async_wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        let (ec, opts_data) = (ec.clone(), opts_data.to_vec());
+        spawn_blocking(move || JweKeyWrapper {}.wrap_keys(&ec, &opts_data)).await
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_unwrap_keys(&self, dc: &DecryptConfig, annotation: &[u8]) -> Result<Vec<u8>> {
+        let (dc, annotation) = (dc.clone(), annotation.to_vec());
+        spawn_blocking(move || JweKeyWrapper {}.unwrap_keys(&dc, &annotation)).await
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
+use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Result};
 use base64::Engine;
@@ -15,7 +16,7 @@ use crate::utils::{self, CommandExecuter};
 #[cfg(feature = "keywrap-keyprovider-native")]
 mod native;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 enum OpKey {
     Wrap,
     Unwrap,
@@ -128,17 +129,17 @@ impl KeyProviderKeyWrapProtocolOutput {
     }
 
     #[cfg(feature = "keywrap-keyprovider-ttrpc")]
-    fn from_ttrpc(input: Vec<u8>, conn: &str, operation: OpKey) -> Result<Self> {
-        let c = ttrpc::Client::connect(conn)?;
+    async fn from_ttrpc(input: Vec<u8>, conn: &str, operation: OpKey) -> Result<Self> {
+        let c = ttrpc::asynchronous::Client::connect(conn)?;
 
         let kc = crate::utils::ttrpc::keyprovider_ttrpc::KeyProviderServiceClient::new(c);
-        let kc1 = kc.clone();
         let mut req = crate::utils::ttrpc::keyprovider::KeyProviderKeyWrapProtocolInput::new();
         req.KeyProviderKeyWrapProtocolInput = input;
 
         let ttrpc_output = match operation {
-            OpKey::Wrap => kc1
+            OpKey::Wrap => kc
                 .wrap_key(ttrpc::context::with_timeout(50 * 1000 * 1000 * 1000), &req)
+                .await
                 .map_err(|_| {
                     anyhow!(
                         "keyprovider: Error from ttrpc server for {:?} operation",
@@ -146,8 +147,9 @@ impl KeyProviderKeyWrapProtocolOutput {
                     )
                 })?,
 
-            OpKey::Unwrap => kc1
+            OpKey::Unwrap => kc
                 .un_wrap_key(ttrpc::context::with_timeout(50 * 1000 * 1000 * 1000), &req)
+                .await
                 .map_err(|e| {
                     anyhow!(
                         "keyprovider: Error from ttrpc server for {:?} operation: {e:?}",
@@ -182,7 +184,7 @@ impl KeyProviderKeyWrapProtocolOutput {
     }
 
     #[cfg(feature = "keywrap-keyprovider-native")]
-    fn from_native(annotation: &str, dc_config: &DecryptConfig) -> Result<Self> {
+    async fn from_native(annotation: &str, dc_config: &DecryptConfig) -> Result<Self> {
         let kbc_kbs_pair = if let Some(list) = dc_config.param.get("attestation-agent") {
             list.first()
                 .ok_or_else(|| anyhow!("keyprovider: empty kbc::kbs pair"))?
@@ -193,25 +195,28 @@ impl KeyProviderKeyWrapProtocolOutput {
         let (kbc, kbs) = pair_str
             .split_once("::")
             .ok_or_else(|| anyhow!("keyprovider: invalid kbc::kbs pair"))?;
-        let kbs = kbs.to_string();
-        let kbc = kbc.to_string();
-        let annotation = annotation.to_string();
-
-        let handler = std::thread::spawn(move || {
-            create_async_runtime()?.block_on(async {
-                native::decrypt_image_layer_annotation(&kbs, &kbc, &annotation)
-                    .await
-                    .map_err(|e| format!("{e:?}"))
-            })
-        });
-
-        match handler.join() {
-            Ok(Ok(v)) => Ok(KeyProviderKeyWrapProtocolOutput {
-                key_unwrap_results: Some(KeyUnwrapResults { opts_data: v }),
-                ..Default::default()
-            }),
-            Ok(Err(e)) => Err(anyhow!("keyprovider: retrieve opts_data failed: {e:?}")),
-            Err(e) => Err(anyhow!("keyprovider: retrieve opts_data failed: {e:?}")),
+
+        let opts_data = native::decrypt_image_layer_annotation(kbs, kbc, annotation)
+            .await
+            .map_err(|e| anyhow!("keyprovider: retrieve opts_data failed: {e:?}"))?;
+
+        Ok(KeyProviderKeyWrapProtocolOutput {
+            key_unwrap_results: Some(KeyUnwrapResults { opts_data }),
+            ..Default::default()
+        })
+    }
+
+    fn into_annotation(self) -> Result<Vec<u8>> {
+        match self.key_wrap_results {
+            Some(result) => Ok(result.annotation),
+            None => Err(anyhow!("keyprovider: get NULL reply from provider")),
+        }
+    }
+
+    fn into_opts_data(self) -> Result<Vec<u8>> {
+        match self.key_unwrap_results {
+            Some(result) => Ok(result.opts_data),
+            None => Err(anyhow!("keyprovider: get NULL reply from provider")),
         }
     }
 }
@@ -221,7 +226,7 @@ impl KeyProviderKeyWrapProtocolOutput {
 pub struct KeyProviderKeyWrapper {
     pub provider: String,
     pub attrs: KeyProviderAttrs,
-    pub runner: Option<Box<dyn CommandExecuter>>,
+    pub runner: Option<Arc<dyn CommandExecuter>>,
 }
 
 impl KeyProviderKeyWrapper {
@@ -240,130 +245,91 @@ impl KeyProviderKeyWrapper {
         KeyProviderKeyWrapper {
             provider,
             attrs,
-            runner,
+            runner: runner.map(Arc::from),
         }
     }
 
-    fn wrap_key_cmd(&self, _input: Vec<u8>, _cmd: &crate::config::Command) -> Result<Vec<u8>> {
-        if let Some(_runner) = self.runner.as_ref() {
-            #[cfg(not(feature = "keywrap-keyprovider-cmd"))]
-            {
-                Err(anyhow!("keyprovider: no support of keyprovider-grpc"))
-            }
-            #[cfg(feature = "keywrap-keyprovider-cmd")]
-            {
-                let protocol_output = KeyProviderKeyWrapProtocolOutput::from_command(
-                    _input, _cmd, _runner,
-                )
-                .map_err(|e| {
-                    anyhow!(
-                        "keyprovider: error from binary provider for {} operation: {e:?}",
-                        OpKey::Wrap,
-                    )
-                })?;
-                if let Some(result) = protocol_output.key_wrap_results {
-                    Ok(result.annotation)
-                } else {
-                    Err(anyhow!("keyprovider: get NULL reply from provider"))
-                }
-            }
-        } else {
-            Err(anyhow!("keyprovider: runner for binary provider is NULL"))
+    fn wrap_key_input(&self, enc_config: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        if !enc_config.param.contains_key(&self.provider) {
+            return Err(anyhow!(
+                "keyprovider: unknown provider {} for operation {}",
+                &self.provider,
+                OpKey::Wrap
+            ));
         }
+
+        let opts_data_str = String::from_utf8(opts_data.to_vec())
+            .map_err(|_| anyhow!("keyprovider: can not convert option data to string"))?;
+        let key_wrap_params = KeyWrapParams {
+            ec: Some(enc_config.clone()),
+            opts_data: Some(opts_data_str),
+        };
+        let input = KeyProviderKeyWrapProtocolInput {
+            op: OpKey::Wrap.to_string(),
+            key_wrap_params,
+            key_unwrap_params: KeyUnwrapParams::default(),
+        };
+        serde_json::to_vec(&input).map_err(|_| {
+            anyhow!(
+                "keyprovider: error while serializing input parameters for {} operation",
+                OpKey::Wrap
+            )
+        })
     }
 
-    fn wrap_key_grpc(&self, _input: Vec<u8>, grpc: &str) -> Result<Vec<u8>> {
-        #[cfg(not(feature = "keywrap-keyprovider-grpc"))]
-        {
-            Err(anyhow!(
-                "keyprovider: no support of keyprovider-grpc, {}",
-                grpc
-            ))
-        }
-        #[cfg(feature = "keywrap-keyprovider-grpc")]
-        {
-            let grpc = grpc.to_string();
-            let handler = std::thread::spawn(move || {
-                create_async_runtime()?.block_on(async {
-                    KeyProviderKeyWrapProtocolOutput::from_grpc(_input, &grpc, OpKey::Wrap)
-                        .await
-                        .map_err(|e| format!("{e:?}"))
-                })
-            });
-            let protocol_output = match handler.join() {
-                Ok(Ok(v)) => v,
-                Ok(Err(e)) => {
-                    return Err(anyhow!(
-                        "keyprovider: grpc provider failed to execute {} operation: {}",
-                        OpKey::Wrap,
-                        e
-                    ));
-                }
-                Err(e) => {
-                    return Err(anyhow!(
-                        "keyprovider: grpc provider failed to execute {} operation: {e:?}",
-                        OpKey::Wrap,
-                    ));
-                }
-            };
-            if let Some(result) = protocol_output.key_wrap_results {
-                Ok(result.annotation)
-            } else {
-                Err(anyhow!("keyprovider: get NULL reply from provider"))
-            }
-        }
+    fn unwrap_key_input(&self, dc_config: &DecryptConfig, json_string: &[u8]) -> Result<Vec<u8>> {
+        let annotation_str = String::from_utf8(json_string.to_vec())
+            .map_err(|_| anyhow!("keyprovider: can not convert json data to string"))?;
+        let key_unwrap_params = KeyUnwrapParams {
+            dc: Some(dc_config.clone()),
+            annotation: Some(base64::engine::general_purpose::STANDARD.encode(annotation_str)),
+        };
+        let input = KeyProviderKeyWrapProtocolInput {
+            op: OpKey::Unwrap.to_string(),
+            key_wrap_params: KeyWrapParams::default(),
+            key_unwrap_params,
+        };
+        serde_json::to_vec(&input).map_err(|_| {
+            anyhow!(
+                "keyprovider: error while serializing input parameters for {} operation",
+                OpKey::Unwrap
+            )
+        })
     }
 
-    fn wrap_key_ttrpc(&self, _input: Vec<u8>, ttrpc: &str) -> Result<Vec<u8>> {
-        #[cfg(not(feature = "keywrap-keyprovider-ttrpc"))]
-        {
-            Err(anyhow!(
-                "keyprovider: no support of keyprovider-ttrpc, {}",
-                ttrpc
-            ))
-        }
-        #[cfg(feature = "keywrap-keyprovider-ttrpc")]
-        {
-            use anyhow::Context;
-
-            let ttrpc = ttrpc.to_string();
-            let protocol_output =
-                KeyProviderKeyWrapProtocolOutput::from_ttrpc(_input, &ttrpc, OpKey::Wrap)
-                    .context("keyprovider: ttrpc provider failed to execute Wrap")?;
-            if let Some(result) = protocol_output.key_wrap_results {
-                Ok(result.annotation)
-            } else {
-                Err(anyhow!("keyprovider: get NULL reply from provider"))
-            }
+    fn key_cmd(
+        &self,
+        input: Vec<u8>,
+        cmd: &crate::config::Command,
+        operation: OpKey,
+    ) -> Result<KeyProviderKeyWrapProtocolOutput> {
+        match self.runner.as_ref() {
+            Some(runner) => run_key_cmd(runner.as_ref(), input, cmd, operation),
+            None => bail!("keyprovider: runner for binary provider is NULL"),
         }
     }
 
-    fn unwrap_key_cmd(
+    /// Call the binary key provider on the blocking thread pool of the async runtime.
+    #[cfg(feature = "async-io")]
+    async fn async_key_cmd(
         &self,
-        _input: Vec<u8>,
-        _cmd: &crate::config::Command,
+        input: Vec<u8>,
+        cmd: &crate::config::Command,
+        operation: OpKey,
     ) -> Result<KeyProviderKeyWrapProtocolOutput> {
-        if let Some(_runner) = self.runner.as_ref() {
-            #[cfg(not(feature = "keywrap-keyprovider-cmd"))]
-            return Err(anyhow!("keyprovider: no support of keyprovider-grpc"));
-            #[cfg(feature = "keywrap-keyprovider-cmd")]
-            {
-                KeyProviderKeyWrapProtocolOutput::from_command(_input, _cmd, _runner).map_err(|e| {
-                    anyhow!(
-                        "keyprovider: error from binary provider for {} operation: {e:?}",
-                        OpKey::Unwrap,
-                    )
-                })
-            }
-        } else {
+        let Some(runner) = self.runner.clone() else {
             bail!("keyprovider: runner for binary provider is NULL");
-        }
+        };
+        let cmd = cmd.clone();
+        crate::keywrap::spawn_blocking(move || run_key_cmd(runner.as_ref(), input, &cmd, operation))
+            .await
     }
 
-    fn unwrap_key_grpc(
+    async fn key_grpc(
         &self,
         _input: Vec<u8>,
         grpc: &str,
+        _operation: OpKey,
     ) -> Result<KeyProviderKeyWrapProtocolOutput> {
         #[cfg(not(feature = "keywrap-keyprovider-grpc"))]
         return Err(anyhow!(
@@ -372,31 +338,22 @@ impl KeyProviderKeyWrapper {
         ));
         #[cfg(feature = "keywrap-keyprovider-grpc")]
         {
-            let grpc = grpc.to_string();
-            let handler = std::thread::spawn(move || {
-                create_async_runtime()?.block_on(async {
-                    KeyProviderKeyWrapProtocolOutput::from_grpc(_input, &grpc, OpKey::Unwrap)
-                        .await
-                        .map_err(|e| {
-                            format!(
-                                "keyprovider: grpc provider failed to execute {} operation: {e:?}",
-                                OpKey::Wrap,
-                            )
-                        })
+            KeyProviderKeyWrapProtocolOutput::from_grpc(_input, grpc, _operation)
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "keyprovider: grpc provider failed to execute {} operation: {e:?}",
+                        _operation,
+                    )
                 })
-            });
-            match handler.join() {
-                Ok(Ok(v)) => Ok(v),
-                Ok(Err(e)) => bail!("failed to unwrap key by gRPC, {e:?}"),
-                Err(e) => bail!("failed to unwrap key by gRPC, {e:?}"),
-            }
         }
     }
 
-    fn unwrap_key_ttrpc(
+    async fn key_ttrpc(
         &self,
         _input: Vec<u8>,
         ttrpc: &str,
+        _operation: OpKey,
     ) -> Result<KeyProviderKeyWrapProtocolOutput> {
         #[cfg(not(feature = "keywrap-keyprovider-ttrpc"))]
         return Err(anyhow!(
@@ -405,15 +362,18 @@ impl KeyProviderKeyWrapper {
         ));
         #[cfg(feature = "keywrap-keyprovider-ttrpc")]
         {
-            use anyhow::Context;
-            let ttrpc = ttrpc.to_string();
-
-            KeyProviderKeyWrapProtocolOutput::from_ttrpc(_input, &ttrpc, OpKey::Unwrap)
-                .context("keyprovider: failed to unwrap key by ttrpc")
+            KeyProviderKeyWrapProtocolOutput::from_ttrpc(_input, ttrpc, _operation)
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "keyprovider: ttrpc provider failed to execute {} operation: {e:?}",
+                        _operation,
+                    )
+                })
         }
     }
 
-    fn unwrap_key_native(
+    async fn unwrap_key_native(
         &self,
         _dc_config: &DecryptConfig,
         _json_string: &[u8],
@@ -423,101 +383,73 @@ impl KeyProviderKeyWrapper {
         #[cfg(feature = "keywrap-keyprovider-native")]
         {
             let content = String::from_utf8(_json_string.to_vec())?;
-            KeyProviderKeyWrapProtocolOutput::from_native(&content, _dc_config).map_err(|e| {
-                anyhow!(
-                    "keyprovider: error from crate provider for {} operation: {e:?}",
-                    OpKey::Unwrap,
-                )
-            })
+            KeyProviderKeyWrapProtocolOutput::from_native(&content, _dc_config)
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "keyprovider: error from crate provider for {} operation: {e:?}",
+                        OpKey::Unwrap,
+                    )
+                })
+        }
+    }
+
+    /// Call the grpc, ttrpc or native (only to unwrap keys with the given decrypt config and
+    /// annotation) key provider.
+    async fn key_async(
+        &self,
+        input: Vec<u8>,
+        operation: OpKey,
+        unwrap_params: Option<(&DecryptConfig, &[u8])>,
+    ) -> Result<KeyProviderKeyWrapProtocolOutput> {
+        if let Some(grpc) = self.attrs.grpc.as_ref() {
+            self.key_grpc(input, grpc, operation).await
+        } else if let Some(ttrpc) = self.attrs.ttrpc.as_ref() {
+            self.key_ttrpc(input, ttrpc, operation).await
+        } else if let (Some(_native), Some((dc_config, json_string))) =
+            (self.attrs.native.as_ref(), unwrap_params)
+        {
+            self.unwrap_key_native(dc_config, json_string).await
+        } else {
+            Err(anyhow!(
+                "keyprovider: invalid configuration, both grpc and runner are NULL"
+            ))
         }
     }
 }
 
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
 impl KeyWrapper for KeyProviderKeyWrapper {
     /// WrapKeys calls appropriate binary-executable or grpc/ttrpc server for wrapping the session
     /// key for recipients and gets encrypted optsData, which describe the symmetric key used for
     /// encrypting the layer.
+    ///
+    /// The grpc/ttrpc server is called on a separate thread, prefer `async_wrap_keys` within an
+    /// async runtime.
     fn wrap_keys(&self, enc_config: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
-        if !enc_config.param.contains_key(&self.provider) {
-            return Err(anyhow!(
-                "keyprovider: unknown provider {} for operation {}",
-                &self.provider,
-                OpKey::Wrap
-            ));
-        }
-
-        let opts_data_str = String::from_utf8(opts_data.to_vec())
-            .map_err(|_| anyhow!("keyprovider: can not convert option data to string"))?;
-        let key_wrap_params = KeyWrapParams {
-            ec: Some(enc_config.clone()),
-            opts_data: Some(opts_data_str),
+        let input = self.wrap_key_input(enc_config, opts_data)?;
+        let protocol_output = match self.attrs.cmd.as_ref() {
+            Some(cmd) => self.key_cmd(input, cmd, OpKey::Wrap)?,
+            None => block_on(self.key_async(input, OpKey::Wrap, None))?,
         };
-        let input = KeyProviderKeyWrapProtocolInput {
-            op: OpKey::Wrap.to_string(),
-            key_wrap_params,
-            key_unwrap_params: KeyUnwrapParams::default(),
-        };
-        let _serialized_input = serde_json::to_vec(&input).map_err(|_| {
-            anyhow!(
-                "keyprovider: error while serializing input parameters for {} operation",
-                OpKey::Wrap
-            )
-        })?;
 
-        if let Some(_cmd) = &self.attrs.cmd {
-            self.wrap_key_cmd(_serialized_input, _cmd)
-        } else if let Some(grpc) = self.attrs.grpc.as_ref() {
-            self.wrap_key_grpc(_serialized_input, grpc)
-        } else if let Some(ttrpc) = self.attrs.ttrpc.as_ref() {
-            self.wrap_key_ttrpc(_serialized_input, ttrpc)
-        } else {
-            Err(anyhow!(
-                "keyprovider: invalid configuration, both grpc and runner are NULL"
-            ))
-        }
+        protocol_output.into_annotation()
     }
 
     /// UnwrapKey calls appropriate binary-executable or grpc/ttrpc server for unwrapping the
     /// session key based on the protocol given in annotation for recipients and gets decrypted
     /// optsData, which describe the symmetric key used for decrypting the layer
+    ///
+    /// The grpc/ttrpc server or the native key provider is called on a separate thread, prefer
+    /// `async_unwrap_keys` within an async runtime.
     fn unwrap_keys(&self, dc_config: &DecryptConfig, json_string: &[u8]) -> Result<Vec<u8>> {
-        let annotation_str = String::from_utf8(json_string.to_vec())
-            .map_err(|_| anyhow!("keyprovider: can not convert json data to string"))?;
-        let key_unwrap_params = KeyUnwrapParams {
-            dc: Some(dc_config.clone()),
-            annotation: Some(base64::engine::general_purpose::STANDARD.encode(annotation_str)),
-        };
-        let input = KeyProviderKeyWrapProtocolInput {
-            op: OpKey::Unwrap.to_string(),
-            key_wrap_params: KeyWrapParams::default(),
-            key_unwrap_params,
+        let input = self.unwrap_key_input(dc_config, json_string)?;
+        let protocol_output = match self.attrs.cmd.as_ref() {
+            Some(cmd) => self.key_cmd(input, cmd, OpKey::Unwrap)?,
+            None => block_on(self.key_async(input, OpKey::Unwrap, Some((dc_config, json_string))))?,
         };
-        let _serialized_input = serde_json::to_vec(&input).map_err(|_| {
-            anyhow!(
-                "keyprovider: error while serializing input parameters for {} operation",
-                OpKey::Unwrap
-            )
-        })?;
 
-        let _protocol_output = if let Some(cmd) = self.attrs.cmd.as_ref() {
-            self.unwrap_key_cmd(_serialized_input, cmd)?
-        } else if let Some(grpc) = self.attrs.grpc.as_ref() {
-            self.unwrap_key_grpc(_serialized_input, grpc)?
-        } else if let Some(ttrpc) = self.attrs.ttrpc.as_ref() {
-            self.unwrap_key_ttrpc(_serialized_input, ttrpc)?
-        } else if let Some(_native) = self.attrs.native.as_ref() {
-            self.unwrap_key_native(dc_config, json_string)?
-        } else {
-            return Err(anyhow!(
-                "keyprovider: invalid configuration, both grpc and runner are NULL"
-            ));
-        };
-
-        if let Some(result) = _protocol_output.key_unwrap_results {
-            Ok(result.opts_data)
-        } else {
-            Err(anyhow!("keyprovider: get NULL reply from provider"))
-        }
+        protocol_output.into_opts_data()
     }
 
     fn annotation_id(&self) -> String {
@@ -530,23 +462,96 @@ impl KeyWrapper for KeyProviderKeyWrapper {
     fn probe(&self, _dc_param: &HashMap<String, Vec<Vec<u8>>>) -> bool {
         true
     }
+
+    #[cfg(feature = "async-io")]
+    async fn async_wrap_keys(
+        &self,
+        enc_config: &EncryptConfig,
+        opts_data: &[u8],
+    ) -> Result<Vec<u8>> {
+        let input = self.wrap_key_input(enc_config, opts_data)?;
+        let protocol_output = match self.attrs.cmd.as_ref() {
+            Some(cmd) => self.async_key_cmd(input, cmd, OpKey::Wrap).await?,
+            None => self.key_async(input, OpKey::Wrap, None).await?,
+        };
+
+        protocol_output.into_annotation()
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_unwrap_keys(
+        &self,
+        dc_config: &DecryptConfig,
+        json_string: &[u8],
+    ) -> Result<Vec<u8>> {
+        let input = self.unwrap_key_input(dc_config, json_string)?;
+        let protocol_output = match self.attrs.cmd.as_ref() {
+            Some(cmd) => self.async_key_cmd(input, cmd, OpKey::Unwrap).await?,
+            None => {
+                self.key_async(input, OpKey::Unwrap, Some((dc_config, json_string)))
+                    .await?
+            }
+        };
+
+        protocol_output.into_opts_data()
+    }
+}
+
+/// Call the binary key provider with the runner.
+fn run_key_cmd(
+    _runner: &dyn CommandExecuter,
+    _input: Vec<u8>,
+    _cmd: &crate::config::Command,
+    _operation: OpKey,
+) -> Result<KeyProviderKeyWrapProtocolOutput> {
+    #[cfg(not(feature = "keywrap-keyprovider-cmd"))]
+    return Err(anyhow!("keyprovider: no support of keyprovider-cmd"));
+    #[cfg(feature = "keywrap-keyprovider-cmd")]
+    {
+        KeyProviderKeyWrapProtocolOutput::from_command(_input, _cmd, _runner).map_err(|e| {
+            anyhow!(
+                "keyprovider: error from binary provider for {} operation: {e:?}",
+                _operation,
+            )
+        })
+    }
 }
 
+/// Run the key provider call to completion on a separate thread with its own runtime, so that
+/// the blocking key wrapper API can be used within an async runtime as well.
 #[cfg(any(
     feature = "keywrap-keyprovider-grpc",
+    feature = "keywrap-keyprovider-ttrpc",
     feature = "keywrap-keyprovider-native"
 ))]
-fn create_async_runtime() -> std::result::Result<tokio::runtime::Runtime, String> {
-    match tokio::runtime::Builder::new_current_thread()
+fn block_on<T: Send>(future: impl std::future::Future<Output = Result<T>> + Send) -> Result<T> {
+    std::thread::scope(|s| {
+        s.spawn(move || create_async_runtime()?.block_on(future))
+            .join()
+            .map_err(|e| anyhow!("keyprovider: failed to call the provider: {e:?}"))?
+    })
+}
+
+#[cfg(not(any(
+    feature = "keywrap-keyprovider-grpc",
+    feature = "keywrap-keyprovider-ttrpc",
+    feature = "keywrap-keyprovider-native"
+)))]
+fn block_on<T>(_future: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    bail!("keyprovider: no support of keyprovider-grpc, keyprovider-ttrpc or keyprovider-native")
+}
+
+#[cfg(any(
+    feature = "keywrap-keyprovider-grpc",
+    feature = "keywrap-keyprovider-ttrpc",
+    feature = "keywrap-keyprovider-native"
+))]
+fn create_async_runtime() -> Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_current_thread()
         .enable_io()
         .enable_time()
         .build()
-    {
-        Err(e) => Err(format!(
-            "keyprovider: failed to create async runtime, {e:?}"
-        )),
-        Ok(rt) => Ok(rt),
-    }
+        .map_err(|e| anyhow!("keyprovider: failed to create async runtime, {e:?}"))
 }
 
 #[cfg(test)]
@@ -1070,6 +1075,71 @@ mod tests {
             .is_err());
     }
 
+    /// Runner which only succeeds once the flag is set by another task, i.e. if it does not
+    /// block the runtime thread.
+    #[cfg(all(feature = "keywrap-keyprovider-cmd", feature = "async-io"))]
+    struct WaitingRunner(Arc<std::sync::atomic::AtomicBool>);
+
+    #[cfg(all(feature = "keywrap-keyprovider-cmd", feature = "async-io"))]
+    impl crate::utils::CommandExecuter for WaitingRunner {
+        fn exec(
+            &self,
+            _cmd: String,
+            _args: &[std::string::String],
+            _input: Vec<u8>,
+        ) -> anyhow::Result<Vec<u8>> {
+            for _ in 0..100 {
+                if self.0.load(std::sync::atomic::Ordering::SeqCst) {
+                    let output = KeyProviderKeyWrapProtocolOutput {
+                        key_wrap_results: None,
+                        key_unwrap_results: Some(KeyUnwrapResults {
+                            opts_data: b"symmetric_key".to_vec(),
+                        }),
+                    };
+                    return Ok(serde_json::to_vec(&output)?);
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+
+            bail!("the runtime thread is blocked")
+        }
+    }
+
+    #[cfg(all(feature = "keywrap-keyprovider-cmd", feature = "async-io"))]
+    #[tokio::test]
+    async fn test_async_key_provider_command_does_not_block() {
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let attrs = crate::config::KeyProviderAttrs {
+            cmd: Some(crate::config::Command {
+                path: "/usr/lib/keyprovider-unwrapkey".to_string(),
+                args: None,
+            }),
+            grpc: None,
+            ttrpc: None,
+            native: None,
+        };
+        let keyprovider_key_wrapper = KeyProviderKeyWrapper::new(
+            "keyprovider".to_string(),
+            attrs,
+            Some(Box::new(WaitingRunner(ran.clone()))),
+        );
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_key_provider(vec!["keyprovider".to_string().into_bytes()])
+            .is_ok());
+
+        // The test runtime has a single thread, so the task only runs if the command does not
+        // block it
+        let task =
+            tokio::spawn(async move { ran.store(true, std::sync::atomic::Ordering::SeqCst) });
+        let unwrapped_key = keyprovider_key_wrapper
+            .async_unwrap_keys(&dc, b"{}")
+            .await
+            .unwrap();
+        task.await.unwrap();
+        assert_eq!(b"symmetric_key".to_vec(), unwrapped_key);
+    }
+
     #[cfg(feature = "keywrap-keyprovider-grpc")]
     #[test]
     fn test_key_provider_grpc_tcp_success() {
@@ -1169,6 +1239,18 @@ mod tests {
         let unwrapped_key = key_wrap_output_result.unwrap();
         assert_eq!(opts_data.to_vec(), unwrapped_key);
 
+        // The async key wrapper calls the provider without a separate thread
+        #[cfg(feature = "async-io")]
+        {
+            let json_string = rt
+                .block_on(keyprovider_key_wrapper.async_wrap_keys(&ec, &b64_opts_data))
+                .unwrap();
+            let unwrapped_key = rt
+                .block_on(keyprovider_key_wrapper.async_unwrap_keys(&dc, &json_string))
+                .unwrap();
+            assert_eq!(opts_data.to_vec(), unwrapped_key);
+        }
+
         // runtime shutdown for stopping grpc server
         rt.shutdown_background();
     }
@@ -1219,6 +1301,19 @@ mod tests {
         let key_wrap_output_result = keyprovider_key_wrapper.unwrap_keys(&dc, &json_string);
         let unwrapped_key = key_wrap_output_result.unwrap();
         assert_eq!(opts_data.to_vec(), unwrapped_key);
+
+        // The async key wrapper calls the provider without a separate thread
+        #[cfg(feature = "async-io")]
+        {
+            let json_string = rt
+                .block_on(keyprovider_key_wrapper.async_wrap_keys(&ec, &b64_opts_data))
+                .unwrap();
+            let unwrapped_key = rt
+                .block_on(keyprovider_key_wrapper.async_unwrap_keys(&dc, &json_string))
+                .unwrap();
+            assert_eq!(opts_data.to_vec(), unwrapped_key);
+        }
+
         // runtime shutdown for stopping ttrpc server
         rt.shutdown_background();
     }
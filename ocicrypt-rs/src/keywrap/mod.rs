@@ -11,10 +11,15 @@ use crate::config::{DecryptConfig, EncryptConfig};
 pub mod jwe;
 #[cfg(feature = "keywrap-keyprovider")]
 pub mod keyprovider;
+#[cfg(feature = "keywrap-pgp")]
+pub mod pgp;
+#[cfg(feature = "keywrap-pkcs7")]
+pub mod pkcs7;
 
 /// KeyWrapper is the interface used for wrapping keys using
 /// a specific encryption technology (pgp, jwe, pkcs7, pkcs11, keyprovider)
 #[allow(unused_variables)]
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
 pub trait KeyWrapper: Send + Sync {
     /// wrap keys data with encrypt config.
     fn wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>>;
@@ -48,8 +53,40 @@ pub trait KeyWrapper: Send + Sync {
     fn recipients(&self, recipients: String) -> Option<Vec<String>> {
         None
     }
+
+    /// async_wrap_keys (optional) is the async version of `wrap_keys`. Key wrappers calling out
+    /// to remote services should implement it natively, and key wrappers doing blocking work
+    /// should run `wrap_keys` with [`spawn_blocking`].
+    /// If not implemented, `wrap_keys` is called inline.
+    #[cfg(feature = "async-io")]
+    async fn async_wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        self.wrap_keys(ec, opts_data)
+    }
+
+    /// async_unwrap_keys (optional) is the async version of `unwrap_keys`. Key wrappers calling
+    /// out to remote services should implement it natively, and key wrappers doing blocking work
+    /// should run `unwrap_keys` with [`spawn_blocking`].
+    /// If not implemented, `unwrap_keys` is called inline.
+    #[cfg(feature = "async-io")]
+    async fn async_unwrap_keys(&self, dc: &DecryptConfig, annotation: &[u8]) -> Result<Vec<u8>> {
+        self.unwrap_keys(dc, annotation)
+    }
+}
+
+/// Run a blocking key wrapper call, e.g. the public key crypto of the JWE, PKCS#7 and PGP key
+/// wrappers, on the blocking thread pool of the async runtime.
+#[cfg(feature = "async-io")]
+pub async fn spawn_blocking<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to run the key wrapper: {e}"))?
 }
 
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
 impl<W: KeyWrapper + ?Sized> KeyWrapper for Box<W> {
     #[inline]
     fn wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
@@ -85,4 +122,16 @@ impl<W: KeyWrapper + ?Sized> KeyWrapper for Box<W> {
     fn recipients(&self, recipients: String) -> Option<Vec<String>> {
         (**self).recipients(recipients)
     }
+
+    #[cfg(feature = "async-io")]
+    #[inline]
+    async fn async_wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        (**self).async_wrap_keys(ec, opts_data).await
+    }
+
+    #[cfg(feature = "async-io")]
+    #[inline]
+    async fn async_unwrap_keys(&self, dc: &DecryptConfig, annotation: &[u8]) -> Result<Vec<u8>> {
+        (**self).async_unwrap_keys(dc, annotation).await
+    }
 }
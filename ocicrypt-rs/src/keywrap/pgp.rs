@@ -0,0 +1,402 @@
+// Copyright The ocicrypt Authors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! OpenPGP key wrapper, i.e. the layer keys are wrapped into an OpenPGP
+//! message for the public keys of the recipients, as Go ocicrypt does with
+//! `gpg-recipients` and `gpg-pubkeyringfile`.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use anyhow::{anyhow, bail, Result};
+use base64::Engine;
+use sequoia_openpgp as openpgp;
+
+use openpgp::cert::amalgamation::key::PrimaryKey;
+use openpgp::cert::CertParser;
+use openpgp::crypto::{Password, SessionKey};
+use openpgp::packet::key::{SecretParts, UnspecifiedRole};
+use openpgp::packet::{Key, PKESK, SKESK};
+use openpgp::parse::stream::{
+    DecryptionHelper, DecryptorBuilder, MessageStructure, VerificationHelper,
+};
+use openpgp::parse::{PacketParser, PacketParserResult, Parse};
+use openpgp::policy::StandardPolicy;
+use openpgp::serialize::stream::{Encryptor2, LiteralWriter, Message};
+use openpgp::types::SymmetricAlgorithm;
+use openpgp::{Cert, Fingerprint, KeyHandle, KeyID, Packet};
+
+use crate::config::{DecryptConfig, EncryptConfig};
+#[cfg(feature = "async-io")]
+use crate::keywrap::spawn_blocking;
+use crate::keywrap::KeyWrapper;
+
+/// A OpenPGP keywrapper
+#[derive(Debug)]
+pub struct PgpKeyWrapper {}
+
+// Parse the certificates of a keyring, in binary or ASCII armor
+fn read_keyring(keyring: &[u8]) -> Result<Vec<Cert>> {
+    CertParser::from_bytes(keyring)?
+        .collect::<openpgp::Result<Vec<_>>>()
+        .map_err(|e| anyhow!("pgp: failed to read keyring: {e}"))
+}
+
+// Get the name and the email of a User ID, e.g. `name <email>`
+fn name_and_email(user_id: &openpgp::packet::UserID) -> (Option<String>, Option<String>) {
+    (
+        user_id.name2().ok().flatten().map(str::to_string),
+        user_id.email2().ok().flatten().map(str::to_string),
+    )
+}
+
+// Select the certificates of the recipients, which are matched by the name or
+// the email of a User ID. All the recipients must be found.
+fn select_recipients(keyring: Vec<Cert>, recipients: &[Vec<u8>]) -> Result<Vec<Cert>> {
+    let recipients: Vec<String> = recipients
+        .iter()
+        .map(|recipient| String::from_utf8_lossy(recipient).to_string())
+        .collect();
+    let mut found = vec![false; recipients.len()];
+    let mut selected = Vec::new();
+
+    for cert in keyring {
+        let mut matched = false;
+        for user_id in cert.userids() {
+            let (name, email) = name_and_email(user_id.userid());
+            for (idx, recipient) in recipients.iter().enumerate() {
+                if name.as_ref() == Some(recipient) || email.as_ref() == Some(recipient) {
+                    found[idx] = true;
+                    matched = true;
+                }
+            }
+        }
+
+        if matched {
+            selected.push(cert);
+        }
+    }
+
+    let not_found: Vec<&str> = recipients
+        .iter()
+        .zip(found)
+        .filter(|(_, found)| !found)
+        .map(|(recipient, _)| recipient.as_str())
+        .collect();
+    if !not_found.is_empty() {
+        bail!(
+            "pgp: No key found for the following recipients: {}",
+            not_found.join(", ")
+        );
+    }
+
+    Ok(selected)
+}
+
+// Encrypt the data for one key of each certificate. As in Go ocicrypt, the
+// newest encryption subkey is used, or the primary key if no subkey can
+// encrypt.
+fn encrypt(data: &[u8], certs: &[Cert]) -> Result<Vec<u8>> {
+    let policy = StandardPolicy::new();
+    let recipients: Vec<_> = certs
+        .iter()
+        .filter_map(|cert| {
+            cert.keys()
+                .with_policy(&policy, None)
+                .supported()
+                .alive()
+                .revoked(false)
+                .for_transport_encryption()
+                .for_storage_encryption()
+                .max_by_key(|key| (!key.primary(), key.creation_time()))
+        })
+        .collect();
+    if recipients.is_empty() {
+        bail!("pgp: no valid encryption key of the recipients");
+    }
+
+    let mut sink = Vec::new();
+    let message = Message::new(&mut sink);
+    let message = Encryptor2::for_recipients(message, recipients).build()?;
+    let mut message = LiteralWriter::new(message).build()?;
+    message.write_all(data)?;
+    message.finalize()?;
+
+    Ok(sink)
+}
+
+// Get the secret keys able to decrypt of the private keyrings, decrypted
+// with the passwords
+fn decryption_keys(
+    keyrings: &[Vec<u8>],
+    passwords: &[Vec<u8>],
+) -> Result<Vec<Key<SecretParts, UnspecifiedRole>>> {
+    let policy = StandardPolicy::new();
+    let mut keys = Vec::new();
+    for (idx, keyring) in keyrings.iter().enumerate() {
+        let password = passwords.get(idx).cloned().map(Password::from);
+        for cert in read_keyring(keyring)? {
+            for key in cert
+                .keys()
+                .with_policy(&policy, None)
+                .secret()
+                .supported()
+                .for_transport_encryption()
+                .for_storage_encryption()
+            {
+                let key = key.key().clone();
+                let key = match (&password, key.secret().is_encrypted()) {
+                    (Some(password), true) => key.decrypt_secret(password)?,
+                    (None, true) => bail!("pgp: missing password of private key"),
+                    (_, false) => key,
+                };
+                keys.push(key);
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+struct Helper {
+    keys: Vec<Key<SecretParts, UnspecifiedRole>>,
+}
+
+impl VerificationHelper for Helper {
+    fn get_certs(&mut self, _ids: &[KeyHandle]) -> openpgp::Result<Vec<Cert>> {
+        Ok(Vec::new())
+    }
+
+    // The wrapped keys are not signed
+    fn check(&mut self, _structure: MessageStructure) -> openpgp::Result<()> {
+        Ok(())
+    }
+}
+
+impl DecryptionHelper for Helper {
+    fn decrypt<D>(
+        &mut self,
+        pkesks: &[PKESK],
+        _skesks: &[SKESK],
+        sym_algo: Option<SymmetricAlgorithm>,
+        mut decrypt: D,
+    ) -> openpgp::Result<Option<Fingerprint>>
+    where
+        D: FnMut(SymmetricAlgorithm, &SessionKey) -> bool,
+    {
+        for pkesk in pkesks {
+            for key in &self.keys {
+                if *pkesk.recipient() != key.keyid() && !pkesk.recipient().is_wildcard() {
+                    continue;
+                }
+
+                let mut keypair = key.clone().into_keypair()?;
+                if pkesk
+                    .decrypt(&mut keypair, sym_algo)
+                    .map(|(algo, session_key)| decrypt(algo, &session_key))
+                    .unwrap_or(false)
+                {
+                    return Ok(Some(key.fingerprint()));
+                }
+            }
+        }
+
+        Err(anyhow!("pgp: No suitable private key found for decryption"))
+    }
+}
+
+// Get the key IDs of the recipients of the OpenPGP messages, given as comma
+// separated base64
+fn key_ids(b64_packets: &str) -> Result<Vec<u64>> {
+    let mut key_ids = Vec::new();
+    for b64_packet in b64_packets.split(',') {
+        let packet = base64::engine::general_purpose::STANDARD.decode(b64_packet)?;
+        let mut ppr = PacketParser::from_bytes(&packet)?;
+        while let PacketParserResult::Some(pp) = ppr {
+            if let Packet::PKESK(pkesk) = &pp.packet {
+                if let KeyID::V4(id) = pkesk.recipient() {
+                    key_ids.push(u64::from_be_bytes(*id));
+                }
+            }
+            ppr = pp.next()?.1;
+        }
+    }
+
+    Ok(key_ids)
+}
+
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
+impl KeyWrapper for PgpKeyWrapper {
+    /// Wrap the keys for the gpg recipients in the public keyring of the
+    /// encrypt config. If no recipients are given, the keys are not wrapped.
+    fn wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        let recipients = match ec.param.get("gpg-recipients") {
+            Some(recipients) if !recipients.is_empty() => recipients,
+            _ => return Ok(vec![]),
+        };
+        let keyring = ec
+            .param
+            .get("gpg-pubkeyringfile")
+            .and_then(|keyrings| keyrings.first())
+            .ok_or_else(|| anyhow!("pgp: invalid configuration for keywrap"))?;
+
+        let certs = select_recipients(read_keyring(keyring)?, recipients)?;
+        encrypt(opts_data, &certs)
+    }
+
+    fn unwrap_keys(&self, dc: &DecryptConfig, pgp_packet: &[u8]) -> Result<Vec<u8>> {
+        let keyrings = self
+            .private_keys(&dc.param)
+            .ok_or_else(|| anyhow!("pgp: invalid configuration for keyunwrap"))?;
+        let passwords = dc
+            .param
+            .get("gpg-privatekeys-passwords")
+            .cloned()
+            .unwrap_or_default();
+        let keys = decryption_keys(&keyrings, &passwords)?;
+
+        let policy = StandardPolicy::new();
+        let mut decryptor = DecryptorBuilder::from_bytes(pgp_packet)?.with_policy(
+            &policy,
+            None,
+            Helper { keys },
+        )?;
+        let mut opts_data = Vec::new();
+        decryptor.read_to_end(&mut opts_data)?;
+
+        Ok(opts_data)
+    }
+
+    fn annotation_id(&self) -> String {
+        "org.opencontainers.image.enc.keys.pgp".to_string()
+    }
+
+    fn probe(&self, dc_param: &HashMap<String, Vec<Vec<u8>>>) -> bool {
+        dc_param.contains_key("gpg-privatekeys")
+    }
+
+    fn private_keys(&self, dc_param: &HashMap<String, Vec<Vec<u8>>>) -> Option<Vec<Vec<u8>>> {
+        dc_param.get("gpg-privatekeys").cloned()
+    }
+
+    fn keyids_from_packet(&self, packet: String) -> Option<Vec<u64>> {
+        key_ids(&packet).ok()
+    }
+
+    fn recipients(&self, recipients: String) -> Option<Vec<String>> {
+        key_ids(&recipients)
+            .ok()
+            .map(|ids| ids.iter().map(|id| format!("0x{id:x}")).collect())
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        let (ec, opts_data) = (ec.clone(), opts_data.to_vec());
+        spawn_blocking(move || PgpKeyWrapper {}.wrap_keys(&ec, &opts_data)).await
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_unwrap_keys(&self, dc: &DecryptConfig, annotation: &[u8]) -> Result<Vec<u8>> {
+        let (dc, annotation) = (dc.clone(), annotation.to_vec());
+        spawn_blocking(move || PgpKeyWrapper {}.unwrap_keys(&dc, &annotation)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    // Key ID of the encryption subkey of `pgp_public.gpg`
+    const SUBKEY_ID: u64 = 0x1c5866c4d40c675f;
+
+    fn decrypt_config(path: &std::path::Path) -> DecryptConfig {
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_gpg(
+                vec![fs::read(path.join("pgp_private.gpg")).unwrap()],
+                vec![fs::read(path.join("passwordfile")).unwrap()]
+            )
+            .is_ok());
+        dc
+    }
+
+    #[test]
+    fn test_keywrap_pgp() {
+        let path = load_data_path();
+        let keyring = fs::read(path.join("pgp_public.gpg")).unwrap();
+
+        let mut ec = EncryptConfig::default();
+        let payload = b"test".to_vec();
+
+        let pgp_key_wrapper = PgpKeyWrapper {};
+
+        assert!(!pgp_key_wrapper.probe(&DecryptConfig::default().param));
+
+        // No recipients, no wrapped keys
+        assert!(pgp_key_wrapper.wrap_keys(&ec, &payload).unwrap().is_empty());
+
+        assert!(ec
+            .encrypt_with_gpg(vec![b"nobody@example.com".to_vec()], keyring.clone())
+            .is_ok());
+        assert!(pgp_key_wrapper.wrap_keys(&ec, &payload).is_err());
+
+        for recipient in ["ocicrypt@example.com", "ocicrypt"] {
+            let mut ec = EncryptConfig::default();
+            assert!(ec
+                .encrypt_with_gpg(vec![recipient.as_bytes().to_vec()], keyring.clone())
+                .is_ok());
+            let packet = pgp_key_wrapper.wrap_keys(&ec, &payload).unwrap();
+
+            let dc = decrypt_config(&path);
+            assert!(pgp_key_wrapper.probe(&dc.param));
+            assert_eq!(pgp_key_wrapper.unwrap_keys(&dc, &packet).unwrap(), payload);
+
+            let b64_packet = base64::engine::general_purpose::STANDARD.encode(&packet);
+            assert_eq!(
+                pgp_key_wrapper.keyids_from_packet(b64_packet).unwrap(),
+                vec![SUBKEY_ID]
+            );
+        }
+
+        assert_eq!(
+            pgp_key_wrapper.annotation_id(),
+            "org.opencontainers.image.enc.keys.pgp".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unwrap_gpg_packet() {
+        let path = load_data_path();
+        // Created by `gpg --encrypt`
+        let packet = fs::read(path.join("pgp_wrapped_keys.gpg")).unwrap();
+        let opts = fs::read(path.join("keywrap_opts.json")).unwrap();
+
+        let pgp_key_wrapper = PgpKeyWrapper {};
+        let dc = decrypt_config(&path);
+        assert_eq!(pgp_key_wrapper.unwrap_keys(&dc, &packet).unwrap(), opts);
+
+        let b64_packet = base64::engine::general_purpose::STANDARD.encode(&packet);
+        assert_eq!(
+            pgp_key_wrapper.recipients(format!("{b64_packet},{b64_packet}")),
+            Some(vec![format!("0x{SUBKEY_ID:x}"), format!("0x{SUBKEY_ID:x}")])
+        );
+
+        // Wrong password
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_gpg(
+                vec![fs::read(path.join("pgp_private.gpg")).unwrap()],
+                vec![b"654321".to_vec()]
+            )
+            .is_ok());
+        assert!(pgp_key_wrapper.unwrap_keys(&dc, &packet).is_err());
+    }
+
+    fn load_data_path() -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("data");
+        path
+    }
+}
@@ -0,0 +1,597 @@
+// Copyright The ocicrypt Authors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! PKCS#7 (CMS) key wrapper, i.e. the layer keys are wrapped into an
+//! EnvelopedData for the X.509 certificates of the recipients.
+//!
+//! The keys are wrapped as Go ocicrypt does: the EnvelopedData is encrypted
+//! with AES-128-GCM, and the content encryption key is transported with
+//! RSA PKCS#1 v1.5 for each recipient. Unwrapping also accepts the
+//! EnvelopedData created by OpenSSL, i.e. encrypted with AES or Triple DES
+//! in CBC mode.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+use openssl::encrypt::{Decrypter, Encrypter};
+use openssl::pkey::{PKey, Private};
+use openssl::rsa::Padding;
+use openssl::symm::{self, Cipher};
+use openssl::x509::X509;
+use yasna::models::{ObjectIdentifier, TaggedDerValue};
+use yasna::tags::{TAG_OCTETSTRING, TAG_SEQUENCE};
+use yasna::{ASN1Error, BERReader, Tag};
+
+use crate::config::{DecryptConfig, EncryptConfig};
+#[cfg(feature = "async-io")]
+use crate::keywrap::spawn_blocking;
+use crate::keywrap::KeyWrapper;
+
+const OID_DATA: &[u64] = &[1, 2, 840, 113549, 1, 7, 1];
+const OID_ENVELOPED_DATA: &[u64] = &[1, 2, 840, 113549, 1, 7, 3];
+const OID_RSA_ENCRYPTION: &[u64] = &[1, 2, 840, 113549, 1, 1, 1];
+const OID_RSAES_OAEP: &[u64] = &[1, 2, 840, 113549, 1, 1, 7];
+const OID_DES_EDE3_CBC: &[u64] = &[1, 2, 840, 113549, 3, 7];
+const OID_AES128_CBC: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 1, 2];
+const OID_AES192_CBC: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 1, 22];
+const OID_AES256_CBC: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 1, 42];
+const OID_AES128_GCM: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 1, 6];
+const OID_AES256_GCM: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 1, 46];
+
+const GCM_NONCE_SIZE: usize = 12;
+const GCM_TAG_SIZE: usize = 16;
+
+/// A PKCS#7 keywrapper
+#[derive(Debug)]
+pub struct Pkcs7KeyWrapper {}
+
+/// The recipient of a KeyTransRecipientInfo.
+enum RecipientId {
+    IssuerAndSerialNumber { issuer: Vec<u8>, serial: Vec<u8> },
+    SubjectKeyIdentifier(Vec<u8>),
+}
+
+struct KeyTransRecipient {
+    rid: RecipientId,
+    key_encryption_algorithm: ObjectIdentifier,
+    encrypted_key: Vec<u8>,
+}
+
+struct EnvelopedData {
+    recipients: Vec<KeyTransRecipient>,
+    content_encryption_algorithm: ObjectIdentifier,
+    content_encryption_parameters: Option<TaggedDerValue>,
+    encrypted_content: Vec<u8>,
+}
+
+fn oid(components: &[u64]) -> ObjectIdentifier {
+    ObjectIdentifier::from_slice(components)
+}
+
+// Parse X.509 certificates in PEM or DER, skipping other data
+fn collect_x509s(x509s: &[Vec<u8>]) -> Vec<X509> {
+    x509s
+        .iter()
+        .filter_map(|x509| X509::from_pem(x509).or_else(|_| X509::from_der(x509)).ok())
+        .collect()
+}
+
+// Parse a private key in PEM or DER, optionally encrypted with the password
+fn parse_private_key(priv_key: &[u8], password: &[u8]) -> Result<PKey<Private>> {
+    if !password.is_empty() {
+        return PKey::private_key_from_pem_passphrase(priv_key, password)
+            .or_else(|_| PKey::private_key_from_pkcs8_passphrase(priv_key, password))
+            .map_err(|_| anyhow!("pkcs7: failed to parse encrypted private key"));
+    }
+
+    PKey::private_key_from_pem(priv_key)
+        .or_else(|_| PKey::private_key_from_der(priv_key))
+        .map_err(|_| anyhow!("pkcs7: failed to parse private key"))
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn read_recipient(r: BERReader) -> Result<KeyTransRecipient, ASN1Error> {
+    r.read_sequence(|r| {
+        let _version = r.next().read_u32()?;
+        let r_rid = r.next();
+        let rid = if r_rid.lookahead_tag()? == TAG_SEQUENCE {
+            r_rid.read_sequence(|r| {
+                let issuer = r.next().read_der()?;
+                let (serial, _) = r.next().read_bigint_bytes()?;
+                let serial = trim_leading_zeros(&serial).to_vec();
+                Ok(RecipientId::IssuerAndSerialNumber { issuer, serial })
+            })?
+        } else {
+            RecipientId::SubjectKeyIdentifier(
+                r_rid.read_tagged_implicit(Tag::context(0), |r| r.read_bytes())?,
+            )
+        };
+        let key_encryption_algorithm = r.next().read_sequence(|r| {
+            let algorithm = r.next().read_oid()?;
+            r.read_optional(|r| r.read_der())?;
+            Ok(algorithm)
+        })?;
+        let encrypted_key = r.next().read_bytes()?;
+
+        Ok(KeyTransRecipient {
+            rid,
+            key_encryption_algorithm,
+            encrypted_key,
+        })
+    })
+}
+
+fn parse_enveloped_data(packet: &[u8]) -> Result<EnvelopedData> {
+    yasna::parse_ber(packet, |r| {
+        r.read_sequence(|r| {
+            let content_type = r.next().read_oid()?;
+            if content_type != oid(OID_ENVELOPED_DATA) {
+                return Err(ASN1Error::new(yasna::ASN1ErrorKind::Invalid));
+            }
+
+            r.next().read_tagged(Tag::context(0), |r| {
+                r.read_sequence(|r| {
+                    let _version = r.next().read_u32()?;
+                    let mut recipients = Vec::new();
+                    r.next().read_set_of(|r| {
+                        recipients.push(read_recipient(r)?);
+                        Ok(())
+                    })?;
+
+                    let (algorithm, parameters, encrypted_content) =
+                        r.next().read_sequence(|r| {
+                            let _content_type = r.next().read_oid()?;
+                            let (algorithm, parameters) = r.next().read_sequence(|r| {
+                                let algorithm = r.next().read_oid()?;
+                                let parameters = r.read_optional(|r| r.read_tagged_der())?;
+                                Ok((algorithm, parameters))
+                            })?;
+                            let encrypted_content = r.read_optional(|r| {
+                                r.read_tagged_implicit(Tag::context(0), |r| r.read_bytes())
+                            })?;
+                            Ok((algorithm, parameters, encrypted_content))
+                        })?;
+
+                    // unprotectedAttrs
+                    r.read_optional(|r| r.read_der())?;
+
+                    Ok(EnvelopedData {
+                        recipients,
+                        content_encryption_algorithm: algorithm,
+                        content_encryption_parameters: parameters,
+                        encrypted_content: encrypted_content.unwrap_or_default(),
+                    })
+                })
+            })
+        })
+    })
+    .map_err(|e| anyhow!("pkcs7: invalid EnvelopedData: {e}"))
+}
+
+// Parse the GCMParameters of RFC 5084. Go ocicrypt wraps the
+// GCMParameters SEQUENCE in another SEQUENCE, which is accepted as well.
+fn gcm_parameters(parameters: &TaggedDerValue) -> Result<(Vec<u8>, usize)> {
+    let content = parameters.value();
+    let der = match content.first() {
+        Some(0x30) => content.to_vec(),
+        _ => yasna::construct_der(|w| w.write_sequence(|w| w.next().write_der(content))),
+    };
+
+    yasna::parse_der(&der, |r| {
+        r.read_sequence(|r| {
+            let nonce = r.next().read_bytes()?;
+            let icv_len = r.read_optional(|r| r.read_u32())?.unwrap_or(12);
+            Ok((nonce, icv_len as usize))
+        })
+    })
+    .map_err(|e| anyhow!("pkcs7: invalid GCM parameters: {e}"))
+}
+
+impl EnvelopedData {
+    // Find the encrypted content encryption key for the certificate
+    fn encrypted_key(&self, cert: &X509) -> Result<&KeyTransRecipient> {
+        let issuer = cert.issuer_name().to_der()?;
+        let serial = cert.serial_number().to_bn()?.to_vec();
+        let serial = trim_leading_zeros(&serial);
+        let subject_key_id = cert.subject_key_id().map(|id| id.as_slice().to_vec());
+
+        self.recipients
+            .iter()
+            .find(|recipient| match &recipient.rid {
+                RecipientId::IssuerAndSerialNumber {
+                    issuer: rid_issuer,
+                    serial: rid_serial,
+                } => *rid_issuer == issuer && rid_serial.as_slice() == serial,
+                RecipientId::SubjectKeyIdentifier(id) => subject_key_id.as_ref() == Some(id),
+            })
+            .ok_or_else(|| anyhow!("pkcs7: no recipient for certificate"))
+    }
+
+    fn decrypt(&self, cert: &X509, priv_key: &PKey<Private>) -> Result<Vec<u8>> {
+        let recipient = self.encrypted_key(cert)?;
+        let padding = match &recipient.key_encryption_algorithm {
+            alg if *alg == oid(OID_RSA_ENCRYPTION) => Padding::PKCS1,
+            alg if *alg == oid(OID_RSAES_OAEP) => Padding::PKCS1_OAEP,
+            alg => bail!("pkcs7: unsupported key encryption algorithm {alg}"),
+        };
+        let mut decrypter = Decrypter::new(priv_key)?;
+        decrypter.set_rsa_padding(padding)?;
+        let mut key = vec![0; decrypter.decrypt_len(&recipient.encrypted_key)?];
+        let len = decrypter.decrypt(&recipient.encrypted_key, &mut key)?;
+        key.truncate(len);
+
+        let algorithm = &self.content_encryption_algorithm;
+        let parameters = self
+            .content_encryption_parameters
+            .as_ref()
+            .ok_or_else(|| anyhow!("pkcs7: missing content encryption parameters"))?;
+        let cipher = match algorithm {
+            alg if *alg == oid(OID_AES128_GCM) => Some(Cipher::aes_128_gcm()),
+            alg if *alg == oid(OID_AES256_GCM) => Some(Cipher::aes_256_gcm()),
+            _ => None,
+        };
+        if let Some(cipher) = cipher {
+            let (nonce, icv_len) = gcm_parameters(parameters)?;
+            if self.encrypted_content.len() < icv_len {
+                bail!("pkcs7: encrypted content too short");
+            }
+            let (ciphertext, tag) = self
+                .encrypted_content
+                .split_at(self.encrypted_content.len() - icv_len);
+            return symm::decrypt_aead(cipher, &key, Some(&nonce), &[], ciphertext, tag)
+                .context("pkcs7: failed to decrypt content");
+        }
+
+        let cipher = match algorithm {
+            alg if *alg == oid(OID_AES128_CBC) => Cipher::aes_128_cbc(),
+            alg if *alg == oid(OID_AES192_CBC) => Cipher::aes_192_cbc(),
+            alg if *alg == oid(OID_AES256_CBC) => Cipher::aes_256_cbc(),
+            alg if *alg == oid(OID_DES_EDE3_CBC) => Cipher::des_ede3_cbc(),
+            alg => bail!("pkcs7: unsupported content encryption algorithm {alg}"),
+        };
+        if parameters.tag() != TAG_OCTETSTRING {
+            bail!("pkcs7: invalid content encryption IV");
+        }
+        symm::decrypt(
+            cipher,
+            &key,
+            Some(parameters.value()),
+            &self.encrypted_content,
+        )
+        .context("pkcs7: failed to decrypt content")
+    }
+}
+
+// Encode the KeyTransRecipientInfo of the certificate for the key
+fn recipient_info(cert: &X509, key: &[u8]) -> Result<Vec<u8>> {
+    let pub_key = cert.public_key()?;
+    let mut encrypter = Encrypter::new(&pub_key)?;
+    encrypter.set_rsa_padding(Padding::PKCS1)?;
+    let mut encrypted_key = vec![0; encrypter.encrypt_len(key)?];
+    let len = encrypter.encrypt(key, &mut encrypted_key)?;
+    encrypted_key.truncate(len);
+
+    let issuer = cert.issuer_name().to_der()?;
+    let serial = cert.serial_number().to_bn()?.to_vec();
+    Ok(yasna::construct_der(|w| {
+        w.write_sequence(|w| {
+            w.next().write_u32(0);
+            w.next().write_sequence(|w| {
+                w.next().write_der(&issuer);
+                w.next().write_bigint_bytes(&serial, true);
+            });
+            w.next().write_sequence(|w| {
+                w.next().write_oid(&oid(OID_RSA_ENCRYPTION));
+            });
+            w.next().write_bytes(&encrypted_key);
+        });
+    }))
+}
+
+// Create an EnvelopedData of the data for the certificates as Go ocicrypt does
+fn encrypt(data: &[u8], certs: &[X509]) -> Result<Vec<u8>> {
+    let mut key = [0; 16];
+    let mut nonce = [0; GCM_NONCE_SIZE];
+    openssl::rand::rand_bytes(&mut key)?;
+    openssl::rand::rand_bytes(&mut nonce)?;
+
+    let mut tag = [0; GCM_TAG_SIZE];
+    let mut encrypted_content = symm::encrypt_aead(
+        Cipher::aes_128_gcm(),
+        &key,
+        Some(&nonce),
+        &[],
+        data,
+        &mut tag,
+    )?;
+    encrypted_content.extend_from_slice(&tag);
+
+    let recipients = certs
+        .iter()
+        .map(|cert| recipient_info(cert, &key))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(yasna::construct_der(|w| {
+        w.write_sequence(|w| {
+            w.next().write_oid(&oid(OID_ENVELOPED_DATA));
+            w.next().write_tagged(Tag::context(0), |w| {
+                w.write_sequence(|w| {
+                    w.next().write_u32(0);
+                    w.next().write_set_of(|w| {
+                        for recipient in &recipients {
+                            w.next().write_der(recipient);
+                        }
+                    });
+                    w.next().write_sequence(|w| {
+                        w.next().write_oid(&oid(OID_DATA));
+                        w.next().write_sequence(|w| {
+                            w.next().write_oid(&oid(OID_AES128_GCM));
+                            w.next().write_sequence(|w| {
+                                w.next().write_sequence(|w| {
+                                    w.next().write_bytes(&nonce);
+                                    w.next().write_u32(GCM_TAG_SIZE as u32);
+                                });
+                            });
+                        });
+                        w.next().write_tagged_implicit(Tag::context(0), |w| {
+                            w.write_bytes(&encrypted_content)
+                        });
+                    });
+                });
+            });
+        });
+    }))
+}
+
+#[cfg_attr(feature = "async-io", async_trait::async_trait)]
+impl KeyWrapper for Pkcs7KeyWrapper {
+    /// Wrap the keys for the x509 certificates in the encrypt config. If no
+    /// certificates are given, the keys are not wrapped.
+    fn wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        let x509s = match ec.param.get("x509s") {
+            Some(x509s) => collect_x509s(x509s),
+            None => return Ok(vec![]),
+        };
+        if x509s.is_empty() {
+            return Ok(vec![]);
+        }
+
+        encrypt(opts_data, &x509s)
+    }
+
+    fn unwrap_keys(&self, dc: &DecryptConfig, pkcs7_packet: &[u8]) -> Result<Vec<u8>> {
+        let privkeys = self
+            .private_keys(&dc.param)
+            .ok_or_else(|| anyhow!("pkcs7: invalid configuration for keyunwrap"))?;
+        let passwords = dc.param.get("privkeys-passwords");
+        let x509s = collect_x509s(dc.param.get("x509s").map(Vec::as_slice).unwrap_or(&[]));
+        if x509s.is_empty() {
+            bail!("pkcs7: no x509 certificates found for keyunwrap");
+        }
+
+        let enveloped_data = parse_enveloped_data(pkcs7_packet)?;
+        for (idx, privkey) in privkeys.iter().enumerate() {
+            let password = passwords
+                .and_then(|passwords| passwords.get(idx))
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            // Private keys of other keywrappers, e.g. JWK, are skipped
+            let Ok(privkey) = parse_private_key(privkey, password) else {
+                continue;
+            };
+
+            for x509 in &x509s {
+                let Ok(pubkey) = x509.public_key() else {
+                    continue;
+                };
+                if !pubkey.public_eq(&privkey) {
+                    continue;
+                }
+
+                return enveloped_data.decrypt(x509, &privkey);
+            }
+        }
+
+        Err(anyhow!(
+            "pkcs7: No suitable private key found for decryption"
+        ))
+    }
+
+    fn annotation_id(&self) -> String {
+        "org.opencontainers.image.enc.keys.pkcs7".to_string()
+    }
+
+    fn probe(&self, dc_param: &HashMap<String, Vec<Vec<u8>>>) -> bool {
+        dc_param.contains_key("privkeys") && dc_param.contains_key("x509s")
+    }
+
+    fn private_keys(&self, dc_param: &HashMap<String, Vec<Vec<u8>>>) -> Option<Vec<Vec<u8>>> {
+        dc_param.get("privkeys").cloned()
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_wrap_keys(&self, ec: &EncryptConfig, opts_data: &[u8]) -> Result<Vec<u8>> {
+        let (ec, opts_data) = (ec.clone(), opts_data.to_vec());
+        spawn_blocking(move || Pkcs7KeyWrapper {}.wrap_keys(&ec, &opts_data)).await
+    }
+
+    #[cfg(feature = "async-io")]
+    async fn async_unwrap_keys(&self, dc: &DecryptConfig, annotation: &[u8]) -> Result<Vec<u8>> {
+        let (dc, annotation) = (dc.clone(), annotation.to_vec());
+        spawn_blocking(move || Pkcs7KeyWrapper {}.unwrap_keys(&dc, &annotation)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use std::fs;
+    use std::path::PathBuf;
+    use yasna::PCBit;
+
+    #[test]
+    fn test_keywrap_pkcs7() {
+        let path = load_data_path();
+        let cert = fs::read(path.join("public_certificate.pem")).unwrap();
+
+        let mut ec = EncryptConfig::default();
+        let mut dc = DecryptConfig::default();
+        let payload = b"test".to_vec();
+
+        let pkcs7_key_wrapper = Pkcs7KeyWrapper {};
+
+        assert!(!pkcs7_key_wrapper.probe(&dc.param));
+        assert!(pkcs7_key_wrapper.private_keys(&dc.param).is_none());
+
+        // No certificates, no wrapped keys
+        assert!(pkcs7_key_wrapper
+            .wrap_keys(&ec, &payload)
+            .unwrap()
+            .is_empty());
+
+        assert!(ec.encrypt_with_pkcs7(vec![cert.clone()]).is_ok());
+        let packet = pkcs7_key_wrapper.wrap_keys(&ec, &payload).unwrap();
+
+        // The JWK private key is skipped
+        let privkeys = vec![
+            fs::read(path.join("RSA_private.jwk")).unwrap(),
+            fs::read(path.join("private_key.pem")).unwrap(),
+        ];
+        assert!(dc
+            .decrypt_with_priv_keys(privkeys, vec![vec![], vec![]])
+            .is_ok());
+        assert!(!pkcs7_key_wrapper.probe(&dc.param));
+        assert!(pkcs7_key_wrapper.unwrap_keys(&dc, &packet).is_err());
+
+        assert!(dc.decrypt_with_x509s(vec![cert]).is_ok());
+        assert!(pkcs7_key_wrapper.probe(&dc.param));
+        assert_eq!(
+            pkcs7_key_wrapper.unwrap_keys(&dc, &packet).unwrap(),
+            payload
+        );
+
+        assert_eq!(
+            pkcs7_key_wrapper.annotation_id(),
+            "org.opencontainers.image.enc.keys.pkcs7".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unwrap_openssl_pkcs7() {
+        let path = load_data_path();
+        let opts = fs::read(path.join("keywrap_opts.json")).unwrap();
+
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_priv_keys(
+                vec![fs::read(path.join("private_key8.der")).unwrap()],
+                vec![vec![]]
+            )
+            .is_ok());
+        assert!(dc
+            .decrypt_with_x509s(vec![fs::read(path.join("public_certificate.pem")).unwrap()])
+            .is_ok());
+
+        let pkcs7_key_wrapper = Pkcs7KeyWrapper {};
+        // Created by `openssl cms` (AES-256-CBC) and `openssl smime` (DES-EDE3-CBC,
+        // indefinite length BER)
+        for packet in ["pkcs7_wrapped_keys.der", "pkcs7_wrapped_keys_smime.der"] {
+            let packet = fs::read(path.join(packet)).unwrap();
+            assert_eq!(pkcs7_key_wrapper.unwrap_keys(&dc, &packet).unwrap(), opts);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_go_pkcs7() {
+        let path = load_data_path();
+        let annotations: HashMap<String, String> =
+            serde_json::from_slice(&fs::read(path.join("go_pkcs7_annotations.json")).unwrap())
+                .unwrap();
+        let packet = STANDARD
+            .decode(&annotations["org.opencontainers.image.enc.keys.pkcs7"])
+            .unwrap();
+
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_priv_keys(
+                vec![fs::read(path.join("private_key.pem")).unwrap()],
+                vec![vec![]]
+            )
+            .is_ok());
+        assert!(dc
+            .decrypt_with_x509s(vec![fs::read(path.join("public_certificate.pem")).unwrap()])
+            .is_ok());
+
+        // Go ocicrypt wraps the private options of the layer
+        let opts_data = Pkcs7KeyWrapper {}.unwrap_keys(&dc, &packet).unwrap();
+        let opts: serde_json::Value = serde_json::from_slice(&opts_data).unwrap();
+        assert_eq!(
+            STANDARD
+                .decode(opts["symkey"].as_str().unwrap())
+                .unwrap()
+                .len(),
+            32
+        );
+        assert!(opts["cipheroptions"]["nonce"].is_string());
+    }
+
+    #[test]
+    fn test_unwrap_pkcs7_wrong_key() {
+        let path = load_data_path();
+        let packet = fs::read(path.join("pkcs7_wrapped_keys.der")).unwrap();
+
+        let mut dc = DecryptConfig::default();
+        assert!(dc
+            .decrypt_with_priv_keys(
+                vec![fs::read(path.join("certificate_key.pem")).unwrap()],
+                vec![vec![]]
+            )
+            .is_ok());
+        assert!(dc
+            .decrypt_with_x509s(vec![fs::read(path.join("certificate.pem")).unwrap()])
+            .is_ok());
+
+        assert!(Pkcs7KeyWrapper {}.unwrap_keys(&dc, &packet).is_err());
+        assert!(Pkcs7KeyWrapper {}.unwrap_keys(&dc, b"invalid").is_err());
+    }
+
+    #[test]
+    fn test_gcm_parameters() {
+        let nonce = [7; GCM_NONCE_SIZE];
+        let params = yasna::construct_der(|w| {
+            w.write_sequence(|w| {
+                w.next().write_bytes(&nonce);
+                w.next().write_u32(GCM_TAG_SIZE as u32);
+            })
+        });
+
+        // RFC 5084
+        let value = TaggedDerValue::from_tag_pc_and_bytes(
+            TAG_SEQUENCE,
+            PCBit::Constructed,
+            params[2..].to_vec(),
+        );
+        assert_eq!(
+            gcm_parameters(&value).unwrap(),
+            (nonce.to_vec(), GCM_TAG_SIZE)
+        );
+
+        // Go ocicrypt
+        let value = TaggedDerValue::from_tag_pc_and_bytes(TAG_SEQUENCE, PCBit::Primitive, params);
+        assert_eq!(
+            gcm_parameters(&value).unwrap(),
+            (nonce.to_vec(), GCM_TAG_SIZE)
+        );
+    }
+
+    fn load_data_path() -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("data");
+        path
+    }
+}
@@ -34,6 +34,22 @@ lazy_static! {
             );
         }
 
+        #[cfg(feature = "keywrap-pkcs7")]
+        {
+            m.insert(
+                "pkcs7".to_string(),
+                Box::new(crate::keywrap::pkcs7::Pkcs7KeyWrapper {}) as Box<dyn KeyWrapper>,
+            );
+        }
+
+        #[cfg(feature = "keywrap-pgp")]
+        {
+            m.insert(
+                "pgp".to_string(),
+                Box::new(crate::keywrap::pgp::PgpKeyWrapper {}) as Box<dyn KeyWrapper>,
+            );
+        }
+
         #[cfg(feature = "keywrap-keyprovider")]
         {
             let ocicrypt_config =
@@ -64,7 +80,7 @@ lazy_static! {
     };
 }
 
-/// get_key_wrapper looks up the encryptor interface given an encryption scheme (gpg, jwe)
+/// get_key_wrapper looks up the encryptor interface given an encryption scheme (pgp, jwe, pkcs7)
 #[allow(clippy::borrowed_box)]
 pub fn get_key_wrapper(scheme: &str) -> Result<&Box<dyn KeyWrapper>> {
     KEY_WRAPPERS
@@ -14,3 +14,10 @@ pub const MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ENC: &str =
 /// MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_GZIP_ENC is MIME type used for non distributable encrypted compressed layers.
 pub const MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_GZIP_ENC: &str =
     "application/vnd.oci.image.layer.nondistributable.v1.tar+gzip+encrypted";
+
+/// MEDIA_TYPE_LAYER_ZSTD_ENC is MIME type used for encrypted zstd compressed layers.
+pub const MEDIA_TYPE_LAYER_ZSTD_ENC: &str = "application/vnd.oci.image.layer.v1.tar+zstd+encrypted";
+
+/// MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ZSTD_ENC is MIME type used for non distributable encrypted zstd compressed layers.
+pub const MEDIA_TYPE_LAYER_NON_DISTRIBUTABLE_ZSTD_ENC: &str =
+    "application/vnd.oci.image.layer.nondistributable.v1.tar+zstd+encrypted";
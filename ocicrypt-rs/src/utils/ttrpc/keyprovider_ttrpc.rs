@@ -17,29 +17,28 @@
 use protobuf::{CodedInputStream, CodedOutputStream, Message};
 use std::collections::HashMap;
 use std::sync::Arc;
+use async_trait::async_trait;
 
 #[derive(Clone)]
 pub struct KeyProviderServiceClient {
-    client: ::ttrpc::Client,
+    client: ::ttrpc::r#async::Client,
 }
 
 impl KeyProviderServiceClient {
-    pub fn new(client: ::ttrpc::Client) -> Self {
+    pub fn new(client: ::ttrpc::r#async::Client) -> Self {
         KeyProviderServiceClient {
             client,
         }
     }
 
-    pub fn wrap_key(&self, ctx: ttrpc::context::Context, req: &super::keyprovider::KeyProviderKeyWrapProtocolInput) -> ::ttrpc::Result<super::keyprovider::KeyProviderKeyWrapProtocolOutput> {
+    pub async fn wrap_key(&self, ctx: ttrpc::context::Context, req: &super::keyprovider::KeyProviderKeyWrapProtocolInput) -> ::ttrpc::Result<super::keyprovider::KeyProviderKeyWrapProtocolOutput> {
         let mut cres = super::keyprovider::KeyProviderKeyWrapProtocolOutput::new();
-        ::ttrpc::client_request!(self, ctx, req, "keyprovider.KeyProviderService", "WrapKey", cres);
-        Ok(cres)
+        ::ttrpc::async_client_request!(self, ctx, req, "keyprovider.KeyProviderService", "WrapKey", cres);
     }
 
-    pub fn un_wrap_key(&self, ctx: ttrpc::context::Context, req: &super::keyprovider::KeyProviderKeyWrapProtocolInput) -> ::ttrpc::Result<super::keyprovider::KeyProviderKeyWrapProtocolOutput> {
+    pub async fn un_wrap_key(&self, ctx: ttrpc::context::Context, req: &super::keyprovider::KeyProviderKeyWrapProtocolInput) -> ::ttrpc::Result<super::keyprovider::KeyProviderKeyWrapProtocolOutput> {
         let mut cres = super::keyprovider::KeyProviderKeyWrapProtocolOutput::new();
-        ::ttrpc::client_request!(self, ctx, req, "keyprovider.KeyProviderService", "UnWrapKey", cres);
-        Ok(cres)
+        ::ttrpc::async_client_request!(self, ctx, req, "keyprovider.KeyProviderService", "UnWrapKey", cres);
     }
 }
 